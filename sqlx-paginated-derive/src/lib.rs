@@ -0,0 +1,287 @@
+//! Derive macro of `sqlx_paginated::PaginatedColumns`, enabled in `sqlx-paginated`
+//! with the `derive` feature.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Lit, Meta, Token,
+};
+
+/// Generates a `{Struct}Columns` enum with a variant per serialized field of the
+/// struct, and implements `sqlx_paginated::PaginatedColumns` with it.
+///
+/// Column names are the names the struct serializes its fields with, honouring
+/// `#[serde(rename = "...")]`, `#[serde(rename_all = "...")]`, `#[serde(skip)]` and
+/// `#[serde(skip_serializing)]`, so they match the columns the query parameters
+/// are validated against. `#[serde(flatten)]` fields are rejected, and so are
+/// `#[serde(skip_serializing_if = "...")]` fields, which the query parameters
+/// only accept when the default value of the struct serializes them.
+///
+/// ```ignore
+/// #[derive(Serialize, Default, PaginatedColumns)]
+/// struct User {
+///     #[serde(rename = "full_name")]
+///     name: String,
+///     created_at: String,
+/// }
+///
+/// assert_eq!(UserColumns::Name.as_str(), "full_name");
+/// assert_eq!(UserColumns::CreatedAt.as_str(), "created_at");
+/// ```
+#[proc_macro_derive(PaginatedColumns, attributes(serde))]
+pub fn derive_paginated_columns(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PaginatedColumns can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PaginatedColumns can only be derived for structs with named fields",
+        ));
+    };
+
+    let rename_all = container_rename_all(&input.attrs)?;
+    let mut variants = Vec::new();
+    let mut columns = Vec::new();
+
+    for field in &fields.named {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let attributes = field_attributes(&field.attrs)?;
+        if attributes.skip {
+            continue;
+        }
+
+        let name = ident.unraw().to_string();
+        let column = match (attributes.rename, &rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => rule.apply(&name),
+            (None, None) => name.clone(),
+        };
+
+        variants.push(format_ident!("{}", RenameRule::Pascal.apply(&name)));
+        columns.push(column);
+    }
+
+    let visibility = &input.vis;
+    let model = &input.ident;
+    let enum_name = format_ident!("{}Columns", model);
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let enum_doc = format!("Columns of `{}`, derived with `PaginatedColumns`.", model);
+    let variant_docs = columns.iter().map(|column| format!("`{}`", column));
+
+    Ok(quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #visibility enum #enum_name {
+            #(
+                #[doc = #variant_docs]
+                #variants,
+            )*
+        }
+
+        impl #enum_name {
+            /// Every column, in field order.
+            pub const ALL: &'static [#enum_name] = &[#(#enum_name::#variants),*];
+
+            /// Returns the name of the column.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(#enum_name::#variants => #columns,)*
+                }
+            }
+        }
+
+        impl ::std::convert::AsRef<str> for #enum_name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::convert::From<#enum_name> for ::std::string::String {
+            fn from(column: #enum_name) -> Self {
+                column.as_str().to_string()
+            }
+        }
+
+        impl #impl_generics ::sqlx_paginated::PaginatedColumns for #model #type_generics #where_clause {
+            type Column = #enum_name;
+
+            fn columns() -> &'static [#enum_name] {
+                #enum_name::ALL
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct FieldAttributes {
+    rename: Option<String>,
+    skip: bool,
+}
+
+/// Reads the `rename_all` rule of the container, the serialize one when split.
+fn container_rename_all(attrs: &[Attribute]) -> syn::Result<Option<RenameRule>> {
+    let mut rule = None;
+
+    for meta in serde_metas(attrs)? {
+        if meta.path().is_ident("rename_all") {
+            if let Some(value) = serialize_name(&meta)? {
+                rule = Some(RenameRule::parse(&value, &meta)?);
+            }
+        }
+    }
+
+    Ok(rule)
+}
+
+/// Reads the serde attributes changing whether a field is serialized and its name.
+fn field_attributes(attrs: &[Attribute]) -> syn::Result<FieldAttributes> {
+    let mut attributes = FieldAttributes::default();
+
+    for meta in serde_metas(attrs)? {
+        let path = meta.path();
+        if path.is_ident("rename") {
+            if let Some(value) = serialize_name(&meta)? {
+                attributes.rename = Some(value);
+            }
+        } else if path.is_ident("skip") || path.is_ident("skip_serializing") {
+            attributes.skip = true;
+        } else if path.is_ident("flatten") {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "PaginatedColumns does not support flattened fields",
+            ));
+        } else if path.is_ident("skip_serializing_if") {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "PaginatedColumns does not support conditionally skipped fields",
+            ));
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Flattens the items of every `#[serde(...)]` attribute.
+fn serde_metas(attrs: &[Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        metas.extend(attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(metas)
+}
+
+/// Returns the serialize value of `name = "..."` or `name(serialize = "...")`.
+fn serialize_name(meta: &Meta) -> syn::Result<Option<String>> {
+    match meta {
+        Meta::NameValue(name_value) => string_literal(&name_value.value).map(Some),
+        Meta::List(list) => {
+            let items = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            for item in items {
+                if let Meta::NameValue(name_value) = item {
+                    if name_value.path.is_ident("serialize") {
+                        return string_literal(&name_value.value).map(Some);
+                    }
+                }
+            }
+            Ok(None)
+        }
+        Meta::Path(_) => Ok(None),
+    }
+}
+
+fn string_literal(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => Ok(value.value()),
+        _ => Err(syn::Error::new_spanned(expr, "expected a string literal")),
+    }
+}
+
+/// The `rename_all` rules of serde, applied to snake_case field names.
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(value: &str, meta: &Meta) -> syn::Result<Self> {
+        match value {
+            "lowercase" => Ok(RenameRule::Lower),
+            "UPPERCASE" => Ok(RenameRule::Upper),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "camelCase" => Ok(RenameRule::Camel),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            "kebab-case" => Ok(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(RenameRule::ScreamingKebab),
+            _ => Err(syn::Error::new_spanned(
+                meta,
+                format!("unknown rename rule `{}`", value),
+            )),
+        }
+    }
+
+    fn apply(&self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
@@ -1,15 +1,63 @@
 mod paginated_query_as;
 
+#[cfg(feature = "axum")]
+pub use crate::paginated_query_as::axum;
+
+#[cfg(feature = "testing")]
+pub use crate::paginated_query_as::testing;
+
+#[cfg(feature = "derive")]
+pub use sqlx_paginated_derive::PaginatedColumns;
+
 pub use crate::paginated_query_as::{
-    paginated_query_as, DatabaseQueryDefaults, FlatQueryParams, PaginatedQueryBuilder,
-    PaginatedResponse, QueryBuilder, QueryFilterCondition, QueryFilterOperator, QueryParams,
-    QueryParamsBuilder, QuerySortDirection,
+    paginate_in_memory, paginated_query_as, ComputedProperty, ComputedPropertyBuilder, CountEvent,
+    DatabaseQueryDefaults, ExecutedSql, FieldType, FilterLogic, FlatFilterGroup, FlatQueryParams,
+    FlatQueryParamsBuilder, KeyedPaginatedResponse, KeysetCursor, KeysetDirection, MatchInfo,
+    PageMeta, PageToken, PaginatedColumns, PaginatedQueryBuilder, PaginatedResponse,
+    PaginationContext, PaginationError, ParamError, QueryBuilder, QueryDialect,
+    QueryFilterCondition, QueryFilterGroup, QueryFilterOperator, QueryNullsPosition, QueryParams,
+    QueryParamsBuilder, QuerySortDirection, RangeBoundCheck, SearchAnchor, SearchMode,
+    SearchOptions, UnionPaginatedQuery, ValidationConfig, WhereTree,
 };
 
+/// Everything needed to build parameters and paginated queries in one import.
+///
+/// Filters are built from `QueryFilterCondition` and its constructors, or from a
+/// `QueryFilterOperator` and a value.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use sqlx_paginated::prelude::*;
+///
+/// #[derive(Serialize, Default)]
+/// struct Product {
+///     name: String,
+///     price: f64,
+///     status: String,
+/// }
+///
+/// let params = QueryParamsBuilder::<Product>::new()
+///     .with_filter_condition("price", QueryFilterCondition::greater_than("10"))
+///     .with_filter_condition(
+///         "status",
+///         QueryFilterCondition::new(QueryFilterOperator::NotEqual, Some("deleted")),
+///     )
+///     .build();
+///
+/// assert_eq!(params.filters["price"][0].operator, QueryFilterOperator::GreaterThan);
+/// assert_eq!(params.filters["status"][0].value.as_deref(), Some("deleted"));
+/// ```
 pub mod prelude {
     pub use super::{
-        paginated_query_as, DatabaseQueryDefaults, FlatQueryParams, PaginatedQueryBuilder,
-        PaginatedResponse, QueryBuilder, QueryFilterCondition, QueryFilterOperator, QueryParams,
-        QueryParamsBuilder, QuerySortDirection,
+        paginate_in_memory, paginated_query_as, ComputedProperty, ComputedPropertyBuilder,
+        CountEvent, DatabaseQueryDefaults, ExecutedSql, FieldType, FilterLogic, FlatFilterGroup,
+        FlatQueryParams, FlatQueryParamsBuilder, KeyedPaginatedResponse, KeysetCursor,
+        KeysetDirection, MatchInfo, PageMeta, PageToken, PaginatedColumns, PaginatedQueryBuilder,
+        PaginatedResponse, PaginationContext, PaginationError, ParamError, QueryBuilder,
+        QueryDialect, QueryFilterCondition, QueryFilterGroup, QueryFilterOperator,
+        QueryNullsPosition, QueryParams, QueryParamsBuilder, QuerySortDirection, RangeBoundCheck,
+        SearchAnchor, SearchMode, SearchOptions, UnionPaginatedQuery, ValidationConfig, WhereTree,
     };
 }
@@ -0,0 +1,214 @@
+//! An Axum extractor of typed query parameters, enabled with the `axum` feature.
+
+use crate::{FlatQueryParams, ParamError, QueryParams, ValidationConfig};
+use ::axum::extract::{FromRequestParts, Query};
+use ::axum::http::request::Parts;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use ::axum::Json;
+use serde::Serialize;
+use std::fmt;
+
+/// Extracts the `QueryParams` of a model from the query string, validated
+/// against the model.
+///
+/// The query string is parsed into `FlatQueryParams` and converted with
+/// `QueryParams::try_from`, so that unknown operators and misspelled parameters
+/// are rejected with a 400 response before the query is built. See
+/// `PaginatedQueryRejection` for the response body.
+///
+/// Parameters that aren't model columns, such as `utm_source` or a `_`
+/// cache-buster, are dropped rather than rejected. A `ValidationConfig` request
+/// extension replaces this, e.g. `.layer(Extension(ValidationConfig::default()))`
+/// to reject them as well.
+///
+/// # Examples
+///
+/// ```rust
+/// use axum::{Extension, Json};
+/// use serde::Serialize;
+/// use sqlx::{FromRow, PgPool, Postgres};
+/// use sqlx_paginated::axum::PaginatedQuery;
+/// use sqlx_paginated::{paginated_query_as, PaginatedResponse};
+///
+/// #[derive(Serialize, FromRow, Default)]
+/// struct User {
+///     name: String,
+///     created_at: String,
+/// }
+///
+/// async fn list_users(
+///     PaginatedQuery(params): PaginatedQuery<User>,
+///     Extension(pool): Extension<PgPool>,
+/// ) -> Json<PaginatedResponse<User>> {
+///     let users = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+///         .with_params(params)
+///         .fetch_paginated(&pool)
+///         .await
+///         .unwrap();
+///
+///     Json(users)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct PaginatedQuery<T: 'static>(pub QueryParams<'static, T>);
+
+impl<T, S> FromRequestParts<S> for PaginatedQuery<T>
+where
+    T: Default + Serialize + Send + 'static,
+    S: Send + Sync,
+{
+    type Rejection = PaginatedQueryRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) =
+            Query::<FlatQueryParams>::try_from_uri(&parts.uri).map_err(|rejection| {
+                PaginatedQueryRejection::InvalidQueryString(rejection.body_text())
+            })?;
+
+        let config = parts
+            .extensions
+            .get::<ValidationConfig>()
+            .copied()
+            .unwrap_or(ValidationConfig {
+                ignore_unknown_filters: true,
+            });
+
+        QueryParams::try_from((&params, config))
+            .map(PaginatedQuery)
+            .map_err(PaginatedQueryRejection::InvalidParams)
+    }
+}
+
+/// Rejection of `PaginatedQuery`, responding with `400 Bad Request` and a JSON
+/// body holding an `error` message and the `details` of each problem, e.g.
+/// `{"error": "invalid query parameters", "details": ["unknown column `secret`"]}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaginatedQueryRejection {
+    /// The query string can't be parsed into `FlatQueryParams`
+    InvalidQueryString(String),
+
+    /// The parameters don't match the model, as reported by `FlatQueryParams::validate`
+    InvalidParams(Vec<ParamError>),
+}
+
+impl fmt::Display for PaginatedQueryRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginatedQueryRejection::InvalidQueryString(message) => {
+                write!(f, "invalid query string: {}", message)
+            }
+            PaginatedQueryRejection::InvalidParams(_) => write!(f, "invalid query parameters"),
+        }
+    }
+}
+
+impl std::error::Error for PaginatedQueryRejection {}
+
+impl IntoResponse for PaginatedQueryRejection {
+    fn into_response(self) -> Response {
+        let details: Vec<String> = match &self {
+            PaginatedQueryRejection::InvalidQueryString(_) => Vec::new(),
+            PaginatedQueryRejection::InvalidParams(errors) => {
+                errors.iter().map(ToString::to_string).collect()
+            }
+        };
+
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": self.to_string(),
+                "details": details,
+            })),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use ::axum::http::Request;
+
+    #[derive(Serialize, Default)]
+    struct Product {
+        name: String,
+        price: f64,
+        created_at: String,
+    }
+
+    async fn extract(uri: &str) -> Result<PaginatedQuery<Product>, PaginatedQueryRejection> {
+        extract_with(uri, None).await
+    }
+
+    async fn extract_with(
+        uri: &str,
+        config: Option<ValidationConfig>,
+    ) -> Result<PaginatedQuery<Product>, PaginatedQueryRejection> {
+        let (mut parts, _) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+        if let Some(config) = config {
+            parts.extensions.insert(config);
+        }
+        PaginatedQuery::<Product>::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn test_extracts_validated_params() {
+        let PaginatedQuery(params) = extract("/products?page=2&page_size=20&price[gt]=10")
+            .await
+            .unwrap();
+
+        assert_eq!(params.pagination.page, 2);
+        assert_eq!(params.pagination.page_size, 20);
+        assert!(params.filters.contains_key("price"));
+    }
+
+    #[tokio::test]
+    async fn test_drops_params_that_are_not_columns() {
+        let PaginatedQuery(params) = extract("/products?utm_source=mail&_=1712&price[gt]=10")
+            .await
+            .unwrap();
+
+        assert_eq!(params.filters.len(), 1);
+        assert!(params.filters.contains_key("price"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_misspelled_params_by_default() {
+        let rejection = extract("/products?page_siez=20").await.err().unwrap();
+
+        assert_eq!(
+            rejection,
+            PaginatedQueryRejection::InvalidParams(vec![ParamError::MisspelledParam {
+                key: "page_siez".to_string(),
+                expected: "page_size".to_string(),
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_params_not_matching_the_model() {
+        let rejection = extract_with(
+            "/products?secret=1&page_siez=20",
+            Some(ValidationConfig::default()),
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert_eq!(
+            rejection,
+            PaginatedQueryRejection::InvalidParams(vec![
+                ParamError::MisspelledParam {
+                    key: "page_siez".to_string(),
+                    expected: "page_size".to_string(),
+                },
+                ParamError::UnknownColumn {
+                    column: "secret".to_string(),
+                },
+            ])
+        );
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
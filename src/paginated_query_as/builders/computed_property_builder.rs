@@ -0,0 +1,220 @@
+use crate::paginated_query_as::internal::rewrite_placeholders;
+use crate::ComputedProperty;
+
+/// A builder for constructing `ComputedProperty` definitions.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::ComputedPropertyBuilder;
+///
+/// let paid_orders = ComputedPropertyBuilder::new(
+///     "paid_orders",
+///     "COUNT(orders.id) FILTER (WHERE orders.status = 'paid')",
+/// )
+/// .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+/// .as_aggregate()
+/// .selected()
+/// .build();
+///
+/// assert!(paid_orders.aggregate);
+/// ```
+pub struct ComputedPropertyBuilder {
+    property: ComputedProperty,
+}
+
+impl ComputedPropertyBuilder {
+    /// Creates a new `ComputedPropertyBuilder` from a name and a SQL expression.
+    ///
+    /// The expression is used verbatim, so it may contain any construct supported
+    /// by the target database, e.g. `GREATEST(...)` or `COUNT(*) FILTER (WHERE ...)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name used to reference the property from sort parameters
+    /// * `expression` - SQL expression producing the value
+    pub fn new(name: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self {
+            property: ComputedProperty::new(name, expression),
+        }
+    }
+
+    /// Adds a JOIN clause that the expression depends on.
+    ///
+    /// Joined tables are only added to the records query while the property is
+    /// selected or used as the sort key, and to the records and count queries while
+    /// a searchable property is searched.
+    ///
+    /// # Arguments
+    ///
+    /// * `join` - Complete JOIN clause, e.g. `LEFT JOIN stats ON stats.user_id = base_query.id`
+    pub fn with_join(mut self, join: impl Into<String>) -> Self {
+        self.property.joins.push(join.into());
+        self
+    }
+
+    /// Adds a JOIN clause that the expression depends on, binding values in the
+    /// clause, e.g. the tenant of the joined rows.
+    ///
+    /// Each `?` or `$?` token of the clause, outside string literals, is replaced
+    /// by a placeholder numbered after the other arguments of the query, bound to
+    /// the value at the same position. Values are bound as text without a type
+    /// cast, so cast the token in the clause when the column isn't text, e.g.
+    /// `orders.tenant_id = ?::integer` on PostgreSQL. Clauses with a different
+    /// number of tokens and values are ignored.
+    ///
+    /// The values are bound while the join is added to a query, as with `with_join`.
+    /// Since the clause comes before the conditions in the statement, queries
+    /// adding it fail with `PaginationError::UnorderedArguments` on databases
+    /// with positional placeholders, such as MySQL. Properties binding the same
+    /// clause to different values each add their own join.
+    ///
+    /// # Arguments
+    ///
+    /// * `join` - JOIN clause with `?` tokens, e.g. `LEFT JOIN orders ON orders.user_id = base_query.id AND orders.tenant_id = ?`
+    /// * `values` - Values of the tokens, in order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::ComputedPropertyBuilder;
+    ///
+    /// let tenant_orders = ComputedPropertyBuilder::new("order_count", "COUNT(orders.id)")
+    ///     .with_join_bound(
+    ///         "LEFT JOIN orders ON orders.user_id = base_query.id AND orders.tenant_id = ?",
+    ///         vec!["42"],
+    ///     )
+    ///     .as_aggregate()
+    ///     .build();
+    ///
+    /// assert_eq!(tenant_orders.joins.len(), 1);
+    /// ```
+    pub fn with_join_bound(mut self, join: impl Into<String>, values: Vec<&str>) -> Self {
+        let join = join.into().replace("$?", "?");
+
+        let mut tokens = 0;
+        rewrite_placeholders(&join, '?', |_| {
+            tokens += 1;
+            String::new()
+        });
+
+        if tokens != values.len() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(join = %join, "Skipping bound join with mismatched values");
+            return self;
+        }
+
+        self.property.join_values.insert(
+            join.clone(),
+            values.into_iter().map(str::to_string).collect(),
+        );
+        self.property.joins.push(join);
+        self
+    }
+
+    /// Sets a column used to order rows that share the same computed value.
+    ///
+    /// Expressions such as `GREATEST(...)` frequently produce ties; a unique tie
+    /// breaker keeps the page boundaries stable. The tie breaker is sorted in the
+    /// same direction as the computed property.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column name of the `base_query` row, e.g. `id`
+    pub fn with_tie_breaker(mut self, column: impl Into<String>) -> Self {
+        self.property.tie_breaker = Some(column.into());
+        self
+    }
+
+    /// Adds the expression to the records query selection, aliased by the property name.
+    ///
+    /// The model should declare a field with the same name to receive the value.
+    pub fn selected(mut self) -> Self {
+        self.property.selected = true;
+        self
+    }
+
+    /// Marks the expression as an aggregate.
+    ///
+    /// While the property is active the records query is grouped by the model
+    /// columns of `base_query`, so aggregates over joined rows (including
+    /// Postgres `FILTER (WHERE ...)` clauses) yield one value per record.
+    pub fn as_aggregate(mut self) -> Self {
+        self.property.aggregate = true;
+        self
+    }
+
+    /// Lets clients search the expression by naming the property in `search_columns`.
+    ///
+    /// While the property is searched, its joins are added to the records and count
+    /// queries, so that the search conditions can refer to the joined tables. The
+    /// search conditions are built by `QueryBuilder::with_search_property`. A base
+    /// row matching several joined rows is returned once per match, unless
+    /// `PaginatedQueryBuilder::with_distinct` is enabled.
+    pub fn searchable(mut self) -> Self {
+        self.property.searchable = true;
+        self
+    }
+
+    /// Builds and returns the final `ComputedProperty`.
+    pub fn build(self) -> ComputedProperty {
+        self.property
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults() {
+        let property = ComputedPropertyBuilder::new("rank", "LEAST(a, b)").build();
+
+        assert_eq!(property, ComputedProperty::new("rank", "LEAST(a, b)"));
+    }
+
+    #[test]
+    fn test_builder_with_all_options() {
+        let property = ComputedPropertyBuilder::new(
+            "paid_orders",
+            "COUNT(orders.id) FILTER (WHERE orders.status = 'paid')",
+        )
+        .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+        .with_tie_breaker("id")
+        .selected()
+        .as_aggregate()
+        .searchable()
+        .build();
+
+        assert_eq!(
+            property.expression,
+            "COUNT(orders.id) FILTER (WHERE orders.status = 'paid')"
+        );
+        assert_eq!(
+            property.joins,
+            vec!["LEFT JOIN orders ON orders.customer_id = base_query.id".to_string()]
+        );
+        assert_eq!(property.tie_breaker.as_deref(), Some("id"));
+        assert!(property.selected);
+        assert!(property.aggregate);
+        assert!(property.searchable);
+    }
+
+    #[test]
+    fn test_join_bound_keeps_values_of_the_clause() {
+        let property = ComputedPropertyBuilder::new("order_count", "COUNT(orders.id)")
+            .with_join_bound(
+                "LEFT JOIN orders ON orders.user_id = base_query.id AND orders.tenant_id = $?",
+                vec!["42"],
+            )
+            .with_join_bound("LEFT JOIN tags ON tags.label = '?' AND tags.id = ?", vec![])
+            .build();
+
+        let join = "LEFT JOIN orders ON orders.user_id = base_query.id AND orders.tenant_id = ?";
+        assert_eq!(property.joins, vec![join.to_string()]);
+        assert_eq!(
+            property.join_values.get(join),
+            Some(&vec!["42".to_string()])
+        );
+    }
+}
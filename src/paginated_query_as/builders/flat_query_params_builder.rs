@@ -0,0 +1,329 @@
+use crate::paginated_query_as::builders::query_params_builder::append_sort;
+use crate::paginated_query_as::internal::{
+    clamp_page, QueryDateRangeParams, QueryPaginationParams, QuerySearchParams, QuerySort,
+    DEFAULT_DATE_RANGE_COLUMN_NAME,
+};
+use crate::paginated_query_as::models::{
+    FlatQueryParams, QueryFilterCondition, QueryFilterOperator, QueryNullsPosition,
+    QuerySortDirection, SearchAnchor, SearchMode,
+};
+use chrono::{DateTime, Utc};
+
+/// Builds `FlatQueryParams` without going through a query string.
+///
+/// Mirrors `QueryParamsBuilder`, for generating request parameters on the client
+/// side or in tests. There is no model to check columns against, so every column
+/// is kept; columns are validated once the parameters reach `QueryParamsBuilder`
+/// or `FlatQueryParams::validate`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use sqlx_paginated::{FlatQueryParamsBuilder, QueryFilterOperator, QueryParams};
+///
+/// #[derive(Serialize, Default)]
+/// struct Product {
+///     name: String,
+///     price: f64,
+/// }
+///
+/// let flat = FlatQueryParamsBuilder::new()
+///     .with_pagination(2, 20)
+///     .with_filter_operator("price", QueryFilterOperator::GreaterThan, "10")
+///     .build();
+///
+/// let params = QueryParams::<Product>::from(flat);
+/// assert_eq!(params.pagination.page, 2);
+/// assert_eq!(params.filters["price"][0].operator, QueryFilterOperator::GreaterThan);
+/// ```
+#[derive(Default)]
+pub struct FlatQueryParamsBuilder {
+    query: FlatQueryParams,
+}
+
+impl FlatQueryParamsBuilder {
+    /// Creates a builder with no parameters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets pagination parameters, clamped like the `page`/`page_size` query
+    /// string parameters.
+    pub fn with_pagination(mut self, page: i64, page_size: i64) -> Self {
+        self.query.pagination = Some(QueryPaginationParams::requested(
+            clamp_page(page),
+            page_size,
+        ));
+        self
+    }
+
+    /// Sets the page token, taking precedence over the page number.
+    pub fn with_page_token(mut self, token: impl Into<String>) -> Self {
+        self.query.page_token = Some(token.into());
+        self
+    }
+
+    /// Sets the sort column and direction, or adds a secondary sort column when
+    /// called again.
+    pub fn with_sort(
+        mut self,
+        sort_column: impl Into<String>,
+        sort_direction: QuerySortDirection,
+    ) -> Self {
+        self.query.sort = Some(append_sort(
+            self.query.sort.take().unwrap_or_default(),
+            QuerySort {
+                column: sort_column.into(),
+                direction: sort_direction,
+                nulls: QueryNullsPosition::Default,
+            },
+        ));
+        self
+    }
+
+    /// Sets the search term and the columns to search in.
+    pub fn with_search(
+        mut self,
+        search: impl Into<String>,
+        search_columns: Vec<impl Into<String>>,
+    ) -> Self {
+        let search_params = self.search_params();
+        search_params.search = Some(search.into());
+        search_params.search_columns = Some(search_columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the search term and columns, matched with Postgres full-text search
+    /// using the given text search configuration, e.g. `english`.
+    pub fn with_full_text_search(
+        self,
+        search: impl Into<String>,
+        search_columns: Vec<impl Into<String>>,
+        config: impl Into<String>,
+    ) -> Self {
+        let mut builder = self.with_search(search, search_columns);
+        builder.search_params().full_text_config = Some(config.into());
+        builder
+    }
+
+    /// Sets how the search term is matched against the search columns.
+    pub fn with_search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_params().search_mode = search_mode;
+        self
+    }
+
+    /// Sets where the `%` wildcards are placed around the search term.
+    pub fn with_search_anchor(mut self, search_anchor: SearchAnchor) -> Self {
+        self.search_params().search_anchor = search_anchor;
+        self
+    }
+
+    /// Sets the minimum number of search tokens a row must match.
+    pub fn with_min_should_match(mut self, min_should_match: usize) -> Self {
+        self.search_params().min_should_match = Some(min_should_match);
+        self
+    }
+
+    /// Sets the date range, on `created_at` unless a column is given.
+    pub fn with_date_range(
+        mut self,
+        date_after: Option<DateTime<Utc>>,
+        date_before: Option<DateTime<Utc>>,
+        column_name: Option<impl Into<String>>,
+    ) -> Self {
+        self.query.date_range = Some(QueryDateRangeParams {
+            date_after,
+            date_before,
+            date_column: Some(
+                column_name.map_or_else(|| DEFAULT_DATE_RANGE_COLUMN_NAME.to_string(), Into::into),
+            ),
+        });
+        self
+    }
+
+    /// Adds a filter condition with an operator.
+    pub fn with_filter_operator(
+        self,
+        key: impl Into<String>,
+        operator: QueryFilterOperator,
+        value: impl Into<String>,
+    ) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::new(operator, Some(value)))
+    }
+
+    /// Adds an equality filter condition.
+    pub fn with_filter(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::equal(value))
+    }
+
+    /// Adds a BETWEEN filter condition, matching `low` to `high` inclusive.
+    pub fn with_filter_between(
+        self,
+        key: impl Into<String>,
+        low: impl Into<String>,
+        high: impl Into<String>,
+    ) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::between(low, high))
+    }
+
+    /// Adds a NOT BETWEEN filter condition, matching values outside `low` to `high`
+    /// inclusive.
+    pub fn with_filter_not_between(
+        self,
+        key: impl Into<String>,
+        low: impl Into<String>,
+        high: impl Into<String>,
+    ) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::not_between(low, high))
+    }
+
+    /// Adds an IS NULL or IS NOT NULL filter condition.
+    pub fn with_filter_null(self, key: impl Into<String>, is_null: bool) -> Self {
+        let condition = if is_null {
+            QueryFilterCondition::is_null()
+        } else {
+            QueryFilterCondition::is_not_null()
+        };
+        self.with_filter_condition(key, condition)
+    }
+
+    /// Adds an IS TRUE or IS FALSE filter condition.
+    pub fn with_filter_is(self, key: impl Into<String>, value: bool) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::is(value))
+    }
+
+    /// Adds an IS NOT TRUE or IS NOT FALSE filter condition, matching NULL values.
+    pub fn with_filter_is_not(self, key: impl Into<String>, value: bool) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::is_not(value))
+    }
+
+    /// Adds an IN filter condition with multiple values.
+    pub fn with_filter_in(self, key: impl Into<String>, values: Vec<impl Into<String>>) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::in_list(values))
+    }
+
+    /// Adds a NOT IN filter condition with multiple values.
+    pub fn with_filter_not_in(
+        self,
+        key: impl Into<String>,
+        values: Vec<impl Into<String>>,
+    ) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::not_in_list(values))
+    }
+
+    /// Adds a filter condition, combined with AND with any other filter on the column.
+    pub fn with_filter_condition(
+        mut self,
+        key: impl Into<String>,
+        condition: QueryFilterCondition,
+    ) -> Self {
+        self.query
+            .filters
+            .get_or_insert_with(Default::default)
+            .entry(key.into())
+            .or_default()
+            .push(condition);
+        self
+    }
+
+    /// Builds and returns the final flat query parameters.
+    pub fn build(self) -> FlatQueryParams {
+        self.query
+    }
+
+    fn search_params(&mut self) -> &mut QuerySearchParams {
+        self.query.search.get_or_insert_with(Default::default)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::QueryParams;
+    use serde::Serialize;
+
+    #[derive(Serialize, Default)]
+    struct Product {
+        name: String,
+        price: f64,
+        status: String,
+        deleted_at: Option<String>,
+        created_at: String,
+    }
+
+    fn condition<'a>(
+        params: &'a QueryParams<Product>,
+        column: &str,
+    ) -> (QueryFilterOperator, Option<&'a str>) {
+        let condition = &params.filters[column][0];
+        (condition.operator.clone(), condition.value.as_deref())
+    }
+
+    #[test]
+    fn test_builds_flat_params_matching_query_params() {
+        let flat = FlatQueryParamsBuilder::new()
+            .with_pagination(3, 500)
+            .with_sort("price", QuerySortDirection::Ascending)
+            .with_search("lamp", vec!["name"])
+            .with_search_anchor(SearchAnchor::Prefix)
+            .with_filter("status", "active")
+            .with_filter_operator("price", QueryFilterOperator::LessOrEqual, "99.5")
+            .with_filter_null("deleted_at", true)
+            .build();
+
+        assert!(flat.validate::<Product>().is_ok());
+
+        let params = QueryParams::<Product>::from(flat);
+        assert_eq!(params.pagination.page, 3);
+        assert_eq!(params.pagination.page_size, 50);
+        assert_eq!(params.sort.sort_column, "price");
+        assert!(params.sort.is_explicit());
+        assert_eq!(params.search.patterns(), vec!["lamp%".to_string()]);
+        assert_eq!(params.filters.len(), 3);
+        assert_eq!(
+            condition(&params, "status"),
+            (QueryFilterOperator::Equal, Some("active"))
+        );
+        assert_eq!(
+            condition(&params, "price"),
+            (QueryFilterOperator::LessOrEqual, Some("99.5"))
+        );
+        assert_eq!(
+            condition(&params, "deleted_at"),
+            (QueryFilterOperator::IsNull, None)
+        );
+    }
+
+    #[test]
+    fn test_round_trips_through_query_string_form() {
+        let flat = FlatQueryParamsBuilder::new()
+            .with_search("desk lamp", vec!["name", "status"])
+            .with_search_mode(SearchMode::AnyTokenAnyColumn)
+            .with_filter_in("status", vec!["active", "pending"])
+            .build();
+
+        let json = serde_json::to_value(QueryParams::<Product>::from(flat)).unwrap();
+        let params =
+            QueryParams::<Product>::from(serde_json::from_value::<FlatQueryParams>(json).unwrap());
+
+        assert_eq!(params.search.search_mode, SearchMode::AnyTokenAnyColumn);
+        assert_eq!(
+            condition(&params, "status"),
+            (QueryFilterOperator::In, Some("active,pending"))
+        );
+    }
+
+    #[test]
+    fn test_empty_builder_matches_default_params() {
+        let flat = FlatQueryParamsBuilder::new().build();
+
+        assert!(flat.pagination.is_none());
+        assert!(flat.filters.is_none());
+
+        let params = QueryParams::<Product>::from(flat);
+        assert_eq!(params.pagination.page, 1);
+        assert!(!params.sort.is_explicit());
+    }
+}
@@ -1,7 +1,13 @@
+mod computed_property_builder;
+mod flat_query_params_builder;
 mod paginated_query_builder;
 mod query_builders;
 mod query_params_builder;
+mod union_paginated_query;
 
+pub use computed_property_builder::*;
+pub use flat_query_params_builder::*;
 pub use paginated_query_builder::*;
 pub use query_builders::*;
 pub use query_params_builder::*;
+pub use union_paginated_query::*;
@@ -1,16 +1,188 @@
-use crate::paginated_query_as::internal::quote_identifier;
-use crate::paginated_query_as::models::QuerySortDirection;
-use crate::{FlatQueryParams, PaginatedResponse, QueryParams};
+use crate::paginated_query_as::internal::{
+    build_sort_order_case, get_struct_field_meta, get_struct_field_names, quote_identifier,
+    requalify_table_references, resolve_sort_column, rewrite_placeholders, QueryDialect,
+    QueryPaginationParams,
+};
+use crate::paginated_query_as::models::{QueryNullsPosition, QuerySortDirection};
+#[cfg(feature = "postgres")]
+use crate::MatchInfo;
+use crate::{
+    ComputedProperty, CountEvent, ExecutedSql, FieldType, FlatQueryParams, KeyedPaginatedResponse,
+    KeysetCursor, KeysetDirection, PageMeta, PageToken, PaginatedResponse, PaginationContext,
+    PaginationError, QueryBuilder, QueryFilterCondition, QueryFilterOperator, QueryParams,
+    RangeBoundCheck,
+};
 use serde::Serialize;
 use sqlx::{
-    query::QueryAs, AssertSqlSafe, Database, Execute, Executor, FromRow, IntoArguments, Pool,
+    query::QueryAs, Arguments, AssertSqlSafe, Database, Encode, Execute, Executor, FromRow,
+    IntoArguments, Pool, Type,
 };
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::marker::PhantomData;
 
-type QueryBuilderFn<T, DB> = Box<
+/// Similarity search settings set by `with_autocomplete`.
+#[cfg(feature = "postgres")]
+struct Autocomplete {
+    column: String,
+    term: String,
+    limit: i64,
+    threshold: f64,
+}
+
+/// Binds one argument supplied to the builder, such as a prepended CTE argument.
+type ArgumentBinder<DB> = Box<
+    dyn Fn(&mut <DB as Database>::Arguments) -> Result<(), sqlx::error::BoxDynError> + Send + Sync,
+>;
+
+/// Decodes the JSON plan of an `EXPLAIN (FORMAT JSON)` row, for databases whose
+/// JSON values the generic builder can't decode.
+type PlanDecoder<DB> = fn(&<DB as Database>::Row) -> Option<serde_json::Value>;
+
+/// A JOIN clause of a computed property with the values of its `?` tokens, none
+/// for clauses added with `with_join`.
+type PropertyJoin = (String, Vec<String>);
+
+/// A CTE added before `base_query` by `with_prepended_cte`.
+struct PrependedCte<DB: Database> {
+    name: String,
+    sql: String,
+    /// The CTE with its placeholders offset past the condition arguments
+    bound_sql: String,
+    arguments: Vec<ArgumentBinder<DB>>,
+}
+
+/// An approximate row counter set by `with_counter_table`.
+struct CounterTable<DB: Database> {
+    table: String,
+    name_column: String,
+    count_column: String,
+    key: ArgumentBinder<DB>,
+}
+
+/// A lower bound on a timestamp column scoping the count, set by `with_count_window`.
+struct CountWindow<DB: Database> {
+    column: String,
+    since: ArgumentBinder<DB>,
+    /// The lower bound, keying the totals shared through a `PaginationContext`
+    since_key: String,
+}
+
+/// A sentinel replacing NULLs of a sort column, set by `with_sort_coalesce`.
+struct SortCoalesce<DB: Database> {
+    column: String,
+    sentinel: ArgumentBinder<DB>,
+    /// The placeholder of the sentinel, once bound for the records query
+    placeholder: Option<String>,
+}
+
+/// Keyset pagination settings, set by `with_cursor`.
+struct Keyset<DB: Database> {
+    column: String,
+    tie_breaker: String,
+    direction: QuerySortDirection,
+    /// The sort key of the last record of the previous page, with the binders of
+    /// its sort column and tie breaker values
+    after: Option<(KeysetCursor, [CursorValue<DB>; 2])>,
+}
+
+/// A `with_cursor` value bound for the type of its model field.
+struct CursorValue<DB: Database> {
+    bind: ArgumentBinder<DB>,
+    /// Whether the placeholder takes the type cast the dialect infers from the value
+    cast: bool,
+}
+
+impl<DB: Database> CursorValue<DB> {
+    /// Binds values of number and boolean fields as numbers and booleans, and
+    /// values of other fields as text. Text is cast to the type inferred from the
+    /// value, e.g. a timestamp or a UUID, unless it reads as a number or a boolean,
+    /// which a text field holds as text.
+    fn new(value: String, field_type: FieldType) -> Self
+    where
+        String: for<'e> Encode<'e, DB> + Type<DB>,
+        i64: for<'e> Encode<'e, DB> + Type<DB>,
+        f64: for<'e> Encode<'e, DB> + Type<DB>,
+        bool: for<'e> Encode<'e, DB> + Type<DB>,
+    {
+        let typed = |bind: ArgumentBinder<DB>| Self { bind, cast: false };
+        match field_type {
+            FieldType::Number => {
+                if let Ok(number) = value.parse::<i64>() {
+                    return typed(Box::new(move |arguments| arguments.add(number)));
+                }
+                if let Ok(number) = value.parse::<f64>() {
+                    return typed(Box::new(move |arguments| arguments.add(number)));
+                }
+            }
+            FieldType::Boolean => {
+                if let Ok(boolean) = value.parse::<bool>() {
+                    return typed(Box::new(move |arguments| arguments.add(boolean)));
+                }
+            }
+            _ => {}
+        }
+
+        let cast = field_type != FieldType::Number
+            && value.parse::<f64>().is_err()
+            && !matches!(value.to_lowercase().as_str(), "t" | "f" | "true" | "false");
+        Self {
+            bind: Box::new(move |arguments| arguments.add(value.clone())),
+            cast,
+        }
+    }
+}
+
+/// Total records, total pages, pagination and whether the total is an estimate.
+type PaginationTotals = (
+    Option<i64>,
+    Option<i64>,
+    Option<QueryPaginationParams>,
+    bool,
+);
+
+/// The records query of the requested page, with its conditions and LIMIT clause.
+struct PageQuery<DB: Database> {
+    sql: String,
+    /// Read by `generated_sql_from_dialect`
+    #[cfg_attr(not(feature = "testing"), allow(dead_code))]
+    conditions: Vec<String>,
+    #[cfg_attr(not(feature = "testing"), allow(dead_code))]
+    limit_clause: String,
+    arguments: DB::Arguments,
+}
+
+type ConditionsFn<T, DB> = Box<
     dyn for<'p> Fn(&'p QueryParams<T>) -> (Vec<String>, <DB as Database>::Arguments) + Send + Sync,
 >;
 
+type HavingQueryBuilderFn<T, DB> =
+    Box<dyn for<'p> Fn(&'p QueryParams<T>) -> QueryBuilder<'p, T, DB> + Send + Sync>;
+
+/// The conditions, `HAVING` conditions and arguments of a query builder.
+type BuiltConditions<DB> = (Vec<String>, Vec<String>, <DB as Database>::Arguments);
+
+pub(crate) type DefaultQueryBuilderFn<T, DB> =
+    for<'p> fn(&'p QueryParams<T>, &[ComputedProperty]) -> QueryBuilder<'p, T, DB>;
+
+/// Builds the query conditions and arguments from the parameters.
+enum QueryBuilderFn<T, DB: Database> {
+    /// Set by `new` and `with_query_builder`
+    Conditions(ConditionsFn<T, DB>),
+    /// Set by `with_having_query_builder`, returning the query builder before its
+    /// `HAVING` conditions are built
+    Having(HavingQueryBuilderFn<T, DB>),
+    /// Set by `paginated_query_as`, returning the default query builder with the
+    /// computed properties registered
+    Default(DefaultQueryBuilderFn<T, DB>),
+}
+
+type DefaultScopeFn<DB> = Box<
+    dyn Fn(Vec<String>, <DB as Database>::Arguments) -> (Vec<String>, <DB as Database>::Arguments)
+        + Send
+        + Sync,
+>;
+
 pub struct PaginatedQueryBuilder<'q, T, DB, A>
 where
     DB: Database,
@@ -19,10 +191,95 @@ where
     base_sql: sqlx::SqlStr,
     params: QueryParams<'q, T>,
     totals_count_enabled: bool,
+    cached_total: Option<i64>,
+    context: Option<PaginationContext>,
+    page_bounds_column: Option<String>,
+    page_tokens_enabled: bool,
+    sort_coalesce: Option<SortCoalesce<DB>>,
+    sort_order_maps: HashMap<String, Vec<String>>,
+    /// Whether the dialect of the records query supports `NULLS FIRST`/`NULLS LAST`
+    native_nulls_ordering: bool,
+    /// The dialect of the executed queries, quoting their identifiers, set by the
+    /// database specific implementations, identifiers are double quoted until then
+    dialect: Option<&'static (dyn QueryDialect + Sync)>,
+    /// Whether the base query is a subquery of the records and count queries
+    /// instead of a `base_query` CTE
+    inline_base_query: bool,
+    keyset: Option<Keyset<DB>>,
+    keyset_direction: KeysetDirection,
+    counter_table: Option<CounterTable<DB>>,
+    /// The SQL executed by `fetch_paginated_debug`, recorded while fetching
+    executed_sql: Option<ExecutedSql>,
+    count_window: Option<CountWindow<DB>>,
+    count_cap: Option<i64>,
+    /// Decodes the `EXPLAIN (FORMAT JSON)` plan of the count query, set by
+    /// `with_estimated_count` on PostgreSQL
+    estimated_count: Option<PlanDecoder<DB>>,
+    /// Column whose non-NULL values mark soft-deleted rows, set by `with_soft_delete`
+    soft_delete_column: Option<String>,
+    /// Whether the total counts the soft-deleted rows, set by `with_count_includes_trashed`
+    count_includes_trashed: bool,
+    /// The rows of the total that are not soft-deleted, when the total includes them
+    total_active: Option<i64>,
+    /// Adds the `with_default_scope` conditions to the query conditions
+    default_scope: Option<DefaultScopeFn<DB>>,
+    /// Whether the default scope and soft-delete conditions are skipped, set by `with_include_deleted`
+    include_deleted: bool,
+    /// Whether the total is read off a `COUNT(*) OVER()` column of the records query
+    window_count: bool,
+    windowed_records: bool,
+    distinct: bool,
+    /// Columns of the records query's `DISTINCT ON`, set by `with_distinct_on`
+    distinct_on: Vec<String>,
+    computed_properties: Vec<ComputedProperty>,
+    /// Binders of the values of the bound JOIN clauses of the computed properties
+    join_arguments: HashMap<PropertyJoin, Vec<ArgumentBinder<DB>>>,
+    /// The bound JOIN clauses with the placeholders of the query being built
+    bound_joins: HashMap<PropertyJoin, String>,
+    /// The `HAVING` conditions of the query being built
+    having: Vec<String>,
+    records_select: Option<String>,
+    base_table: Option<String>,
+    primary_key: Option<String>,
+    primary_key_count_enabled: bool,
+    explicit_sort_required: bool,
+    required_filters: Vec<String>,
+    range_bound_check: Option<RangeBoundCheck>,
+    max_offset: Option<i64>,
+    max_offset_clamped: bool,
+    max_page_size: Option<i64>,
+    column_mappings: HashMap<String, String>,
+    #[cfg(feature = "postgres")]
+    autocomplete: Option<Autocomplete>,
+    prepended_ctes: Vec<PrependedCte<DB>>,
     build_query_fn: QueryBuilderFn<T, DB>,
     _arguments: PhantomData<A>,
 }
 
+/// Returns whether the low bound of a [NOT] BETWEEN condition is greater than its high
+/// bound, comparing the bounds as numbers when `numeric` and as strings otherwise.
+/// Numeric bounds that don't parse are left to the database.
+fn is_inverted_range(condition: &QueryFilterCondition, numeric: bool) -> bool {
+    if !matches!(
+        condition.operator,
+        QueryFilterOperator::Between | QueryFilterOperator::NotBetween
+    ) {
+        return false;
+    }
+    let Some((low, high)) = condition.bounds() else {
+        return false;
+    };
+
+    if numeric {
+        match (low.parse::<f64>(), high.parse::<f64>()) {
+            (Ok(low), Ok(high)) => low > high,
+            _ => false,
+        }
+    } else {
+        low > high
+    }
+}
+
 /// A builder for constructing and executing paginated queries.
 ///
 /// This builder provides a fluent interface for creating paginated queries.
@@ -47,7 +304,7 @@ where
     DB::Arguments: IntoArguments<DB>,
     for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
     usize: sqlx::ColumnIndex<<DB as Database>::Row>,
-    i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB> + Send + Unpin,
+    i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB> + for<'e> Encode<'e, DB> + Send + Unpin,
 {
     /// Creates a new `PaginatedQueryBuilder` with default settings.
     ///
@@ -90,7 +347,50 @@ where
             base_sql,
             params: FlatQueryParams::default().into(),
             totals_count_enabled: true,
-            build_query_fn: Box::new(build_query_fn),
+            cached_total: None,
+            context: None,
+            page_bounds_column: None,
+            page_tokens_enabled: false,
+            sort_coalesce: None,
+            sort_order_maps: HashMap::new(),
+            native_nulls_ordering: true,
+            dialect: None,
+            inline_base_query: false,
+            keyset: None,
+            keyset_direction: KeysetDirection::Forward,
+            counter_table: None,
+            executed_sql: None,
+            count_window: None,
+            count_cap: None,
+            estimated_count: None,
+            soft_delete_column: None,
+            count_includes_trashed: false,
+            total_active: None,
+            default_scope: None,
+            include_deleted: false,
+            window_count: false,
+            windowed_records: false,
+            distinct: false,
+            distinct_on: Vec::new(),
+            computed_properties: Vec::new(),
+            join_arguments: HashMap::new(),
+            bound_joins: HashMap::new(),
+            having: Vec::new(),
+            records_select: None,
+            base_table: None,
+            primary_key: None,
+            primary_key_count_enabled: false,
+            explicit_sort_required: false,
+            required_filters: Vec::new(),
+            range_bound_check: None,
+            max_offset: None,
+            max_offset_clamped: false,
+            max_page_size: None,
+            column_mappings: HashMap::new(),
+            #[cfg(feature = "postgres")]
+            autocomplete: None,
+            prepended_ctes: Vec::new(),
+            build_query_fn: QueryBuilderFn::Conditions(Box::new(build_query_fn)),
             _arguments: PhantomData,
         }
     }
@@ -99,374 +399,6454 @@ where
     where
         F: for<'p> Fn(&'p QueryParams<T>) -> (Vec<String>, DB::Arguments) + Send + Sync + 'static,
     {
-        self.build_query_fn = Box::new(build_query_fn);
+        self.build_query_fn = QueryBuilderFn::Conditions(Box::new(build_query_fn));
         self
     }
 
-    pub fn with_params(mut self, params: impl Into<QueryParams<'q, T>>) -> Self {
-        self.params = params.into();
-        self
-    }
-
-    /// Disables the calculation of total record count.
+    /// Sets the function returning the `QueryBuilder` of the query conditions and
+    /// `HAVING` conditions, which are built with `QueryBuilder::build_with_having`
+    /// once the `with_default_scope` conditions are added.
     ///
-    /// When disabled, the response will not include total count or total pages.
-    /// This can improve query performance for large datasets where the total
-    /// count is not needed.
+    /// The `HAVING` conditions filter aggregate computed properties, routed by
+    /// `QueryBuilder::with_aggregate_property`. The default query builder of
+    /// `paginated_query_as` already routes the aggregate properties registered with
+    /// `with_computed_property`, so this is only needed by custom query builders.
+    /// While there are any, the records
+    /// are grouped by the model columns and the conditions follow the `GROUP BY`,
+    /// and the total counts the grouped rows. Aggregate properties named by a
+    /// filter add their joins to the records and count queries.
     ///
-    /// # Returns
+    /// Since the `HAVING` conditions follow the other conditions in the statement,
+    /// queries with any fail with `PaginationError::UnorderedArguments` on
+    /// databases with positional placeholders, such as MySQL.
     ///
-    /// Returns self for method chaining
-    pub fn disable_totals_count(mut self) -> Self {
-        self.totals_count_enabled = false;
-        self
-    }
-
-    /// Builds the base query with CTE (Common Table Expression).
+    /// # Arguments
     ///
-    /// # Returns
+    /// * `build_query_fn` - Function returning the query builder of the conditions and `HAVING` conditions
     ///
-    /// Returns the SQL string for the base query wrapped in a CTE
-    fn build_base_query(&self) -> String {
-        format!("WITH base_query AS ({})", self.base_sql.as_str())
-    }
-
-    /// Builds the WHERE clause from the provided conditions.
+    /// # Examples
     ///
-    /// # Arguments
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{paginated_query_as, ComputedPropertyBuilder, QueryBuilder};
     ///
-    /// * `conditions` - Vector of condition strings to join with AND
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct CustomerExample {
+    ///     name: String,
+    ///     order_total: f64,
+    /// }
     ///
-    /// # Returns
+    /// let order_total = ComputedPropertyBuilder::new("order_total", "SUM(orders.total)")
+    ///     .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+    ///     .as_aggregate()
+    ///     .selected()
+    ///     .build();
+    /// let having_property = order_total.clone();
     ///
-    /// Returns the formatted WHERE clause or empty string if no conditions
-    fn build_where_clause(&self, conditions: &[String]) -> String {
-        if conditions.is_empty() {
-            String::new()
-        } else {
-            format!(" WHERE {}", conditions.join(" AND "))
-        }
+    /// let builder = paginated_query_as::<CustomerExample, Postgres>("SELECT * FROM customers")
+    ///     .with_computed_property(order_total)
+    ///     .with_having_query_builder(move |params| {
+    ///         QueryBuilder::<CustomerExample, Postgres>::new()
+    ///             .with_aggregate_property(&having_property)
+    ///             .with_search(params)
+    ///             .with_filters(params)
+    ///     });
+    /// ```
+    pub fn with_having_query_builder<F>(mut self, build_query_fn: F) -> Self
+    where
+        F: for<'p> Fn(&'p QueryParams<T>) -> QueryBuilder<'p, T, DB> + Send + Sync + 'static,
+    {
+        self.build_query_fn = QueryBuilderFn::Having(Box::new(build_query_fn));
+        self
     }
 
-    /// Builds the ORDER BY clause based on sort parameters.
-    ///
-    /// # Returns
-    ///
-    /// Returns the formatted ORDER BY clause with proper column quoting
-    fn build_order_clause(&self) -> String {
-        let order = match self.params.sort.sort_direction {
-            QuerySortDirection::Ascending => "ASC",
-            QuerySortDirection::Descending => "DESC",
-        };
-        let column_name = quote_identifier(&self.params.sort.sort_column);
-
-        format!(" ORDER BY {} {}", column_name, order)
+    /// Sets the default query builder function of `paginated_query_as`, which
+    /// registers the searchable and aggregate computed properties.
+    pub(crate) fn with_default_query_builder(
+        mut self,
+        build_query_fn: DefaultQueryBuilderFn<T, DB>,
+    ) -> Self {
+        self.build_query_fn = QueryBuilderFn::Default(build_query_fn);
+        self
     }
 
-    fn build_limit_offset_clause(&self) -> String {
-        let pagination = &self.params.pagination;
-        let offset = (pagination.page - 1) * pagination.page_size;
-
-        format!(" LIMIT {} OFFSET {}", pagination.page_size, offset)
+    pub fn with_params(mut self, params: impl Into<QueryParams<'q, T>>) -> Self {
+        self.params = params.into();
+        if let Some(max_page_size) = self.max_page_size {
+            self.params.pagination.clamp_to(max_page_size);
+        }
+        self.params
+            .column_mappings
+            .extend(self.column_mappings.clone());
+        if self.range_bound_check == Some(RangeBoundCheck::Swap) {
+            self.swap_inverted_ranges();
+        }
+        self
     }
-}
 
-#[cfg(feature = "postgres")]
-impl<'q, T, A> PaginatedQueryBuilder<'q, T, sqlx::Postgres, A>
-where
-    T: for<'r> FromRow<'r, <sqlx::Postgres as sqlx::Database>::Row>
-        + Send
-        + Unpin
-        + Serialize
-        + Default,
-    A: IntoArguments<sqlx::Postgres> + Send,
-{
-    /// Creates a new `PaginatedQueryBuilder` for PostgreSQL with default settings.
+    /// Maps a model field to the database column it is read from, for fields
+    /// renamed with `#[serde(rename = "...")]`.
+    ///
+    /// Parameters name columns by their serialized field names, which are also the
+    /// names reported by `#[derive(Serialize)]`, while the base query usually
+    /// returns the Rust field names that `FromRow` reads. With a mapping, sorting
+    /// by the serde name orders by the database column, and a `QueryBuilder`
+    /// building the conditions from the parameters, as the default query building
+    /// function does, filters and searches the database column as well. Fields
+    /// that are not model fields are ignored.
     ///
     /// # Arguments
     ///
-    /// * `query` - The base query to paginate
+    /// * `name` - Serialized name of the field
+    /// * `column` - Database column of the field
     ///
-    /// # Default Settings
+    /// # Returns
     ///
-    /// - Totals calculation is enabled
-    /// - Uses default query parameters
-    /// - Uses safe default query building function
+    /// Returns self for method chaining
     ///
     /// # Examples
     ///
     /// ```rust
     /// use sqlx::{FromRow, Postgres};
     /// use serde::{Serialize};
-    /// use sqlx_paginated::PaginatedQueryBuilder;
+    /// use sqlx_paginated::paginated_query_as;
     ///
     /// #[derive(Serialize, FromRow, Default)]
     /// struct UserExample {
-    ///     name: String
+    ///     #[serde(rename = "emailAddress")]
+    ///     email: String,
     /// }
-    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
-    /// let builder = PaginatedQueryBuilder::<UserExample, Postgres, _>::new_with_defaults(base_query);
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_column_mapping("emailAddress", "email");
     /// ```
-    pub fn new_with_defaults(query: sqlx::query::QueryAs<'q, sqlx::Postgres, T, A>) -> Self {
-        use crate::paginated_query_as::examples::postgres_examples::build_query_with_safe_defaults;
-        Self::new(query, |params| build_query_with_safe_defaults::<T>(params))
+    pub fn with_column_mapping(mut self, name: &str, column: impl Into<String>) -> Self {
+        let column = column.into();
+
+        if get_struct_field_names::<T>()
+            .iter()
+            .any(|field| field == name)
+        {
+            self.column_mappings
+                .insert(name.to_string(), column.clone());
+            self.params.column_mappings.insert(name.to_string(), column);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(name = %name, column = %column, "Skipping invalid column mapping");
+        }
+        self
     }
 
-    /// Executes the paginated query and returns the results.
+    /// Registers a computed property that can be selected or used as a sort key.
     ///
-    /// # Arguments
+    /// When a requested sort column matches the name of a registered computed
+    /// property, its expression is emitted in the ORDER BY clause and its joins are
+    /// added to the records query. Selected properties are always added to the
+    /// records query, and aggregate properties group the records by the model
+    /// columns. Sort columns that do not match a registered property are treated
+    /// as plain columns.
     ///
-    /// * `pool` - PostgreSQL database connection pool
+    /// The default query builder of `paginated_query_as` matches searchable
+    /// properties in search and filters aggregate properties in `HAVING`, as
+    /// `QueryBuilder::with_search_property` and `with_aggregate_property` do.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Returns a Result containing a `PaginatedResponse<T>` with:
-    /// - Records for the requested page
-    /// - Optional Pagination information (if enabled)
-    /// - Optional total count and total pages (if enabled)
+    /// * `property` - The computed property to register
     ///
-    /// # Errors
+    /// # Returns
     ///
-    /// Returns `sqlx::Error` if the query execution fails
+    /// Returns self for method chaining
     ///
-    /// # Example
+    /// # Examples
     ///
-    /// ```rust,no_run
-    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
     /// use serde::Serialize;
-    /// use sqlx_paginated::{PaginatedQueryBuilder, QueryParamsBuilder};
+    /// use sqlx_paginated::{paginated_query_as, ComputedPropertyBuilder, QueryParamsBuilder, QuerySortDirection};
     ///
     /// #[derive(Serialize, FromRow, Default)]
-    /// struct User {
-    ///     id: i32,
-    ///     name: String,
+    /// struct UserExample {
+    ///     name: String
     /// }
     ///
-    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
-    /// let params = QueryParamsBuilder::<User>::new()
-    ///     .with_pagination(1, 10)
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_sort("last_activity", QuerySortDirection::Descending)
     ///     .build();
     ///
-    /// let result = PaginatedQueryBuilder::<User, Postgres, _>::new_with_defaults(
-    ///     sqlx::query_as::<Postgres, User>("SELECT * FROM users")
-    /// )
-    /// .with_params(params)
-    /// .fetch_paginated(&pool)
-    /// .await?;
-    /// # Ok(())
-    /// # }
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_computed_property(
+    ///         ComputedPropertyBuilder::new("last_activity", "GREATEST(updated_at, created_at)")
+    ///             .with_tie_breaker("id")
+    ///             .build(),
+    ///     )
+    ///     .with_params(params);
     /// ```
-    pub async fn fetch_paginated(
-        self,
-        pool: &sqlx::PgPool,
-    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
-        let base_sql = self.build_base_query();
-        let params_ref = &self.params;
-        let (conditions, main_arguments) = (self.build_query_fn)(params_ref);
-        let where_clause = self.build_where_clause(&conditions);
-
-        let count_sql = if self.totals_count_enabled {
-            Some(format!(
-                "{} SELECT COUNT(*) FROM base_query{}",
-                base_sql, where_clause
-            ))
-        } else {
-            None
-        };
-
-        let mut main_sql = format!("{} SELECT * FROM base_query{}", base_sql, where_clause);
-        main_sql.push_str(&self.build_order_clause());
-        main_sql.push_str(&self.build_limit_offset_clause());
-
-        let (total, total_pages, pagination) = if let Some(count_sql_str) = count_sql.as_ref() {
-            let (_, count_arguments) = (self.build_query_fn)(params_ref);
-            let pagination_arguments = self.params.pagination.clone();
-
-            let count: i64 =
-                sqlx::query_scalar_with(AssertSqlSafe(count_sql_str.as_str()), count_arguments)
-                    .fetch_one(pool)
-                    .await?;
-
-            let available_pages = match count {
-                0 => 0,
-                _ => (count + pagination_arguments.page_size - 1) / pagination_arguments.page_size,
-            };
-
-            (
-                Some(count),
-                Some(available_pages),
-                Some(pagination_arguments),
-            )
-        } else {
-            (None, None, None)
-        };
-
-        // For PostgreSQL, PgArguments doesn't have lifetime constraints
-        let records =
-            sqlx::query_as_with::<sqlx::Postgres, T, _>(AssertSqlSafe(main_sql), main_arguments)
-                .fetch_all(pool)
-                .await?;
+    pub fn with_computed_property(mut self, property: ComputedProperty) -> Self
+    where
+        String: for<'e> Encode<'e, DB> + Type<DB>,
+    {
+        for (join, values) in &property.join_values {
+            let arguments = values
+                .iter()
+                .map(|value| -> ArgumentBinder<DB> {
+                    let value = value.clone();
+                    Box::new(move |arguments| arguments.add(value.clone()))
+                })
+                .collect();
+            self.join_arguments
+                .insert((join.clone(), values.clone()), arguments);
+        }
 
-        Ok(PaginatedResponse {
-            records,
-            pagination,
-            total,
-            total_pages,
-        })
+        self.computed_properties
+            .retain(|existing| existing.name != property.name);
+        self.computed_properties.push(property);
+        self
     }
-}
 
-#[cfg(feature = "sqlite")]
-impl<'q, T, A> PaginatedQueryBuilder<'q, T, sqlx::Sqlite, A>
-where
-    T: for<'r> FromRow<'r, <sqlx::Sqlite as sqlx::Database>::Row>
-        + Send
-        + Unpin
-        + Serialize
-        + Default,
-    A: IntoArguments<sqlx::Sqlite> + Send,
-{
-    /// Creates a new `PaginatedQueryBuilder` for SQLite with default settings.
+    /// Replaces the `SELECT *` list of the records query.
+    ///
+    /// Only the query fetching the page is affected; the count query keeps using
+    /// `COUNT(*)`, so heavier per-row expressions (e.g. window functions) are only
+    /// evaluated for the rows of the requested page. Selected computed properties
+    /// are appended after the custom selection.
+    ///
+    /// The fragment is trusted SQL supplied by the caller and must never contain
+    /// request input. Empty fragments and fragments containing `;` are ignored.
     ///
     /// # Arguments
     ///
-    /// * `query` - The base query to paginate
+    /// * `select` - Selection list, e.g. `*, ROW_NUMBER() OVER (ORDER BY created_at) AS position`
     ///
-    /// # Default Settings
+    /// # Returns
     ///
-    /// - Totals calculation is enabled
-    /// - Uses default query parameters
-    /// - Uses safe default query building function
+    /// Returns self for method chaining
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use sqlx::{FromRow, Sqlite};
-    /// use serde::{Serialize};
-    /// use sqlx_paginated::PaginatedQueryBuilder;
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::paginated_query_as;
     ///
     /// #[derive(Serialize, FromRow, Default)]
     /// struct UserExample {
-    ///     name: String
+    ///     name: String,
+    ///     name_length: i32,
     /// }
-    /// let base_query = sqlx::query_as::<Sqlite, UserExample>("SELECT * FROM users");
-    /// let builder = PaginatedQueryBuilder::<UserExample, Sqlite, _>::new_with_defaults(base_query);
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_records_select("*, LENGTH(name) AS name_length");
     /// ```
-    pub fn new_with_defaults(query: sqlx::query::QueryAs<'q, sqlx::Sqlite, T, A>) -> Self {
-        use crate::QueryBuilder;
-        Self::new(query, |params| {
-            QueryBuilder::<T, sqlx::Sqlite>::new()
-                .with_search(params)
-                .with_filters(params)
-                .with_date_range(params)
-                .build()
-        })
+    pub fn with_records_select(mut self, select: impl Into<String>) -> Self {
+        let select = select.into();
+        let select = select.trim();
+
+        if select.is_empty() || select.contains(';') {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(select = %select, "Skipping invalid records select");
+        } else {
+            self.records_select = Some(select.to_string());
+        }
+        self
     }
 
-    /// Executes the paginated query and returns the results.
+    /// Declares the table the base query reads from.
+    ///
+    /// The base query is selected as `base_query`, so the JOIN clauses of computed
+    /// properties can't refer to the table by its name. Once declared, references
+    /// to its columns in the clauses, e.g. `users.id` in
+    /// `LEFT JOIN orders ON orders.user_id = users.id`, are rewritten to
+    /// `base_query.id`. Schema-qualified references are kept as is, and so are the
+    /// clauses joining the table itself without an alias, whose references are to
+    /// the joined rows.
     ///
     /// # Arguments
     ///
-    /// * `pool` - SQLite database connection pool
+    /// * `table` - Name of the underlying table, e.g. `users`
     ///
     /// # Returns
     ///
-    /// Returns a Result containing a `PaginatedResponse<T>` with:
-    /// - Records for the requested page
-    /// - Optional Pagination information (if enabled)
-    /// - Optional total count and total pages (if enabled)
+    /// Returns self for method chaining
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns `sqlx::Error` if the query execution fails
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, ComputedPropertyBuilder, QueryParamsBuilder, QuerySortDirection};
     ///
-    /// # Implementation Note
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     name: String,
+    /// }
     ///
-    /// All SQL strings are built and kept in scope before executing queries.
+    /// let (records_sql, _) = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_base_table("users")
+    ///     .with_computed_property(
+    ///         ComputedPropertyBuilder::new("order_count", "COUNT(orders.id)")
+    ///             .with_join("LEFT JOIN orders ON orders.user_id = users.id")
+    ///             .as_aggregate()
+    ///             .build(),
+    ///     )
+    ///     .with_params(
+    ///         QueryParamsBuilder::<UserExample>::new()
+    ///             .with_sort("order_count", QuerySortDirection::Descending)
+    ///             .build(),
+    ///     )
+    ///     .to_sql()
+    ///     .unwrap();
     ///
-    /// # Example
+    /// assert!(records_sql.contains("ON orders.user_id = base_query.id"));
+    /// ```
+    pub fn with_base_table(mut self, table: impl Into<String>) -> Self {
+        self.base_table = Some(table.into());
+        self
+    }
+
+    /// Declares the primary key column of the base table.
     ///
-    /// ```rust,no_run
-    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// # Arguments
+    ///
+    /// * `column` - Primary key column, e.g. `id`
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_primary_key(mut self, column: impl Into<String>) -> Self {
+        self.primary_key = Some(column.into());
+        self
+    }
+
+    /// Counts the declared primary key instead of `*` for the total.
+    ///
+    /// Some planners answer `COUNT(pk)` from a small covering index faster than
+    /// `COUNT(*)`. Whether this helps depends on the database and the table, so
+    /// it is opt-in and should be verified with a benchmark. It only takes effect
+    /// when both the base table and its primary key are declared; otherwise the
+    /// count keeps using `COUNT(*)`.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
     /// use serde::Serialize;
-    /// use sqlx_paginated::{PaginatedQueryBuilder, QueryParamsBuilder};
+    /// use sqlx_paginated::paginated_query_as;
     ///
     /// #[derive(Serialize, FromRow, Default)]
-    /// struct User {
-    ///     id: i32,
+    /// struct UserExample {
+    ///     id: i64,
     ///     name: String,
     /// }
     ///
-    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
-    /// let params = QueryParamsBuilder::<User>::new()
-    ///     .with_pagination(1, 10)
-    ///     .build();
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_base_table("users")
+    ///     .with_primary_key("id")
+    ///     .with_primary_key_count();
+    /// ```
+    pub fn with_primary_key_count(mut self) -> Self {
+        self.primary_key_count_enabled = true;
+        self
+    }
+
+    /// Returns each base row once when joins of the active computed properties
+    /// match several joined rows, e.g. a post searched through its tags.
     ///
-    /// let result = PaginatedQueryBuilder::<User, Sqlite, _>::new_with_defaults(
-    ///     sqlx::query_as::<Sqlite, User>("SELECT * FROM users")
-    /// )
-    /// .with_params(params)
-    /// .fetch_paginated(&pool)
-    /// .await?;
-    /// # Ok(())
-    /// # }
+    /// While joins are active, the records are grouped by the model columns of
+    /// `base_query`, and the count counts the distinct `with_primary_key` values, or
+    /// the distinct base rows when no primary key is declared. Without active joins
+    /// the queries are unchanged.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{paginated_query_as, ComputedPropertyBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct PostExample {
+    ///     title: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<PostExample, Postgres>("SELECT * FROM posts")
+    ///     .with_computed_property(
+    ///         ComputedPropertyBuilder::new("tag", "tags.label")
+    ///             .with_join("JOIN tags ON tags.post_id = base_query.id")
+    ///             .searchable()
+    ///             .build(),
+    ///     )
+    ///     .with_primary_key("id")
+    ///     .with_distinct();
     /// ```
-    pub async fn fetch_paginated(
-        self,
-        pool: &sqlx::SqlitePool,
-    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
-        let base_sql = self.build_base_query();
-        let params_ref = &self.params;
-        let (conditions, main_arguments) = (self.build_query_fn)(params_ref);
-        let where_clause = self.build_where_clause(&conditions);
+    pub fn with_distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
 
-        // Build all SQL strings first and keep them in scope
-        let count_sql = if self.totals_count_enabled {
-            Some(format!(
-                "{} SELECT COUNT(*) FROM base_query{}",
-                base_sql, where_clause
-            ))
-        } else {
-            None
+    /// Requires the caller to supply a sort column.
+    ///
+    /// By default a missing sort falls back to `created_at DESC`. When required,
+    /// fetching fails with `PaginationError::MissingSort` unless the parameters
+    /// set `sort_column` or `order_by`, or were built with `QueryParamsBuilder::with_sort`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .require_explicit_sort();
+    /// ```
+    pub fn require_explicit_sort(mut self) -> Self {
+        self.explicit_sort_required = true;
+        self
+    }
+
+    /// Requires the caller to filter on a field, e.g. a tenant or account scope
+    /// that must never be left out.
+    ///
+    /// Fetching fails with `PaginationError::MissingRequiredFilter` unless the
+    /// parameters hold a condition on the field in `filters` comparing it to a
+    /// value, and the query builder function emitted a condition on the field for
+    /// it, so a filter that ends up dropped, e.g. `IN` with an empty list, doesn't
+    /// count. Null and boolean checks such as `IS NOT NULL` don't scope the query
+    /// and don't count, nor do conditions inside filter groups, since an OR group
+    /// doesn't scope the query either, or the `with_default_scope` conditions.
+    /// Requiring a field the model doesn't have rejects every request.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Field that must be filtered on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct InvoiceExample {
+    ///     account_id: i64,
+    ///     amount: f64,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<InvoiceExample, Postgres>("SELECT * FROM invoices")
+    ///     .require_filter("account_id");
+    /// ```
+    pub fn require_filter(mut self, field: impl Into<String>) -> Self {
+        let field = field.into();
+
+        if !self.required_filters.contains(&field) {
+            self.required_filters.push(field);
+        }
+        self
+    }
+
+    /// Checks that the low bound of every BETWEEN and NOT BETWEEN condition is not
+    /// greater than its high bound.
+    ///
+    /// Bounds of numeric model fields are compared as numbers, so `100,10` on a
+    /// price is inverted, and bounds of any other field are compared as strings.
+    /// An inverted BETWEEN range matches no rows and an inverted NOT BETWEEN range
+    /// matches every row, which this check either corrects by swapping the bounds
+    /// or reports as `PaginationError::InvalidRange` from the fetch methods.
+    /// Without it, conditions are used as given.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Whether inverted ranges are swapped or rejected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{paginated_query_as, RangeBoundCheck};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct ProductExample {
+    ///     name: String,
+    ///     price: f64,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<ProductExample, Postgres>("SELECT * FROM products")
+    ///     .with_range_bound_check(RangeBoundCheck::Swap);
+    /// ```
+    pub fn with_range_bound_check(mut self, mode: RangeBoundCheck) -> Self {
+        self.range_bound_check = Some(mode);
+        if mode == RangeBoundCheck::Swap {
+            self.swap_inverted_ranges();
+        }
+        self
+    }
+
+    /// Returns the model fields whose bounds are compared as numbers.
+    fn numeric_fields() -> Vec<String> {
+        get_struct_field_meta::<T>()
+            .into_iter()
+            .filter(|field| field.field_type == FieldType::Number)
+            .map(|field| field.name)
+            .collect()
+    }
+
+    /// Swaps the bounds of the inverted [NOT] BETWEEN conditions of the parameters.
+    fn swap_inverted_ranges(&mut self) {
+        let numeric_fields = Self::numeric_fields();
+        let params = &mut self.params;
+        let conditions = params
+            .filters
+            .iter_mut()
+            .flat_map(|(column, conditions)| {
+                conditions
+                    .iter_mut()
+                    .map(move |condition| (&*column, condition))
+            })
+            .chain(params.filter_groups.iter_mut().flat_map(|group| {
+                group
+                    .conditions
+                    .iter_mut()
+                    .map(|(column, condition)| (&*column, condition))
+            }));
+
+        for (column, condition) in conditions {
+            if is_inverted_range(condition, numeric_fields.contains(column)) {
+                if let Some((low, high)) = condition.bounds() {
+                    condition.value = Some(format!("{},{}", high, low));
+                }
+            }
+        }
+    }
+
+    /// Returns the column of the first inverted [NOT] BETWEEN condition of the parameters.
+    fn find_inverted_range(&self) -> Option<String> {
+        let numeric_fields = Self::numeric_fields();
+
+        self.params
+            .filters
+            .iter()
+            .flat_map(|(column, conditions)| {
+                conditions.iter().map(move |condition| (column, condition))
+            })
+            .chain(self.params.filter_groups.iter().flat_map(|group| {
+                group
+                    .conditions
+                    .iter()
+                    .map(|(column, condition)| (column, condition))
+            }))
+            .find(|(column, condition)| {
+                is_inverted_range(condition, numeric_fields.contains(column))
+            })
+            .map(|(column, _)| column.clone())
+    }
+
+    /// Adds a CTE before `base_query`, producing `WITH name AS (sql), base_query AS (...) SELECT ...`.
+    ///
+    /// The base query and the joins of computed properties can reference the CTE,
+    /// e.g. a precomputed aggregate joined with
+    /// `LEFT JOIN order_totals ON order_totals.user_id = base_query.id`.
+    /// CTEs are emitted in the order they were added; adding a CTE with an existing
+    /// name replaces it.
+    ///
+    /// Placeholders in `sql` are written as if `args` were the only arguments
+    /// (`$1`, `$2` on PostgreSQL, `?` on SQLite). The arguments are bound after the
+    /// condition arguments and the placeholders are renumbered to match.
+    ///
+    /// The SQL is trusted and must never contain request input other than through
+    /// `args`. Empty names, the reserved `base_query` name and SQL containing `;`
+    /// are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the CTE
+    /// * `sql` - Query of the CTE
+    /// * `args` - Values bound to the placeholders of `sql`, in order
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, ComputedPropertyBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     name: String,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_prepended_cte(
+    ///         "order_totals",
+    ///         "SELECT user_id, SUM(amount) AS total FROM orders WHERE status = $1 GROUP BY user_id",
+    ///         vec!["paid".to_string()],
+    ///     )
+    ///     .with_computed_property(
+    ///         ComputedPropertyBuilder::new("order_total", "COALESCE(order_totals.total, 0)")
+    ///             .with_join("LEFT JOIN order_totals ON order_totals.user_id = base_query.id")
+    ///             .build(),
+    ///     );
+    /// ```
+    pub fn with_prepended_cte<V>(
+        mut self,
+        name: impl Into<String>,
+        sql: impl Into<String>,
+        args: Vec<V>,
+    ) -> Self
+    where
+        V: for<'e> Encode<'e, DB> + Type<DB> + Clone + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let sql = sql.into();
+        let sql = sql.trim().trim_end_matches(';').trim_end();
+
+        if name.is_empty() || name == "base_query" || sql.is_empty() || sql.contains(';') {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(name = %name, "Skipping invalid prepended CTE");
+            return self;
+        }
+
+        let arguments = args
+            .into_iter()
+            .map(|value| -> ArgumentBinder<DB> {
+                Box::new(move |arguments| arguments.add(value.clone()))
+            })
+            .collect();
+
+        self.prepended_ctes.retain(|existing| existing.name != name);
+        self.prepended_ctes.push(PrependedCte {
+            name,
+            sql: sql.to_string(),
+            bound_sql: sql.to_string(),
+            arguments,
+        });
+        self
+    }
+
+    /// Builds the conditions and arguments of the records query, binding the
+    /// values of its JOIN clauses.
+    fn build_conditions(
+        &mut self,
+        dialect: &dyn QueryDialect,
+    ) -> Result<(Vec<String>, DB::Arguments), sqlx::Error> {
+        let joins = Self::collect_joins(self.active_properties());
+        self.build_conditions_with_joins(dialect, joins, false)
+    }
+
+    /// Takes the conditions, `HAVING` conditions and arguments of a query builder,
+    /// after checking the required filters and applying the default scope.
+    ///
+    /// For dialects without numbered placeholders, the `HAVING` conditions are
+    /// numbered after the arguments bound before them, since the numbered
+    /// placeholders of the cursor or the count window come between the conditions
+    /// and the `HAVING` conditions in the statement.
+    fn build_having_conditions(
+        &self,
+        mut query_builder: QueryBuilder<'_, T, DB>,
+        dialect: &dyn QueryDialect,
+    ) -> Result<BuiltConditions<DB>, sqlx::Error> {
+        if let Some(error) = query_builder.encode_error.take() {
+            return Err(sqlx::Error::Encode(error));
+        }
+        self.check_required_filters(&query_builder.conditions, dialect)?;
+        if let Some(scope) = self
+            .default_scope
+            .as_ref()
+            .filter(|_| !self.include_deleted)
+        {
+            (query_builder.conditions, query_builder.arguments) = scope(
+                std::mem::take(&mut query_builder.conditions),
+                std::mem::take(&mut query_builder.arguments),
+            );
+        }
+        let offset = query_builder.arguments.len();
+        let (conditions, mut having, arguments) = query_builder.build_with_having();
+        if !having.is_empty() && !dialect.reuses_placeholders() {
+            having = vec![dialect.offset_placeholders(&having.join(" AND "), offset)];
+        }
+        Ok((conditions, having, arguments))
+    }
+
+    /// Builds the query conditions and arguments, binding the arguments of the
+    /// prepended CTEs and the values of the given JOIN clauses after the condition
+    /// arguments. The `with_default_scope` conditions follow the conditions of the
+    /// query builder function, and the `with_soft_delete` condition is added unless
+    /// `include_trashed`, both unless `with_include_deleted`.
+    ///
+    /// Placeholders of the CTEs and joins are offset past the condition arguments.
+    /// Since the CTEs and joins come first in the statement, the conditions are
+    /// renumbered as well for dialects with positional placeholders.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginationError::UnorderedArguments` when the CTEs or the joins
+    /// have arguments, or there are `HAVING` conditions, and the dialect can't
+    /// number its placeholders.
+    fn build_conditions_with_joins(
+        &mut self,
+        dialect: &dyn QueryDialect,
+        joins: Vec<PropertyJoin>,
+        include_trashed: bool,
+    ) -> Result<(Vec<String>, DB::Arguments), sqlx::Error> {
+        self.params.column_qualifier = (!joins.is_empty()).then(|| "base_query".to_string());
+        let scope = self
+            .default_scope
+            .as_ref()
+            .filter(|_| !self.include_deleted);
+        let (mut conditions, having, mut arguments) = match &self.build_query_fn {
+            QueryBuilderFn::Conditions(build_query_fn) => {
+                let (mut conditions, mut arguments) = build_query_fn(&self.params);
+                self.check_required_filters(&conditions, dialect)?;
+                if let Some(scope) = scope {
+                    (conditions, arguments) = scope(conditions, arguments);
+                }
+                (conditions, Vec::new(), arguments)
+            }
+            QueryBuilderFn::Having(build_query_fn) => {
+                self.build_having_conditions(build_query_fn(&self.params), dialect)?
+            }
+            QueryBuilderFn::Default(build_query_fn) => self.build_having_conditions(
+                build_query_fn(&self.params, &self.computed_properties),
+                dialect,
+            )?,
         };
+        if let (Some(column), false) = (
+            &self.soft_delete_column,
+            include_trashed || self.include_deleted,
+        ) {
+            conditions.push(format!(
+                "{} IS NULL",
+                self.build_records_column(column, !joins.is_empty())
+            ));
+        }
+
+        if !having.is_empty() && !dialect.numbers_placeholders() {
+            return Err(PaginationError::UnorderedArguments {
+                feature: "with_having_query_builder",
+            }
+            .into());
+        }
+
+        let binds_ctes = self
+            .prepended_ctes
+            .iter()
+            .any(|cte| !cte.arguments.is_empty());
+        let binds_joins = joins
+            .iter()
+            .any(|join| self.join_arguments.contains_key(join));
+        if binds_ctes && !dialect.numbers_placeholders() {
+            return Err(PaginationError::UnorderedArguments {
+                feature: "with_prepended_cte",
+            }
+            .into());
+        }
+        if binds_joins && !dialect.numbers_placeholders() {
+            return Err(PaginationError::UnorderedArguments {
+                feature: "with_join_bound",
+            }
+            .into());
+        }
 
-        let mut main_sql = format!("{} SELECT * FROM base_query{}", base_sql, where_clause);
-        main_sql.push_str(&self.build_order_clause());
-        main_sql.push_str(&self.build_limit_offset_clause());
+        if (binds_ctes || binds_joins) && !conditions.is_empty() {
+            conditions = vec![dialect.offset_placeholders(&conditions.join(" AND "), 0)];
+        }
+
+        if binds_ctes {
+            for cte in &mut self.prepended_ctes {
+                cte.bound_sql = dialect.offset_placeholders(&cte.sql, arguments.len());
+                for bind in &cte.arguments {
+                    bind(&mut arguments).map_err(sqlx::Error::Encode)?;
+                }
+            }
+        }
+
+        self.having = having;
+        self.bind_joins(&joins, &mut arguments, dialect)?;
 
-        let (total, total_pages, pagination) = if let Some(count_sql_str) = count_sql.as_ref() {
-            let (_, count_arguments) = (self.build_query_fn)(params_ref);
-            let pagination_arguments = self.params.pagination.clone();
+        if self.windowed_records {
+            self.bind_count_window(&mut conditions, &mut arguments, dialect, !joins.is_empty())?;
+        }
 
-            let count: i64 =
-                sqlx::query_scalar_with(AssertSqlSafe(count_sql_str.as_str()), count_arguments)
-                    .fetch_one(pool)
-                    .await?;
+        Ok((conditions, arguments))
+    }
 
-            let available_pages = match count {
-                0 => 0,
-                _ => (count + pagination_arguments.page_size - 1) / pagination_arguments.page_size,
+    /// Binds the values of the given JOIN clauses after the given arguments,
+    /// keeping the clauses with their placeholders for the query being built.
+    ///
+    /// References to the `with_base_table` table in the clauses are rewritten to
+    /// `base_query`, the name the base query is selected as.
+    fn bind_joins(
+        &mut self,
+        joins: &[PropertyJoin],
+        arguments: &mut DB::Arguments,
+        dialect: &dyn QueryDialect,
+    ) -> Result<(), sqlx::Error> {
+        let mut bound_joins = HashMap::new();
+        for property_join in joins {
+            let join = &property_join.0;
+            let requalified = match &self.base_table {
+                Some(table) => requalify_table_references(join, table, "base_query"),
+                None => join.clone(),
+            };
+            let Some(binders) = self.join_arguments.get(property_join) else {
+                if requalified != *join {
+                    bound_joins.insert(property_join.clone(), requalified);
+                }
+                continue;
             };
 
-            (
-                Some(count),
-                Some(available_pages),
-                Some(pagination_arguments),
-            )
-        } else {
-            (None, None, None)
-        };
+            let mut placeholders = Vec::with_capacity(binders.len());
+            for bind in binders {
+                bind(arguments).map_err(sqlx::Error::Encode)?;
+                placeholders.push(
+                    dialect.offset_placeholders(&dialect.placeholder(1), arguments.len() - 1),
+                );
+            }
 
-        let records =
-            sqlx::query_as_with::<sqlx::Sqlite, T, _>(AssertSqlSafe(main_sql), main_arguments)
-                .fetch_all(pool)
-                .await?;
+            let mut placeholders = placeholders.into_iter();
+            bound_joins.insert(
+                property_join.clone(),
+                rewrite_placeholders(&requalified, '?', |_| {
+                    placeholders.next().unwrap_or_default()
+                }),
+            );
+        }
 
-        Ok(PaginatedResponse {
-            records,
-            pagination,
-            total,
-            total_pages,
-        })
+        self.bound_joins = bound_joins;
+        Ok(())
+    }
+
+    /// Builds the conditions and arguments of the count query, scoped to the count
+    /// window if any, with the soft-deleted rows when `with_count_includes_trashed`.
+    fn build_count_conditions(
+        &mut self,
+        dialect: &dyn QueryDialect,
+    ) -> Result<(Vec<String>, DB::Arguments), sqlx::Error> {
+        let include_trashed = self.counts_trashed();
+        self.build_count_conditions_with_trashed(dialect, include_trashed)
+    }
+
+    /// Builds the conditions and arguments of the count query, scoped to the count
+    /// window if any, with the soft-deleted rows when `include_trashed`.
+    fn build_count_conditions_with_trashed(
+        &mut self,
+        dialect: &dyn QueryDialect,
+        include_trashed: bool,
+    ) -> Result<(Vec<String>, DB::Arguments), sqlx::Error> {
+        let joins = Self::collect_joins(self.counted_properties());
+        let joined = !joins.is_empty();
+        let (mut conditions, mut arguments) =
+            self.build_conditions_with_joins(dialect, joins, include_trashed)?;
+
+        if !self.windowed_records {
+            self.bind_count_window(&mut conditions, &mut arguments, dialect, joined)?;
+        }
+
+        Ok((conditions, arguments))
+    }
+
+    /// Binds the lower bound of the count window after the given arguments, adding
+    /// the condition keeping the rows newer than it, qualified with `base_query`
+    /// when the query is `joined`.
+    fn bind_count_window(
+        &self,
+        conditions: &mut Vec<String>,
+        arguments: &mut DB::Arguments,
+        dialect: &dyn QueryDialect,
+        joined: bool,
+    ) -> Result<(), sqlx::Error> {
+        if let Some(window) = &self.count_window {
+            (window.since)(arguments).map_err(sqlx::Error::Encode)?;
+            conditions.push(format!(
+                "{} >= {}",
+                self.build_records_column(&window.column, joined),
+                dialect.offset_placeholders(&dialect.placeholder(1), arguments.len() - 1)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Rejects pages starting past the given offset.
+    ///
+    /// Large OFFSET values make the database scan and discard every preceding row,
+    /// so deep pages get slower the further they are. Fetching a page whose offset
+    /// exceeds `max_offset` fails with `PaginationError::OffsetTooLarge`; use
+    /// keyset pagination when deep pages are needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_offset` - Largest allowed offset, in rows
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_max_offset(10_000);
+    /// ```
+    pub fn with_max_offset(mut self, max_offset: i64) -> Self {
+        self.max_offset = Some(max_offset.max(0));
+        self.max_offset_clamped = false;
+        self
+    }
+
+    /// Like `with_max_offset`, but fetches the last page within `max_offset`
+    /// instead of failing. The response reports the page actually fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_offset` - Largest allowed offset, in rows
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_clamped_max_offset(mut self, max_offset: i64) -> Self {
+        self.max_offset = Some(max_offset.max(0));
+        self.max_offset_clamped = true;
+        self
+    }
+
+    /// Replaces the maximum page size of 50 that requested page sizes are clamped
+    /// to, raising or lowering the LIMIT of the records query.
+    ///
+    /// The requested page size is clamped to the range from the minimum page size
+    /// of 10 to `max_page_size`, whether the parameters are set before or after.
+    /// A maximum below the minimum becomes the page size, and a maximum of 0 or
+    /// less falls back to the minimum page size rather than fetching no records.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_page_size` - Largest number of records per page
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_max_page_size(200);
+    /// ```
+    pub fn with_max_page_size(mut self, max_page_size: i64) -> Self {
+        self.max_page_size = Some(max_page_size);
+        self.params.pagination.clamp_to(max_page_size);
+        self
+    }
+
+    /// Checks the parameters against the configured guardrails.
+    fn check_params(&self) -> Result<(), PaginationError> {
+        if self.explicit_sort_required && !self.params.sort.is_explicit() {
+            return Err(PaginationError::MissingSort);
+        }
+        if self.range_bound_check == Some(RangeBoundCheck::Reject) {
+            if let Some(column) = self.find_inverted_range() {
+                return Err(PaginationError::InvalidRange { column });
+            }
+        }
+        self.check_distinct_on()
+    }
+
+    /// Rejects the conditions built for the parameters when a `require_filter`
+    /// field isn't compared to a value: the parameters must filter the field with
+    /// an operator taking a value, and one of the top-level conditions must start
+    /// with the column.
+    fn check_required_filters(
+        &self,
+        conditions: &[String],
+        dialect: &dyn QueryDialect,
+    ) -> Result<(), PaginationError> {
+        let missing = self.required_filters.iter().find(|field| {
+            let requested = self.params.filters.get(*field).is_some_and(|filters| {
+                filters.iter().any(|condition| {
+                    condition.operator.requires_value()
+                        && condition
+                            .value
+                            .as_deref()
+                            .is_some_and(|value| !value.trim().is_empty())
+                })
+            });
+
+            let column = self
+                .column_mappings
+                .get(*field)
+                .map_or(field.as_str(), String::as_str);
+            let reference = format!("{} ", dialect.quote_identifier(column));
+            let emitted = conditions.iter().any(|condition| {
+                condition
+                    .strip_prefix("base_query.")
+                    .unwrap_or(condition)
+                    .starts_with(&reference)
+            });
+
+            !(requested && emitted)
+        });
+
+        match missing {
+            Some(field) => Err(PaginationError::MissingRequiredFilter {
+                field: field.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Rejects `with_distinct_on` columns that aren't the leading sort terms, in
+    /// any order, as Postgres requires of `DISTINCT ON` expressions.
+    fn check_distinct_on(&self) -> Result<(), PaginationError> {
+        if self.distinct_on.is_empty() {
+            return Ok(());
+        }
+
+        let columns = self.build_distinct_on_columns(!self.build_active_joins().is_empty());
+        let leading: Vec<String> = self
+            .build_sort_terms()
+            .into_iter()
+            .take(columns.len())
+            .map(|(term, _, _)| term)
+            .collect();
+
+        if columns.iter().all(|column| leading.contains(column)) {
+            Ok(())
+        } else {
+            Err(PaginationError::UnsortedDistinctOn {
+                columns: self.distinct_on.clone(),
+            })
+        }
+    }
+
+    /// Disables the calculation of total record count.
+    ///
+    /// When disabled, the response will not include total count or total pages.
+    /// This can improve query performance for large datasets where the total
+    /// count is not needed.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn disable_totals_count(mut self) -> Self {
+        self.totals_count_enabled = false;
+        self
+    }
+
+    /// Enables the calculation of total record count, e.g. in keyset mode where
+    /// `with_cursor` disables it.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn enable_totals_count(mut self) -> Self {
+        self.totals_count_enabled = true;
+        self
+    }
+
+    /// Uses a total record count the caller already knows instead of counting.
+    ///
+    /// Useful for infinite scrolling: the total fetched with the first page can be
+    /// supplied when fetching the next pages with the same filters, skipping the
+    /// count query while still reporting the total and total pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `total` - Total number of records matching the filters
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_cached_total(1_250);
+    /// ```
+    pub fn with_cached_total(mut self, total: i64) -> Self {
+        self.totals_count_enabled = true;
+        self.cached_total = Some(total.max(0));
+        self
+    }
+
+    /// Shares a request-scoped `PaginationContext` with other builders, reusing the
+    /// totals they counted for the same count query and parameters, and telling
+    /// the context's observer of every total.
+    ///
+    /// A total given with `with_cached_total`, or read from a counter table, takes
+    /// precedence and isn't shared. Totals of builders whose count query may bind
+    /// values outside the parameters, through a custom query builder function,
+    /// `with_default_scope` or a prepended CTE with arguments, are counted every
+    /// time and still told to the observer.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Context shared by the builders of a request
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_context(mut self, context: &PaginationContext) -> Self {
+        self.context = Some(context.clone());
+        self
+    }
+
+    /// Reads the total from a counter table instead of counting the matching rows.
+    ///
+    /// For tables too large for `COUNT(*)` whose row count is maintained elsewhere,
+    /// for instance by triggers, as `SELECT count_column FROM table WHERE
+    /// name_column = key_value`. The counter ignores the filters, so the total is
+    /// reported as an estimate through `PaginatedResponse::total_is_estimate`.
+    /// The rows are counted when the counter table has no row for the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - Counter table, optionally qualified with its schema
+    /// * `name_column` - Column identifying the counted table
+    /// * `count_column` - Column holding the approximate count, as a `BIGINT`
+    /// * `key_value` - Value of `name_column` for the paginated table
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_counter_table("table_counts", "table_name", "approx_count", "users");
+    /// ```
+    pub fn with_counter_table<V>(
+        mut self,
+        table: &str,
+        name_column: &str,
+        count_column: &str,
+        key_value: V,
+    ) -> Self
+    where
+        V: for<'e> Encode<'e, DB> + Type<DB> + Clone + Send + Sync + 'static,
+    {
+        self.counter_table = Some(CounterTable {
+            table: table.to_string(),
+            name_column: name_column.to_string(),
+            count_column: count_column.to_string(),
+            key: Box::new(move |arguments| arguments.add(key_value.clone())),
+        });
+        self
+    }
+
+    /// Counts only the rows whose timestamp column is at or after `since`.
+    ///
+    /// For append-heavy tables where the meaningful total is the recent one, e.g.
+    /// events of the last 30 days, scoping the count to the window keeps it cheap
+    /// on an index of the column. The records are not scoped unless
+    /// `with_windowed_records` is called as well, so the total may then be lower
+    /// than the records reachable by paging.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Timestamp column bounding the window
+    /// * `since` - Oldest timestamp counted, inclusive
+    ///
+    /// # Details
+    ///
+    /// Only applies if the column exists in the model struct.
+    /// Logs a warning if tracing is enabled and the column is invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, Utc};
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct EventExample {
+    ///     name: String,
+    ///     created_at: Option<chrono::DateTime<Utc>>,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<EventExample, Postgres>("SELECT * FROM events")
+    ///     .with_count_window("created_at", Utc::now() - Duration::days(30));
+    /// ```
+    pub fn with_count_window(
+        mut self,
+        column: impl Into<String>,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Self
+    where
+        chrono::DateTime<chrono::Utc>: for<'e> Encode<'e, DB> + Type<DB>,
+    {
+        let column = column.into();
+
+        if get_struct_field_names::<T>().contains(&column) {
+            self.count_window = Some(CountWindow {
+                column,
+                since: Box::new(move |arguments| arguments.add(since)),
+                since_key: since.to_rfc3339(),
+            });
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping invalid count window column");
+        }
+        self
+    }
+
+    /// Scopes the records to the window of `with_count_window` as well, so that
+    /// the pages and the total cover the same rows.
+    ///
+    /// Has no effect without a count window.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_windowed_records(mut self) -> Self {
+        self.windowed_records = true;
+        self
+    }
+
+    /// Stops counting past a cap, for "99+" style counters where an exact count
+    /// of many rows is wasted work.
+    ///
+    /// The count query selects at most `cap + 1` matching rows, `SELECT COUNT(*)
+    /// FROM (SELECT 1 FROM base_query WHERE ... LIMIT cap + 1)`, so the database
+    /// can stop scanning early. When more rows match, the total is `cap + 1` and
+    /// `PaginatedResponse::total_capped_at` holds the cap. Total pages are then
+    /// counted from the capped total as well, and once the page reaches it
+    /// `has_next` is unknown and left out. Negative caps count as 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `cap` - Largest total reported exactly
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct NotificationExample {
+    ///     title: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<NotificationExample, Postgres>("SELECT * FROM notifications")
+    ///     .with_count_capped(99);
+    /// ```
+    pub fn with_count_capped(mut self, cap: i64) -> Self {
+        self.count_cap = Some(cap.max(0));
+        self
+    }
+
+    /// Hides soft-deleted rows, whose column is not NULL, from the records and
+    /// the total, adding `"column" IS NULL` to the conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column holding the deletion mark, e.g. `deleted_at`
+    ///
+    /// # Details
+    ///
+    /// Only applies if the column exists in the model struct.
+    /// Logs a warning if tracing is enabled and the column is invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct DocumentExample {
+    ///     title: String,
+    ///     deleted_at: Option<String>,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<DocumentExample, Postgres>("SELECT * FROM documents")
+    ///     .with_soft_delete("deleted_at");
+    /// ```
+    pub fn with_soft_delete(mut self, column: impl Into<String>) -> Self {
+        let column = column.into();
+
+        if get_struct_field_names::<T>().contains(&column) {
+            self.soft_delete_column = Some(column);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping invalid soft delete column");
+        }
+        self
+    }
+
+    /// Counts the soft-deleted rows of `with_soft_delete` in the total, while the
+    /// records still leave them out, e.g. for "3 of 10 (2 archived hidden)".
+    ///
+    /// The count query omits the soft-delete condition, and a second count query
+    /// keeps it for `PaginatedResponse::total_active`. Total pages, page navigation
+    /// and page tokens follow `total_active`, the rows the records are paged from.
+    /// Without `with_soft_delete` this has no effect.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct DocumentExample {
+    ///     title: String,
+    ///     deleted_at: Option<String>,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<DocumentExample, Postgres>("SELECT * FROM documents")
+    ///     .with_soft_delete("deleted_at")
+    ///     .with_count_includes_trashed();
+    /// ```
+    pub fn with_count_includes_trashed(mut self) -> Self {
+        self.count_includes_trashed = true;
+        self
+    }
+
+    /// Whether the total counts soft-deleted rows, with `total_active` counted apart.
+    fn counts_trashed(&self) -> bool {
+        self.count_includes_trashed && self.soft_delete_column.is_some() && !self.include_deleted
+    }
+
+    /// Adds conditions to every query, the records and the count, unless the
+    /// request opts out with `with_include_deleted`, e.g. to leave out the
+    /// soft-deleted rows of every listing.
+    ///
+    /// The scope receives a `QueryBuilder` holding the conditions and arguments of
+    /// the query builder function, and returns it with its own conditions added.
+    /// Values bound by the scope, e.g. with `QueryBuilder::with_condition`, are
+    /// numbered after the ones of the filters and before the ones of the
+    /// `with_having_query_builder` conditions, so they compose. The conditions
+    /// apply to the outer select of the `base_query` CTE.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Function adding the scope conditions to a `QueryBuilder`
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct DocumentExample {
+    ///     title: String,
+    ///     tenant_id: i64,
+    ///     deleted_at: Option<String>,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<DocumentExample, Postgres>("SELECT * FROM documents")
+    ///     .with_default_scope(|query_builder| {
+    ///         query_builder
+    ///             .with_raw_condition("\"deleted_at\" IS NULL")
+    ///             .with_condition("tenant_id", "=", "42".to_string())
+    ///     });
+    /// ```
+    pub fn with_default_scope<F>(mut self, scope: F) -> Self
+    where
+        F: for<'b> Fn(QueryBuilder<'b, T, DB>) -> QueryBuilder<'b, T, DB> + Send + Sync + 'static,
+        for<'b> QueryBuilder<'b, T, DB>: Default,
+    {
+        self.default_scope = Some(Box::new(move |conditions, arguments| {
+            let query_builder = scope(QueryBuilder {
+                conditions,
+                arguments,
+                ..QueryBuilder::default()
+            });
+            (query_builder.conditions, query_builder.arguments)
+        }));
+        self
+    }
+
+    /// Skips the `with_default_scope` conditions and the `with_soft_delete`
+    /// condition, for requests explicitly asking for the deleted rows as well.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct DocumentExample {
+    ///     title: String,
+    ///     deleted_at: Option<String>,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<DocumentExample, Postgres>("SELECT * FROM documents")
+    ///     .with_default_scope(|query_builder| query_builder.with_raw_condition("\"deleted_at\" IS NULL"))
+    ///     .with_include_deleted();
+    /// ```
+    pub fn with_include_deleted(mut self) -> Self {
+        self.include_deleted = true;
+        self
+    }
+
+    /// Returns the cap of `with_count_capped` when the total exceeds it.
+    fn build_total_capped_at(&self, total: Option<i64>) -> Option<i64> {
+        self.count_cap
+            .filter(|cap| total.is_some_and(|total| total > *cap))
+    }
+
+    /// Reports the values of a column on the first and last records of each page,
+    /// in `PaginatedResponse::page_bounds`, so that clients can build their own
+    /// cursors for the previous and next pages.
+    ///
+    /// Values are read from the serialized records: text as is, numbers and
+    /// booleans in their JSON form. Empty pages, and pages where either value is
+    /// `null`, have no bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column to report, usually the sort column
+    ///
+    /// # Details
+    ///
+    /// Only applies if the column exists in the model struct.
+    /// Logs a warning if tracing is enabled and the column is invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_page_bounds("name");
+    /// ```
+    pub fn with_page_bounds(mut self, column: impl Into<String>) -> Self {
+        let column = column.into();
+
+        if get_struct_field_names::<T>().contains(&column) {
+            self.page_bounds_column = Some(column);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping invalid page bounds column");
+        }
+        self
+    }
+
+    /// Switches to keyset pagination, fetching the records sorted after a cursor
+    /// rather than skipping `OFFSET` rows, so that deep pages stay fast.
+    ///
+    /// Records are sorted by the column then the tie breaker, both in the given
+    /// direction, and the records query selects the page with
+    /// `WHERE (column, tie_breaker) > (value, tie_breaker_value) ... LIMIT n`
+    /// (`<` when descending). The tie breaker, usually the primary key, keeps the
+    /// order total when the column has duplicate values. Both columns must be
+    /// `NOT NULL`, since the comparison skips rows with NULL values.
+    ///
+    /// The response carries the cursor of the next page in
+    /// `PaginatedResponse::next_cursor`, and of the previous page in
+    /// `previous_cursor`, fetched with `with_cursor_direction`. The page and sort
+    /// of the parameters are not used. Totals are disabled, since pages have no number; use
+    /// `enable_totals_count` to still count every matching record.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column to sort by
+    /// * `tie_breaker` - Unique column ordering records with equal sort values
+    /// * `cursor` - `next_cursor` of the previous page, or `None` for the first page
+    /// * `direction` - Sort direction of both columns
+    ///
+    /// # Details
+    ///
+    /// Cursor values are bound as the type of their model field: numbers and
+    /// booleans as such, and other values as text, so a text column holding `400`
+    /// is compared as text.
+    ///
+    /// Only applies if both columns exist in the model struct and neither is an
+    /// `Option` field, which may hold NULL values. Invalid cursors fetch the first
+    /// page. Logs a warning if tracing is enabled and the columns or the cursor are
+    /// invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{paginated_query_as, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     name: String
+    /// }
+    ///
+    /// # fn cursor_from_request() -> Option<String> { None }
+    /// let cursor: Option<String> = cursor_from_request();
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_cursor("name", "id", cursor.as_deref(), QuerySortDirection::Ascending);
+    /// ```
+    pub fn with_cursor(
+        mut self,
+        column: impl Into<String>,
+        tie_breaker: impl Into<String>,
+        cursor: Option<&str>,
+        direction: QuerySortDirection,
+    ) -> Self
+    where
+        String: for<'e> Encode<'e, DB> + Type<DB>,
+        i64: for<'e> Encode<'e, DB> + Type<DB>,
+        f64: for<'e> Encode<'e, DB> + Type<DB>,
+        bool: for<'e> Encode<'e, DB> + Type<DB>,
+    {
+        let column = column.into();
+        let tie_breaker = tie_breaker.into();
+        let fields = get_struct_field_meta::<T>();
+        let field_type = |column: &str| {
+            fields
+                .iter()
+                .find(|field| field.name == column)
+                .map(|field| field.field_type)
+        };
+
+        let (Some(column_type), Some(tie_breaker_type)) =
+            (field_type(&column), field_type(&tie_breaker))
+        else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, tie_breaker = %tie_breaker, "Skipping invalid cursor columns");
+            return self;
+        };
+        if column_type == FieldType::Unknown || tie_breaker_type == FieldType::Unknown {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, tie_breaker = %tie_breaker, "Skipping nullable cursor columns");
+            return self;
+        }
+
+        let after = cursor.and_then(|cursor| {
+            let decoded = KeysetCursor::decode(cursor);
+            if decoded.is_none() {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(cursor = %cursor, "Ignoring invalid cursor");
+            }
+            decoded
+        });
+
+        self.keyset = Some(Keyset {
+            direction,
+            after: after.map(|cursor| {
+                let values = [
+                    CursorValue::new(cursor.value.clone(), column_type),
+                    CursorValue::new(cursor.tie_breaker.clone(), tie_breaker_type),
+                ];
+                (cursor, values)
+            }),
+            column,
+            tie_breaker,
+        });
+        self.totals_count_enabled = false;
+        self
+    }
+
+    /// Sets which side of the `with_cursor` cursor the page is fetched from.
+    ///
+    /// Backward pages select the records sorted before the cursor, by flipping the
+    /// comparison and the `ORDER BY` of the records query, and are returned in the
+    /// order of `with_cursor`. Paging backward from the `previous_cursor` of a page
+    /// fetches the page before it, and without a cursor fetches the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - `KeysetDirection::Backward` for the page before the cursor
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{paginated_query_as, KeysetDirection, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     name: String
+    /// }
+    ///
+    /// # fn previous_cursor_from_request() -> Option<String> { None }
+    /// let cursor: Option<String> = previous_cursor_from_request();
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_cursor("name", "id", cursor.as_deref(), QuerySortDirection::Ascending)
+    ///     .with_cursor_direction(KeysetDirection::Backward);
+    /// ```
+    pub fn with_cursor_direction(mut self, direction: KeysetDirection) -> Self {
+        self.keyset_direction = direction;
+        self
+    }
+
+    /// Returns the direction records are scanned in from the cursor, the
+    /// `with_cursor` direction flipped for backward pages.
+    fn keyset_scan_direction(&self, keyset: &Keyset<DB>) -> QuerySortDirection {
+        match (self.keyset_direction, &keyset.direction) {
+            (KeysetDirection::Forward, direction) => direction.clone(),
+            (KeysetDirection::Backward, QuerySortDirection::Ascending) => {
+                QuerySortDirection::Descending
+            }
+            (KeysetDirection::Backward, QuerySortDirection::Descending) => {
+                QuerySortDirection::Ascending
+            }
+        }
+    }
+
+    /// Sorts NULLs of a column as the given sentinel value, by ordering on
+    /// `COALESCE(column, sentinel)`, e.g. so that NULL names sort as an empty string.
+    ///
+    /// Unlike `NULLS FIRST`/`NULLS LAST`, this works the same on every database.
+    /// The sentinel is bound as an argument of the records query, and only when
+    /// the column is part of the sort.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Sort column whose NULLs are replaced
+    /// * `sentinel` - Value NULLs sort as, of the column type
+    ///
+    /// # Details
+    ///
+    /// Only applies if the column exists in the model struct.
+    /// Logs a warning if tracing is enabled and the column is invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: Option<String>
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_sort_coalesce("name", String::new());
+    /// ```
+    pub fn with_sort_coalesce<V>(mut self, column: impl Into<String>, sentinel: V) -> Self
+    where
+        V: for<'e> Encode<'e, DB> + Type<DB> + Clone + Send + Sync + 'static,
+    {
+        let column = column.into();
+
+        if get_struct_field_names::<T>().contains(&column) {
+            self.sort_coalesce = Some(SortCoalesce {
+                column,
+                sentinel: Box::new(move |arguments| arguments.add(sentinel.clone())),
+                placeholder: None,
+            });
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping invalid sort coalesce column");
+        }
+        self
+    }
+
+    /// Sorts a column in a custom order of its values rather than alphabetically,
+    /// e.g. `priority` as `high`, `medium`, `low`, by ordering on
+    /// `CASE column WHEN 'high' THEN 0 WHEN 'medium' THEN 1 ... END`.
+    ///
+    /// Values missing from the order sort after the listed ones. The values are
+    /// inlined into the SQL as quoted literals, since they come from the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Sort column ordered by its values
+    /// * `order` - Values of the column in ascending order
+    ///
+    /// # Details
+    ///
+    /// Only applies if the column exists in the model struct and the order lists
+    /// at least one value.
+    /// Logs a warning if tracing is enabled and the column or order is invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct TaskExample {
+    ///     priority: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<TaskExample, Postgres>("SELECT * FROM tasks")
+    ///     .with_sort_order_map("priority", vec!["high", "medium", "low"]);
+    /// ```
+    pub fn with_sort_order_map(mut self, column: impl Into<String>, order: Vec<&str>) -> Self {
+        let column = column.into();
+
+        if order.is_empty() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping empty sort order map");
+        } else if get_struct_field_names::<T>().contains(&column) {
+            self.sort_order_maps.insert(
+                column,
+                order.into_iter().map(|value| value.to_string()).collect(),
+            );
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping invalid sort order map column");
+        }
+        self
+    }
+
+    /// Prepares the sort terms for the dialect of the records query: records
+    /// whether it supports `NULLS FIRST`/`NULLS LAST`, and binds the
+    /// `with_sort_coalesce` sentinel after the given arguments, when its column is
+    /// sorted on, so that the sort terms can refer to it.
+    fn bind_sort_terms(
+        &mut self,
+        arguments: &mut DB::Arguments,
+        dialect: &dyn QueryDialect,
+    ) -> Result<(), sqlx::Error> {
+        self.native_nulls_ordering = dialect.supports_nulls_ordering();
+
+        if self.keyset.is_some() {
+            return Ok(());
+        }
+
+        let sorts = self.params.sort.sorts();
+        let Some(coalesce) = self
+            .sort_coalesce
+            .as_mut()
+            .filter(|coalesce| sorts.iter().any(|sort| sort.column == coalesce.column))
+        else {
+            return Ok(());
+        };
+
+        (coalesce.sentinel)(arguments).map_err(sqlx::Error::Encode)?;
+        coalesce.placeholder =
+            Some(dialect.offset_placeholders(&dialect.placeholder(1), arguments.len() - 1));
+        Ok(())
+    }
+
+    /// Rejects a bound `with_sort_coalesce` sentinel for queries referring to the
+    /// sort terms before the conditions, which dialects that can't number their
+    /// placeholders can't bind out of order.
+    fn check_sort_terms_order(&self, dialect: &dyn QueryDialect) -> Result<(), PaginationError> {
+        let bound = self
+            .sort_coalesce
+            .as_ref()
+            .is_some_and(|coalesce| coalesce.placeholder.is_some());
+
+        if bound && !dialect.numbers_placeholders() {
+            return Err(PaginationError::UnorderedArguments {
+                feature: "with_sort_coalesce",
+            });
+        }
+        Ok(())
+    }
+
+    /// Reports a `PaginatedResponse::next_page_token` when there may be a
+    /// next page, for AIP-158 style APIs reading the page from a `page_token`.
+    ///
+    /// With totals, the token is set when records remain past the current page.
+    /// Without totals, it is set whenever the page is full, so the last page may
+    /// be followed by an empty one.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_page_tokens();
+    /// ```
+    pub fn with_page_tokens(mut self) -> Self {
+        self.page_tokens_enabled = true;
+        self
+    }
+
+    /// Returns the token of the page following the current one, if any.
+    fn build_next_page_token(&self, records: usize, total: Option<i64>) -> Option<String> {
+        if !self.page_tokens_enabled {
+            return None;
+        }
+
+        let pagination = &self.params.pagination;
+        let offset = pagination.offset() + pagination.page_size;
+        let capped = self.build_total_capped_at(total).is_some();
+        // Past a capped total, more records may follow
+        let has_next = match total {
+            Some(total) if offset < total => true,
+            Some(_) if !capped => false,
+            _ => records as i64 >= pagination.page_size,
+        };
+
+        has_next.then(|| PageToken { offset }.encode())
+    }
+
+    /// Returns the page bounds column values of the first and last records.
+    fn build_page_bounds<'r>(
+        &self,
+        mut records: impl DoubleEndedIterator<Item = &'r T>,
+    ) -> Option<(String, String)>
+    where
+        T: 'r,
+    {
+        let column = self.page_bounds_column.as_deref()?;
+        let first = records.next()?;
+        let last = records.next_back().unwrap_or(first);
+
+        Some((
+            serialized_column_value(first, column)?,
+            serialized_column_value(last, column)?,
+        ))
+    }
+
+    /// Returns the registered computed property with the given name, if any.
+    fn computed_property(&self, name: &str) -> Option<&ComputedProperty> {
+        self.computed_properties
+            .iter()
+            .find(|property| property.name == name)
+    }
+
+    /// Builds the CTEs (Common Table Expressions) preceding the query: the
+    /// prepended CTEs, then the base query as `base_query` unless it is inlined.
+    ///
+    /// Wrapping keeps set operations such as `UNION ALL` intact: filters, sorting
+    /// and counting apply to the combined rows. A trailing statement terminator
+    /// is removed since it is not allowed inside the CTE.
+    fn build_base_ctes(&self) -> Vec<String> {
+        let mut ctes: Vec<String> = self
+            .prepended_ctes
+            .iter()
+            .map(|cte| format!("{} AS ({})", self.quote(&cte.name), cte.bound_sql))
+            .collect();
+        if !self.inline_base_query {
+            ctes.push(format!("base_query AS ({})", self.build_base_sql()));
+        }
+        ctes
+    }
+
+    /// Builds the `WITH` clause of the base CTEs, followed by a space, or an
+    /// empty string when there are none.
+    fn build_base_query(&self) -> String {
+        let ctes = self.build_base_ctes();
+        if ctes.is_empty() {
+            return String::new();
+        }
+
+        format!("WITH {} ", ctes.join(", "))
+    }
+
+    /// Returns the base query without a trailing statement terminator.
+    fn build_base_sql(&self) -> &str {
+        self.base_sql.as_str().trim_end().trim_end_matches(';')
+    }
+
+    /// Returns what the records and count queries select from: the `base_query`
+    /// CTE, or the base query as a `base_query` subquery when inlined.
+    fn build_base_source(&self) -> String {
+        if self.inline_base_query {
+            format!("({}) AS base_query", self.build_base_sql())
+        } else {
+            "base_query".to_string()
+        }
+    }
+
+    /// Builds the WHERE clause from the provided conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `conditions` - Vector of condition strings to join with AND
+    ///
+    /// # Returns
+    ///
+    /// Returns the formatted WHERE clause or empty string if no conditions
+    fn build_where_clause(&self, conditions: &[String]) -> String {
+        if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        }
+    }
+
+    /// Builds the HAVING clause of the `HAVING` conditions, or an empty string
+    /// when there are none.
+    fn build_having_clause(&self) -> String {
+        if self.having.is_empty() {
+            String::new()
+        } else {
+            format!(" HAVING {}", self.having.join(" AND "))
+        }
+    }
+
+    /// Returns the computed properties used by the records query: every selected
+    /// property plus the ones referenced by the sort columns, searched or filtered.
+    fn active_properties(&self) -> Vec<&ComputedProperty> {
+        let sorts = self.params.sort.sorts();
+        let searched = self.searched_properties();
+        let filtered = self.filtered_properties();
+        self.computed_properties
+            .iter()
+            .filter(|property| {
+                property.selected
+                    || sorts.iter().any(|sort| sort.column == property.name)
+                    || searched.contains(property)
+                    || filtered.contains(property)
+            })
+            .collect()
+    }
+
+    /// Returns the aggregate computed properties named by a filter, which the
+    /// `HAVING` conditions may refer to.
+    fn filtered_properties(&self) -> Vec<&ComputedProperty> {
+        self.computed_properties
+            .iter()
+            .filter(|property| {
+                property.aggregate && self.params.filters.contains_key(&property.name)
+            })
+            .collect()
+    }
+
+    /// Returns the computed properties whose joins the count query needs: the
+    /// searched properties and the filtered aggregate properties.
+    fn counted_properties(&self) -> Vec<&ComputedProperty> {
+        let mut properties = self.searched_properties();
+        for property in self.filtered_properties() {
+            if !properties.contains(&property) {
+                properties.push(property);
+            }
+        }
+        properties
+    }
+
+    /// Returns the searchable computed properties named in the search columns,
+    /// when a search term is present.
+    fn searched_properties(&self) -> Vec<&ComputedProperty> {
+        if self.params.search.terms().is_empty() {
+            return Vec::new();
+        }
+
+        let columns = self.params.search.columns();
+        self.computed_properties
+            .iter()
+            .filter(|property| property.searchable && columns.contains(&property.name.as_str()))
+            .collect()
+    }
+
+    /// Returns the JOIN clauses of the searched and filtered aggregate computed
+    /// properties, without duplicates. The conditions may refer to them, so the
+    /// count query needs them.
+    fn build_count_joins(&self) -> Vec<&str> {
+        self.build_bound_joins(self.counted_properties())
+    }
+
+    /// Returns the JOIN clauses of the given properties with their values, without
+    /// duplicates. A clause bound to different values by two properties is kept
+    /// once for each.
+    fn collect_joins(properties: Vec<&ComputedProperty>) -> Vec<PropertyJoin> {
+        let mut joins: Vec<PropertyJoin> = Vec::new();
+        for property in properties {
+            for join in &property.joins {
+                let join = (
+                    join.clone(),
+                    property.join_values.get(join).cloned().unwrap_or_default(),
+                );
+                if !joins.contains(&join) {
+                    joins.push(join);
+                }
+            }
+        }
+        joins
+    }
+
+    /// Returns the JOIN clauses of the given properties, without duplicates, with
+    /// the placeholders bound to their values in the query being built.
+    fn build_bound_joins<'p>(&'p self, properties: Vec<&'p ComputedProperty>) -> Vec<&'p str> {
+        let mut joins: Vec<&str> = Vec::new();
+        for property in properties {
+            for join in &property.joins {
+                let values = property.join_values.get(join).cloned().unwrap_or_default();
+                let join = self
+                    .bound_joins
+                    .get(&(join.clone(), values))
+                    .unwrap_or(join)
+                    .as_str();
+                if !joins.contains(&join) {
+                    joins.push(join);
+                }
+            }
+        }
+        joins
+    }
+
+    /// Returns the model columns the records are grouped by when an active
+    /// computed property is an aggregate or there are `HAVING` conditions, or when
+    /// `with_distinct` de-duplicates joined rows, or `None` when no grouping is needed.
+    fn build_group_columns(&self) -> Option<Vec<String>> {
+        let aggregated = !self.having.is_empty()
+            || self
+                .active_properties()
+                .iter()
+                .any(|property| property.aggregate);
+        let deduplicated = self.distinct && !self.build_active_joins().is_empty();
+        if !aggregated && !deduplicated {
+            return None;
+        }
+
+        Some(
+            get_struct_field_names::<T>()
+                .into_iter()
+                .filter(|field| {
+                    !self
+                        .computed_properties
+                        .iter()
+                        .any(|property| &property.name == field)
+                })
+                .map(|field| self.build_records_column(&field, true))
+                .collect(),
+        )
+    }
+
+    /// Returns the JOIN clauses of the active computed properties, without duplicates.
+    fn build_active_joins(&self) -> Vec<&str> {
+        self.build_bound_joins(self.active_properties())
+    }
+
+    /// Returns the quoted `with_distinct_on` columns of the records query.
+    fn build_distinct_on_columns(&self, joined: bool) -> Vec<String> {
+        self.distinct_on
+            .iter()
+            .map(|column| self.build_records_column(column, joined))
+            .collect()
+    }
+
+    /// Returns the `DISTINCT ON (...)` of the records selection, or an empty
+    /// string without `with_distinct_on` columns.
+    fn build_distinct_on_clause(&self, joined: bool) -> String {
+        if self.distinct_on.is_empty() {
+            return String::new();
+        }
+        format!(
+            "DISTINCT ON ({}) ",
+            self.build_distinct_on_columns(joined).join(", ")
+        )
+    }
+
+    /// Quotes a column of the records query, qualifying it with `base_query` when
+    /// joined tables could make the name ambiguous.
+    fn build_records_column(&self, column: &str, joined: bool) -> String {
+        let column = self
+            .column_mappings
+            .get(column)
+            .map_or(column, String::as_str);
+        if joined && !column.contains('.') {
+            format!("base_query.{}", self.quote(column))
+        } else {
+            self.quote(column)
+        }
+    }
+
+    /// Quotes an identifier, qualified or not, for the dialect of the query.
+    fn quote(&self, identifier: &str) -> String {
+        match self.dialect {
+            Some(dialect) => identifier
+                .split('.')
+                .map(|part| dialect.quote_identifier(part))
+                .collect::<Vec<_>>()
+                .join("."),
+            None => quote_identifier(identifier),
+        }
+    }
+
+    /// Builds the SELECT and FROM clauses of the records query.
+    ///
+    /// Without active computed properties all `base_query` columns are selected,
+    /// unless a custom selection was set with `with_records_select`.
+    /// Otherwise selected expressions are appended under their property names and
+    /// the joins they require are added; aggregated queries select the grouped
+    /// model columns instead of `base_query.*`. Extra expressions are appended last.
+    fn build_records_from_clause(
+        &self,
+        group_columns: Option<&[String]>,
+        extra_selection: &[String],
+    ) -> String {
+        let active = self.active_properties();
+        let joins = self.build_active_joins();
+
+        if group_columns.is_none() && joins.is_empty() && !active.iter().any(|p| p.selected) {
+            let mut selection = vec![self.records_select.as_deref().unwrap_or("*").to_string()];
+            selection.extend_from_slice(extra_selection);
+            return format!(
+                "SELECT {}{} FROM {}",
+                self.build_distinct_on_clause(false),
+                selection.join(", "),
+                self.build_base_source()
+            );
+        }
+
+        let mut selection = match (&self.records_select, group_columns) {
+            (Some(select), _) => vec![select.clone()],
+            (None, Some(columns)) if !columns.is_empty() => columns.to_vec(),
+            _ => vec!["base_query.*".to_string()],
+        };
+        selection.extend(
+            active
+                .iter()
+                .filter(|property| property.selected)
+                .map(|property| {
+                    format!(
+                        "({}) AS {}",
+                        property.expression,
+                        self.quote(&property.name)
+                    )
+                }),
+        );
+        selection.extend_from_slice(extra_selection);
+
+        let mut clause = format!(
+            "SELECT {}{} FROM {}",
+            self.build_distinct_on_clause(!joins.is_empty()),
+            selection.join(", "),
+            self.build_base_source()
+        );
+        for join in joins {
+            clause.push(' ');
+            clause.push_str(join);
+        }
+        clause
+    }
+
+    /// Returns the sort expressions of the records query with their directions,
+    /// in order of precedence.
+    ///
+    /// In keyset mode, these are the `with_cursor` column and tie breaker, in
+    /// the scan direction of `with_cursor_direction`.
+    /// Columns naming a computed property emit its expression, followed by its
+    /// tie breaker. Without an explicit sort, the default sort column falls back to
+    /// `updated_at` or `id` when the model lacks `created_at`. The `with_sort_coalesce` column is wrapped
+    /// in `COALESCE()` once its sentinel is bound. Columns with a `with_sort_order_map`
+    /// order emit a `CASE` expression ranking their values, while other case
+    /// insensitive sorts wrap the column in `LOWER()`. A `with_sort_nulls` placement
+    /// is kept with its term when the dialect supports `NULLS FIRST`/`NULLS LAST`,
+    /// and is otherwise emulated by a preceding `IS NULL` term, unless the column
+    /// is coalesced and can't be NULL.
+    fn build_sort_terms(&self) -> Vec<(String, QuerySortDirection, QueryNullsPosition)> {
+        let joined = !self.build_active_joins().is_empty();
+        let mut terms = Vec::new();
+
+        if let Some(keyset) = &self.keyset {
+            for column in [&keyset.column, &keyset.tie_breaker] {
+                terms.push((
+                    self.build_records_column(column, joined),
+                    self.keyset_scan_direction(keyset),
+                    QueryNullsPosition::Default,
+                ));
+            }
+            return terms;
+        }
+
+        for sort in self.params.sort.sorts() {
+            match self.computed_property(&sort.column) {
+                Some(property) => {
+                    terms.push((
+                        format!("({})", property.expression),
+                        sort.direction.clone(),
+                        QueryNullsPosition::Default,
+                    ));
+                    if let Some(tie_breaker) = &property.tie_breaker {
+                        terms.push((
+                            self.build_records_column(tie_breaker, joined),
+                            sort.direction.clone(),
+                            QueryNullsPosition::Default,
+                        ));
+                    }
+                }
+                None => {
+                    let sort_column = if self.params.sort.is_explicit() {
+                        sort.column.clone()
+                    } else {
+                        resolve_sort_column::<T>(&sort.column)
+                    };
+                    let mut term = self.build_records_column(&sort_column, joined);
+                    let mut coalesced = false;
+                    if let Some(SortCoalesce {
+                        column,
+                        placeholder: Some(placeholder),
+                        ..
+                    }) = &self.sort_coalesce
+                    {
+                        if *column == sort.column {
+                            term = format!("COALESCE({}, {})", term, placeholder);
+                            coalesced = true;
+                        }
+                    }
+                    if let Some(order) = self.sort_order_maps.get(&sort.column) {
+                        term = build_sort_order_case(&term, order);
+                    } else if self.params.sort.is_case_insensitive() {
+                        term = format!("LOWER({})", term);
+                    }
+                    match sort.nulls {
+                        QueryNullsPosition::Default => {}
+                        _ if self.native_nulls_ordering || coalesced => {}
+                        QueryNullsPosition::First => terms.push((
+                            format!("{} IS NULL", term),
+                            QuerySortDirection::Descending,
+                            QueryNullsPosition::Default,
+                        )),
+                        QueryNullsPosition::Last => terms.push((
+                            format!("{} IS NULL", term),
+                            QuerySortDirection::Ascending,
+                            QueryNullsPosition::Default,
+                        )),
+                    }
+                    let nulls = if self.native_nulls_ordering {
+                        sort.nulls
+                    } else {
+                        QueryNullsPosition::Default
+                    };
+                    terms.push((term, sort.direction, nulls));
+                }
+            }
+        }
+
+        terms
+    }
+
+    /// Builds the ORDER BY clause based on sort parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns the formatted ORDER BY clause with every sort term and proper column quoting.
+    fn build_order_clause(&self) -> String {
+        let terms: Vec<String> = self
+            .build_sort_terms()
+            .into_iter()
+            .map(|(term, direction, nulls)| {
+                let order = match direction {
+                    QuerySortDirection::Ascending => "ASC",
+                    QuerySortDirection::Descending => "DESC",
+                };
+                match nulls {
+                    QueryNullsPosition::First => format!("{} {} NULLS FIRST", term, order),
+                    QueryNullsPosition::Last => format!("{} {} NULLS LAST", term, order),
+                    QueryNullsPosition::Default => format!("{} {}", term, order),
+                }
+            })
+            .collect();
+
+        format!(" ORDER BY {}", terms.join(", "))
+    }
+
+    /// Appends a value to the arguments, returning the placeholder referring to it.
+    ///
+    /// The placeholder is numbered, as positional placeholders would restart after
+    /// conditions renumbered for prepended CTEs.
+    fn push_argument<V>(
+        arguments: &mut DB::Arguments,
+        dialect: &dyn QueryDialect,
+        value: V,
+    ) -> Result<String, sqlx::Error>
+    where
+        V: for<'e> Encode<'e, DB> + Type<DB>,
+    {
+        arguments.add(value).map_err(sqlx::Error::Encode)?;
+        Ok(dialect.offset_placeholders(&dialect.placeholder(1), arguments.len() - 1))
+    }
+
+    /// Builds the LIMIT and OFFSET clause for the given placeholders, or only the
+    /// LIMIT clause for the first page, where the offset is 0.
+    fn build_limit_offset_clause(
+        &self,
+        limit_placeholder: &str,
+        offset_placeholder: Option<&str>,
+    ) -> String {
+        match offset_placeholder {
+            Some(offset_placeholder) => {
+                format!(" LIMIT {} OFFSET {}", limit_placeholder, offset_placeholder)
+            }
+            None => format!(" LIMIT {}", limit_placeholder),
+        }
+    }
+
+    /// Binds the page size and offset after the given arguments, so that the SQL
+    /// is the same for every page but the first, which has no offset.
+    ///
+    /// # Returns
+    ///
+    /// Returns the LIMIT and OFFSET clause referring to the bound values, without
+    /// OFFSET on the first page
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginationError::OffsetTooLarge` when the offset exceeds the maximum
+    /// offset, unless it is clamped, in which case the page is moved back to the last
+    /// page within the maximum.
+    fn bind_limit_offset(
+        &mut self,
+        arguments: &mut DB::Arguments,
+        dialect: &dyn QueryDialect,
+    ) -> Result<String, sqlx::Error> {
+        let pagination = &mut self.params.pagination;
+        let mut offset = pagination.offset();
+
+        if let Some(max_offset) = self.max_offset.filter(|max_offset| offset > *max_offset) {
+            if !self.max_offset_clamped {
+                return Err(PaginationError::OffsetTooLarge { offset, max_offset }.into());
+            }
+            pagination.token_offset = None;
+            pagination.page = max_offset / pagination.page_size + 1;
+            offset = pagination.offset();
+        }
+        let pagination = &self.params.pagination;
+
+        let limit_placeholder = Self::push_argument(arguments, dialect, pagination.page_size)?;
+        let offset_placeholder = match offset {
+            0 => None,
+            _ => Some(Self::push_argument(arguments, dialect, offset)?),
+        };
+
+        Ok(self.build_limit_offset_clause(&limit_placeholder, offset_placeholder.as_deref()))
+    }
+
+    /// Binds the arguments selecting the requested page after the given arguments.
+    ///
+    /// In keyset mode, adds the condition selecting the records after the cursor,
+    /// or before it for backward pages, and fetches one record more than the page
+    /// size to tell whether there is a further page. Otherwise binds the page size and offset.
+    ///
+    /// # Returns
+    ///
+    /// Returns the LIMIT clause referring to the bound values
+    fn bind_page(
+        &mut self,
+        conditions: &mut Vec<String>,
+        arguments: &mut DB::Arguments,
+        dialect: &dyn QueryDialect,
+    ) -> Result<String, sqlx::Error> {
+        let Some(keyset) = &self.keyset else {
+            return self.bind_limit_offset(arguments, dialect);
+        };
+
+        if let Some((cursor, values)) = &keyset.after {
+            let mut placeholders = Vec::new();
+            for (value, bound) in [&cursor.value, &cursor.tie_breaker].into_iter().zip(values) {
+                (bound.bind)(arguments).map_err(sqlx::Error::Encode)?;
+                let placeholder =
+                    dialect.offset_placeholders(&dialect.placeholder(1), arguments.len() - 1);
+                placeholders.push(if bound.cast {
+                    dialect.cast_placeholder(&placeholder, value)
+                } else {
+                    placeholder
+                });
+            }
+
+            let joined = !self.build_active_joins().is_empty();
+            let comparison = match self.keyset_scan_direction(keyset) {
+                QuerySortDirection::Ascending => ">",
+                QuerySortDirection::Descending => "<",
+            };
+            conditions.push(format!(
+                "({}, {}) {} ({})",
+                self.build_records_column(&keyset.column, joined),
+                self.build_records_column(&keyset.tie_breaker, joined),
+                comparison,
+                placeholders.join(", ")
+            ));
+        }
+
+        let limit_placeholder =
+            Self::push_argument(arguments, dialect, self.params.pagination.page_size + 1)?;
+        Ok(format!(" LIMIT {}", limit_placeholder))
+    }
+
+    /// Drops the extra record fetched in keyset mode and restores the order of
+    /// backward pages, returning the `next_cursor` and `previous_cursor` built
+    /// from the last and first records of the page.
+    ///
+    /// The cursor on the side the page was fetched towards is set when the extra
+    /// record was fetched, and the one on the side of the given cursor when a
+    /// cursor was given.
+    fn build_cursors<R>(
+        &self,
+        records: &mut Vec<R>,
+        record: impl Fn(&R) -> &T,
+    ) -> (Option<String>, Option<String>) {
+        let Some(keyset) = &self.keyset else {
+            return (None, None);
+        };
+        let page_size = usize::try_from(self.params.pagination.page_size).unwrap_or(0);
+        let overflowed = records.len() > page_size;
+        records.truncate(page_size);
+
+        let (has_next, has_previous) = match self.keyset_direction {
+            KeysetDirection::Forward => (overflowed, keyset.after.is_some()),
+            KeysetDirection::Backward => {
+                records.reverse();
+                (keyset.after.is_some(), overflowed)
+            }
+        };
+        let cursor = |record: &T| {
+            Some(
+                KeysetCursor {
+                    value: serialized_column_value(record, &keyset.column)?,
+                    tie_breaker: serialized_column_value(record, &keyset.tie_breaker)?,
+                }
+                .encode(),
+            )
+        };
+
+        (
+            records
+                .last()
+                .filter(|_| has_next)
+                .and_then(|last| cursor(record(last))),
+            records
+                .first()
+                .filter(|_| has_previous)
+                .and_then(|first| cursor(record(first))),
+        )
+    }
+
+    /// Returns whether there are next and previous pages: from the overflow record
+    /// and the cursor in keyset mode, from the total when counted, and `None`
+    /// otherwise. Whether a next page follows is unknown once the page reaches a
+    /// total capped by `with_count_capped`.
+    fn build_page_navigation(
+        &self,
+        total: Option<i64>,
+        next_cursor: Option<&String>,
+        previous_cursor: Option<&String>,
+    ) -> (Option<bool>, Option<bool>) {
+        if let Some(keyset) = &self.keyset {
+            return match self.keyset_direction {
+                KeysetDirection::Forward => {
+                    (Some(next_cursor.is_some()), Some(keyset.after.is_some()))
+                }
+                KeysetDirection::Backward => (
+                    Some(keyset.after.is_some()),
+                    Some(previous_cursor.is_some()),
+                ),
+            };
+        }
+
+        let pagination = &self.params.pagination;
+        let capped = self.build_total_capped_at(total).is_some();
+        match total {
+            Some(total) => (
+                Some(pagination.offset() + pagination.page_size < total)
+                    .filter(|has_next| *has_next || !capped),
+                Some(pagination.offset() > 0),
+            ),
+            None => (None, None),
+        }
+    }
+
+    /// Builds the SQL for fetching the records of the requested page.
+    fn build_records_sql(&self, conditions: &[String], limit_clause: &str) -> String {
+        self.build_records_sql_with_selection(conditions, &[], limit_clause)
+    }
+
+    /// Builds the SQL for fetching the records of the requested page, selecting
+    /// the given expressions in addition to the records columns.
+    fn build_records_sql_with_selection(
+        &self,
+        conditions: &[String],
+        extra_selection: &[String],
+        limit_clause: &str,
+    ) -> String {
+        self.build_records_sql_with_clauses(
+            conditions,
+            extra_selection,
+            &self.build_order_clause(),
+            limit_clause,
+        )
+    }
+
+    /// Builds the SQL for fetching records with the given ORDER BY and LIMIT clauses.
+    fn build_records_sql_with_clauses(
+        &self,
+        conditions: &[String],
+        extra_selection: &[String],
+        order_clause: &str,
+        limit_clause: &str,
+    ) -> String {
+        let group_columns = self.build_group_columns();
+        let group_clause = match &group_columns {
+            Some(columns) if !columns.is_empty() => format!(" GROUP BY {}", columns.join(", ")),
+            _ => String::new(),
+        };
+
+        format!(
+            "{}{}{}{}{}{}{}",
+            self.build_base_query(),
+            self.build_records_from_clause(group_columns.as_deref(), extra_selection),
+            self.build_where_clause(conditions),
+            group_clause,
+            self.build_having_clause(),
+            order_clause,
+            limit_clause
+        )
+    }
+
+    /// Builds the `__match_<column>` boolean expressions of the given search column
+    /// conditions, reporting whether each search column matched.
+    #[cfg(feature = "postgres")]
+    fn build_match_selection(&self, matches: &[(String, String)]) -> Vec<String> {
+        matches
+            .iter()
+            .map(|(column, condition)| {
+                format!(
+                    "{} AS {}",
+                    condition,
+                    self.quote(&format!("__match_{}", column))
+                )
+            })
+            .collect()
+    }
+
+    /// Builds the `ROW_NUMBER()` selection numbering records in the order of the
+    /// records query.
+    fn build_row_number_selection(&self, dialect: &dyn QueryDialect) -> String {
+        format!(
+            "{} AS {}",
+            dialect.signed_integer(&format!(
+                "ROW_NUMBER() OVER ({})",
+                self.build_order_clause().trim_start()
+            )),
+            self.quote("__row_num")
+        )
+    }
+
+    /// Builds the SQL for counting all records matching the conditions.
+    ///
+    /// The joins of the searched computed properties are added for the conditions
+    /// to refer to. With `with_distinct`, joined rows are counted once per base row:
+    /// by their distinct primary key when declared, or else through a subquery
+    /// selecting the distinct base rows. With `with_distinct_on`, the distinct
+    /// values of its columns are counted. With `HAVING` conditions, the grouped
+    /// rows matching them are counted.
+    fn build_count_sql(&self, conditions: &[String]) -> String {
+        let mut source = self.build_base_source();
+        let joins = self.build_count_joins();
+        for join in &joins {
+            source.push(' ');
+            source.push_str(join);
+        }
+
+        if let Some(columns) = self
+            .build_group_columns()
+            .filter(|columns| !columns.is_empty() && !self.having.is_empty())
+        {
+            let limit = self
+                .count_cap
+                .map(|cap| format!(" LIMIT {}", cap.saturating_add(1)))
+                .unwrap_or_default();
+
+            return format!(
+                "{}SELECT COUNT(*) FROM (SELECT 1 FROM {}{} GROUP BY {}{}{}) AS grouped_rows",
+                self.build_base_query(),
+                source,
+                self.build_where_clause(conditions),
+                columns.join(", "),
+                self.build_having_clause(),
+                limit
+            );
+        }
+
+        if !self.distinct_on.is_empty() {
+            let limit = self
+                .count_cap
+                .map(|cap| format!(" LIMIT {}", cap.saturating_add(1)))
+                .unwrap_or_default();
+
+            return format!(
+                "{}SELECT COUNT(*) FROM (SELECT DISTINCT {} FROM {}{}{}) AS distinct_rows",
+                self.build_base_query(),
+                self.build_distinct_on_columns(!joins.is_empty()).join(", "),
+                source,
+                self.build_where_clause(conditions),
+                limit
+            );
+        }
+
+        if let Some(cap) = self.count_cap {
+            let rows = match (&self.primary_key, self.distinct && !joins.is_empty()) {
+                (Some(primary_key), true) => {
+                    format!("DISTINCT {}", self.build_records_column(primary_key, true))
+                }
+                (None, true) => "DISTINCT base_query.*".to_string(),
+                (_, false) => "1".to_string(),
+            };
+
+            return format!(
+                "{}SELECT COUNT(*) FROM (SELECT {} FROM {}{} LIMIT {}) AS capped_rows",
+                self.build_base_query(),
+                rows,
+                source,
+                self.build_where_clause(conditions),
+                cap.saturating_add(1)
+            );
+        }
+
+        if self.distinct && !joins.is_empty() {
+            return match &self.primary_key {
+                Some(primary_key) => format!(
+                    "{}SELECT COUNT(DISTINCT {}) FROM {}{}",
+                    self.build_base_query(),
+                    self.build_records_column(primary_key, true),
+                    source,
+                    self.build_where_clause(conditions)
+                ),
+                None => format!(
+                    "{}SELECT COUNT(*) FROM (SELECT DISTINCT base_query.* FROM {}{}) AS distinct_rows",
+                    self.build_base_query(),
+                    source,
+                    self.build_where_clause(conditions)
+                ),
+            };
+        }
+
+        let counted = match (&self.base_table, &self.primary_key) {
+            (Some(_), Some(primary_key)) if self.primary_key_count_enabled => {
+                self.build_records_column(primary_key, !joins.is_empty())
+            }
+            _ => "*".to_string(),
+        };
+
+        format!(
+            "{}SELECT COUNT({}) FROM {}{}",
+            self.build_base_query(),
+            counted,
+            source,
+            self.build_where_clause(conditions)
+        )
+    }
+
+    /// Builds the SQL locating a record within the sorted, filtered rows.
+    ///
+    /// The query returns whether the record matches the conditions, and how many
+    /// matching rows sort strictly before it. Rows are compared term by term so
+    /// that mixed sort directions are honoured.
+    fn build_page_of_sql(
+        &self,
+        conditions: &[String],
+        pk_column: &str,
+        placeholder: &str,
+    ) -> String {
+        let group_columns = self.build_group_columns();
+        let group_clause = match &group_columns {
+            Some(columns) if !columns.is_empty() => format!(" GROUP BY {}", columns.join(", ")),
+            _ => String::new(),
+        };
+        let joins = self.build_active_joins();
+        let terms = self.build_sort_terms();
+
+        let mut selection = vec![format!(
+            "{} AS {}",
+            self.build_records_column(pk_column, !joins.is_empty()),
+            self.quote("__pk")
+        )];
+        selection.extend(terms.iter().enumerate().map(|(idx, (term, _, _))| {
+            format!("{} AS {}", term, self.quote(&format!("__sort_{}", idx)))
+        }));
+
+        let mut sorted = format!(
+            "SELECT {} FROM {}",
+            selection.join(", "),
+            self.build_base_source()
+        );
+        for join in joins {
+            sorted.push(' ');
+            sorted.push_str(join);
+        }
+
+        let before: Vec<String> = terms
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, direction, _))| {
+                let operator = match direction {
+                    QuerySortDirection::Ascending => "<",
+                    QuerySortDirection::Descending => ">",
+                };
+                let sort = |idx: usize| self.quote(&format!("__sort_{}", idx));
+                let mut comparison: Vec<String> = (0..idx)
+                    .map(|prev| format!("sorted.{0} = target.{0}", sort(prev)))
+                    .collect();
+                comparison.push(format!("sorted.{0} {1} target.{0}", sort(idx), operator));
+                format!("({})", comparison.join(" AND "))
+            })
+            .collect();
+
+        let mut ctes = self.build_base_ctes();
+        ctes.push(format!(
+            "sorted AS ({}{}{}{})",
+            sorted,
+            self.build_where_clause(conditions),
+            group_clause,
+            self.build_having_clause()
+        ));
+        ctes.push(format!(
+            "target AS (SELECT * FROM sorted WHERE {} = {})",
+            self.quote("__pk"),
+            placeholder
+        ));
+
+        format!(
+            "WITH {} SELECT (SELECT COUNT(*) FROM target), \
+             (SELECT COUNT(*) FROM sorted, target WHERE {})",
+            ctes.join(", "),
+            before.join(" OR ")
+        )
+    }
+
+    /// Finds the page a record falls on under the current sort and filters.
+    ///
+    /// Shared by the database specific `fetch_page_of` implementations.
+    async fn fetch_page_of_from_pool<V>(
+        mut self,
+        pool: &Pool<DB>,
+        dialect: &'static (dyn QueryDialect + Sync),
+        pk_column: &str,
+        pk_value: V,
+    ) -> Result<Option<i64>, sqlx::Error>
+    where
+        V: for<'e> Encode<'e, DB> + Type<DB> + Send + 'q,
+    {
+        self.dialect = Some(dialect);
+        self.check_params()?;
+        if !get_struct_field_names::<T>().contains(&pk_column.to_string()) {
+            return Err(sqlx::Error::ColumnNotFound(pk_column.to_string()));
+        }
+
+        let (conditions, mut arguments) = self.build_conditions(dialect)?;
+        self.bind_sort_terms(&mut arguments, dialect)?;
+        self.check_sort_terms_order(dialect)?;
+        let placeholder = Self::push_argument(&mut arguments, dialect, pk_value)?;
+
+        let sql = self.build_page_of_sql(&conditions, pk_column, &placeholder);
+        let (found, position): (i64, i64) =
+            sqlx::query_as_with::<DB, (i64, i64), _>(AssertSqlSafe(sql), arguments)
+                .fetch_one(pool)
+                .await?;
+
+        if found == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(position / self.params.pagination.page_size + 1))
+    }
+
+    /// Executes the count query against the given pool, when totals are enabled
+    /// and no cached total was supplied, reading the counter table first if any.
+    /// With `with_count_includes_trashed`, the rows that are not soft-deleted are
+    /// counted as well, for `total_active` and the total pages.
+    ///
+    /// Returns the total records, total pages and pagination of the response.
+    async fn fetch_totals(
+        &mut self,
+        pool: &Pool<DB>,
+        dialect: &dyn QueryDialect,
+    ) -> Result<PaginationTotals, sqlx::Error> {
+        if !self.totals_count_enabled {
+            return Ok((None, None, None, false));
+        }
+
+        let mut estimate: Option<i64> = None;
+        if let (None, Some(counter)) = (self.cached_total, &self.counter_table) {
+            let mut arguments = DB::Arguments::default();
+            (counter.key)(&mut arguments).map_err(sqlx::Error::Encode)?;
+            let sql = format!(
+                "SELECT {} FROM {} WHERE {} = {}",
+                self.quote(&counter.count_column),
+                self.quote(&counter.table),
+                self.quote(&counter.name_column),
+                dialect.placeholder(1)
+            );
+
+            estimate = sqlx::query_scalar_with(AssertSqlSafe(sql), arguments)
+                .fetch_optional(pool)
+                .await?;
+        }
+
+        if let (None, None, Some(decode_plan)) = (self.cached_total, estimate, self.estimated_count)
+        {
+            estimate = self
+                .fetch_count_estimate(pool, dialect, decode_plan)
+                .await?;
+        }
+
+        let count = match self.cached_total.or(estimate) {
+            Some(total) => total,
+            None => {
+                let (conditions, count_arguments) = self.build_count_conditions(dialect)?;
+                let count_sql = self.build_count_sql(&conditions);
+
+                let context_key = self
+                    .context
+                    .as_ref()
+                    .and_then(|_| self.context_cache_key(&count_sql));
+                let cached = match (&self.context, &context_key) {
+                    (Some(context), Some(key)) => context.cached_total(key),
+                    _ => None,
+                };
+
+                let total = match cached {
+                    Some(total) => total,
+                    None => {
+                        if let Some(executed) = &mut self.executed_sql {
+                            executed.count_sql = Some(count_sql.clone());
+                        }
+                        sqlx::query_scalar_with(AssertSqlSafe(count_sql.clone()), count_arguments)
+                            .fetch_one(pool)
+                            .await?
+                    }
+                };
+
+                if let Some(context) = &self.context {
+                    if let (None, Some(key)) = (cached, context_key) {
+                        context.cache_total(key, total);
+                    }
+                    context.observe(&CountEvent {
+                        count_sql: &count_sql,
+                        total,
+                        cached: cached.is_some(),
+                    });
+                }
+                total
+            }
+        };
+
+        if self.counts_trashed() {
+            let (conditions, count_arguments) =
+                self.build_count_conditions_with_trashed(dialect, false)?;
+            let count_sql = self.build_count_sql(&conditions);
+            let total_active = sqlx::query_scalar_with(AssertSqlSafe(count_sql), count_arguments)
+                .fetch_one(pool)
+                .await?;
+            self.total_active = Some(total_active);
+
+            let (_, total_pages, pagination, _) = self.build_totals(total_active, false);
+            return Ok((Some(count), total_pages, pagination, estimate.is_some()));
+        }
+
+        Ok(self.build_totals(count, estimate.is_some()))
+    }
+
+    /// Estimates the total as set by `with_estimated_count`, from the table
+    /// statistics when there are no conditions, or else from the plan of the count
+    /// query.
+    ///
+    /// Returns `None` when the dialect has no estimates or they can't be obtained.
+    async fn fetch_count_estimate(
+        &mut self,
+        pool: &Pool<DB>,
+        dialect: &dyn QueryDialect,
+        decode_plan: PlanDecoder<DB>,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        let (conditions, count_arguments) = self.build_count_conditions(dialect)?;
+
+        let table_estimate = match &self.base_table {
+            Some(table) if conditions.is_empty() => dialect.table_row_estimate(table),
+            _ => None,
+        };
+        if let Some(sql) = table_estimate {
+            match sqlx::query_scalar::<DB, i64>(AssertSqlSafe(sql))
+                .fetch_optional(pool)
+                .await
+            {
+                Ok(Some(rows)) if rows >= 0 => return Ok(Some(rows)),
+                Ok(_) => {}
+                Err(_error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %_error, "Skipping table row estimate");
+                }
+            }
+        }
+
+        let Some(sql) = dialect.explain_json(&self.build_count_sql(&conditions)) else {
+            return Ok(None);
+        };
+        match sqlx::query_with::<DB, _>(AssertSqlSafe(sql), count_arguments)
+            .fetch_one(pool)
+            .await
+        {
+            Ok(row) => Ok(decode_plan(&row).and_then(|plan| planner_row_estimate(&plan))),
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_error, "Skipping planner row estimate");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns the totals of the response for the total records.
+    fn build_totals(&self, count: i64, is_estimate: bool) -> PaginationTotals {
+        let pagination_arguments = self.params.pagination.clone();
+        let available_pages = match count {
+            0 => 0,
+            _ => (count + pagination_arguments.page_size - 1) / pagination_arguments.page_size,
+        };
+
+        (
+            Some(count),
+            Some(available_pages),
+            Some(pagination_arguments),
+            is_estimate,
+        )
+    }
+
+    /// Whether the total is read off the records query, as set by
+    /// `with_window_count`. Totals that aren't the number of records the records
+    /// query matches before its limit, or that are cached or estimated instead of
+    /// counted, keep the count query.
+    fn window_count_enabled(&self) -> bool {
+        self.window_count
+            && self.totals_count_enabled
+            && self.cached_total.is_none()
+            && self.context.is_none()
+            && self.counter_table.is_none()
+            && self.count_window.is_none()
+            && self.count_cap.is_none()
+            && self.estimated_count.is_none()
+            && !self.counts_trashed()
+            && self.keyset.is_none()
+            && !self.distinct
+            && self.distinct_on.is_empty()
+    }
+
+    /// Builds the SQL for fetching the records of the requested page, selecting
+    /// the window count when enabled.
+    fn build_page_records_sql(&self, conditions: &[String], limit_clause: &str) -> String {
+        if self.window_count_enabled() {
+            self.build_records_sql_with_selection(
+                conditions,
+                &[format!("COUNT(*) OVER () AS {}", self.quote("__total"))],
+                limit_clause,
+            )
+        } else {
+            self.build_records_sql(conditions, limit_clause)
+        }
+    }
+
+    /// Returns the key of the count query in the shared `PaginationContext`: the
+    /// count SQL, the parameters other than the page and sort, and the values bound
+    /// outside the parameters, those of the computed property joins and the count
+    /// window.
+    ///
+    /// Returns `None`, so the total is neither reused nor shared, when the count
+    /// query may bind values the key can't hold: those of a custom query builder
+    /// function, of `with_default_scope` or of the prepended CTEs.
+    fn context_cache_key(&self, count_sql: &str) -> Option<String> {
+        let custom_values = !matches!(self.build_query_fn, QueryBuilderFn::Default(_))
+            || (self.default_scope.is_some() && !self.include_deleted)
+            || self
+                .prepended_ctes
+                .iter()
+                .any(|cte| !cte.arguments.is_empty());
+        if custom_values {
+            return None;
+        }
+
+        let mut params = serde_json::to_value(&self.params).unwrap_or_default();
+        if let Some(params) = params.as_object_mut() {
+            for key in [
+                "page",
+                "page_size",
+                "page_token",
+                "sort_column",
+                "sort_direction",
+                "order_by",
+            ] {
+                params.remove(key);
+            }
+        }
+
+        let mut join_values: Vec<_> = self.join_arguments.keys().collect();
+        join_values.sort();
+
+        Some(format!(
+            "{}\n{}\n{:?}\n{:?}",
+            count_sql,
+            params,
+            join_values,
+            self.count_window.as_ref().map(|window| &window.since_key)
+        ))
+    }
+
+    /// Executes only the count query against the given pool.
+    ///
+    /// Shared by the database specific `fetch_meta` implementations.
+    async fn fetch_meta_from_pool(
+        mut self,
+        pool: &Pool<DB>,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<PageMeta, sqlx::Error> {
+        self.dialect = Some(dialect);
+        self.check_params()?;
+        self.totals_count_enabled = true;
+
+        let (total, total_pages, _, _) = self.fetch_totals(pool, dialect).await?;
+        let pagination = &self.params.pagination;
+
+        Ok(PageMeta {
+            page: pagination.page,
+            page_size: pagination.page_size,
+            total: total.unwrap_or_default(),
+            total_pages: total_pages.unwrap_or_default(),
+        })
+    }
+
+    /// Executes the count and records queries against the given pool.
+    ///
+    /// Shared by the database specific `fetch_paginated` implementations.
+    async fn fetch_paginated_from_pool(
+        mut self,
+        pool: &Pool<DB>,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<PaginatedResponse<T>, sqlx::Error>
+    where
+        &'static str: sqlx::ColumnIndex<DB::Row>,
+    {
+        self.fetch_page(pool, dialect).await
+    }
+
+    /// Executes the count and records queries like `fetch_paginated_from_pool`,
+    /// returning the SQL recorded while fetching.
+    ///
+    /// Shared by the database specific `fetch_paginated_debug` implementations.
+    async fn fetch_paginated_debug_from_pool(
+        mut self,
+        pool: &Pool<DB>,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<(PaginatedResponse<T>, ExecutedSql), sqlx::Error>
+    where
+        &'static str: sqlx::ColumnIndex<DB::Row>,
+    {
+        self.executed_sql = Some(ExecutedSql::default());
+        let result = self.fetch_page(pool, dialect).await?;
+
+        Ok((result, self.executed_sql.take().unwrap_or_default()))
+    }
+
+    /// Executes the count and records queries, recording their SQL when
+    /// `executed_sql` is set.
+    async fn fetch_page(
+        &mut self,
+        pool: &Pool<DB>,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<PaginatedResponse<T>, sqlx::Error>
+    where
+        &'static str: sqlx::ColumnIndex<DB::Row>,
+    {
+        let page = self.build_page_query(dialect)?;
+        if let Some(executed) = &mut self.executed_sql {
+            executed.records_sql = page.sql.clone();
+            executed.arguments = page.arguments.len();
+        }
+
+        if self.window_count_enabled() {
+            return self
+                .fetch_paginated_with_window_count(pool, dialect, page.sql, page.arguments)
+                .await;
+        }
+
+        let totals = self.fetch_totals(pool, dialect).await?;
+
+        let records = sqlx::query_as_with::<DB, T, _>(AssertSqlSafe(page.sql), page.arguments)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(self.build_paginated_response(records, totals))
+    }
+
+    /// Builds the records query of the requested page, after checking the
+    /// parameters.
+    fn build_page_query(
+        &mut self,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<PageQuery<DB>, sqlx::Error> {
+        self.dialect = Some(dialect);
+        self.check_params()?;
+        let (mut conditions, mut arguments) = self.build_conditions(dialect)?;
+        self.bind_sort_terms(&mut arguments, dialect)?;
+        let limit_clause = self.bind_page(&mut conditions, &mut arguments, dialect)?;
+        let sql = self.build_page_records_sql(&conditions, &limit_clause);
+
+        Ok(PageQuery {
+            sql,
+            conditions,
+            limit_clause,
+            arguments,
+        })
+    }
+
+    /// Executes the records query selecting the window count, reading the total
+    /// off the first row.
+    ///
+    /// A page past the last one has no row to read the total from, so the total
+    /// is then counted by the count query. An empty first page has no records at
+    /// all.
+    async fn fetch_paginated_with_window_count(
+        &mut self,
+        pool: &Pool<DB>,
+        dialect: &dyn QueryDialect,
+        main_sql: String,
+        main_arguments: DB::Arguments,
+    ) -> Result<PaginatedResponse<T>, sqlx::Error>
+    where
+        &'static str: sqlx::ColumnIndex<DB::Row>,
+    {
+        use sqlx::Row;
+
+        let rows = sqlx::query_with::<DB, _>(AssertSqlSafe(main_sql), main_arguments)
+            .fetch_all(pool)
+            .await?;
+
+        let records = rows
+            .iter()
+            .map(T::from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        let totals = match rows.first() {
+            Some(row) => self.build_totals(row.try_get("__total")?, false),
+            None if self.params.pagination.page <= 1 => self.build_totals(0, false),
+            None => self.fetch_totals(pool, dialect).await?,
+        };
+
+        Ok(self.build_paginated_response(records, totals))
+    }
+
+    /// Builds the response of a records page and its totals.
+    fn build_paginated_response(
+        &self,
+        mut records: Vec<T>,
+        (total, total_pages, pagination, total_is_estimate): PaginationTotals,
+    ) -> PaginatedResponse<T> {
+        let (next_cursor, previous_cursor) = self.build_cursors(&mut records, |record| record);
+        let page_bounds = self.build_page_bounds(records.iter());
+        let next_page_token =
+            self.build_next_page_token(records.len(), self.total_active.or(total));
+        let (has_next, has_previous) = self.build_page_navigation(
+            self.total_active.or(total),
+            next_cursor.as_ref(),
+            previous_cursor.as_ref(),
+        );
+
+        PaginatedResponse {
+            records,
+            pagination,
+            total,
+            total_pages,
+            total_is_estimate,
+            total_active: self.total_active,
+            total_capped_at: self.build_total_capped_at(total),
+            page_bounds,
+            next_page_token,
+            next_cursor,
+            previous_cursor,
+            has_next,
+            has_previous,
+        }
+    }
+
+    /// Builds the SQL of the records and count queries without executing them.
+    ///
+    /// Shared by the database specific `to_sql` implementations.
+    fn build_sql_from_dialect(
+        &mut self,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<(String, String), sqlx::Error> {
+        let page = self.build_page_query(dialect)?;
+
+        let (conditions, _) = self.build_count_conditions(dialect)?;
+        let count_sql = self.build_count_sql(&conditions);
+
+        Ok((page.sql, count_sql))
+    }
+
+    /// Builds the SQL of the records and count queries without executing them.
+    ///
+    /// Shared by the database specific `GeneratedSqlSource` implementations.
+    #[cfg(feature = "testing")]
+    pub(crate) fn generated_sql_from_dialect(
+        mut self,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<crate::paginated_query_as::testing::GeneratedSql, sqlx::Error> {
+        let page = self.build_page_query(dialect)?;
+
+        let count_sql = if self.totals_count_enabled {
+            let (conditions, _) = self.build_count_conditions(dialect)?;
+            Some(self.build_count_sql(&conditions))
+        } else {
+            None
+        };
+
+        Ok(crate::paginated_query_as::testing::GeneratedSql::new(
+            &self.build_where_clause(&page.conditions),
+            &self.build_order_clause(),
+            &page.limit_clause,
+            &page.sql,
+            count_sql.as_deref(),
+        ))
+    }
+
+    /// Executes the count and records queries against the given pool, numbering
+    /// each record by its position among all matching rows.
+    ///
+    /// Shared by the database specific `fetch_paginated_with_row_numbers`
+    /// implementations.
+    async fn fetch_paginated_with_row_numbers_from_pool(
+        mut self,
+        pool: &Pool<DB>,
+        dialect: &'static (dyn QueryDialect + Sync),
+    ) -> Result<PaginatedResponse<(T, i64)>, sqlx::Error>
+    where
+        &'static str: sqlx::ColumnIndex<DB::Row>,
+    {
+        self.dialect = Some(dialect);
+        use sqlx::Row;
+
+        self.check_params()?;
+        let (mut conditions, mut main_arguments) = self.build_conditions(dialect)?;
+        self.bind_sort_terms(&mut main_arguments, dialect)?;
+        self.check_sort_terms_order(dialect)?;
+        let limit_clause = self.bind_page(&mut conditions, &mut main_arguments, dialect)?;
+        let row_number_selection = [self.build_row_number_selection(dialect)];
+        let main_sql = self.build_records_sql_with_selection(
+            &conditions,
+            &row_number_selection,
+            &limit_clause,
+        );
+
+        let (total, total_pages, pagination, total_is_estimate) =
+            self.fetch_totals(pool, dialect).await?;
+
+        let rows = sqlx::query_with::<DB, _>(AssertSqlSafe(main_sql), main_arguments)
+            .fetch_all(pool)
+            .await?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let row_number: i64 = row.try_get("__row_num")?;
+            records.push((T::from_row(&row)?, row_number));
+        }
+        let (next_cursor, previous_cursor) = self.build_cursors(&mut records, |(record, _)| record);
+        let page_bounds = self.build_page_bounds(records.iter().map(|(record, _)| record));
+        let next_page_token =
+            self.build_next_page_token(records.len(), self.total_active.or(total));
+        let (has_next, has_previous) = self.build_page_navigation(
+            self.total_active.or(total),
+            next_cursor.as_ref(),
+            previous_cursor.as_ref(),
+        );
+
+        Ok(PaginatedResponse {
+            records,
+            pagination,
+            total,
+            total_pages,
+            total_is_estimate,
+            total_active: self.total_active,
+            total_capped_at: self.build_total_capped_at(total),
+            page_bounds,
+            next_page_token,
+            next_cursor,
+            previous_cursor,
+            has_next,
+            has_previous,
+        })
+    }
+}
+
+/// Returns the number of rows the planner expects from an `EXPLAIN (FORMAT JSON)`
+/// plan. The plain aggregate of a count query is skipped for the rows it counts.
+fn planner_row_estimate(plan: &serde_json::Value) -> Option<i64> {
+    let mut node = plan.get(0)?.get("Plan")?;
+    while node.get("Node Type").and_then(serde_json::Value::as_str) == Some("Aggregate")
+        && node.get("Strategy").and_then(serde_json::Value::as_str) == Some("Plain")
+    {
+        node = node.get("Plans")?.get(0)?;
+    }
+
+    node.get("Plan Rows")?
+        .as_f64()
+        .map(|rows| rows.round() as i64)
+}
+
+/// Decodes the plan of a PostgreSQL `EXPLAIN (FORMAT JSON)` row.
+#[cfg(feature = "postgres")]
+fn decode_postgres_plan(row: &sqlx::postgres::PgRow) -> Option<serde_json::Value> {
+    use sqlx::{types::Json, Row};
+
+    row.try_get::<Json<serde_json::Value>, _>(0)
+        .ok()
+        .map(|Json(plan)| plan)
+}
+
+/// Returns the value of a column of a serialized record: text as is, numbers and
+/// booleans in their JSON form, and `None` for `null` or missing columns.
+fn serialized_column_value<T: Serialize>(record: &T, column: &str) -> Option<String> {
+    match serde_json::to_value(record).ok()?.get(column)? {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'q, T, A> PaginatedQueryBuilder<'q, T, sqlx::Postgres, A>
+where
+    T: for<'r> FromRow<'r, <sqlx::Postgres as sqlx::Database>::Row>
+        + Send
+        + Unpin
+        + Serialize
+        + Default,
+    A: IntoArguments<sqlx::Postgres> + Send,
+{
+    /// Creates a new `PaginatedQueryBuilder` for PostgreSQL with default settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The base query to paginate
+    ///
+    /// # Default Settings
+    ///
+    /// - Totals calculation is enabled
+    /// - Uses default query parameters
+    /// - Uses safe default query building function
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::PaginatedQueryBuilder;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Postgres, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::<UserExample, Postgres, _>::new_with_defaults(base_query);
+    /// ```
+    pub fn new_with_defaults(query: sqlx::query::QueryAs<'q, sqlx::Postgres, T, A>) -> Self {
+        use crate::paginated_query_as::examples::postgres_examples::build_query_with_safe_defaults;
+        Self::new(query, |params| build_query_with_safe_defaults::<T>(params))
+    }
+
+    /// Reads the total off the records query instead of running a separate count
+    /// query, saving a round-trip and a second building of the query arguments.
+    ///
+    /// The records query selects `COUNT(*) OVER () AS "__total"`, which is not
+    /// decoded into the model. When the page has no records the total is 0 on the
+    /// first page, and counted by the count query past it.
+    ///
+    /// The count query is kept when the total is cached, shared through a
+    /// `PaginationContext`, read from a counter table, windowed, capped, or counted
+    /// with `with_distinct` or `with_distinct_on`, and in keyset mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+    ///     .with_window_count();
+    /// ```
+    pub fn with_window_count(mut self) -> Self {
+        self.window_count = true;
+        self
+    }
+
+    /// Estimates the total from the statistics of the database instead of counting
+    /// it, for large tables where a rough total is enough.
+    ///
+    /// Without conditions, the total is the row estimate of the table declared with
+    /// `with_base_table`. Otherwise, or without a declared table, it is the number
+    /// of rows the planner expects the count query to count, from
+    /// `EXPLAIN (FORMAT JSON)`. Estimated totals set
+    /// `PaginatedResponse::total_is_estimate`.
+    ///
+    /// The total is counted exactly when the estimate can't be obtained, e.g. for
+    /// tables never analyzed.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct EventExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<EventExample, Postgres>("SELECT * FROM events")
+    ///     .with_base_table("events")
+    ///     .with_estimated_count();
+    /// ```
+    pub fn with_estimated_count(mut self) -> Self {
+        self.estimated_count = Some(decode_postgres_plan);
+        self
+    }
+
+    /// Returns one record per distinct value of the given columns, the first one
+    /// in sort order, with `SELECT DISTINCT ON (...)`.
+    ///
+    /// Postgres requires the `DISTINCT ON` columns to be the leading `ORDER BY`
+    /// terms, so the sort must start with these columns, in any order and without
+    /// case insensitive sorting, before further sort columns pick the record kept
+    /// per value. Otherwise the fetch fails before running a query, with
+    /// `PaginationError::UnsortedDistinctOn`. The total counts the distinct values.
+    ///
+    /// Columns that aren't fields of the model are skipped, logging a warning
+    /// with the `tracing` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Columns whose distinct values are returned once
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct OrderExample {
+    ///     customer_id: i64,
+    ///     created_at: String,
+    /// }
+    ///
+    /// // The latest order of every customer
+    /// let params = QueryParamsBuilder::<OrderExample>::new()
+    ///     .with_sort("customer_id", QuerySortDirection::Ascending)
+    ///     .with_sort("created_at", QuerySortDirection::Descending)
+    ///     .build();
+    /// let builder = paginated_query_as::<OrderExample, Postgres>("SELECT * FROM orders")
+    ///     .with_params(params)
+    ///     .with_distinct_on(vec!["customer_id"]);
+    /// ```
+    pub fn with_distinct_on(mut self, columns: Vec<&str>) -> Self {
+        let fields = get_struct_field_names::<T>();
+        for column in columns {
+            if !fields.iter().any(|field| field == column) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(column = %column, "Skipping DISTINCT ON column missing from the model");
+                continue;
+            }
+            if !self.distinct_on.iter().any(|existing| existing == column) {
+                self.distinct_on.push(column.to_string());
+            }
+        }
+        self
+    }
+
+    /// Executes the paginated query and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a `PaginatedResponse<T>` with:
+    /// - Records for the requested page
+    /// - Optional Pagination information (if enabled)
+    /// - Optional total count and total pages (if enabled)
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{PaginatedQueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_pagination(1, 10)
+    ///     .build();
+    ///
+    /// let result = PaginatedQueryBuilder::<User, Postgres, _>::new_with_defaults(
+    ///     sqlx::query_as::<Postgres, User>("SELECT * FROM users")
+    /// )
+    /// .with_params(params)
+    /// .fetch_paginated(&pool)
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated(
+        mut self,
+        pool: &sqlx::PgPool,
+    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
+        if let Some(autocomplete) = self.autocomplete.take() {
+            return self.fetch_autocomplete(pool, &autocomplete).await;
+        }
+        self.fetch_paginated_from_pool(pool, &crate::paginated_query_as::internal::PostgresDialect)
+            .await
+    }
+
+    /// Executes the paginated query like `fetch_paginated`, returning the records
+    /// in a map keyed by the given function, e.g. for lookup table endpoints.
+    ///
+    /// The records keep the query order. Keys are expected to be unique, e.g. a
+    /// column with a unique index: when several records share a key, the last one
+    /// wins, at the position of the first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    /// * `key_fn` - Function returning the key of a record
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct Country {
+    ///     code: String,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let result = paginated_query_as::<Country, Postgres>("SELECT * FROM countries")
+    ///     .with_params(QueryParamsBuilder::<Country>::new().build())
+    ///     .fetch_paginated_keyed(&pool, |country| country.code.clone())
+    ///     .await?;
+    ///
+    /// println!("{:?}", result.records.get("US").map(|country| &country.name));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_keyed<K, F>(
+        self,
+        pool: &sqlx::PgPool,
+        key_fn: F,
+    ) -> Result<KeyedPaginatedResponse<K, T>, sqlx::Error>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        Ok(self.fetch_paginated(pool).await?.into_keyed(key_fn))
+    }
+
+    /// Returns the records and count SQL `fetch_paginated` would execute, with
+    /// their placeholders, without executing them or needing a pool.
+    ///
+    /// For logging the generated SQL or asserting on it in tests. The values are
+    /// bound as for `fetch_paginated`, which may be called afterwards. The count
+    /// SQL is returned even when totals are disabled, and autocomplete queries
+    /// aren't covered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` when fetching would fail before reaching the
+    /// database, e.g. for a missing required sort or an offset past
+    /// `with_max_offset`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// let (records_sql, count_sql) = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().with_filter("name", Some("john")).build())
+    ///     .to_sql()
+    ///     .unwrap();
+    ///
+    /// assert!(records_sql.contains("\"name\" = $1"));
+    /// assert!(count_sql.contains("COUNT(*)"));
+    /// ```
+    pub fn to_sql(&mut self) -> Result<(String, String), sqlx::Error> {
+        self.build_sql_from_dialect(&crate::paginated_query_as::internal::PostgresDialect)
+    }
+
+    /// Executes the paginated query like `fetch_paginated`, returning the SQL of
+    /// the records and count queries next to the results, e.g. for audit logging
+    /// or one-off debugging without a `PaginationContext` observer.
+    ///
+    /// The SQL is recorded as the queries are executed. The count SQL is `None`
+    /// when no count query ran: totals disabled, a total given with
+    /// `with_cached_total`, read from a counter table or the window count,
+    /// estimated, or reused from a `PaginationContext`. Autocomplete queries
+    /// aren't covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let (result, executed) = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().build())
+    ///     .fetch_paginated_debug(&pool)
+    ///     .await?;
+    ///
+    /// println!("{} ({} arguments)", executed.records_sql, executed.arguments);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_debug(
+        self,
+        pool: &sqlx::PgPool,
+    ) -> Result<(PaginatedResponse<T>, ExecutedSql), sqlx::Error> {
+        self.fetch_paginated_debug_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::PostgresDialect,
+        )
+        .await
+    }
+
+    /// Runs only the count query, returning the pagination metadata without the
+    /// records, e.g. to prefetch a paginator.
+    ///
+    /// The count honours `with_cached_total` and `with_counter_table`, and runs
+    /// even when totals are disabled with `disable_totals_count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let meta = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().with_pagination(2, 20).build())
+    ///     .fetch_meta(&pool)
+    ///     .await?;
+    ///
+    /// println!("page {} of {}", meta.page, meta.total_pages);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_meta(self, pool: &sqlx::PgPool) -> Result<PageMeta, sqlx::Error> {
+        self.fetch_meta_from_pool(pool, &crate::paginated_query_as::internal::PostgresDialect)
+            .await
+    }
+
+    /// Turns the query into a `pg_trgm` similarity search for autocomplete.
+    ///
+    /// `fetch_paginated` then returns the `limit` rows most similar to `term`,
+    /// ordered by `similarity(column, term) DESC`, in addition to the usual filters.
+    /// The term is bound once and reused by the `%` operator, the threshold check
+    /// and the ordering. Sorting and pagination parameters are ignored and totals
+    /// are not calculated.
+    ///
+    /// Requires the `pg_trgm` extension. The `%` operator uses the
+    /// `pg_trgm.similarity_threshold` setting (0.3 by default), so thresholds below
+    /// it also need that setting lowered. A trigram index on `column` keeps the
+    /// lookup fast. Columns that are not model fields are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Text column to compare against the term
+    /// * `term` - Text typed by the user
+    /// * `limit` - Maximum number of suggestions, at least 1
+    /// * `threshold` - Minimum similarity between 0 and 1
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct CityExample {
+    ///     name: String
+    /// }
+    ///
+    /// let builder = paginated_query_as::<CityExample, Postgres>("SELECT * FROM cities")
+    ///     .with_autocomplete("name", "amsterdm", 5, 0.3);
+    /// ```
+    pub fn with_autocomplete(
+        mut self,
+        column: impl Into<String>,
+        term: impl Into<String>,
+        limit: i64,
+        threshold: f64,
+    ) -> Self {
+        let column = column.into();
+
+        if get_struct_field_names::<T>().contains(&column) {
+            self.autocomplete = Some(Autocomplete {
+                column,
+                term: term.into(),
+                limit: limit.max(1),
+                threshold: if threshold.is_nan() {
+                    0.0
+                } else {
+                    threshold.clamp(0.0, 1.0)
+                },
+            });
+            self.totals_count_enabled = false;
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %column, "Skipping invalid autocomplete column");
+        }
+        self
+    }
+
+    /// Builds the autocomplete condition and ORDER BY clause for the given term placeholder.
+    fn build_autocomplete_clauses(
+        &self,
+        autocomplete: &Autocomplete,
+        placeholder: &str,
+    ) -> (String, String) {
+        let joined = !self.build_active_joins().is_empty();
+        let column = self.build_records_column(&autocomplete.column, joined);
+
+        (
+            format!(
+                "{0} % {1} AND similarity({0}, {1}) >= {2}",
+                column, placeholder, autocomplete.threshold
+            ),
+            format!(" ORDER BY similarity({}, {}) DESC", column, placeholder),
+        )
+    }
+
+    /// Executes the similarity search configured by `with_autocomplete`.
+    async fn fetch_autocomplete(
+        mut self,
+        pool: &sqlx::PgPool,
+        autocomplete: &Autocomplete,
+    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        self.check_params()?;
+
+        let (mut conditions, mut arguments) = self.build_conditions(&PostgresDialect)?;
+        let placeholder =
+            Self::push_argument(&mut arguments, &PostgresDialect, autocomplete.term.clone())?;
+        let limit_placeholder =
+            Self::push_argument(&mut arguments, &PostgresDialect, autocomplete.limit)?;
+
+        let (condition, order_clause) = self.build_autocomplete_clauses(autocomplete, &placeholder);
+        conditions.push(condition);
+        let limit_clause = format!(" LIMIT {}", limit_placeholder);
+        let sql =
+            self.build_records_sql_with_clauses(&conditions, &[], &order_clause, &limit_clause);
+
+        let records = sqlx::query_as_with::<sqlx::Postgres, T, _>(AssertSqlSafe(sql), arguments)
+            .fetch_all(pool)
+            .await?;
+
+        let page_bounds = self.build_page_bounds(records.iter());
+
+        Ok(PaginatedResponse {
+            records,
+            pagination: None,
+            total: None,
+            total_pages: None,
+            total_is_estimate: false,
+            total_active: None,
+            total_capped_at: None,
+            page_bounds,
+            next_page_token: None,
+            next_cursor: None,
+            previous_cursor: None,
+            has_next: None,
+            has_previous: None,
+        })
+    }
+
+    /// Finds the page containing a record under the current sort and filters.
+    ///
+    /// Counts the matching rows sorting strictly before the record and divides by
+    /// the page size, which helps "jump to record" navigation. Rows tied with the
+    /// record on every sort column are not counted, so add a unique sort column
+    /// when ties are possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    /// * `pk_column` - Column uniquely identifying the record
+    /// * `pk_value` - Value of `pk_column` for the record
+    ///
+    /// # Returns
+    ///
+    /// Returns the 1-based page number, or `None` when the record doesn't match the filters
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error::ColumnNotFound` when `pk_column` isn't a model field,
+    /// or `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// let page = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_page_of(&pool, "id", 42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_page_of<V>(
+        self,
+        pool: &sqlx::PgPool,
+        pk_column: &str,
+        pk_value: V,
+    ) -> Result<Option<i64>, sqlx::Error>
+    where
+        V: for<'e> Encode<'e, sqlx::Postgres> + Type<sqlx::Postgres> + Send + 'q,
+    {
+        self.fetch_page_of_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::PostgresDialect,
+            pk_column,
+            pk_value,
+        )
+        .await
+    }
+
+    /// Executes the paginated query, returning each record with its 1-based row
+    /// number among all rows matching the filters, under the current sort.
+    ///
+    /// Row numbers come from `ROW_NUMBER()` over the same ORDER BY as the page, so
+    /// the records of page 2 of size 10 are numbered 11 to 20. Rows tied on every
+    /// sort column may be numbered in any order among themselves, so add a unique
+    /// sort column when ties are possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution or decoding fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_pagination(2, 10)
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// let result = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_paginated_with_row_numbers(&pool)
+    ///     .await?;
+    ///
+    /// for (user, row_number) in result.records {
+    ///     println!("#{} {}", row_number, user.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_with_row_numbers(
+        self,
+        pool: &sqlx::PgPool,
+    ) -> Result<PaginatedResponse<(T, i64)>, sqlx::Error> {
+        self.fetch_paginated_with_row_numbers_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::PostgresDialect,
+        )
+        .await
+    }
+
+    /// Executes the paginated query, returning which search columns matched for each record.
+    ///
+    /// Each search column is reported as matched when it matches any search term,
+    /// with the expressions and patterns of the search conditions, including the
+    /// search options, typed search and search properties of the query builder.
+    /// Records carry an empty `MatchInfo` when no search term is set. Only the
+    /// columns the search applies to are reported, and the conditions of
+    /// `with_query_builder` functions are matched with the default search options.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - PostgreSQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution or decoding fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, PgPool, Postgres};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    ///     email: String,
+    /// }
+    ///
+    /// # async fn example(pool: PgPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_search("john", vec!["name", "email"])
+    ///     .build();
+    ///
+    /// let result = paginated_query_as::<User, Postgres>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_paginated_with_matches(&pool)
+    ///     .await?;
+    ///
+    /// for (user, matches) in result.records {
+    ///     println!("{} matched in {:?}", user.name, matches.matched_columns);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_with_matches(
+        mut self,
+        pool: &sqlx::PgPool,
+    ) -> Result<PaginatedResponse<(T, MatchInfo)>, sqlx::Error> {
+        use sqlx::Row;
+
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        self.check_params()?;
+        let (mut conditions, main_arguments) = self.build_conditions(&PostgresDialect)?;
+        let query_builder = match &self.build_query_fn {
+            QueryBuilderFn::Conditions(_) => QueryBuilder::<T, sqlx::Postgres>::new(),
+            QueryBuilderFn::Having(build_query_fn) => build_query_fn(&self.params),
+            QueryBuilderFn::Default(build_query_fn) => {
+                build_query_fn(&self.params, &self.computed_properties)
+            }
+        };
+        let (matches, mut main_arguments) =
+            query_builder.build_search_matches(&self.params, main_arguments);
+        let match_selection = self.build_match_selection(&matches);
+        self.bind_sort_terms(&mut main_arguments, &PostgresDialect)?;
+        let limit_clause =
+            self.bind_page(&mut conditions, &mut main_arguments, &PostgresDialect)?;
+        let main_sql =
+            self.build_records_sql_with_selection(&conditions, &match_selection, &limit_clause);
+
+        let (total, total_pages, pagination, total_is_estimate) =
+            self.fetch_totals(pool, &PostgresDialect).await?;
+
+        let rows = sqlx::query_with::<sqlx::Postgres, _>(AssertSqlSafe(main_sql), main_arguments)
+            .fetch_all(pool)
+            .await?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let record = T::from_row(&row)?;
+            let mut matched_columns = Vec::new();
+            for (column, _) in &matches {
+                let matched: Option<bool> = row.try_get(format!("__match_{}", column).as_str())?;
+                if matched.unwrap_or(false) {
+                    matched_columns.push(column.clone());
+                }
+            }
+            records.push((record, MatchInfo { matched_columns }));
+        }
+        let (next_cursor, previous_cursor) = self.build_cursors(&mut records, |(record, _)| record);
+        let page_bounds = self.build_page_bounds(records.iter().map(|(record, _)| record));
+        let next_page_token =
+            self.build_next_page_token(records.len(), self.total_active.or(total));
+        let (has_next, has_previous) = self.build_page_navigation(
+            self.total_active.or(total),
+            next_cursor.as_ref(),
+            previous_cursor.as_ref(),
+        );
+
+        Ok(PaginatedResponse {
+            records,
+            pagination,
+            total,
+            total_pages,
+            total_is_estimate,
+            total_active: self.total_active,
+            total_capped_at: self.build_total_capped_at(total),
+            page_bounds,
+            next_page_token,
+            next_cursor,
+            previous_cursor,
+            has_next,
+            has_previous,
+        })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<'q, T, A> PaginatedQueryBuilder<'q, T, sqlx::Sqlite, A>
+where
+    T: for<'r> FromRow<'r, <sqlx::Sqlite as sqlx::Database>::Row>
+        + Send
+        + Unpin
+        + Serialize
+        + Default,
+    A: IntoArguments<sqlx::Sqlite> + Send,
+{
+    /// Creates a new `PaginatedQueryBuilder` for SQLite with default settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The base query to paginate
+    ///
+    /// # Default Settings
+    ///
+    /// - Totals calculation is enabled
+    /// - Uses default query parameters
+    /// - Uses safe default query building function
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Sqlite};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::PaginatedQueryBuilder;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<Sqlite, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::<UserExample, Sqlite, _>::new_with_defaults(base_query);
+    /// ```
+    pub fn new_with_defaults(query: sqlx::query::QueryAs<'q, sqlx::Sqlite, T, A>) -> Self {
+        use crate::QueryBuilder;
+        Self::new(query, |params| {
+            QueryBuilder::<T, sqlx::Sqlite>::new()
+                .with_search(params)
+                .with_filters(params)
+                .with_date_range(params)
+                .build()
+        })
+    }
+
+    /// Selects from the base query as a subquery instead of a `base_query` CTE,
+    /// producing `SELECT * FROM (sql) AS base_query WHERE ...`.
+    ///
+    /// Some SQLite versions don't push the filters and sorting of the outer query
+    /// into a CTE, scanning the table instead of using its indexes, while they
+    /// flatten an equivalent subquery. Results are the same in both modes. The
+    /// base query can still be referred to as `base_query` by conditions and
+    /// joins, and prepended CTEs are kept.
+    ///
+    /// Requires the `sqlite-inline-base-query` feature.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::paginated_query_as;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// let builder = paginated_query_as::<User, Sqlite>("SELECT * FROM users")
+    ///     .with_inline_base_query();
+    /// ```
+    #[cfg(feature = "sqlite-inline-base-query")]
+    pub fn with_inline_base_query(mut self) -> Self {
+        self.inline_base_query = true;
+        self
+    }
+
+    /// Executes the paginated query and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a `PaginatedResponse<T>` with:
+    /// - Records for the requested page
+    /// - Optional Pagination information (if enabled)
+    /// - Optional total count and total pages (if enabled)
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Implementation Note
+    ///
+    /// All SQL strings are built and kept in scope before executing queries.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{PaginatedQueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_pagination(1, 10)
+    ///     .build();
+    ///
+    /// let result = PaginatedQueryBuilder::<User, Sqlite, _>::new_with_defaults(
+    ///     sqlx::query_as::<Sqlite, User>("SELECT * FROM users")
+    /// )
+    /// .with_params(params)
+    /// .fetch_paginated(&pool)
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated(
+        self,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
+        self.fetch_paginated_from_pool(pool, &crate::paginated_query_as::internal::SqliteDialect)
+            .await
+    }
+
+    /// Executes the paginated query like `fetch_paginated`, returning the records
+    /// in a map keyed by the given function, e.g. for lookup table endpoints.
+    ///
+    /// The records keep the query order. Keys are expected to be unique, e.g. a
+    /// column with a unique index: when several records share a key, the last one
+    /// wins, at the position of the first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite database connection pool
+    /// * `key_fn` - Function returning the key of a record
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct Country {
+    ///     code: String,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    /// let result = paginated_query_as::<Country, Sqlite>("SELECT * FROM countries")
+    ///     .with_params(QueryParamsBuilder::<Country>::new().build())
+    ///     .fetch_paginated_keyed(&pool, |country| country.code.clone())
+    ///     .await?;
+    ///
+    /// println!("{:?}", result.records.get("US").map(|country| &country.name));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_keyed<K, F>(
+        self,
+        pool: &sqlx::SqlitePool,
+        key_fn: F,
+    ) -> Result<KeyedPaginatedResponse<K, T>, sqlx::Error>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        Ok(self.fetch_paginated(pool).await?.into_keyed(key_fn))
+    }
+
+    /// Returns the records and count SQL `fetch_paginated` would execute, with
+    /// their placeholders, without executing them or needing a pool.
+    ///
+    /// For logging the generated SQL or asserting on it in tests. The values are
+    /// bound as for `fetch_paginated`, which may be called afterwards. The count
+    /// SQL is returned even when totals are disabled, and autocomplete queries
+    /// aren't covered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` when fetching would fail before reaching the
+    /// database, e.g. for a missing required sort or an offset past
+    /// `with_max_offset`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// let (records_sql, count_sql) = paginated_query_as::<User, Sqlite>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().with_filter("name", Some("john")).build())
+    ///     .to_sql()
+    ///     .unwrap();
+    ///
+    /// assert!(records_sql.contains("\"name\" = ?"));
+    /// assert!(count_sql.contains("COUNT(*)"));
+    /// ```
+    pub fn to_sql(&mut self) -> Result<(String, String), sqlx::Error> {
+        self.build_sql_from_dialect(&crate::paginated_query_as::internal::SqliteDialect)
+    }
+
+    /// Executes the paginated query like `fetch_paginated`, returning the SQL of
+    /// the records and count queries next to the results, e.g. for audit logging
+    /// or one-off debugging without a `PaginationContext` observer.
+    ///
+    /// The SQL is recorded as the queries are executed. The count SQL is `None`
+    /// when no count query ran: totals disabled, a total given with
+    /// `with_cached_total`, read from a counter table or the window count,
+    /// estimated, or reused from a `PaginationContext`. Autocomplete queries
+    /// aren't covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    /// let (result, executed) = paginated_query_as::<User, Sqlite>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().build())
+    ///     .fetch_paginated_debug(&pool)
+    ///     .await?;
+    ///
+    /// println!("{} ({} arguments)", executed.records_sql, executed.arguments);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_debug(
+        self,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<(PaginatedResponse<T>, ExecutedSql), sqlx::Error> {
+        self.fetch_paginated_debug_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::SqliteDialect,
+        )
+        .await
+    }
+
+    /// Runs only the count query, returning the pagination metadata without the
+    /// records, e.g. to prefetch a paginator.
+    ///
+    /// The count honours `with_cached_total` and `with_counter_table`, and runs
+    /// even when totals are disabled with `disable_totals_count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    /// let meta = paginated_query_as::<User, Sqlite>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().with_pagination(2, 20).build())
+    ///     .fetch_meta(&pool)
+    ///     .await?;
+    ///
+    /// println!("page {} of {}", meta.page, meta.total_pages);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_meta(self, pool: &sqlx::SqlitePool) -> Result<PageMeta, sqlx::Error> {
+        self.fetch_meta_from_pool(pool, &crate::paginated_query_as::internal::SqliteDialect)
+            .await
+    }
+
+    /// Finds the page containing a record under the current sort and filters.
+    ///
+    /// Counts the matching rows sorting strictly before the record and divides by
+    /// the page size, which helps "jump to record" navigation. Rows tied with the
+    /// record on every sort column are not counted, so add a unique sort column
+    /// when ties are possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite database connection pool
+    /// * `pk_column` - Column uniquely identifying the record
+    /// * `pk_value` - Value of `pk_column` for the record
+    ///
+    /// # Returns
+    ///
+    /// Returns the 1-based page number, or `None` when the record doesn't match the filters
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error::ColumnNotFound` when `pk_column` isn't a model field,
+    /// or `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// let page = paginated_query_as::<User, Sqlite>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_page_of(&pool, "id", 42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_page_of<V>(
+        self,
+        pool: &sqlx::SqlitePool,
+        pk_column: &str,
+        pk_value: V,
+    ) -> Result<Option<i64>, sqlx::Error>
+    where
+        V: for<'e> Encode<'e, sqlx::Sqlite> + Type<sqlx::Sqlite> + Send + 'q,
+    {
+        self.fetch_page_of_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::SqliteDialect,
+            pk_column,
+            pk_value,
+        )
+        .await
+    }
+
+    /// Executes the paginated query, returning each record with its 1-based row
+    /// number among all rows matching the filters, under the current sort.
+    ///
+    /// Row numbers come from `ROW_NUMBER()` over the same ORDER BY as the page, so
+    /// the records of page 2 of size 10 are numbered 11 to 20. Rows tied on every
+    /// sort column may be numbered in any order among themselves, so add a unique
+    /// sort column when ties are possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution or decoding fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, SqlitePool, Sqlite};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_pagination(2, 10)
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// let result = paginated_query_as::<User, Sqlite>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_paginated_with_row_numbers(&pool)
+    ///     .await?;
+    ///
+    /// for (user, row_number) in result.records {
+    ///     println!("#{} {}", row_number, user.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_with_row_numbers(
+        self,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<PaginatedResponse<(T, i64)>, sqlx::Error> {
+        self.fetch_paginated_with_row_numbers_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::SqliteDialect,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<'q, T, A> PaginatedQueryBuilder<'q, T, sqlx::MySql, A>
+where
+    T: for<'r> FromRow<'r, <sqlx::MySql as sqlx::Database>::Row>
+        + Send
+        + Unpin
+        + Serialize
+        + Default,
+    A: IntoArguments<sqlx::MySql> + Send,
+{
+    /// Creates a new `PaginatedQueryBuilder` for MySQL with default settings.
+    ///
+    /// Prepended CTEs with arguments, and
+    /// `with_sort_coalesce` in `fetch_page_of` and
+    /// `fetch_paginated_with_row_numbers`, bind arguments out of statement order
+    /// and fail with `PaginationError::UnorderedArguments`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The base query to paginate
+    ///
+    /// # Default Settings
+    ///
+    /// - Totals calculation is enabled
+    /// - Uses default query parameters
+    /// - Uses safe default query building function
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, MySql};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::PaginatedQueryBuilder;
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let base_query = sqlx::query_as::<MySql, UserExample>("SELECT * FROM users");
+    /// let builder = PaginatedQueryBuilder::<UserExample, MySql, _>::new_with_defaults(base_query);
+    /// ```
+    pub fn new_with_defaults(query: sqlx::query::QueryAs<'q, sqlx::MySql, T, A>) -> Self {
+        use crate::QueryBuilder;
+        Self::new(query, |params| {
+            QueryBuilder::<T, sqlx::MySql>::new()
+                .with_search(params)
+                .with_filters(params)
+                .with_date_range(params)
+                .build()
+        })
+    }
+
+    /// Executes the paginated query and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - MySQL database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing a `PaginatedResponse<T>` with:
+    /// - Records for the requested page
+    /// - Optional Pagination information (if enabled)
+    /// - Optional total count and total pages (if enabled)
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Implementation Note
+    ///
+    /// All SQL strings are built and kept in scope before executing queries.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, MySqlPool, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{PaginatedQueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: MySqlPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_pagination(1, 10)
+    ///     .build();
+    ///
+    /// let result = PaginatedQueryBuilder::<User, MySql, _>::new_with_defaults(
+    ///     sqlx::query_as::<MySql, User>("SELECT * FROM users")
+    /// )
+    /// .with_params(params)
+    /// .fetch_paginated(&pool)
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated(
+        self,
+        pool: &sqlx::MySqlPool,
+    ) -> Result<PaginatedResponse<T>, sqlx::Error> {
+        self.fetch_paginated_from_pool(pool, &crate::paginated_query_as::internal::MySqlDialect)
+            .await
+    }
+
+    /// Executes the paginated query like `fetch_paginated`, returning the records
+    /// in a map keyed by the given function, e.g. for lookup table endpoints.
+    ///
+    /// The records keep the query order. Keys are expected to be unique, e.g. a
+    /// column with a unique index: when several records share a key, the last one
+    /// wins, at the position of the first.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - MySQL database connection pool
+    /// * `key_fn` - Function returning the key of a record
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, MySqlPool, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct Country {
+    ///     code: String,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: MySqlPool) -> Result<(), sqlx::Error> {
+    /// let result = paginated_query_as::<Country, MySql>("SELECT * FROM countries")
+    ///     .with_params(QueryParamsBuilder::<Country>::new().build())
+    ///     .fetch_paginated_keyed(&pool, |country| country.code.clone())
+    ///     .await?;
+    ///
+    /// println!("{:?}", result.records.get("US").map(|country| &country.name));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_keyed<K, F>(
+        self,
+        pool: &sqlx::MySqlPool,
+        key_fn: F,
+    ) -> Result<KeyedPaginatedResponse<K, T>, sqlx::Error>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        Ok(self.fetch_paginated(pool).await?.into_keyed(key_fn))
+    }
+
+    /// Returns the records and count SQL `fetch_paginated` would execute, with
+    /// their placeholders, without executing them or needing a pool.
+    ///
+    /// For logging the generated SQL or asserting on it in tests. The values are
+    /// bound as for `fetch_paginated`, which may be called afterwards. The count
+    /// SQL is returned even when totals are disabled, and autocomplete queries
+    /// aren't covered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` when fetching would fail before reaching the
+    /// database, e.g. for a missing required sort or an offset past
+    /// `with_max_offset`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::{FromRow, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// let (records_sql, count_sql) = paginated_query_as::<User, MySql>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().with_filter("name", Some("john")).build())
+    ///     .to_sql()
+    ///     .unwrap();
+    ///
+    /// assert!(records_sql.contains("`name` = ?"));
+    /// assert!(count_sql.contains("COUNT(*)"));
+    /// ```
+    pub fn to_sql(&mut self) -> Result<(String, String), sqlx::Error> {
+        self.build_sql_from_dialect(&crate::paginated_query_as::internal::MySqlDialect)
+    }
+
+    /// Executes the paginated query like `fetch_paginated`, returning the SQL of
+    /// the records and count queries next to the results, e.g. for audit logging
+    /// or one-off debugging without a `PaginationContext` observer.
+    ///
+    /// The SQL is recorded as the queries are executed. The count SQL is `None`
+    /// when no count query ran: totals disabled, a total given with
+    /// `with_cached_total`, read from a counter table or the window count,
+    /// estimated, or reused from a `PaginationContext`. Autocomplete queries
+    /// aren't covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - MySQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, MySqlPool, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: MySqlPool) -> Result<(), sqlx::Error> {
+    /// let (result, executed) = paginated_query_as::<User, MySql>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().build())
+    ///     .fetch_paginated_debug(&pool)
+    ///     .await?;
+    ///
+    /// println!("{} ({} arguments)", executed.records_sql, executed.arguments);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_debug(
+        self,
+        pool: &sqlx::MySqlPool,
+    ) -> Result<(PaginatedResponse<T>, ExecutedSql), sqlx::Error> {
+        self.fetch_paginated_debug_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::MySqlDialect,
+        )
+        .await
+    }
+
+    /// Runs only the count query, returning the pagination metadata without the
+    /// records, e.g. to prefetch a paginator.
+    ///
+    /// The count honours `with_cached_total` and `with_counter_table`, and runs
+    /// even when totals are disabled with `disable_totals_count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - MySQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, MySqlPool, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: MySqlPool) -> Result<(), sqlx::Error> {
+    /// let meta = paginated_query_as::<User, MySql>("SELECT * FROM users")
+    ///     .with_params(QueryParamsBuilder::<User>::new().with_pagination(2, 20).build())
+    ///     .fetch_meta(&pool)
+    ///     .await?;
+    ///
+    /// println!("page {} of {}", meta.page, meta.total_pages);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_meta(self, pool: &sqlx::MySqlPool) -> Result<PageMeta, sqlx::Error> {
+        self.fetch_meta_from_pool(pool, &crate::paginated_query_as::internal::MySqlDialect)
+            .await
+    }
+
+    /// Finds the page containing a record under the current sort and filters.
+    ///
+    /// Counts the matching rows sorting strictly before the record and divides by
+    /// the page size, which helps "jump to record" navigation. Rows tied with the
+    /// record on every sort column are not counted, so add a unique sort column
+    /// when ties are possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - MySQL database connection pool
+    /// * `pk_column` - Column uniquely identifying the record
+    /// * `pk_value` - Value of `pk_column` for the record
+    ///
+    /// # Returns
+    ///
+    /// Returns the 1-based page number, or `None` when the record doesn't match the filters
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error::ColumnNotFound` when `pk_column` isn't a model field,
+    /// or `sqlx::Error` if the query execution fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, MySqlPool, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: MySqlPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// let page = paginated_query_as::<User, MySql>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_page_of(&pool, "id", 42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_page_of<V>(
+        self,
+        pool: &sqlx::MySqlPool,
+        pk_column: &str,
+        pk_value: V,
+    ) -> Result<Option<i64>, sqlx::Error>
+    where
+        V: for<'e> Encode<'e, sqlx::MySql> + Type<sqlx::MySql> + Send + 'q,
+    {
+        self.fetch_page_of_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::MySqlDialect,
+            pk_column,
+            pk_value,
+        )
+        .await
+    }
+
+    /// Executes the paginated query, returning each record with its 1-based row
+    /// number among all rows matching the filters, under the current sort.
+    ///
+    /// Row numbers come from `ROW_NUMBER()` over the same ORDER BY as the page, so
+    /// the records of page 2 of size 10 are numbered 11 to 20. Rows tied on every
+    /// sort column may be numbered in any order among themselves, so add a unique
+    /// sort column when ties are possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - MySQL database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` if the query execution or decoding fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sqlx::{FromRow, MySqlPool, MySql};
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, FromRow, Default)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example(pool: MySqlPool) -> Result<(), sqlx::Error> {
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_pagination(2, 10)
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// let result = paginated_query_as::<User, MySql>("SELECT * FROM users")
+    ///     .with_params(params)
+    ///     .fetch_paginated_with_row_numbers(&pool)
+    ///     .await?;
+    ///
+    /// for (user, row_number) in result.records {
+    ///     println!("#{} {}", row_number, user.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paginated_with_row_numbers(
+        self,
+        pool: &sqlx::MySqlPool,
+    ) -> Result<PaginatedResponse<(T, i64)>, sqlx::Error> {
+        self.fetch_paginated_with_row_numbers_from_pool(
+            pool,
+            &crate::paginated_query_as::internal::MySqlDialect,
+        )
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{paginated_query_as, ComputedPropertyBuilder, QueryParamsBuilder};
+    use sqlx::Postgres;
+
+    #[derive(Serialize, FromRow, Default)]
+    struct TestModel {
+        id: i32,
+        name: String,
+        created_at: String,
+        updated_at: String,
+    }
+
+    fn sorted_by(column: &str) -> QueryParams<'static, TestModel> {
+        QueryParamsBuilder::<TestModel>::new()
+            .with_sort(column, QuerySortDirection::Descending)
+            .build()
+    }
+
+    #[test]
+    fn test_plain_sort_column_is_quoted() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(sorted_by("created_at"));
+
+        assert_eq!(
+            builder.build_records_sql(&[], ""),
+            "WITH base_query AS (SELECT * FROM users) SELECT * FROM base_query \
+             ORDER BY \"created_at\" DESC"
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_sort_wraps_column_in_lower() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_sort_case_insensitive("name", QuerySortDirection::Ascending)
+                .build(),
+        );
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .ends_with(" ORDER BY LOWER(\"name\") ASC"));
+    }
+
+    #[test]
+    fn test_case_insensitive_sort_skips_non_text_columns() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort_case_insensitive("id", QuerySortDirection::Ascending)
+            .build();
+        let builder =
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(params);
+
+        assert!(!builder.build_records_sql(&[], "").contains("LOWER("));
+    }
+
+    #[test]
+    fn test_sort_order_map_emits_case_in_given_order() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestModel>::new()
+                    .with_sort_case_insensitive("name", QuerySortDirection::Ascending)
+                    .build(),
+            )
+            .with_sort_order_map("name", vec!["high", "medium", "low"])
+            .with_sort_order_map("unknown", vec!["a"]);
+
+        assert!(builder.build_records_sql(&[], "").ends_with(
+            " ORDER BY CASE \"name\" WHEN 'high' THEN 0 WHEN 'medium' THEN 1 \
+             WHEN 'low' THEN 2 ELSE 3 END ASC"
+        ));
+        assert!(!builder.sort_order_maps.contains_key("unknown"));
+    }
+
+    #[test]
+    fn test_sort_order_map_ignores_empty_order() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(sorted_by("name"))
+            .with_sort_order_map("name", vec![]);
+
+        assert!(builder.sort_order_maps.is_empty());
+        assert!(builder
+            .build_records_sql(&[], "")
+            .ends_with(" ORDER BY \"name\" DESC"));
+    }
+
+    #[test]
+    fn test_computed_property_expression_in_order_by() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(
+                ComputedPropertyBuilder::new("last_activity", "GREATEST(updated_at, created_at)")
+                    .with_tie_breaker("id")
+                    .build(),
+            )
+            .with_params(sorted_by("last_activity"));
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .contains(" ORDER BY (GREATEST(updated_at, created_at)) DESC, \"id\" DESC"));
+    }
+
+    #[test]
+    fn test_unregistered_computed_property_is_treated_as_column() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(ComputedProperty::new(
+                "last_activity",
+                "GREATEST(updated_at, created_at)",
+            ))
+            .with_params(sorted_by("priority"));
+
+        let sql = builder.build_records_sql(&[], "");
+        assert!(sql.contains(" ORDER BY \"priority\" DESC"));
+        assert!(!sql.contains("GREATEST"));
+    }
+
+    #[test]
+    fn test_joined_conditions_qualify_columns_with_base_query() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let property = ComputedPropertyBuilder::new("score", "COALESCE(stats.score, 0)")
+            .with_join("LEFT JOIN stats ON stats.user_id = base_query.id")
+            .build();
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("name", Some("John"))
+            .with_sort("score", QuerySortDirection::Descending)
+            .build();
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(property)
+            .with_query_builder(|params| {
+                crate::QueryBuilder::<TestModel, Postgres>::new()
+                    .with_filters(params)
+                    .with_exists("stats", ("user_id", "id"), vec![])
+                    .build()
+            })
+            .with_params(params);
+
+        let (conditions, _) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(
+            conditions,
+            vec![
+                "base_query.\"name\" = $1".to_string(),
+                "EXISTS (SELECT 1 FROM \"stats\" WHERE \"stats\".\"user_id\" = base_query.\"id\")"
+                    .to_string(),
+            ]
+        );
+
+        let (conditions, _) = builder
+            .with_params(sorted_by("name"))
+            .build_conditions(&PostgresDialect)
+            .unwrap();
+        assert_eq!(
+            conditions,
+            vec!["EXISTS (SELECT 1 FROM \"stats\" WHERE \"stats\".\"user_id\" = \"base_query\".\"id\")".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_computed_property_joins_only_when_sorting_by_it() {
+        let property = ComputedPropertyBuilder::new("score", "COALESCE(stats.score, 0)")
+            .with_join("LEFT JOIN stats ON stats.user_id = base_query.id")
+            .build();
+        let conditions = vec!["\"name\" = $1".to_string()];
+
+        let active = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(property.clone())
+            .with_params(sorted_by("score"));
+        assert!(active.build_records_sql(&conditions, "").contains(
+            "SELECT base_query.* FROM base_query LEFT JOIN stats ON stats.user_id = base_query.id WHERE \"name\" = $1"
+        ));
+        assert!(!active.build_count_sql(&conditions).contains("JOIN"));
+
+        let inactive = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(property)
+            .with_params(sorted_by("name"));
+        assert!(!inactive.build_records_sql(&conditions, "").contains("JOIN"));
+    }
+
+    #[test]
+    fn test_searched_property_joins_records_and_count() {
+        let tag = ComputedPropertyBuilder::new("tag", "tags.label")
+            .with_join("JOIN tags ON tags.user_id = base_query.id")
+            .searchable()
+            .build();
+        let conditions = vec!["(LOWER((tags.label)) LIKE LOWER($1))".to_string()];
+        let searched = |column: &str| {
+            QueryParamsBuilder::<TestModel>::new()
+                .with_search("rust", vec![column])
+                .build()
+        };
+
+        let duplicated = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(tag.clone())
+            .with_params(searched("tag"));
+        assert!(duplicated.build_records_sql(&conditions, "").contains(
+            "SELECT base_query.* FROM base_query JOIN tags ON tags.user_id = base_query.id WHERE"
+        ));
+        assert_eq!(
+            duplicated.build_count_sql(&conditions),
+            "WITH base_query AS (SELECT * FROM users) SELECT COUNT(*) FROM base_query \
+             JOIN tags ON tags.user_id = base_query.id WHERE (LOWER((tags.label)) LIKE LOWER($1))"
+        );
+
+        let distinct = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(tag.clone())
+            .with_params(searched("tag"))
+            .with_primary_key("id")
+            .with_distinct();
+        assert!(distinct.build_records_sql(&conditions, "").contains(
+            " GROUP BY base_query.\"created_at\", base_query.\"id\", base_query.\"name\", base_query.\"updated_at\" \
+             ORDER BY base_query.\"created_at\" DESC"
+        ));
+        assert_eq!(
+            distinct.build_count_sql(&conditions),
+            "WITH base_query AS (SELECT * FROM users) SELECT COUNT(DISTINCT base_query.\"id\") \
+             FROM base_query JOIN tags ON tags.user_id = base_query.id \
+             WHERE (LOWER((tags.label)) LIKE LOWER($1))"
+        );
+
+        let without_primary_key = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(tag.clone())
+            .with_params(searched("tag"))
+            .with_distinct();
+        assert!(without_primary_key.build_count_sql(&conditions).contains(
+            "SELECT COUNT(*) FROM (SELECT DISTINCT base_query.* FROM base_query JOIN tags"
+        ));
+
+        let unsearched = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(tag)
+            .with_params(searched("name"))
+            .with_distinct();
+        assert!(!unsearched.build_records_sql(&[], "").contains("JOIN"));
+        assert!(!unsearched.build_records_sql(&[], "").contains("GROUP BY"));
+        assert!(!unsearched.build_count_sql(&[]).contains("DISTINCT"));
+    }
+
+    #[test]
+    fn test_distinct_on_selects_and_counts_distinct_values() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort("name", QuerySortDirection::Ascending)
+            .with_sort("created_at", QuerySortDirection::Descending)
+            .build();
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(params)
+            .with_distinct_on(vec!["name", "missing", "name"]);
+
+        assert!(builder.check_params().is_ok());
+        assert_eq!(
+            builder.build_records_sql(&[], ""),
+            "WITH base_query AS (SELECT * FROM users) SELECT DISTINCT ON (\"name\") * \
+             FROM base_query ORDER BY \"name\" ASC, \"created_at\" DESC"
+        );
+        assert_eq!(
+            builder.build_count_sql(&[]),
+            "WITH base_query AS (SELECT * FROM users) SELECT COUNT(*) \
+             FROM (SELECT DISTINCT \"name\" FROM base_query) AS distinct_rows"
+        );
+        assert!(!builder.with_window_count().window_count_enabled());
+    }
+
+    #[test]
+    fn test_distinct_on_requires_leading_sort_columns() {
+        let distinct_on = |params: QueryParams<'static, TestModel>| {
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+                .with_params(params)
+                .with_distinct_on(vec!["id", "name"])
+        };
+
+        let swapped = QueryParamsBuilder::<TestModel>::new()
+            .with_sort("name", QuerySortDirection::Ascending)
+            .with_sort("id", QuerySortDirection::Descending)
+            .build();
+        assert!(distinct_on(swapped).check_params().is_ok());
+
+        let trailing = QueryParamsBuilder::<TestModel>::new()
+            .with_sort("name", QuerySortDirection::Ascending)
+            .with_sort("created_at", QuerySortDirection::Descending)
+            .with_sort("id", QuerySortDirection::Descending)
+            .build();
+        assert_eq!(
+            distinct_on(trailing).check_params(),
+            Err(PaginationError::UnsortedDistinctOn {
+                columns: vec!["id".to_string(), "name".to_string()]
+            })
+        );
+        assert!(distinct_on(sorted_by("name")).check_params().is_err());
+    }
+
+    #[test]
+    fn test_registering_same_computed_property_replaces_it() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(ComputedProperty::new("rank", "LEAST(a, b)"))
+            .with_computed_property(ComputedProperty::new("rank", "GREATEST(a, b)"))
+            .with_params(sorted_by("rank"));
+
+        assert_eq!(builder.computed_properties.len(), 1);
+        assert!(builder
+            .build_records_sql(&[], "")
+            .contains(" ORDER BY (GREATEST(a, b)) DESC"));
+    }
+
+    #[test]
+    fn test_selected_filtered_aggregate_groups_by_model_columns() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(
+                ComputedPropertyBuilder::new(
+                    "paid_orders",
+                    "COUNT(orders.id) FILTER (WHERE orders.status = 'paid')",
+                )
+                .with_join("LEFT JOIN orders ON orders.user_id = base_query.id")
+                .as_aggregate()
+                .selected()
+                .build(),
+            )
+            .with_params(sorted_by("paid_orders"));
+
+        assert_eq!(
+            builder.build_records_sql(&["\"name\" = $1".to_string()], ""),
+            "WITH base_query AS (SELECT * FROM users) \
+             SELECT base_query.\"created_at\", base_query.\"id\", base_query.\"name\", base_query.\"updated_at\", \
+             (COUNT(orders.id) FILTER (WHERE orders.status = 'paid')) AS \"paid_orders\" \
+             FROM base_query LEFT JOIN orders ON orders.user_id = base_query.id \
+             WHERE \"name\" = $1 \
+             GROUP BY base_query.\"created_at\", base_query.\"id\", base_query.\"name\", base_query.\"updated_at\" \
+             ORDER BY (COUNT(orders.id) FILTER (WHERE orders.status = 'paid')) DESC"
+        );
+        assert!(!builder.build_count_sql(&[]).contains("GROUP BY"));
+    }
+
+    #[derive(Serialize, FromRow, Default)]
+    struct CustomerModel {
+        id: i32,
+        name: String,
+        order_total: f64,
+    }
+
+    fn order_total_builder(
+        params: QueryParams<'static, CustomerModel>,
+    ) -> PaginatedQueryBuilder<'static, CustomerModel, Postgres, sqlx::postgres::PgArguments> {
+        let order_total = ComputedPropertyBuilder::new("order_total", "SUM(orders.total)")
+            .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+            .as_aggregate()
+            .build();
+        let having_property = order_total.clone();
+
+        paginated_query_as::<CustomerModel, Postgres>("SELECT id, name FROM customers")
+            .with_computed_property(order_total)
+            .with_having_query_builder(move |params| {
+                crate::QueryBuilder::<CustomerModel, Postgres>::new()
+                    .with_aggregate_property(&having_property)
+                    .with_filters(params)
+            })
+            .with_params(params)
+    }
+
+    #[test]
+    fn test_aggregate_property_filter_is_a_having_condition() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<CustomerModel>::new()
+            .with_filter("name", Some("John"))
+            .with_filter_operator(
+                "order_total",
+                crate::QueryFilterOperator::GreaterOrEqual,
+                "100",
+            )
+            .with_sort("name", QuerySortDirection::Ascending)
+            .build();
+        let mut builder = order_total_builder(params);
+
+        let (conditions, _) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, vec!["base_query.\"name\" = $1".to_string()]);
+        assert_eq!(
+            builder.build_records_sql(&conditions, ""),
+            "WITH base_query AS (SELECT id, name FROM customers) \
+             SELECT base_query.\"id\", base_query.\"name\" \
+             FROM base_query LEFT JOIN orders ON orders.customer_id = base_query.id \
+             WHERE base_query.\"name\" = $1 \
+             GROUP BY base_query.\"id\", base_query.\"name\" \
+             HAVING (SUM(orders.total)) >= $2 \
+             ORDER BY base_query.\"name\" ASC"
+        );
+
+        let (conditions, _) = builder.build_count_conditions(&PostgresDialect).unwrap();
+        assert_eq!(
+            builder.build_count_sql(&conditions),
+            "WITH base_query AS (SELECT id, name FROM customers) \
+             SELECT COUNT(*) FROM (SELECT 1 FROM base_query \
+             LEFT JOIN orders ON orders.customer_id = base_query.id \
+             WHERE base_query.\"name\" = $1 \
+             GROUP BY base_query.\"id\", base_query.\"name\" \
+             HAVING (SUM(orders.total)) >= $2) AS grouped_rows"
+        );
+    }
+
+    #[test]
+    fn test_default_query_builder_filters_aggregate_properties_in_having() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<CustomerModel>::new()
+            .with_filter_operator(
+                "order_total",
+                crate::QueryFilterOperator::GreaterThan,
+                "99.5",
+            )
+            .build();
+        let mut builder =
+            paginated_query_as::<CustomerModel, Postgres>("SELECT id, name FROM customers")
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("order_total", "SUM(orders.total)")
+                        .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+                        .as_aggregate()
+                        .build(),
+                )
+                .with_params(params);
+
+        let (conditions, arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert!(conditions.is_empty());
+        assert_eq!(arguments.len(), 1);
+        assert_eq!(builder.having, vec!["(SUM(orders.total)) > $1".to_string()]);
+    }
+
+    #[test]
+    fn test_unfiltered_aggregate_property_adds_no_grouping() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<CustomerModel>::new()
+            .with_filter("name", Some("John"))
+            .build();
+        let mut builder = order_total_builder(params);
+
+        let (conditions, _) = builder.build_count_conditions(&PostgresDialect).unwrap();
+        let sql = builder.build_count_sql(&conditions);
+        assert!(!sql.contains("GROUP BY"));
+        assert!(!sql.contains("JOIN"));
+    }
+
+    #[test]
+    fn test_selected_property_qualifies_plain_sort_column_when_joined() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(
+                ComputedPropertyBuilder::new("score", "stats.score")
+                    .with_join("LEFT JOIN stats ON stats.user_id = base_query.id")
+                    .selected()
+                    .build(),
+            )
+            .with_params(sorted_by("created_at"));
+
+        let sql = builder.build_records_sql(&[], "");
+        assert!(sql.contains("SELECT base_query.*, (stats.score) AS \"score\" FROM base_query"));
+        assert!(sql.contains(" ORDER BY base_query.\"created_at\" DESC"));
+        assert!(!sql.contains("GROUP BY"));
+    }
+
+    #[test]
+    fn test_union_base_query_is_wrapped_as_a_whole() {
+        let builder = paginated_query_as::<TestModel, Postgres>(
+            "SELECT id, name FROM users UNION ALL SELECT id, name FROM admins;\n",
+        )
+        .with_params(sorted_by("name"));
+        let conditions = vec!["\"id\" <= $1".to_string()];
+
+        assert_eq!(
+            builder.build_records_sql(&conditions, ""),
+            "WITH base_query AS (SELECT id, name FROM users UNION ALL SELECT id, name FROM admins) \
+             SELECT * FROM base_query WHERE \"id\" <= $1 ORDER BY \"name\" DESC"
+        );
+        assert_eq!(
+            builder.build_count_sql(&conditions),
+            "WITH base_query AS (SELECT id, name FROM users UNION ALL SELECT id, name FROM admins) \
+             SELECT COUNT(*) FROM base_query WHERE \"id\" <= $1"
+        );
+    }
+
+    #[test]
+    fn test_records_select_only_replaces_records_selection() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_records_select("*, ROW_NUMBER() OVER (ORDER BY created_at) AS position")
+            .with_params(sorted_by("name"));
+
+        assert!(builder.build_records_sql(&[], "").contains(
+            "SELECT *, ROW_NUMBER() OVER (ORDER BY created_at) AS position FROM base_query ORDER BY"
+        ));
+        assert!(builder
+            .build_count_sql(&[])
+            .ends_with("SELECT COUNT(*) FROM base_query"));
+    }
+
+    #[test]
+    fn test_records_select_ignores_invalid_fragments() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_records_select("   ")
+            .with_records_select("*; DROP TABLE users")
+            .with_params(sorted_by("name"));
+
+        assert!(builder.records_select.is_none());
+        assert!(builder
+            .build_records_sql(&[], "")
+            .contains("SELECT * FROM base_query ORDER BY"));
+    }
+
+    #[derive(Serialize, FromRow, Default)]
+    struct UpdatedOnlyModel {
+        id: i32,
+        updated_at: String,
+    }
+
+    #[derive(Serialize, FromRow, Default)]
+    struct IdOnlyModel {
+        id: i32,
+    }
+
+    #[test]
+    fn test_default_sort_falls_back_to_updated_at() {
+        let builder = paginated_query_as::<UpdatedOnlyModel, Postgres>("SELECT * FROM users");
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .contains(" ORDER BY \"updated_at\" DESC"));
+    }
+
+    #[test]
+    fn test_explicit_created_at_sort_is_not_rewritten() {
+        let params: QueryParams<UpdatedOnlyModel> = serde_json::from_value::<FlatQueryParams>(
+            serde_json::json!({ "sort_column": "created_at" }),
+        )
+        .unwrap()
+        .into();
+        let builder = paginated_query_as::<UpdatedOnlyModel, Postgres>("SELECT * FROM users")
+            .with_params(params);
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .contains(" ORDER BY \"created_at\" DESC"));
+    }
+
+    #[test]
+    fn test_default_sort_falls_back_to_id() {
+        let builder = paginated_query_as::<IdOnlyModel, Postgres>("SELECT * FROM users");
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .contains(" ORDER BY \"id\" DESC"));
+    }
+
+    #[test]
+    fn test_order_by_emits_every_sort_column() {
+        let params: QueryParams<TestModel> = serde_json::from_value::<FlatQueryParams>(
+            serde_json::json!({ "order_by": "name.asc,last_activity.desc,-id" }),
+        )
+        .unwrap()
+        .into();
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(ComputedProperty::new(
+                "last_activity",
+                "GREATEST(updated_at, created_at)",
+            ))
+            .with_params(params);
+
+        assert!(builder.build_records_sql(&[], "").ends_with(
+            " ORDER BY \"name\" ASC, (GREATEST(updated_at, created_at)) DESC, \"id\" DESC"
+        ));
+    }
+
+    #[test]
+    fn test_repeated_with_sort_emits_every_sort_column() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_sort("name", QuerySortDirection::Ascending)
+                .with_sort("created_at", QuerySortDirection::Descending)
+                .build(),
+        );
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .ends_with(" ORDER BY \"name\" ASC, \"created_at\" DESC"));
+    }
+
+    #[test]
+    fn test_sort_nulls_position_emits_nulls_clause() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(
+            QueryParamsBuilder::<TestModel>::new()
+                .with_sort_nulls(
+                    "updated_at",
+                    QuerySortDirection::Ascending,
+                    QueryNullsPosition::First,
+                )
+                .with_sort("id", QuerySortDirection::Descending)
+                .build(),
+        );
+
+        assert!(builder
+            .build_records_sql(&[], "")
+            .ends_with(" ORDER BY \"updated_at\" ASC NULLS FIRST, \"id\" DESC"));
+    }
+
+    #[test]
+    fn test_page_of_compares_sort_terms_in_precedence_order() {
+        let params: QueryParams<TestModel> = serde_json::from_value::<FlatQueryParams>(
+            serde_json::json!({ "order_by": "name.asc,-id" }),
+        )
+        .unwrap()
+        .into();
+        let builder =
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(params);
+        let sql = builder.build_page_of_sql(&["\"name\" = $1".to_string()], "id", "$2");
+
+        assert!(sql.contains(
+            "sorted AS (SELECT \"id\" AS \"__pk\", \"name\" AS \"__sort_0\", \"id\" AS \"__sort_1\" \
+             FROM base_query WHERE \"name\" = $1), target AS (SELECT * FROM sorted WHERE \"__pk\" = $2)"
+        ));
+        assert!(sql.ends_with(
+            "WHERE (sorted.\"__sort_0\" < target.\"__sort_0\") OR (sorted.\"__sort_0\" = target.\"__sort_0\" \
+             AND sorted.\"__sort_1\" > target.\"__sort_1\"))"
+        ));
+    }
+
+    #[test]
+    fn test_match_selection_flags_each_search_column() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_search("jo", vec!["name", "missing", "id"])
+            .build();
+        let builder =
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(params);
+
+        let mut arguments = sqlx::postgres::PgArguments::default();
+        arguments.add(1_i64).unwrap();
+        arguments.add(2_i64).unwrap();
+        let (matches, arguments) = QueryBuilder::<TestModel, Postgres>::new()
+            .build_search_matches(&builder.params, arguments);
+        assert_eq!(arguments.len(), 3);
+
+        let selection = builder.build_match_selection(&matches);
+        let sql = builder.build_records_sql_with_selection(&[], &selection, "");
+        assert!(sql.contains(
+            "SELECT *, (LOWER(\"name\") LIKE LOWER($3)) AS \"__match_name\", \
+             (LOWER(\"id\") LIKE LOWER($3)) AS \"__match_id\" FROM base_query"
+        ));
+    }
+
+    #[test]
+    fn test_autocomplete_reuses_term_placeholder() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_autocomplete("name", "jhon", 5, 0.4);
+        let autocomplete = builder.autocomplete.as_ref().unwrap();
+        assert!(!builder.totals_count_enabled);
+
+        let (condition, order_clause) = builder.build_autocomplete_clauses(autocomplete, "$2");
+        let sql = builder.build_records_sql_with_clauses(
+            &["\"id\" > $1".to_string(), condition],
+            &[],
+            &order_clause,
+            " LIMIT $3",
+        );
+
+        assert_eq!(autocomplete.limit, 5);
+        assert!(sql.ends_with(
+            "SELECT * FROM base_query WHERE \"id\" > $1 AND \"name\" % $2 \
+             AND similarity(\"name\", $2) >= 0.4 ORDER BY similarity(\"name\", $2) DESC LIMIT $3"
+        ));
+    }
+
+    #[test]
+    fn test_limit_offset_are_bound_after_conditions() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(3, 20)
+            .with_filter("name", Some("John"))
+            .build();
+        let mut builder =
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_params(params);
+
+        let (conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        let limit_clause = builder
+            .bind_limit_offset(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(limit_clause, " LIMIT $2 OFFSET $3");
+        assert_eq!(arguments.len(), 3);
+
+        let sql = builder.build_records_sql(&conditions, &limit_clause);
+        assert!(
+            sql.ends_with("WHERE \"name\" = $1 ORDER BY \"created_at\" DESC LIMIT $2 OFFSET $3")
+        );
+        assert!(!sql.contains("20") && !sql.contains("40"));
+    }
+
+    #[test]
+    fn test_first_page_omits_offset() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let limit_clause = |page: i64| {
+            let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+                .with_params(
+                    QueryParamsBuilder::<TestModel>::new()
+                        .with_pagination(page, 10)
+                        .build(),
+                );
+            let mut arguments = Default::default();
+            let clause = builder
+                .bind_limit_offset(&mut arguments, &PostgresDialect)
+                .unwrap();
+            (clause, arguments.len())
+        };
+
+        let (first, arguments) = limit_clause(1);
+        assert_eq!(first, " LIMIT $1");
+        assert!(!first.contains("OFFSET"));
+        assert_eq!(arguments, 1);
+
+        let (second, arguments) = limit_clause(2);
+        assert_eq!(second, " LIMIT $1 OFFSET $2");
+        assert_eq!(arguments, 2);
+    }
+
+    #[derive(Serialize, FromRow, Default)]
+    struct ArchivedModel {
+        id: i32,
+        name: String,
+        deleted_at: Option<String>,
+    }
+
+    #[test]
+    fn test_count_includes_trashed_omits_the_soft_delete_condition() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<ArchivedModel>::new()
+            .with_filter("name", Some("John"))
+            .build();
+        let mut builder = paginated_query_as::<ArchivedModel, Postgres>("SELECT * FROM users")
+            .with_params(params)
+            .with_soft_delete("deleted_at")
+            .with_count_includes_trashed();
+
+        let (conditions, _) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(
+            conditions,
+            vec![
+                "\"name\" = $1".to_string(),
+                "\"deleted_at\" IS NULL".to_string()
+            ]
+        );
+
+        let (conditions, _) = builder.build_count_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, vec!["\"name\" = $1".to_string()]);
+
+        let (conditions, _) = builder
+            .build_count_conditions_with_trashed(&PostgresDialect, false)
+            .unwrap();
+        assert_eq!(conditions.len(), 2);
+    }
+
+    #[test]
+    fn test_require_filter_rejects_params_without_the_filter() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+        use crate::QueryFilterOperator;
+
+        let missing = || {
+            Err::<(), _>(PaginationError::MissingRequiredFilter {
+                field: "name".to_string(),
+            })
+        };
+        let check = |params| {
+            paginated_query_as::<ArchivedModel, Postgres>("SELECT * FROM users")
+                .with_params(params)
+                .require_filter("name")
+                .build_conditions(&PostgresDialect)
+                .map(|_| ())
+                .map_err(|error| PaginationError::from_sqlx_error(&error).cloned().unwrap())
+        };
+
+        let searched = QueryParamsBuilder::<ArchivedModel>::new()
+            .with_filter("id", Some("1"))
+            .with_search("john", vec!["name"])
+            .build();
+        assert_eq!(check(searched), missing());
+
+        // An empty IN list emits no condition
+        let dropped = QueryParamsBuilder::<ArchivedModel>::new()
+            .with_filter_operator("name", QueryFilterOperator::In, "")
+            .build();
+        assert_eq!(check(dropped), missing());
+
+        let unscoped = QueryParamsBuilder::<ArchivedModel>::new()
+            .with_filter_operator("name", QueryFilterOperator::IsNotNull, "")
+            .build();
+        assert_eq!(check(unscoped), missing());
+    }
+
+    #[test]
+    fn test_require_filter_accepts_params_with_the_filter() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let mut builder = paginated_query_as::<ArchivedModel, Postgres>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<ArchivedModel>::new()
+                    .with_filter("name", Some("John"))
+                    .build(),
+            )
+            .require_filter("name");
+
+        let (conditions, _) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, vec!["\"name\" = $1".to_string()]);
+    }
+
+    #[test]
+    fn test_default_scope_conditions_follow_the_filters() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let scoped = || {
+            paginated_query_as::<ArchivedModel, Postgres>("SELECT * FROM users")
+                .with_params(
+                    QueryParamsBuilder::<ArchivedModel>::new()
+                        .with_filter("name", Some("John"))
+                        .build(),
+                )
+                .with_default_scope(|query_builder| {
+                    query_builder
+                        .with_raw_condition("\"deleted_at\" IS NULL")
+                        .with_condition("id", ">", "7".to_string())
+                })
+        };
+
+        let mut builder = scoped();
+        let expected = vec![
+            "\"name\" = $1".to_string(),
+            "\"deleted_at\" IS NULL".to_string(),
+            "\"id\" > $2".to_string(),
+        ];
+        let (conditions, arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, expected);
+        assert_eq!(arguments.len(), 2);
+        let (conditions, _) = builder.build_count_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, expected);
+
+        let mut builder = scoped().with_include_deleted();
+        let (conditions, arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, vec!["\"name\" = $1".to_string()]);
+        assert_eq!(arguments.len(), 1);
+    }
+
+    #[test]
+    fn test_soft_delete_condition_is_qualified_with_joins() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let mut builder = paginated_query_as::<ArchivedModel, Postgres>("SELECT * FROM users")
+            .with_computed_property(
+                ComputedPropertyBuilder::new("note", "notes.body")
+                    .with_join("LEFT JOIN notes ON notes.user_id = base_query.id")
+                    .build(),
+            )
+            .with_params(
+                QueryParamsBuilder::<ArchivedModel>::new()
+                    .with_sort("note", QuerySortDirection::Ascending)
+                    .build(),
+            )
+            .with_soft_delete("deleted_at");
+
+        let (conditions, _) = builder.build_conditions(&PostgresDialect).unwrap();
+        assert_eq!(
+            conditions,
+            vec!["base_query.\"deleted_at\" IS NULL".to_string()]
+        );
+        let (conditions, _) = builder.build_count_conditions(&PostgresDialect).unwrap();
+        assert_eq!(conditions, vec!["\"deleted_at\" IS NULL".to_string()]);
+    }
+
+    #[test]
+    fn test_soft_delete_ignores_unknown_columns() {
+        let builder = paginated_query_as::<ArchivedModel, Postgres>("SELECT * FROM users")
+            .with_soft_delete("removed_at")
+            .with_count_includes_trashed();
+
+        assert!(builder.soft_delete_column.is_none());
+        assert!(!builder.counts_trashed());
+    }
+
+    #[test]
+    fn test_sort_coalesce_binds_sentinel_in_order_clause() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(2, 10)
+            .with_sort("name", QuerySortDirection::Ascending)
+            .with_filter("name", Some("John"))
+            .build();
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(params)
+            .with_sort_coalesce("name", String::new());
+
+        let (conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        builder
+            .bind_sort_terms(&mut arguments, &PostgresDialect)
+            .unwrap();
+        let limit_clause = builder
+            .bind_limit_offset(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(arguments.len(), 4);
+
+        let sql = builder.build_records_sql(&conditions, &limit_clause);
+        assert!(sql.ends_with(
+            "WHERE \"name\" = $1 ORDER BY COALESCE(\"name\", $2) ASC LIMIT $3 OFFSET $4"
+        ));
+    }
+
+    #[test]
+    fn test_sort_coalesce_is_not_bound_for_other_sort_columns() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_sort_coalesce("name", String::new())
+            .with_sort_coalesce("unknown", String::new());
+
+        let (conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        builder
+            .bind_sort_terms(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(arguments.len(), 0);
+        assert!(!builder
+            .build_records_sql(&conditions, "")
+            .contains("COALESCE"));
+
+        builder.params.sort.sort_column = "name".to_string();
+        builder
+            .bind_sort_terms(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert!(builder
+            .build_records_sql(&conditions, "")
+            .ends_with("ORDER BY COALESCE(\"name\", $1) DESC"));
+    }
+
+    #[test]
+    fn test_cursor_selects_records_after_the_sort_key() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let cursor = KeysetCursor {
+            value: "John".to_string(),
+            tie_breaker: "42".to_string(),
+        }
+        .encode();
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter("name", Some("John"))
+            .with_pagination(5, 20)
+            .build();
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(params)
+            .with_cursor("name", "id", Some(&cursor), QuerySortDirection::Descending);
+        assert!(!builder.totals_count_enabled);
+
+        let (mut conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        let limit_clause = builder
+            .bind_page(&mut conditions, &mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(arguments.len(), 4);
+
+        assert!(builder
+            .build_records_sql(&conditions, &limit_clause)
+            .ends_with(
+                "WHERE \"name\" = $1 AND (\"name\", \"id\") < ($2, $3) \
+                 ORDER BY \"name\" DESC, \"id\" DESC LIMIT $4"
+            ));
+    }
+
+    #[test]
+    fn test_cursor_values_are_bound_for_their_field_type() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let keyset_condition = |column: &str, value: &str| {
+            let cursor = KeysetCursor {
+                value: value.to_string(),
+                tie_breaker: "42".to_string(),
+            }
+            .encode();
+            let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+                .with_cursor(column, "id", Some(&cursor), QuerySortDirection::Ascending);
+
+            let (mut conditions, mut arguments) =
+                builder.build_conditions(&PostgresDialect).unwrap();
+            builder
+                .bind_page(&mut conditions, &mut arguments, &PostgresDialect)
+                .unwrap();
+            conditions.pop().unwrap()
+        };
+
+        // A text field holding a number is compared as text
+        assert_eq!(
+            keyset_condition("name", "400"),
+            "(\"name\", \"id\") > ($1, $2)"
+        );
+        assert_eq!(
+            keyset_condition("created_at", "2024-01-01T00:00:00Z"),
+            "(\"created_at\", \"id\") > ($1::timestamp with time zone, $2)"
+        );
+    }
+
+    #[test]
+    fn test_cursor_first_page_and_invalid_columns() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_cursor("name", "id", Some("garbage"), QuerySortDirection::Ascending);
+
+        let (mut conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        let limit_clause = builder
+            .bind_page(&mut conditions, &mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(
+            builder.build_records_sql(&conditions, &limit_clause),
+            "WITH base_query AS (SELECT * FROM users) SELECT * FROM base_query \
+             ORDER BY \"name\" ASC, \"id\" ASC LIMIT $1"
+        );
+
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users").with_cursor(
+            "name",
+            "missing",
+            None,
+            QuerySortDirection::Ascending,
+        );
+        assert!(builder.keyset.is_none());
+        assert!(builder.totals_count_enabled);
+    }
+
+    #[test]
+    fn test_cursor_on_nullable_columns_is_skipped() {
+        #[derive(Serialize, FromRow, Default)]
+        struct ScoredModel {
+            id: i32,
+            score: Option<i32>,
+        }
+
+        let builder = paginated_query_as::<ScoredModel, Postgres>("SELECT * FROM users")
+            .with_cursor("score", "id", None, QuerySortDirection::Ascending);
+        assert!(builder.keyset.is_none());
+        assert!(builder.totals_count_enabled);
+    }
+
+    #[test]
+    fn test_next_cursor_drops_the_extra_record() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestModel>::new()
+                    .with_pagination(1, 10)
+                    .build(),
+            )
+            .with_cursor("name", "id", None, QuerySortDirection::Ascending);
+        let record = |id: i32| TestModel {
+            id,
+            name: format!("user {}", id),
+            ..Default::default()
+        };
+
+        let mut records: Vec<TestModel> = (1..=10).map(record).collect();
+        assert_eq!(
+            builder.build_cursors(&mut records, |record| record),
+            (None, None)
+        );
+        assert_eq!(records.len(), 10);
+
+        let mut records: Vec<TestModel> = (1..=11).map(record).collect();
+        let (cursor, previous_cursor) = builder.build_cursors(&mut records, |record| record);
+        assert_eq!(records.len(), 10);
+        assert_eq!(previous_cursor, None);
+        assert_eq!(
+            KeysetCursor::decode(&cursor.unwrap()),
+            Some(KeysetCursor {
+                value: "user 10".to_string(),
+                tie_breaker: "10".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_backward_cursor_pages_before_the_sort_key() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let cursor = KeysetCursor {
+            value: "user 21".to_string(),
+            tie_breaker: "21".to_string(),
+        }
+        .encode();
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestModel>::new()
+                    .with_pagination(1, 10)
+                    .build(),
+            )
+            .with_cursor_direction(KeysetDirection::Backward)
+            .with_cursor("name", "id", Some(&cursor), QuerySortDirection::Ascending);
+
+        let (mut conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        let limit_clause = builder
+            .bind_page(&mut conditions, &mut arguments, &PostgresDialect)
+            .unwrap();
+        assert!(builder
+            .build_records_sql(&conditions, &limit_clause)
+            .ends_with(
+                "WHERE (\"name\", \"id\") < ($1, $2) \
+                 ORDER BY \"name\" DESC, \"id\" DESC LIMIT $3"
+            ));
+
+        // Records arrive nearest to the cursor first, with one extra record
+        let record = |id: i32| TestModel {
+            id,
+            name: format!("user {}", id),
+            ..Default::default()
+        };
+        let mut records: Vec<TestModel> = (10..=20).rev().map(record).collect();
+        let (next_cursor, previous_cursor) = builder.build_cursors(&mut records, |record| record);
+
+        let ids: Vec<i32> = records.iter().map(|record| record.id).collect();
+        assert_eq!(ids, (11..=20).collect::<Vec<_>>());
+        assert_eq!(
+            KeysetCursor::decode(&next_cursor.unwrap())
+                .unwrap()
+                .tie_breaker,
+            "20"
+        );
+        assert_eq!(
+            KeysetCursor::decode(&previous_cursor.unwrap())
+                .unwrap()
+                .tie_breaker,
+            "11"
+        );
+    }
+
+    #[test]
+    fn test_row_numbers_follow_the_page_order() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(sorted_by("name"));
+
+        let selection = [builder
+            .build_row_number_selection(&crate::paginated_query_as::internal::PostgresDialect)];
+        let sql = builder.build_records_sql_with_selection(&[], &selection, "");
+
+        assert!(sql.ends_with(
+            "SELECT *, ROW_NUMBER() OVER (ORDER BY \"name\" DESC) AS \"__row_num\" \
+             FROM base_query ORDER BY \"name\" DESC"
+        ));
+    }
+
+    #[test]
+    fn test_max_offset_rejects_pages_past_the_cap() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let builder_at = |page| {
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+                .with_params(
+                    QueryParamsBuilder::<TestModel>::new()
+                        .with_pagination(page, 10)
+                        .build(),
+                )
+                .with_max_offset(100)
+        };
+
+        let mut arguments = Default::default();
+        let limit_clause = builder_at(11)
+            .bind_limit_offset(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(limit_clause, " LIMIT $1 OFFSET $2");
+
+        let mut arguments = Default::default();
+        let error = builder_at(12)
+            .bind_limit_offset(&mut arguments, &PostgresDialect)
+            .unwrap_err();
+        assert_eq!(
+            PaginationError::from_sqlx_error(&error),
+            Some(&PaginationError::OffsetTooLarge {
+                offset: 110,
+                max_offset: 100
+            })
+        );
+    }
+
+    #[test]
+    fn test_clamped_max_offset_moves_to_last_allowed_page() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestModel>::new()
+                    .with_pagination(50, 10)
+                    .build(),
+            )
+            .with_clamped_max_offset(105);
+
+        let mut arguments = Default::default();
+        builder
+            .bind_limit_offset(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(builder.params.pagination.page, 11);
+    }
+
+    #[test]
+    fn test_prepended_cte_comes_before_base_query() {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(2, 10)
+            .with_filter("name", Some("John"))
+            .with_sort("id", QuerySortDirection::Ascending)
+            .build();
+        let mut builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(params)
+            .with_prepended_cte(
+                "recent",
+                "SELECT user_id FROM logins WHERE at > $1 AND kind = $2;",
+                vec!["2024-01-01".to_string(), "web".to_string()],
+            );
+
+        let (conditions, mut arguments) = builder.build_conditions(&PostgresDialect).unwrap();
+        let limit_clause = builder
+            .bind_limit_offset(&mut arguments, &PostgresDialect)
+            .unwrap();
+        assert_eq!(arguments.len(), 5);
+        assert_eq!(
+            builder.build_records_sql(&conditions, &limit_clause),
+            "WITH \"recent\" AS (SELECT user_id FROM logins WHERE at > $2 AND kind = $3), \
+             base_query AS (SELECT * FROM users) SELECT * FROM base_query \
+             WHERE \"name\" = $1 ORDER BY \"id\" ASC LIMIT $4 OFFSET $5"
+        );
+    }
+
+    #[test]
+    fn test_prepended_cte_ignores_invalid_input() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_prepended_cte("base_query", "SELECT 1", Vec::<i32>::new())
+            .with_prepended_cte("one", "SELECT 1; DROP TABLE users", Vec::<i32>::new())
+            .with_prepended_cte("", "SELECT 1", Vec::<i32>::new());
+
+        assert!(builder.prepended_ctes.is_empty());
+    }
+
+    #[test]
+    fn test_autocomplete_ignores_unknown_column() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_autocomplete("secret", "jhon", 5, 0.4);
+
+        assert!(builder.autocomplete.is_none());
+        assert!(builder.totals_count_enabled);
+    }
+
+    #[test]
+    fn test_primary_key_count_uses_declared_primary_key() {
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_base_table("users")
+            .with_primary_key("id")
+            .with_primary_key_count();
+
+        assert_eq!(
+            builder.build_count_sql(&["\"name\" = $1".to_string()]),
+            "WITH base_query AS (SELECT * FROM users) SELECT COUNT(\"id\") FROM base_query WHERE \"name\" = $1"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_count_requires_opt_in_and_declarations() {
+        let not_enabled = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_base_table("users")
+            .with_primary_key("id");
+        assert!(not_enabled.build_count_sql(&[]).contains("COUNT(*)"));
+
+        let no_primary_key = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_base_table("users")
+            .with_primary_key_count();
+        assert!(no_primary_key.build_count_sql(&[]).contains("COUNT(*)"));
+    }
+
+    #[test]
+    fn test_range_bound_check_swaps_inverted_bounds() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_between("id", "100", "10")
+            .with_filter_between("name", "9", "10")
+            .with_filter_not_between("updated_at", "2024-12-31", "2024-01-01")
+            .build();
+        let builder = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_range_bound_check(RangeBoundCheck::Swap)
+            .with_params(params);
+
+        // Numeric fields compare as numbers, other fields as strings
+        let value = |column: &str| builder.params.filters[column][0].value.clone();
+        assert_eq!(value("id").as_deref(), Some("10,100"));
+        assert_eq!(value("name").as_deref(), Some("10,9"));
+        assert_eq!(
+            value("updated_at").as_deref(),
+            Some("2024-01-01,2024-12-31")
+        );
+
+        let unchanged = paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestModel>::new()
+                    .with_filter_between("id", "100", "10")
+                    .build(),
+            );
+        assert_eq!(
+            unchanged.params.filters["id"][0].value.as_deref(),
+            Some("100,10")
+        );
+    }
+
+    #[test]
+    fn test_range_bound_check_rejects_inverted_bounds() {
+        let check = |params| {
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+                .with_params(params)
+                .with_range_bound_check(RangeBoundCheck::Reject)
+                .check_params()
+        };
+
+        let inverted = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_between("id", "100", "10")
+            .build();
+        assert_eq!(
+            check(inverted),
+            Err(PaginationError::InvalidRange {
+                column: "id".to_string()
+            })
+        );
+
+        let ordered = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_between("id", "9", "10")
+            .with_filter_between("name", "a", "b")
+            .build();
+        assert_eq!(check(ordered), Ok(()));
+    }
+
+    #[test]
+    fn test_planner_row_estimate_skips_the_count_aggregate() {
+        let plan = serde_json::json!([{
+            "Plan": {
+                "Node Type": "Aggregate",
+                "Strategy": "Plain",
+                "Plan Rows": 1,
+                "Plans": [{ "Node Type": "Seq Scan", "Plan Rows": 1234.4 }]
+            }
+        }]);
+        assert_eq!(planner_row_estimate(&plan), Some(1234));
+
+        // Hashed aggregates, e.g. of distinct rows, are the rows counted
+        let plan = serde_json::json!([{
+            "Plan": {
+                "Node Type": "Aggregate",
+                "Strategy": "Hashed",
+                "Plan Rows": 56,
+                "Plans": [{ "Node Type": "Seq Scan", "Plan Rows": 1234 }]
+            }
+        }]);
+        assert_eq!(planner_row_estimate(&plan), Some(56));
+
+        assert_eq!(planner_row_estimate(&serde_json::json!([])), None);
+    }
+
+    #[test]
+    fn test_context_cache_key_holds_values_bound_outside_the_params() {
+        let tenant_orders = |tenant: &str| {
+            paginated_query_as::<TestModel, Postgres>("SELECT * FROM users")
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("order_count", "COUNT(orders.id)")
+                        .with_join_bound(
+                            "LEFT JOIN orders ON orders.user_id = base_query.id AND orders.tenant_id = ?",
+                            vec![tenant],
+                        )
+                        .as_aggregate()
+                        .build(),
+                )
+                .context_cache_key("SELECT COUNT(*) FROM base_query")
+        };
+
+        assert!(tenant_orders("1").is_some());
+        assert_eq!(tenant_orders("1"), tenant_orders("1"));
+        assert_ne!(tenant_orders("1"), tenant_orders("2"));
+    }
+
+    #[test]
+    fn test_context_cache_key_skips_values_it_cannot_hold() {
+        let count_sql = "SELECT COUNT(*) FROM base_query";
+        let builder = || paginated_query_as::<TestModel, Postgres>("SELECT * FROM users");
+
+        assert!(builder().context_cache_key(count_sql).is_some());
+        assert!(builder()
+            .with_query_builder(|params| {
+                crate::QueryBuilder::<TestModel, Postgres>::new()
+                    .with_filters(params)
+                    .build()
+            })
+            .context_cache_key(count_sql)
+            .is_none());
+        assert!(builder()
+            .with_default_scope(|query_builder| {
+                query_builder.with_condition("name", "=", "tenant".to_string())
+            })
+            .context_cache_key(count_sql)
+            .is_none());
+        assert!(builder()
+            .with_prepended_cte("tenants", "SELECT id FROM tenants WHERE id = $1", vec![1])
+            .context_cache_key(count_sql)
+            .is_none());
     }
 }
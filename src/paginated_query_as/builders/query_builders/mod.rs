@@ -5,6 +5,9 @@ mod query_builder_postgres;
 #[cfg(feature = "sqlite")]
 mod query_builder_sqlite;
 
+#[cfg(feature = "mysql")]
+mod query_builder_mysql;
+
 #[allow(unused_imports)]
 #[cfg(feature = "postgres")]
 pub use query_builder_postgres::*;
@@ -13,4 +16,8 @@ pub use query_builder_postgres::*;
 #[cfg(feature = "sqlite")]
 pub use query_builder_sqlite::*;
 
+#[allow(unused_imports)]
+#[cfg(feature = "mysql")]
+pub use query_builder_mysql::*;
+
 pub use query_builder::*;
@@ -1,17 +1,90 @@
-use crate::paginated_query_as::internal::{ColumnProtection, QueryDialect};
-use crate::paginated_query_as::models::{QueryFilterCondition, QueryFilterOperator};
+use crate::paginated_query_as::internal::{
+    get_struct_boolean_field_names, get_struct_field_meta, get_struct_field_names,
+    is_struct_date_time_field, parse_boolean_value, ColumnProtection, FieldType, QueryDialect,
+};
+use crate::paginated_query_as::models::{
+    ComputedProperty, QueryFilterCondition, QueryFilterOperator, SearchOptions, WhereTree,
+};
 use crate::QueryParams;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sqlx::{Arguments, Database, Encode, Type};
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
+/// Binds a list of filter values as one array argument, from the values, whether
+/// they are raw and the type of their field, returning the type cast of its placeholder.
+pub(crate) type ArrayBinder<DB> = fn(
+    &mut <DB as Database>::Arguments,
+    Vec<String>,
+    bool,
+    FieldType,
+) -> Result<String, sqlx::error::BoxDynError>;
+
+/// Binds a `with_filter_raw_value` value without a type, for the database to infer.
+pub(crate) type RawValueBinder<DB> = fn(&mut <DB as Database>::Arguments, String);
+
+/// Binds a numeric filter value as a number, returning `false` when it isn't one.
+pub(crate) type NumberBinder<DB> = fn(&mut <DB as Database>::Arguments, &str) -> bool;
+
+/// Returns the type cast suffix of a filter value from the value and the type of its field.
+pub(crate) type TypeCastFn = Box<dyn Fn(&str, &FieldType) -> String + Send + Sync>;
+
+/// Applies a filter on an aggregate computed property, as a `HAVING` condition.
+pub(crate) type HavingFilter<'q, T, DB> =
+    Box<dyn FnOnce(QueryBuilder<'q, T, DB>) -> QueryBuilder<'q, T, DB> + 'q>;
+
 pub struct QueryBuilder<'q, T, DB: Database> {
     pub conditions: Vec<String>,
+    /// Conditions on aggregate computed properties, for the `HAVING` clause
+    pub having: Vec<String>,
+    /// Filters on aggregate computed properties, applied by `build_with_having`
+    /// so that their arguments follow the ones of the `WHERE` conditions
+    pub(crate) having_filters: Vec<HavingFilter<'q, T, DB>>,
     pub arguments: DB::Arguments,
     pub(crate) valid_columns: Vec<String>,
+    pub(crate) boolean_columns: Vec<String>,
     pub(crate) protection: Option<ColumnProtection>,
     pub(crate) protection_enabled: bool,
+    pub(crate) boolean_null_flag: bool,
+    /// Whether filters with an empty value are ignored instead of matching ''
+    pub(crate) drop_empty_filters: bool,
+    /// Text columns with a search index, restricting search when set
+    pub(crate) indexed_search_columns: Option<Vec<String>>,
+    /// Whether a search naming a column without a search index is rejected as a whole
+    pub(crate) require_indexed_search: bool,
+    /// JSON columns searched through their text form
+    pub(crate) jsonb_columns: Vec<String>,
+    /// Whether numeric columns are searched by equality with numeric terms
+    pub(crate) typed_search_enabled: bool,
+    /// Case sensitivity and anchor of search, overriding the `SearchAnchor` when set
+    pub(crate) search_options: Option<SearchOptions>,
+    /// Expressions of the searchable computed properties, by property name
+    pub(crate) search_properties: HashMap<String, String>,
+    /// Expressions of the aggregate computed properties filtered in `HAVING`, by property name
+    pub(crate) aggregate_properties: HashMap<String, String>,
+    /// Allowed values of enum columns, by column
+    pub(crate) enum_columns: HashMap<String, Vec<String>>,
+    /// Whether enum filters without allowed values match nothing instead of
+    /// adding no condition
+    pub(crate) strict_enum_columns: bool,
+    /// Database columns of renamed model fields, by serialized field name
+    pub(crate) column_mappings: HashMap<String, String>,
+    /// Table qualifying the quoted columns, carried by the parameters
+    pub(crate) column_qualifier: Option<String>,
+    /// Placeholders of the text values bound so far, reused when the dialect allows it
+    pub(crate) bound_values: HashMap<String, String>,
+    /// Binds `NOT IN` values as a single array, set by `with_array_exclusions`
+    pub(crate) array_exclusions: Option<ArrayBinder<DB>>,
+    /// Binds raw filter values without a type when set, and as text otherwise
+    pub(crate) raw_value_binder: Option<RawValueBinder<DB>>,
+    /// Binds the numeric values compared with aggregate computed properties, whose
+    /// results have no column type to convert text to
+    pub(crate) number_binder: Option<NumberBinder<DB>>,
+    /// The first argument that failed to encode, reported by `try_build`
+    pub(crate) encode_error: Option<sqlx::error::BoxDynError>,
+    /// Type casting of filter values, overriding the dialect's when set
+    pub(crate) type_cast: Option<TypeCastFn>,
     pub(crate) dialect: Box<dyn QueryDialect>,
     pub(crate) _phantom: PhantomData<&'q T>,
 }
@@ -22,6 +95,98 @@ where
     DB: Database,
     String: for<'a> Encode<'a, DB> + Type<DB>,
 {
+    /// Creates a new `QueryBuilder` using a custom SQL dialect.
+    ///
+    /// Allows building conditions for databases the crate doesn't ship a dialect for,
+    /// by implementing `QueryDialect` for them. Column protection only applies the
+    /// basic identifier checks, since no database specific blocked patterns are known.
+    ///
+    /// The built conditions can be used with `PaginatedQueryBuilder::new`, which takes
+    /// the query building function directly and doesn't require `DatabaseQueryDefaults`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialect` - The dialect used to quote identifiers, create placeholders and cast values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryDialect, QueryParamsBuilder};
+    ///
+    /// struct BacktickDialect;
+    ///
+    /// impl QueryDialect for BacktickDialect {
+    ///     fn quote_identifier(&self, ident: &str) -> String {
+    ///         format!("`{}`", ident.replace('`', "``"))
+    ///     }
+    ///
+    ///     fn placeholder(&self, _position: usize) -> String {
+    ///         "?".to_string()
+    ///     }
+    ///
+    ///     fn type_cast(&self, _value: &str) -> String {
+    ///         String::new()
+    ///     }
+    ///
+    ///     fn numbers_placeholders(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     fn offset_placeholders(&self, sql: &str, _offset: usize) -> String {
+    ///         sql.to_string()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter("name", Some("john"))
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::with_dialect(BacktickDialect)
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["`name` = ?"]);
+    /// ```
+    pub fn with_dialect(dialect: impl QueryDialect + 'static) -> Self {
+        Self {
+            conditions: Vec::new(),
+            having: Vec::new(),
+            having_filters: Vec::new(),
+            arguments: DB::Arguments::default(),
+            valid_columns: get_struct_field_names::<T>(),
+            boolean_columns: get_struct_boolean_field_names::<T>(),
+            protection: Some(ColumnProtection::new()),
+            protection_enabled: true,
+            boolean_null_flag: false,
+            drop_empty_filters: false,
+            indexed_search_columns: None,
+            require_indexed_search: false,
+            jsonb_columns: Vec::new(),
+            typed_search_enabled: false,
+            search_options: None,
+            search_properties: HashMap::new(),
+            aggregate_properties: HashMap::new(),
+            enum_columns: HashMap::new(),
+            strict_enum_columns: false,
+            column_mappings: HashMap::new(),
+            column_qualifier: None,
+            bound_values: HashMap::new(),
+            array_exclusions: None,
+            raw_value_binder: None,
+            number_binder: None,
+            encode_error: None,
+            type_cast: None,
+            dialect: Box::new(dialect),
+            _phantom: PhantomData,
+        }
+    }
+
     /// Checks if a column exists in the list of valid columns for T struct.
     ///
     /// # Arguments
@@ -35,6 +200,52 @@ where
         self.valid_columns.contains(&column.to_string())
     }
 
+    /// Whether a search column is allowed by `with_indexed_search_columns`.
+    fn is_column_indexed(&self, column: &str) -> bool {
+        self.indexed_search_columns
+            .as_ref()
+            .is_none_or(|indexed| indexed.iter().any(|indexed| indexed == column))
+    }
+
+    /// Whether an identifier outside the model, such as a related table, passes
+    /// column protection.
+    fn is_identifier_safe(&self, identifier: &str) -> bool {
+        match &self.protection {
+            Some(protection) if self.protection_enabled => protection.is_safe(identifier),
+            _ => true,
+        }
+    }
+
+    /// Quotes the database column of a model field, mapped with
+    /// `with_column_mapping` when the field is renamed.
+    fn quote_column(&self, column: &str) -> String {
+        let column = self
+            .column_mappings
+            .get(column)
+            .map_or(column, String::as_str);
+        match &self.column_qualifier {
+            Some(qualifier) if !column.contains('.') => {
+                format!("{}.{}", qualifier, self.dialect.quote_identifier(column))
+            }
+            _ => self.dialect.quote_identifier(column),
+        }
+    }
+
+    /// Adds the column mappings carried by the parameters, set by
+    /// `PaginatedQueryBuilder::with_column_mapping`, keeping the builder's own, and
+    /// the table qualifying the columns when joined.
+    fn apply_column_mappings(&mut self, params: &QueryParams<T>) {
+        if let Some(qualifier) = &params.column_qualifier {
+            self.column_qualifier
+                .get_or_insert_with(|| qualifier.clone());
+        }
+        for (name, column) in &params.column_mappings {
+            self.column_mappings
+                .entry(name.clone())
+                .or_insert_with(|| column.clone());
+        }
+    }
+
     fn is_column_safe(&self, column: &str) -> bool {
         let column_exists = self.has_column(column);
 
@@ -48,6 +259,103 @@ where
         }
     }
 
+    /// Returns the text searched for a search column: the expression of a search
+    /// property, the JSON text of a JSON column, or the quoted column.
+    fn search_column_expression(&self, column: &str) -> String {
+        let table_column = match self.search_properties.get(column) {
+            Some(expression) => format!("({})", expression),
+            None => self.quote_column(column),
+        };
+        if self.jsonb_columns.iter().any(|jsonb| jsonb == column) {
+            format!("CAST({} AS TEXT)", table_column)
+        } else {
+            table_column
+        }
+    }
+
+    /// Adds the full-text search condition matching the search columns, as a single
+    /// document, against the whole search term. Blank terms or no valid columns add
+    /// no condition.
+    fn push_full_text_condition(&mut self, config: &str, term: Option<&str>, columns: &[&str]) {
+        let Some(term) = term.map(str::trim).filter(|term| !term.is_empty()) else {
+            return;
+        };
+        let document = match columns {
+            [] => return,
+            [column] => self.search_column_expression(column),
+            columns => columns
+                .iter()
+                .map(|column| format!("coalesce({}, '')", self.search_column_expression(column)))
+                .collect::<Vec<String>>()
+                .join(" || ' ' || "),
+        };
+
+        let placeholder = self.bind_text(term);
+        let condition = self
+            .dialect
+            .full_text_search(config, &document, &placeholder);
+        self.conditions.push(format!("({})", condition));
+    }
+
+    /// Binds a text value and returns its placeholder.
+    ///
+    /// When the dialect reuses placeholders (Postgres), a value already bound by this
+    /// builder is not bound again and its placeholder is returned instead, keeping
+    /// the argument count and statement small. Other dialects bind every use, since
+    /// their placeholders are positional.
+    ///
+    /// Useful for custom conditions referring to a value also used by the built ones,
+    /// e.g. a relevance expression on the search pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::{Arguments, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::QueryBuilder;
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let mut query_builder = QueryBuilder::<UserExample, Postgres>::new();
+    /// assert_eq!(query_builder.bind_text("john"), "$1");
+    /// assert_eq!(query_builder.bind_text("jane"), "$2");
+    /// assert_eq!(query_builder.bind_text("john"), "$1");
+    /// assert_eq!(query_builder.arguments.len(), 2);
+    /// ```
+    pub fn bind_text(&mut self, value: impl Into<String>) -> String {
+        let value = value.into();
+        let reuse = self.dialect.reuses_placeholders();
+
+        if reuse {
+            if let Some(placeholder) = self.bound_values.get(&value) {
+                return placeholder.clone();
+            }
+        }
+
+        let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+        self.arguments.add(value.clone()).unwrap_or_default();
+        if reuse {
+            self.bound_values.insert(value, placeholder.clone());
+        }
+        placeholder
+    }
+
+    /// Binds a `with_filter_raw_value` value, without a type when the database can
+    /// infer it from the column, and as text otherwise.
+    fn bind_raw_value(&mut self, value: impl Into<String>) -> String {
+        match self.raw_value_binder {
+            Some(bind) => {
+                let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+                bind(&mut self.arguments, value.into());
+                placeholder
+            }
+            None => self.bind_text(value),
+        }
+    }
+
     /// Adds search functionality to the query by creating LIKE conditions for specified columns.
     ///
     /// # Arguments
@@ -59,7 +367,29 @@ where
     /// - Only searches in columns that are both specified and considered safe
     /// - Creates case-insensitive LIKE conditions with wildcards
     /// - Multiple search columns are combined with OR operators
+    /// - With `SearchMode::AnyTokenAnyColumn` the term is split on whitespace and every
+    ///   token/column combination is OR-ed, each token binding its own pattern
+    /// - The `%` wildcard placement follows `SearchAnchor` (`%term%` by default), for
+    ///   every search column, and `SearchAnchor::Exact` compares with `=` instead
+    /// - With `with_search_options`, its `SearchAnchor` replaces the one of the
+    ///   parameters, and case sensitive options compare the column and pattern
+    ///   without `LOWER()`
+    /// - When `with_indexed_search_columns` was called, only the declared indexed
+    ///   columns are searched
+    /// - When `with_require_indexed_search` was called, a search naming any other
+    ///   column is skipped entirely, with a warning when tracing is enabled
+    /// - Columns declared with `with_jsonb_column` are matched on their JSON text
+    /// - Parameters built with `with_full_text_search` match the columns as one
+    ///   document with the dialect's full-text search on Postgres, binding the whole
+    ///   term, and fall back to LIKE search on other dialects
+    /// - Properties declared with `with_search_property` are matched on their expression
+    /// - With `with_typed_search`, numeric columns are matched by equality with
+    ///   numeric terms instead of LIKE, and skipped for other terms
     /// - Empty search text or no valid columns results in no conditions being added
+    /// - On Postgres each pattern is bound once and shared by every search column
+    /// - With `min_should_match` K, a row must match at least K tokens on any column:
+    ///   K = 1 OR-s every condition, K = all tokens AND-s the tokens, and any other K
+    ///   sums a `CASE WHEN ... THEN 1 ELSE 0 END` per token and compares it to K
     ///
     /// # Returns
     ///
@@ -85,43 +415,214 @@ where
     ///     .build();
     /// ```
     pub fn with_search(mut self, params: &QueryParams<T>) -> Self {
-        if let Some(search) = &params.search.search {
-            if let Some(columns) = &params.search.search_columns {
-                let valid_search_columns: Vec<&String> = columns
-                    .iter()
-                    .filter(|column| self.is_column_safe(column))
-                    .collect();
-
-                if !valid_search_columns.is_empty() && !search.trim().is_empty() {
-                    let pattern = format!("%{}%", search);
-                    let use_lower = search.is_ascii();
-
-                    let search_conditions: Vec<String> = valid_search_columns
-                        .iter()
-                        .enumerate()
-                        .map(|(idx, column)| {
-                            let table_column = self.dialect.quote_identifier(column);
-                            let placeholder =
-                                self.dialect.placeholder(self.arguments.len() + idx + 1);
-                            if use_lower {
-                                format!("LOWER({}) LIKE LOWER({})", table_column, placeholder)
-                            } else {
-                                format!("{} LIKE {}", table_column, placeholder)
-                            }
-                        })
-                        .collect();
-
-                    if !search_conditions.is_empty() {
-                        self.conditions
-                            .push(format!("({})", search_conditions.join(" OR ")));
-                        for _ in 0..valid_search_columns.len() {
-                            self.arguments.add(pattern.clone()).unwrap_or_default();
-                        }
+        self.apply_column_mappings(params);
+        let Some(columns) = self.searched_columns(params) else {
+            return self;
+        };
+
+        if let Some(config) = self.full_text_search_config(params) {
+            self.push_full_text_condition(config, params.search.search.as_deref(), &columns);
+            return self;
+        }
+
+        let token_conditions: Vec<Vec<String>> = self
+            .build_search_term_conditions(params, &columns)
+            .into_iter()
+            .map(|conditions| {
+                conditions
+                    .into_iter()
+                    .map(|(_, condition)| condition)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|conditions| !conditions.is_empty())
+            .collect();
+        if let Some(condition) =
+            min_should_match_condition(token_conditions, params.search.min_should_match)
+        {
+            self.conditions.push(condition);
+        }
+        self
+    }
+
+    /// Builds, for each search column, the condition telling whether it matches any
+    /// search term, with the expressions and patterns `with_search` matches it with.
+    /// The patterns are bound after the given arguments. Columns no term can match,
+    /// e.g. numeric columns of a typed search for a text term, are left out.
+    #[cfg(feature = "postgres")]
+    pub(crate) fn build_search_matches(
+        mut self,
+        params: &QueryParams<T>,
+        arguments: DB::Arguments,
+    ) -> (Vec<(String, String)>, DB::Arguments) {
+        self.apply_column_mappings(params);
+        self.arguments = arguments;
+        self.bound_values.clear();
+        let Some(columns) = self.searched_columns(params) else {
+            return (Vec::new(), self.arguments);
+        };
+
+        let mut matches: Vec<(String, Vec<String>)> = columns
+            .iter()
+            .map(|column| (column.to_string(), Vec::new()))
+            .collect();
+        if let Some(config) = self.full_text_search_config(params) {
+            if let Some(term) = params
+                .search
+                .search
+                .as_deref()
+                .map(str::trim)
+                .filter(|term| !term.is_empty())
+            {
+                let placeholder = self.bind_text(term);
+                for (column, conditions) in &mut matches {
+                    let document = self.search_column_expression(column);
+                    conditions.push(format!(
+                        "({})",
+                        self.dialect
+                            .full_text_search(config, &document, &placeholder)
+                    ));
+                }
+            }
+        } else {
+            for term_conditions in self.build_search_term_conditions(params, &columns) {
+                for (column, condition) in term_conditions {
+                    if let Some((_, conditions)) =
+                        matches.iter_mut().find(|(name, _)| *name == column)
+                    {
+                        conditions.push(condition);
                     }
                 }
             }
         }
-        self
+
+        let matches = matches
+            .into_iter()
+            .filter(|(_, conditions)| !conditions.is_empty())
+            .map(|(column, conditions)| (column, format!("({})", conditions.join(" OR "))))
+            .collect();
+        (matches, self.arguments)
+    }
+
+    /// Returns the search columns of the parameters that can be searched: safe
+    /// columns or search properties, among the indexed search columns when declared.
+    /// Returns `None` when `with_require_indexed_search` skips the search.
+    fn searched_columns<'p>(&self, params: &'p QueryParams<T>) -> Option<Vec<&'p str>> {
+        if self.require_indexed_search {
+            if let Some(_column) = params
+                .search
+                .columns()
+                .into_iter()
+                .find(|column| !self.is_column_indexed(column))
+            {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    column = %_column,
+                    "Skipping search on a column without a search index"
+                );
+                return None;
+            }
+        }
+
+        Some(
+            params
+                .search
+                .columns()
+                .into_iter()
+                .filter(|column| {
+                    (self.is_column_safe(column) || self.search_properties.contains_key(*column))
+                        && self.is_column_indexed(column)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the text search configuration of a full-text search, when the
+    /// dialect supports full-text search and the configuration is valid.
+    fn full_text_search_config<'p>(&self, params: &'p QueryParams<T>) -> Option<&'p str> {
+        let config = params
+            .search
+            .full_text_config
+            .as_deref()
+            .filter(|_| self.dialect.supports_full_text_search())?;
+        if is_text_search_config(config) {
+            Some(config)
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                config = %config,
+                "Falling back to LIKE search for an invalid text search configuration"
+            );
+            None
+        }
+    }
+
+    /// Builds, for each search term, the LIKE (or `=`) condition of each given
+    /// column it can match, along with the column, binding the patterns.
+    fn build_search_term_conditions(
+        &mut self,
+        params: &QueryParams<T>,
+        columns: &[&str],
+    ) -> Vec<Vec<(String, String)>> {
+        let numeric_columns: Vec<String> = if self.typed_search_enabled {
+            get_struct_field_meta::<T>()
+                .into_iter()
+                .filter(|field| field.field_type == FieldType::Number)
+                .map(|field| field.name)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let terms = params.search.terms();
+        let anchor = self
+            .search_options
+            .map_or(params.search.search_anchor, |options| options.anchor);
+        let patterns: Vec<String> = terms.iter().map(|term| anchor.pattern(term)).collect();
+        let case_sensitive = self
+            .search_options
+            .is_some_and(|options| options.case_sensitive);
+
+        let mut token_conditions = Vec::new();
+        if columns.is_empty() {
+            return token_conditions;
+        }
+        for (term, pattern) in terms.into_iter().zip(patterns) {
+            let mut search_conditions = Vec::new();
+            let use_lower = !case_sensitive && pattern.is_ascii();
+            let is_numeric_term = term.trim().parse::<f64>().is_ok_and(f64::is_finite);
+
+            for column in columns {
+                if numeric_columns.iter().any(|numeric| numeric == column) {
+                    if is_numeric_term {
+                        let term = term.trim();
+                        let placeholder = self.bind_text(term);
+                        search_conditions.push((
+                            column.to_string(),
+                            format!(
+                                "{} = {}",
+                                self.quote_column(column),
+                                self.cast_placeholder(&placeholder, term, FieldType::Number)
+                            ),
+                        ));
+                    }
+                    continue;
+                }
+
+                let table_column = self.search_column_expression(column);
+                let placeholder = self.bind_text(pattern.clone());
+                let operator = anchor.operator();
+                let condition = if use_lower {
+                    format!(
+                        "LOWER({}) {} LOWER({})",
+                        table_column, operator, placeholder
+                    )
+                } else {
+                    format!("{} {} {}", table_column, operator, placeholder)
+                };
+                search_conditions.push((column.to_string(), condition));
+            }
+            token_conditions.push(search_conditions);
+        }
+        token_conditions
     }
 
     /// Adds filter conditions to the query with support for various operators.
@@ -133,10 +634,32 @@ where
     /// # Details
     ///
     /// - Only applies filters for columns that exist and are considered safe
-    /// - Supports multiple operators: =, !=, >, >=, <, <=, IN, NOT IN, IS NULL, IS NOT NULL, LIKE, NOT LIKE
+    /// - Supports multiple operators: =, !=, >, >=, <, <=, IN, NOT IN, BETWEEN, IS NULL,
+    ///   IS NOT NULL, IS [NOT] TRUE/FALSE, LIKE, NOT LIKE
+    /// - BETWEEN filters without exactly two bounds are skipped with a warning when
+    ///   tracing is enabled
     /// - Automatically handles type casting based on the database dialect
+    /// - RFC3339 timestamps are bound as native `DateTime<Utc>` values when the dialect
+    ///   supports it (Postgres), so no text cast is needed
     /// - Skips invalid columns with a warning when tracing is enabled
+    /// - Filters are applied in column name order, so the generated SQL and placeholder
+    ///   numbering are identical for the same parameters
+    /// - Every condition on a column is applied, combined with AND, e.g. a lower and an
+    ///   upper bound on the same column
+    /// - Filter groups follow the other filters, each as a single parenthesized
+    ///   condition joined by the group's logic, e.g. `("status" = $1 OR "status" = $2)`
     /// - For IN/NOT IN operators, comma-separated values are split into multiple parameters
+    /// - Comparison values of exactly `NOW()`, `CURRENT_DATE` or `CURRENT_TIMESTAMP`
+    ///   on date and time fields, or fields of unknown type such as `Option<DateTime<Utc>>`,
+    ///   are inlined as SQL functions, to compare with the database server time; on
+    ///   other fields they are bound as text
+    /// - Equality filters on boolean fields, and on fields listed in `checkbox_filters`,
+    ///   accept `on`/`off`, `true`/`false`, `1`/`0` and bind the dialect's boolean value,
+    ///   an integer `0`/`1` on SQLite
+    /// - Checkbox filters with an empty or unrecognized value add no condition
+    /// - Equality and IN/NOT IN filters on columns declared with `with_enum_column`
+    ///   drop values outside the allowed set, and add no condition when none is left,
+    ///   or a `1 = 0` condition for equality and IN filters with `with_strict_enum_columns`
     ///
     /// # Returns
     ///
@@ -165,104 +688,184 @@ where
     ///     .with_filters(&initial_params)
     ///     .build();
     /// ```
-    pub fn with_filters(mut self, params: &'q QueryParams<T>) -> Self {
-        for (key, condition) in &params.filters {
-            if self.is_column_safe(key) {
-                self = self.apply_filter_condition(key, condition);
-            } else {
-                #[cfg(feature = "tracing")]
-                tracing::warn!(column = %key, "Skipping invalid filter column");
+    pub fn with_filters(mut self, params: &'q QueryParams<T>) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+        i64: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        self.apply_column_mappings(params);
+
+        // Sorted by column so that conditions and placeholder numbering are stable
+        let mut filters: Vec<_> = params.filters.iter().collect();
+        filters.sort_by_key(|(key, _)| *key);
+
+        for (key, conditions) in filters {
+            for condition in conditions {
+                self = match self.aggregate_properties.get(key.as_str()).cloned() {
+                    Some(expression) => self.apply_having_filter(key, &expression, condition),
+                    None => self.apply_filter(params, key, condition),
+                };
             }
         }
-        self
+        self.apply_filter_groups(params)
     }
 
-    /// Applies a single filter condition to the query.
-    ///
-    /// This is a helper method that handles the SQL generation for different operators.
-    fn apply_filter_condition(mut self, column: &str, condition: &'q QueryFilterCondition) -> Self {
-        let table_column = self.dialect.quote_identifier(column);
+    /// Queues the filter on an aggregate computed property, applied to its
+    /// expression as a `HAVING` condition by `build_with_having`.
+    fn apply_having_filter(
+        mut self,
+        key: &str,
+        expression: &str,
+        condition: &QueryFilterCondition,
+    ) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        if !self.is_column_safe(key) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping invalid filter column");
+            return self;
+        }
 
-        match &condition.operator {
-            QueryFilterOperator::IsNull => {
-                self.conditions.push(format!("{} IS NULL", table_column));
-            }
-            QueryFilterOperator::IsNotNull => {
-                self.conditions
-                    .push(format!("{} IS NOT NULL", table_column));
+        let (key, expression, condition) =
+            (key.to_string(), expression.to_string(), condition.clone());
+        self.having_filters
+            .push(Box::new(move |mut query_builder: Self| {
+                let start = query_builder.conditions.len();
+                query_builder = query_builder.apply_filter_condition(
+                    format!("({})", expression),
+                    Some(&key),
+                    &condition,
+                );
+                let mut having = query_builder.conditions.split_off(start);
+                query_builder.having.append(&mut having);
+                query_builder
+            }));
+        self
+    }
+
+    /// Applies every filter group as a single condition, its conditions joined by
+    /// the group's logic and parenthesized when there are several.
+    fn apply_filter_groups(mut self, params: &'q QueryParams<T>) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+        i64: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        for group in &params.filter_groups {
+            let start = self.conditions.len();
+            for (key, condition) in &group.conditions {
+                self = self.apply_filter(params, key, condition);
             }
-            QueryFilterOperator::In | QueryFilterOperator::NotIn => {
-                if let Some(_value) = &condition.value {
-                    let values = condition.split_values();
-                    if !values.is_empty() {
-                        let mut placeholders = Vec::new();
-                        for val in values {
-                            let next_argument = self.arguments.len() + 1;
-                            let placeholder = self.dialect.placeholder(next_argument);
-                            let type_cast = self.dialect.type_cast(&val);
-                            placeholders.push(format!("{}{}", placeholder, type_cast));
-                            self.arguments.add(val).unwrap_or_default();
-                        }
 
-                        let operator = condition.operator.to_sql();
-                        self.conditions.push(format!(
-                            "{} {} ({})",
-                            table_column,
-                            operator,
-                            placeholders.join(", ")
-                        ));
-                    }
-                }
+            let mut parts = self.conditions.split_off(start);
+            match parts.len() {
+                0 => {}
+                1 => self.conditions.append(&mut parts),
+                _ => self
+                    .conditions
+                    .push(format!("({})", parts.join(group.logic.separator()))),
             }
-            QueryFilterOperator::Like | QueryFilterOperator::NotLike => {
-                if let Some(value) = &condition.value {
-                    let next_argument = self.arguments.len() + 1;
-                    let placeholder = self.dialect.placeholder(next_argument);
-                    let operator = condition.operator.to_sql();
+        }
+        self
+    }
 
-                    self.conditions.push(format!(
-                        "LOWER({}) {} LOWER({})",
-                        table_column, operator, placeholder
-                    ));
-                    self.arguments.add(value).unwrap_or_default();
+    /// Applies the filter on a single column, if the column is safe.
+    fn apply_filter(
+        mut self,
+        params: &'q QueryParams<T>,
+        key: &str,
+        condition: &'q QueryFilterCondition,
+    ) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+        i64: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        if !self.is_column_safe(key) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping invalid filter column");
+            return self;
+        }
+
+        if self.aggregate_properties.contains_key(key) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping aggregate property filter outside of HAVING");
+            return self;
+        }
+
+        if self.drop_empty_filters
+            && condition.operator.requires_value()
+            && condition
+                .value
+                .as_deref()
+                .is_some_and(|value| value.trim().is_empty())
+        {
+            return self;
+        }
+
+        let restricted;
+        let condition = match self.enum_columns.get(key) {
+            Some(allowed) => match restrict_to_allowed_values(condition, allowed) {
+                Some(condition) => {
+                    restricted = condition;
+                    &restricted
                 }
-            }
-            _ => {
-                // Handle all comparison operators: =, !=, >, >=, <, <=
-                if let Some(value) = &condition.value {
-                    let next_argument = self.arguments.len() + 1;
-                    let placeholder = self.dialect.placeholder(next_argument);
-                    let type_cast = self.dialect.type_cast(value);
-                    let operator = condition.operator.to_sql();
+                None if self.strict_enum_columns
+                    && matches!(
+                        condition.operator,
+                        QueryFilterOperator::Equal | QueryFilterOperator::In
+                    ) =>
+                {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(column = %key, "Matching nothing for filter without allowed enum values");
+                    self.conditions.push("1 = 0".to_string());
+                    return self;
+                }
+                None => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(column = %key, "Skipping filter without allowed enum values");
+                    return self;
+                }
+            },
+            None => condition,
+        };
 
-                    self.conditions.push(format!(
-                        "{} {} {}{}",
-                        table_column, operator, placeholder, type_cast
-                    ));
-                    self.arguments.add(value).unwrap_or_default();
+        let is_checkbox = params.checkbox_filters.iter().any(|field| field == key);
+        let is_equality = matches!(
+            condition.operator,
+            QueryFilterOperator::Equal | QueryFilterOperator::NotEqual
+        );
+
+        if is_equality && (is_checkbox || self.boolean_columns.iter().any(|field| field == key)) {
+            match condition.value.as_deref().and_then(parse_boolean_value) {
+                Some(value) => {
+                    self.apply_boolean_filter(key, &condition.operator, value);
+                    return self;
                 }
+                None if is_checkbox => return self,
+                None => {}
             }
         }
 
-        self
+        let table_column = self.quote_column(key);
+        self.apply_filter_condition(table_column, Some(key), condition)
     }
 
-    /// Adds date range conditions to the query for a specified date column.
+    /// Adds the search, filter and date range conditions combined as described by
+    /// a `WhereTree`, as a single condition, instead of combining them all with AND.
     ///
     /// # Arguments
     ///
-    /// * `params` - Query parameters containing date range information
-    ///
-    /// # Type Parameters
-    ///
-    /// Requires `DateTime<Utc>` to be encodable for the target database
+    /// * `params` - Query parameters containing the search, filters and date range
+    /// * `tree` - How the conditions are combined
     ///
     /// # Details
     ///
-    /// - Adds >= condition for date_after if specified
-    /// - Adds <= condition for date_before if specified
-    /// - Only applies to columns that exist and are considered safe
-    /// - Skips invalid date columns with a warning when tracing is enabled
+    /// - Conditions are built like `with_search`, `with_filters` and `with_date_range`
+    ///   build them, and every group of several conditions is parenthesized
+    /// - Arguments are bound in the order the leaves appear in the tree
+    /// - Leaves without a search term, filter or date are left out of `And` groups,
+    ///   and make `Or` groups add no condition, as they match every row
+    /// - Leaves whose conditions are all skipped are left out of their group
     ///
     /// # Returns
     ///
@@ -273,59 +876,467 @@ where
     /// ```rust
     /// use sqlx::Postgres;
     /// use serde::{Serialize};
-    /// use chrono::{DateTime};
-    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, QueryParams};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, WhereTree};
     ///
     /// #[derive(Serialize, Default)]
     /// struct UserExample {
-    ///     name: String
+    ///     id: i64,
+    ///     name: String,
+    ///     status: String,
     /// }
     ///
     /// let initial_params = QueryParamsBuilder::<UserExample>::new()
-    ///         .with_date_range(None, Some(DateTime::parse_from_rfc3339("2024-12-31T23:59:59Z").unwrap().into()), Some("deleted_at"))
-    ///         .build();
-    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
-    ///     .with_date_range(&initial_params)
+    ///     .with_search("john", vec!["name"])
+    ///     .with_filter("id", Some("42"))
+    ///     .with_filter("status", Some("active"))
     ///     .build();
+    ///
+    /// // Active users matching the search, or user 42
+    /// let tree = WhereTree::and(vec![
+    ///     WhereTree::or(vec![WhereTree::Search, WhereTree::filter("id")]),
+    ///     WhereTree::Filters,
+    /// ]);
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_where(&initial_params, tree)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions.len(), 1);
     /// ```
-    pub fn with_date_range(mut self, params: &'q QueryParams<T>) -> Self
+    pub fn with_where(mut self, params: &'q QueryParams<T>, tree: WhereTree) -> Self
     where
         DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+        i64: for<'a> Encode<'a, DB> + Type<DB>,
     {
-        if let Some(date_column) = &params.date_range.date_column {
-            if self.is_column_safe(date_column) {
-                if let Some(after) = params.date_range.date_after {
-                    let next_argument = self.arguments.len() + 1;
-                    let table_column = self.dialect.quote_identifier(date_column);
-                    let placeholder = self.dialect.placeholder(next_argument);
-                    self.conditions
-                        .push(format!("{} >= {}", table_column, placeholder));
-                    self.arguments.add(after).unwrap_or_default();
-                }
+        self.apply_column_mappings(params);
 
-                if let Some(before) = params.date_range.date_before {
-                    let next_argument = self.arguments.len() + 1;
-                    let table_column = self.dialect.quote_identifier(date_column);
-                    let placeholder = self.dialect.placeholder(next_argument);
-                    self.conditions
-                        .push(format!("{} <= {}", table_column, placeholder));
-                    self.arguments.add(before).unwrap_or_default();
-                }
-            } else {
-                #[cfg(feature = "tracing")]
-                tracing::warn!(column = %date_column, "Skipping invalid date column");
-            }
-        }
+        let excluded: Vec<String> = tree
+            .filter_columns()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
 
+        let condition;
+        (self, condition) = self.build_where_node(params, &tree, &excluded);
+        if let Some(condition) = condition {
+            self.conditions.push(condition);
+        }
         self
     }
 
-    /// Adds a custom condition for a specific column with a provided operator and value.
-    ///
-    /// # Arguments
-    ///
-    /// * `column` - The column name to apply the condition to
-    /// * `condition` - The operator or condition to use (e.g., ">", "LIKE", etc.)
+    /// Builds the condition of a `WhereTree` node, or `None` when it has none.
+    fn build_where_node(
+        mut self,
+        params: &'q QueryParams<T>,
+        node: &WhereTree,
+        excluded: &[String],
+    ) -> (Self, Option<String>)
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+        i64: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        if node.is_absent(params, excluded) {
+            return (self, None);
+        }
+
+        let start = self.conditions.len();
+        let (mut parts, separator) = match node {
+            WhereTree::And(children) | WhereTree::Or(children) => {
+                let mut parts = Vec::new();
+                for child in children {
+                    let part;
+                    (self, part) = self.build_where_node(params, child, excluded);
+                    parts.extend(part);
+                }
+                let separator = match node {
+                    WhereTree::Or(_) => " OR ",
+                    _ => " AND ",
+                };
+                (parts, separator)
+            }
+            leaf => {
+                self = match leaf {
+                    WhereTree::Search => self.with_search(params),
+                    WhereTree::DateRange => self.with_date_range(params),
+                    WhereTree::Filter(column) => match params.filters.get_key_value(column) {
+                        Some((key, conditions)) => {
+                            for condition in conditions {
+                                self = self.apply_filter(params, key, condition);
+                            }
+                            self
+                        }
+                        None => self,
+                    },
+                    _ => {
+                        let mut filters: Vec<_> = params
+                            .filters
+                            .iter()
+                            .filter(|(key, _)| !excluded.contains(key))
+                            .collect();
+                        filters.sort_by_key(|(key, _)| *key);
+
+                        for (key, conditions) in filters {
+                            for condition in conditions {
+                                self = self.apply_filter(params, key, condition);
+                            }
+                        }
+                        self.apply_filter_groups(params)
+                    }
+                };
+                (self.conditions.split_off(start), " AND ")
+            }
+        };
+
+        // Single conditions are atomic, groups are parenthesized
+        let condition = match parts.len() {
+            0 => None,
+            1 => parts.pop(),
+            _ => Some(format!("({})", parts.join(separator))),
+        };
+        (self, condition)
+    }
+
+    /// Applies a single filter condition to the query.
+    ///
+    /// This is a helper method that handles the SQL generation for different operators,
+    /// on the given quoted column, of the given model field when it is one.
+    fn apply_filter_condition(
+        mut self,
+        table_column: String,
+        field: Option<&str>,
+        condition: &QueryFilterCondition,
+    ) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        match &condition.operator {
+            QueryFilterOperator::IsNull | QueryFilterOperator::IsNotNull => {
+                let negated = self.boolean_null_flag
+                    && condition.value.as_deref().and_then(parse_boolean_value) == Some(false);
+                let is_null = (condition.operator == QueryFilterOperator::IsNull) != negated;
+
+                if is_null {
+                    self.conditions.push(format!("{} IS NULL", table_column));
+                } else {
+                    self.conditions
+                        .push(format!("{} IS NOT NULL", table_column));
+                }
+            }
+            QueryFilterOperator::IsTrue
+            | QueryFilterOperator::IsFalse
+            | QueryFilterOperator::IsNotTrue
+            | QueryFilterOperator::IsNotFalse => {
+                if let Some((value, negated)) = condition.operator.boolean_test() {
+                    let test = self.dialect.boolean_test(&table_column, value, negated);
+                    self.conditions.push(test);
+                }
+            }
+            QueryFilterOperator::NotIn if self.array_exclusions.is_some() => {
+                let values = condition.split_values();
+                if let (Some(bind_array), false) = (self.array_exclusions, values.is_empty()) {
+                    let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+                    let field_type = field.map_or(FieldType::Unknown, |field| {
+                        get_struct_field_meta::<T>()
+                            .into_iter()
+                            .find(|meta| meta.name == field)
+                            .map_or(FieldType::Unknown, |meta| meta.field_type)
+                    });
+
+                    match bind_array(&mut self.arguments, values, condition.raw_value, field_type) {
+                        Ok(cast) => self.conditions.push(format!(
+                            "{} NOT IN (SELECT x FROM unnest({}{}) x)",
+                            table_column, placeholder, cast
+                        )),
+                        Err(error) => {
+                            self.encode_error.get_or_insert(error);
+                        }
+                    }
+                }
+            }
+            QueryFilterOperator::In | QueryFilterOperator::NotIn => {
+                if let Some(_value) = &condition.value {
+                    let values = condition.split_values();
+                    if !values.is_empty() {
+                        let mut placeholders = Vec::new();
+                        for val in values {
+                            let placeholder = if condition.raw_value {
+                                self.bind_raw_value(val)
+                            } else {
+                                self.bind_filter_value(field, &val)
+                            };
+                            placeholders.push(placeholder);
+                        }
+
+                        let operator = condition.operator.to_sql();
+                        self.conditions.push(format!(
+                            "{} {} ({})",
+                            table_column,
+                            operator,
+                            placeholders.join(", ")
+                        ));
+                    }
+                }
+            }
+            QueryFilterOperator::Between | QueryFilterOperator::NotBetween => {
+                if let Some((low, high)) = condition.bounds() {
+                    let (low, high) = if condition.raw_value {
+                        (self.bind_raw_value(low), self.bind_raw_value(high))
+                    } else {
+                        (
+                            self.bind_filter_value(field, &low),
+                            self.bind_filter_value(field, &high),
+                        )
+                    };
+
+                    let operator = condition.operator.to_sql();
+                    self.conditions.push(format!(
+                        "{} {} {} AND {}",
+                        table_column, operator, low, high
+                    ));
+                } else {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(column = %table_column, "Skipping BETWEEN filter without two bounds");
+                }
+            }
+            QueryFilterOperator::Like | QueryFilterOperator::NotLike => {
+                if let Some(value) = &condition.value {
+                    let placeholder = self.bind_text(value.clone());
+                    let operator = condition.operator.to_sql();
+
+                    self.conditions.push(format!(
+                        "LOWER({}) {} LOWER({})",
+                        table_column, operator, placeholder
+                    ));
+                }
+            }
+            QueryFilterOperator::Regex | QueryFilterOperator::IRegex => {
+                if let Some(value) = &condition.value {
+                    let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+                    let case_insensitive = condition.operator == QueryFilterOperator::IRegex;
+
+                    let Some(regex_match) =
+                        self.dialect
+                            .regex_match(&table_column, &placeholder, case_insensitive)
+                    else {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(column = %table_column, "Skipping regex filter, the dialect has no regular expressions");
+                        return self;
+                    };
+                    self.arguments.add(value.clone()).unwrap_or_default();
+                    self.conditions.push(regex_match);
+                }
+            }
+            QueryFilterOperator::Contains
+            | QueryFilterOperator::ContainedBy
+            | QueryFilterOperator::Overlaps => {
+                if let Some(value) = &condition.value {
+                    let Some((column_cast, cast)) = self.dialect.containment_casts(value) else {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(column = %table_column, "Skipping containment filter, the dialect has no containment operators");
+                        return self;
+                    };
+                    let placeholder = self.bind_text(value.clone());
+                    let cast = if condition.raw_value { "" } else { cast };
+
+                    self.conditions.push(format!(
+                        "{}{} {} {}{}",
+                        table_column,
+                        column_cast,
+                        condition.operator.to_sql(),
+                        placeholder,
+                        cast
+                    ));
+                }
+            }
+            _ => {
+                // Handle all comparison operators: =, !=, >, >=, <, <=
+                if let Some(value) = &condition.value {
+                    // Allowed time functions are a fixed list, safe to inline, but only
+                    // compared with date and time fields
+                    let time_function = field
+                        .filter(|field| is_struct_date_time_field::<T>(field))
+                        .and_then(|_| self.dialect.time_function(value));
+                    let placeholder = if condition.raw_value {
+                        self.bind_raw_value(value.clone())
+                    } else {
+                        match time_function {
+                            Some(function) => function,
+                            None => self.bind_filter_value(field, value),
+                        }
+                    };
+                    let operator = condition.operator.to_sql();
+
+                    self.conditions
+                        .push(format!("{} {} {}", table_column, operator, placeholder));
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Adds an equality condition on a boolean column using the dialect's boolean value.
+    ///
+    /// The value is bound as a `0`/`1` integer when the dialect stores booleans as
+    /// integers, and as the dialect's boolean literal with its type cast otherwise.
+    fn apply_boolean_filter(&mut self, column: &str, operator: &QueryFilterOperator, value: bool)
+    where
+        i64: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        let table_column = self.quote_column(column);
+
+        let placeholder = if self.dialect.binds_integer_booleans() {
+            let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+            self.arguments.add(i64::from(value)).unwrap_or_default();
+            placeholder
+        } else {
+            let literal = self.dialect.boolean_literal(value);
+            let placeholder = self.bind_text(literal);
+            self.cast_placeholder(&placeholder, literal, FieldType::Boolean)
+        };
+
+        self.conditions.push(format!(
+            "{} {} {}",
+            table_column,
+            operator.to_sql(),
+            placeholder
+        ));
+    }
+
+    /// Binds a filter value of a field and returns its placeholder, including any type cast.
+    ///
+    /// Timestamps are bound as `DateTime<Utc>` when the dialect supports native
+    /// timestamps, and numbers compared with numeric aggregate computed properties
+    /// as numbers; all other values are bound as text with a type cast, through
+    /// `bind_text`.
+    fn bind_filter_value(&mut self, field: Option<&str>, value: &str) -> String
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        if self.dialect.binds_native_timestamps() {
+            if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+                let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+                self.arguments
+                    .add(timestamp.with_timezone(&Utc))
+                    .unwrap_or_default();
+                return placeholder;
+            }
+        }
+
+        if let (Some(bind), Some(field)) = (self.number_binder, field) {
+            let numeric = self.aggregate_properties.contains_key(field)
+                && get_struct_field_meta::<T>()
+                    .iter()
+                    .any(|meta| meta.name == field && meta.field_type == FieldType::Number);
+            if numeric {
+                let placeholder = self.dialect.placeholder(self.arguments.len() + 1);
+                if bind(&mut self.arguments, value) {
+                    return placeholder;
+                }
+            }
+        }
+
+        let placeholder = self.bind_text(value);
+        let field_type = match (&self.type_cast, field) {
+            (Some(_), Some(field)) => get_struct_field_meta::<T>()
+                .into_iter()
+                .find(|meta| meta.name == field)
+                .map_or(FieldType::Unknown, |meta| meta.field_type),
+            _ => FieldType::Unknown,
+        };
+
+        self.cast_placeholder(&placeholder, value, field_type)
+    }
+
+    /// Returns the placeholder of a value with its type cast, from the function set
+    /// with `with_type_cast_fn`, or from the dialect.
+    fn cast_placeholder(&self, placeholder: &str, value: &str, field_type: FieldType) -> String {
+        match &self.type_cast {
+            Some(type_cast) => format!("{}{}", placeholder, type_cast(value, &field_type)),
+            None => self.dialect.cast_placeholder(placeholder, value),
+        }
+    }
+
+    /// Adds date range conditions to the query for a specified date column.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters containing date range information
+    ///
+    /// # Type Parameters
+    ///
+    /// Requires `DateTime<Utc>` to be encodable for the target database
+    ///
+    /// # Details
+    ///
+    /// - Adds >= condition for date_after if specified
+    /// - Adds <= condition for date_before if specified
+    /// - Only applies to columns that exist and are considered safe
+    /// - Skips invalid date columns with a warning when tracing is enabled
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use chrono::{DateTime};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, QueryParams};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///         .with_date_range(None, Some(DateTime::parse_from_rfc3339("2024-12-31T23:59:59Z").unwrap().into()), Some("deleted_at"))
+    ///         .build();
+    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_date_range(&initial_params)
+    ///     .build();
+    /// ```
+    pub fn with_date_range(mut self, params: &'q QueryParams<T>) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        self.apply_column_mappings(params);
+
+        if let Some(date_column) = &params.date_range.date_column {
+            if self.is_column_safe(date_column) {
+                if let Some(after) = params.date_range.date_after {
+                    let next_argument = self.arguments.len() + 1;
+                    let table_column = self.quote_column(date_column);
+                    let placeholder = self.dialect.placeholder(next_argument);
+                    self.conditions
+                        .push(format!("{} >= {}", table_column, placeholder));
+                    self.arguments.add(after).unwrap_or_default();
+                }
+
+                if let Some(before) = params.date_range.date_before {
+                    let next_argument = self.arguments.len() + 1;
+                    let table_column = self.quote_column(date_column);
+                    let placeholder = self.dialect.placeholder(next_argument);
+                    self.conditions
+                        .push(format!("{} <= {}", table_column, placeholder));
+                    self.arguments.add(before).unwrap_or_default();
+                }
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(column = %date_column, "Skipping invalid date column");
+            }
+        }
+
+        self
+    }
+
+    /// Adds a custom condition for a specific column with a provided operator and value.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The column name to apply the condition to
+    /// * `condition` - The operator or condition to use (e.g., ">", "LIKE", etc.)
     /// * `value` - The value to compare against
     ///
     /// # Details
@@ -362,7 +1373,7 @@ where
     ) -> Self {
         if self.is_column_safe(column) {
             let next_argument = self.arguments.len() + 1;
-            let table_column = self.dialect.quote_identifier(column);
+            let table_column = self.quote_column(column);
             let placeholder = self.dialect.placeholder(next_argument);
             self.conditions.push(format!(
                 "{} {} {}",
@@ -413,15 +1424,24 @@ where
         self
     }
 
-    /// Allows adding multiple conditions using a closure.
+    /// Adds an `EXISTS (subquery)` condition, typically correlated with the
+    /// paginated rows through the `base_query` alias.
+    ///
+    /// Placeholders in `subquery_sql` are written as if `args` were the only
+    /// arguments (`$1`, `$2` on PostgreSQL, `?` on SQLite). The arguments are
+    /// bound after the arguments of the conditions added so far and the
+    /// placeholders are renumbered to match.
     ///
     /// # Arguments
     ///
-    /// * `f` - Closure that takes a mutable reference to the QueryBuilder
+    /// * `subquery_sql` - Query checked for rows
+    /// * `args` - Values bound to the placeholders of `subquery_sql`, in order
     ///
-    /// # Details
+    /// # Safety
     ///
-    /// Useful for grouping multiple conditions that are logically related
+    /// The SQL is trusted and must never contain request input other than through
+    /// `args`. Empty SQL and SQL containing `;` are skipped with a warning when
+    /// tracing is enabled.
     ///
     /// # Returns
     ///
@@ -432,33 +1452,73 @@ where
     /// ```rust
     /// use sqlx::Postgres;
     /// use serde::{Serialize};
-    /// use sqlx_paginated::{QueryBuilder};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
     ///
     /// #[derive(Serialize, Default)]
     /// struct UserExample {
-    ///     name: String
+    ///     id: i64,
+    ///     name: String,
     /// }
-    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
-    ///     .with_combined_conditions(|builder| {
-    ///         builder.conditions.push("status = 'active'".to_string());
-    ///         builder.conditions.push("age >= 18".to_string());
-    ///     })
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter("name", Some("john"))
     ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_filters(&initial_params)
+    ///     .with_exists_condition(
+    ///         "SELECT 1 FROM orders o WHERE o.user_id = base_query.id AND o.status = $1",
+    ///         vec!["paid".to_string()],
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions[1],
+    ///     "EXISTS (SELECT 1 FROM orders o WHERE o.user_id = base_query.id AND o.status = $2)"
+    /// );
     /// ```
-    pub fn with_combined_conditions<F>(mut self, f: F) -> Self
+    pub fn with_exists_condition<V>(mut self, subquery_sql: impl Into<String>, args: Vec<V>) -> Self
     where
-        F: FnOnce(&mut QueryBuilder<'q, T, DB>),
+        V: for<'a> Encode<'a, DB> + Type<DB>,
     {
-        f(&mut self);
+        let subquery_sql = subquery_sql.into();
+        let subquery_sql = subquery_sql.trim().trim_end_matches(';').trim_end();
+
+        if subquery_sql.is_empty() || subquery_sql.contains(';') {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("Skipping invalid EXISTS subquery");
+            return self;
+        }
+
+        let subquery_sql = self
+            .dialect
+            .offset_placeholders(subquery_sql, self.arguments.len());
+        for value in args {
+            let _ = self.arguments.add(value);
+        }
+        self.conditions.push(format!("EXISTS ({})", subquery_sql));
         self
     }
 
-    /// Disables column protection for this query builder instance.
+    /// Keeps the rows having at least one row in a related table, e.g. users with
+    /// a paid order, with `EXISTS (SELECT 1 FROM "orders" WHERE "orders"."user_id" =
+    /// "base_query"."id" AND "orders"."status" = $1)`.
     ///
-    /// # Safety
+    /// The related rows are matched on `on`, a column of the related table and a
+    /// column of the model, and filtered by the given conditions on columns of the
+    /// related table, bound like other filters.
     ///
-    /// This removes all column safety checks. Use with caution as it may expose
-    /// the application to SQL injection if used with untrusted input.
+    /// # Arguments
+    ///
+    /// * `table` - The related table
+    /// * `on` - The related table column and the model column they must be equal on
+    /// * `filters` - Conditions on columns of the related table
+    ///
+    /// # Details
+    ///
+    /// The model column must be a valid column, and the table and related columns
+    /// must pass column protection. Invalid conditions are skipped with a warning
+    /// when tracing is enabled. The condition refers to the paginated rows through
+    /// the `base_query` alias used by `PaginatedQueryBuilder`.
     ///
     /// # Returns
     ///
@@ -469,30 +1529,907 @@ where
     /// ```rust
     /// use sqlx::Postgres;
     /// use serde::{Serialize};
-    /// use sqlx_paginated::{QueryBuilder};
+    /// use sqlx_paginated::{QueryBuilder, QueryFilterCondition};
     ///
     /// #[derive(Serialize, Default)]
     /// struct UserExample {
-    ///     name: String
+    ///     id: i64,
+    ///     name: String,
     /// }
     ///
-    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
-    ///     .disable_protection()
-    ///     .with_raw_condition("custom_column = 'value'")
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_exists(
+    ///         "orders",
+    ///         ("user_id", "id"),
+    ///         vec![("status", QueryFilterCondition::equal("paid"))],
+    ///     )
     ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec![
+    ///         "EXISTS (SELECT 1 FROM \"orders\" WHERE \"orders\".\"user_id\" = \"base_query\".\"id\" \
+    ///          AND \"orders\".\"status\" = $1)"
+    ///     ]
+    /// );
     /// ```
-    pub fn disable_protection(mut self) -> Self {
-        self.protection_enabled = false;
-        self
+    pub fn with_exists(
+        self,
+        table: &str,
+        on: (&str, &str),
+        filters: Vec<(&str, QueryFilterCondition)>,
+    ) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        self.apply_exists(false, table, on, filters)
     }
 
-    /// Builds the final query conditions and arguments.
+    /// Keeps the rows without any row in a related table, e.g. users without a
+    /// paid order, with `NOT EXISTS (...)`.
+    ///
+    /// Takes the same arguments as `with_exists`.
     ///
     /// # Returns
     ///
-    /// Returns a tuple containing:
-    /// - Vec<String>: List of SQL conditions
-    /// - DB::Arguments: Database-specific arguments for parameter binding
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    /// }
+    ///
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_not_exists("orders", ("user_id", "id"), vec![])
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["NOT EXISTS (SELECT 1 FROM \"orders\" WHERE \"orders\".\"user_id\" = \"base_query\".\"id\")"]
+    /// );
+    /// ```
+    pub fn with_not_exists(
+        self,
+        table: &str,
+        on: (&str, &str),
+        filters: Vec<(&str, QueryFilterCondition)>,
+    ) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        self.apply_exists(true, table, on, filters)
+    }
+
+    /// Adds the correlated `EXISTS` or `NOT EXISTS` condition of `with_exists`.
+    fn apply_exists(
+        mut self,
+        negated: bool,
+        table: &str,
+        on: (&str, &str),
+        filters: Vec<(&str, QueryFilterCondition)>,
+    ) -> Self
+    where
+        DateTime<Utc>: for<'a> Encode<'a, DB> + Type<DB>,
+    {
+        let (related_column, column) = on;
+        let related_identifiers_safe = [table, related_column]
+            .into_iter()
+            .chain(filters.iter().map(|(related, _)| *related))
+            .all(|identifier| self.is_identifier_safe(identifier));
+
+        if !self.is_column_safe(column) || !related_identifiers_safe {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(table = %table, column = %column, "Skipping invalid EXISTS condition");
+            return self;
+        }
+
+        let quoted_table = table
+            .split('.')
+            .map(|part| self.dialect.quote_identifier(part))
+            .collect::<Vec<String>>()
+            .join(".");
+        let related_term = |dialect: &dyn QueryDialect, related: &str| {
+            format!("{}.{}", quoted_table, dialect.quote_identifier(related))
+        };
+
+        // Columns qualified for joins already refer to `base_query`
+        let base_column = match &self.column_qualifier {
+            Some(_) => self.quote_column(column),
+            None => format!(
+                "{}.{}",
+                self.dialect.quote_identifier("base_query"),
+                self.quote_column(column)
+            ),
+        };
+        let mut related_conditions = vec![format!(
+            "{} = {}",
+            related_term(self.dialect.as_ref(), related_column),
+            base_column
+        )];
+
+        // Filters are applied on their own, then moved into the subquery
+        let outer_conditions = std::mem::take(&mut self.conditions);
+        for (related, condition) in &filters {
+            let table_column = related_term(self.dialect.as_ref(), related);
+            self = self.apply_filter_condition(table_column, None, condition);
+        }
+        related_conditions.extend(std::mem::replace(&mut self.conditions, outer_conditions));
+
+        self.conditions.push(format!(
+            "{}EXISTS (SELECT 1 FROM {} WHERE {})",
+            if negated { "NOT " } else { "" },
+            quoted_table,
+            related_conditions.join(" AND ")
+        ));
+        self
+    }
+
+    /// Allows adding multiple conditions using a closure.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure that takes a mutable reference to the QueryBuilder
+    ///
+    /// # Details
+    ///
+    /// Useful for grouping multiple conditions that are logically related
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_combined_conditions(|builder| {
+    ///         builder.conditions.push("status = 'active'".to_string());
+    ///         builder.conditions.push("age >= 18".to_string());
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn with_combined_conditions<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut QueryBuilder<'q, T, DB>),
+    {
+        f(&mut self);
+        self
+    }
+
+    /// Applies a closure to the builder only when a condition holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - Whether the closure should be applied
+    /// * `f` - Closure that receives the builder and returns it
+    ///
+    /// # Returns
+    ///
+    /// Returns self, transformed by `f` if `condition` is true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let is_admin = false;
+    /// let (conditions, _) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .when(!is_admin, |builder| builder.with_raw_condition("status != 'deleted'"))
+    ///     .build();
+    /// assert_eq!(conditions.len(), 1);
+    /// ```
+    pub fn when<F>(self, condition: bool, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        if condition {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Disables column protection for this query builder instance.
+    ///
+    /// # Safety
+    ///
+    /// This removes all column safety checks. Use with caution as it may expose
+    /// the application to SQL injection if used with untrusted input.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let query_builder = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .disable_protection()
+    ///     .with_raw_condition("custom_column = 'value'")
+    ///     .build();
+    /// ```
+    pub fn disable_protection(mut self) -> Self {
+        self.protection_enabled = false;
+        self
+    }
+
+    /// Lets the value of `is_null`/`is_not_null` filters choose the null check.
+    ///
+    /// By default these operators ignore their value, so `deleted_at[is_null]=` and
+    /// `deleted_at[is_null]=false` both emit `IS NULL`. With this flag a value parsing
+    /// as `false` negates the operator: `deleted_at[is_null]=false` emits `IS NOT NULL`
+    /// and `deleted_at[is_not_null]=false` emits `IS NULL`. Empty or `true` values keep
+    /// the operator as is.
+    ///
+    /// Must be called before `with_filters`.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryFilterCondition, QueryFilterOperator, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     deleted_at: Option<String>,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter_condition(
+    ///         "deleted_at",
+    ///         QueryFilterCondition::new(QueryFilterOperator::IsNull, Some("false")),
+    ///     )
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_boolean_null_flag()
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"deleted_at\" IS NOT NULL"]);
+    /// ```
+    pub fn with_boolean_null_flag(mut self) -> Self {
+        self.boolean_null_flag = true;
+        self
+    }
+
+    /// Ignores filters whose value is empty, instead of matching the empty string.
+    ///
+    /// By default `status=` filters on `"status" = ''`, which usually matches no
+    /// records when a form submits its blank fields. With this flag filters whose
+    /// operator takes a value and whose value is empty or whitespace are left out;
+    /// `is_null`/`is_not_null` filters are kept.
+    ///
+    /// Must be called before `with_filters`.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     status: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter("name", Some("john"))
+    ///     .with_filter("status", Some(""))
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_drop_empty_filters()
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"name\" = $1"]);
+    /// ```
+    pub fn with_drop_empty_filters(mut self) -> Self {
+        self.drop_empty_filters = true;
+        self
+    }
+
+    /// Restricts search to the given text columns, declared by the application as
+    /// having a trigram or btree index.
+    ///
+    /// Search columns requested by the client that are not in the list are skipped,
+    /// so search never scans an unindexed column. Combined with
+    /// `SearchAnchor::Prefix`, this keeps search index friendly.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The indexed text columns
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     bio: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("john", vec!["name", "bio"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_indexed_search_columns(vec!["name"])
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["(LOWER(\"name\") LIKE LOWER($1))"]);
+    /// ```
+    pub fn with_indexed_search_columns(mut self, columns: Vec<&str>) -> Self {
+        self.indexed_search_columns = Some(columns.into_iter().map(String::from).collect());
+        self
+    }
+
+    /// Declares the text columns having a search index, like
+    /// `with_indexed_search_columns`, and rejects any search naming another column.
+    ///
+    /// Rather than searching the indexed subset of the requested columns, the whole
+    /// search is skipped, with a warning naming the column when tracing is enabled,
+    /// so a client can't trigger a full table scan by asking for an unindexed column.
+    /// The default search columns, used when a request names none, are checked the
+    /// same way.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The indexed text columns
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     bio: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("john", vec!["name", "bio"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_require_indexed_search(vec!["name"])
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert!(conditions.is_empty());
+    /// ```
+    pub fn with_require_indexed_search(mut self, columns: Vec<&str>) -> Self {
+        self.require_indexed_search = true;
+        self.with_indexed_search_columns(columns)
+    }
+
+    /// Declares a column as holding JSON, such as a Postgres `JSONB` column, so that
+    /// search matches the term against its text form, `CAST(column AS TEXT)`.
+    ///
+    /// The text form includes object keys and JSON punctuation, so a term can also
+    /// match a key name. Filters on the column are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The JSON column
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     name: String,
+    ///     metadata: serde_json::Value,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<ProductExample>::new()
+    ///     .with_search("red", vec!["name", "metadata"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<ProductExample, Postgres>::new()
+    ///     .with_jsonb_column("metadata")
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec![
+    ///         "(LOWER(\"name\") LIKE LOWER($1) OR LOWER(CAST(\"metadata\" AS TEXT)) LIKE LOWER($1))"
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_jsonb_column(mut self, column: impl Into<String>) -> Self {
+        self.jsonb_columns.push(column.into());
+        self
+    }
+
+    /// Enables typed search, matching numeric columns by equality with numeric
+    /// search terms, e.g. `"price" = $2::smallint` for the term `42`, so that
+    /// such matches can use an index.
+    ///
+    /// Numeric columns are the model fields whose default value serializes to a
+    /// number. They are searched by equality only, and skipped for search terms
+    /// that aren't numbers; text columns are searched with LIKE as usual. When
+    /// no column is left to search, no condition is added.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ProductExample {
+    ///     name: String,
+    ///     stock: i32,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<ProductExample>::new()
+    ///     .with_search("42", vec!["name", "stock"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<ProductExample, Postgres>::new()
+    ///     .with_typed_search()
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["(LOWER(\"name\") LIKE LOWER($1) OR \"stock\" = $2::smallint)"]
+    /// );
+    /// ```
+    pub fn with_typed_search(mut self) -> Self {
+        self.typed_search_enabled = true;
+        self
+    }
+
+    /// Sets the case sensitivity and anchor of search.
+    ///
+    /// Case insensitive search wraps the column in `LOWER()`, which a plain btree
+    /// index can't serve. Case sensitive search compares the column as is: with
+    /// `SearchAnchor::Prefix` the pattern has no leading wildcard, so Postgres can
+    /// use a btree index on the column (with the `text_pattern_ops` operator class
+    /// outside the C locale), and `SearchAnchor::Exact` compares with `=`. The
+    /// anchor replaces the `SearchAnchor` of the parameters.
+    ///
+    /// Whether `LIKE` is case sensitive on SQLite and MySQL depends on the
+    /// database settings: SQLite ignores ASCII case unless `case_sensitive_like`
+    /// is enabled, and MySQL follows the collation of the column.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Case sensitivity and anchor, case insensitive `Both` by default
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, SearchAnchor, SearchOptions};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("Jo", vec!["name"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_search_options(SearchOptions {
+    ///         case_sensitive: true,
+    ///         anchor: SearchAnchor::Prefix,
+    ///     })
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["(\"name\" LIKE $1)"]);
+    /// ```
+    pub fn with_search_options(mut self, options: SearchOptions) -> Self {
+        self.search_options = Some(options);
+        self
+    }
+
+    /// Filters an aggregate computed property on its expression, in the `HAVING`
+    /// clause instead of the `WHERE` clause, when the property is a model field.
+    ///
+    /// `with_filters` collects these conditions in `having`, returned by
+    /// `build_with_having`. `PaginatedQueryBuilder::with_having_query_builder`
+    /// builds them, groups the records and emits them after the `GROUP BY`. The same
+    /// property should be registered there with `with_computed_property`, so that
+    /// its joins are added. Numeric values of numeric fields are bound as numbers. Filter groups and `with_where` skip the property.
+    /// Properties not built with `ComputedPropertyBuilder::as_aggregate` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - The aggregate computed property
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ComputedPropertyBuilder, QueryBuilder, QueryFilterOperator, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct CustomerExample {
+    ///     name: String,
+    ///     order_total: f64,
+    /// }
+    ///
+    /// let order_total = ComputedPropertyBuilder::new("order_total", "SUM(orders.total)")
+    ///     .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+    ///     .as_aggregate()
+    ///     .selected()
+    ///     .build();
+    /// let initial_params = QueryParamsBuilder::<CustomerExample>::new()
+    ///     .with_filter_operator("order_total", QueryFilterOperator::GreaterThan, "100")
+    ///     .build();
+    /// let (conditions, having, _arguments) = QueryBuilder::<CustomerExample, Postgres>::new()
+    ///     .with_aggregate_property(&order_total)
+    ///     .with_filters(&initial_params)
+    ///     .build_with_having();
+    ///
+    /// assert!(conditions.is_empty());
+    /// assert_eq!(having, vec!["(SUM(orders.total)) > $1"]);
+    /// ```
+    pub fn with_aggregate_property(mut self, property: &ComputedProperty) -> Self {
+        if property.aggregate {
+            self.aggregate_properties
+                .insert(property.name.clone(), property.expression.clone());
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(property = %property.name, "Skipping non aggregate property");
+        }
+        self
+    }
+
+    /// Lets search match a searchable computed property on its expression, when
+    /// the property is named in `search_columns`.
+    ///
+    /// The expression usually refers to a table joined by the property, whose joins
+    /// `PaginatedQueryBuilder` adds while the property is searched, so the same
+    /// property should be registered there with `with_computed_property`.
+    /// Properties not built with `ComputedPropertyBuilder::searchable` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - The searchable computed property
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{ComputedPropertyBuilder, QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct PostExample {
+    ///     title: String,
+    /// }
+    ///
+    /// let tag = ComputedPropertyBuilder::new("tag", "tags.label")
+    ///     .with_join("JOIN tags ON tags.post_id = base_query.id")
+    ///     .searchable()
+    ///     .build();
+    /// let initial_params = QueryParamsBuilder::<PostExample>::new()
+    ///     .with_search("rust", vec!["title", "tag"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<PostExample, Postgres>::new()
+    ///     .with_search_property(&tag)
+    ///     .with_search(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["(LOWER(\"title\") LIKE LOWER($1) OR LOWER((tags.label)) LIKE LOWER($1))"]
+    /// );
+    /// ```
+    pub fn with_search_property(mut self, property: &ComputedProperty) -> Self {
+        if property.searchable {
+            self.search_properties
+                .insert(property.name.clone(), property.expression.clone());
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(property = %property.name, "Skipping non searchable property");
+        }
+        self
+    }
+
+    /// Registers the searchable properties with `with_search_property` and the
+    /// aggregate ones with `with_aggregate_property`, skipping the others.
+    pub(crate) fn with_computed_properties(mut self, properties: &[ComputedProperty]) -> Self {
+        for property in properties {
+            if property.searchable {
+                self = self.with_search_property(property);
+            }
+            if property.aggregate {
+                self = self.with_aggregate_property(property);
+            }
+        }
+        self
+    }
+
+    /// Maps a model field to the database column it is read from, for fields
+    /// renamed with `#[serde(rename = "...")]`.
+    ///
+    /// Column names are validated against the serialized field names, so a field
+    /// renamed for the API is filtered, searched and sorted by its serde name.
+    /// With a mapping, that name is accepted as before and the conditions refer to
+    /// the database column instead. Fields that are not model fields and unsafe
+    /// column names are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Serialized name of the field
+    /// * `column` - Database column of the field
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     #[serde(rename = "emailAddress")]
+    ///     email: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter("emailAddress", Some("john@example.com"))
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_column_mapping("emailAddress", "email")
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"email\" = $1"]);
+    /// ```
+    pub fn with_column_mapping(mut self, name: &str, column: impl Into<String>) -> Self {
+        let column = column.into();
+
+        if self.has_column(name) && self.is_identifier_safe(&column) {
+            self.column_mappings.insert(name.to_string(), column);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(name = %name, column = %column, "Skipping invalid column mapping");
+        }
+        self
+    }
+
+    /// Declares the allowed values of an enum-like column, such as a `status`
+    /// column, so that filters can't silently match nothing with unknown values.
+    ///
+    /// Equality filters with a value outside the allowed set, and IN/NOT IN
+    /// filters without any allowed value, add no condition; IN/NOT IN filters
+    /// drop their unknown values. Values are compared exactly. Skipped values are
+    /// logged when tracing is enabled. Other operators are unaffected. See
+    /// `with_strict_enum_columns` to match nothing instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The enum column
+    /// * `allowed` - Values the column can hold
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct OrderExample {
+    ///     status: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<OrderExample>::new()
+    ///     .with_filter_in("status", vec!["paid", "bananas"])
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<OrderExample, Postgres>::new()
+    ///     .with_enum_column("status", vec!["pending", "paid", "shipped"])
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"status\" IN ($1)"]);
+    /// ```
+    pub fn with_enum_column(mut self, column: impl Into<String>, allowed: Vec<&str>) -> Self {
+        self.enum_columns.insert(
+            column.into(),
+            allowed.into_iter().map(String::from).collect(),
+        );
+        self
+    }
+
+    /// Makes equality and IN filters on `with_enum_column` columns without any
+    /// allowed value match nothing, with a `1 = 0` condition, instead of adding
+    /// no condition and matching every row.
+    ///
+    /// NOT EQUAL and NOT IN filters without allowed values still add no
+    /// condition, since no row holds an unknown value.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct OrderExample {
+    ///     status: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<OrderExample>::new()
+    ///     .with_filter("status", Some("bananas"))
+    ///     .build();
+    /// let (conditions, _arguments) = QueryBuilder::<OrderExample, Postgres>::new()
+    ///     .with_enum_column("status", vec!["pending", "paid", "shipped"])
+    ///     .with_strict_enum_columns()
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["1 = 0"]);
+    /// ```
+    pub fn with_strict_enum_columns(mut self) -> Self {
+        self.strict_enum_columns = true;
+        self
+    }
+
+    /// Overrides the type casting of filter values with a custom function.
+    ///
+    /// The function receives each value bound with a type cast and the type of
+    /// its model field, `FieldType::Unknown` when unknown, and returns the type
+    /// cast appended to the placeholder, e.g. `"::uuid"`, or an empty string to
+    /// bind the value without a cast. It replaces the whole dialect cast,
+    /// including MySQL's `CAST(? AS ...)` form.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_cast` - Function returning the type cast of a value of a field type
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::Postgres;
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{FieldType, QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     last_ip: String,
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter("last_ip", Some("192.168.1.1"))
+    ///     .build();
+    /// // Text columns holding addresses are compared as text, instead of `$1::inet`
+    /// let (conditions, _arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_type_cast_fn(|value, field_type| match field_type {
+    ///         FieldType::String => String::new(),
+    ///         _ if value.parse::<i64>().is_ok() => "::bigint".to_string(),
+    ///         _ => String::new(),
+    ///     })
+    ///     .with_filters(&initial_params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"last_ip\" = $1"]);
+    /// ```
+    pub fn with_type_cast_fn(
+        mut self,
+        type_cast: impl Fn(&str, &FieldType) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.type_cast = Some(Box::new(type_cast));
+        self
+    }
+
+    /// Builds the final query conditions and arguments.
+    ///
+    /// The `HAVING` conditions of `with_aggregate_property` are left out; use
+    /// `build_with_having` to keep them.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple containing:
+    /// - Vec<String>: List of SQL conditions
+    /// - DB::Arguments: Database-specific arguments for parameter binding
     ///
     /// # Example
     ///
@@ -516,4 +2453,129 @@ where
     pub fn build(self) -> (Vec<String>, DB::Arguments) {
         (self.conditions, self.arguments)
     }
+
+    /// Builds the final query conditions and arguments, like `build`, unless an
+    /// argument failed to encode, e.g. an array bound by `with_array_exclusions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error::Encode` with the error of the first argument that
+    /// failed to encode.
+    pub fn try_build(self) -> Result<(Vec<String>, DB::Arguments), sqlx::Error> {
+        match self.encode_error {
+            Some(error) => Err(sqlx::Error::Encode(error)),
+            None => Ok((self.conditions, self.arguments)),
+        }
+    }
+}
+
+impl<'q, T, DB: Database> QueryBuilder<'q, T, DB> {
+    /// Builds the final query conditions, `HAVING` conditions and arguments.
+    ///
+    /// The `HAVING` conditions are built last, so that their arguments follow the
+    /// ones of the conditions, in the order of the statement.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple containing:
+    /// - Vec<String>: List of SQL conditions
+    /// - Vec<String>: List of `HAVING` conditions on aggregate computed properties
+    /// - DB::Arguments: Database-specific arguments for parameter binding
+    pub fn build_with_having(mut self) -> (Vec<String>, Vec<String>, DB::Arguments) {
+        for apply in std::mem::take(&mut self.having_filters) {
+            self = apply(self);
+        }
+        (self.conditions, self.having, self.arguments)
+    }
+}
+
+/// Binds a numeric filter value as an `i64`, or as an `f64` when it has a fraction.
+pub(crate) fn bind_number<DB>(arguments: &mut DB::Arguments, value: &str) -> bool
+where
+    DB: Database,
+    i64: for<'a> Encode<'a, DB> + Type<DB>,
+    f64: for<'a> Encode<'a, DB> + Type<DB>,
+{
+    let value = value.trim();
+    if let Ok(value) = value.parse::<i64>() {
+        arguments.add(value).is_ok()
+    } else {
+        match value.parse::<f64>() {
+            Ok(value) if value.is_finite() => arguments.add(value).is_ok(),
+            _ => false,
+        }
+    }
+}
+
+/// Whether a text search configuration name, e.g. `english` or `pg_catalog.simple`,
+/// is safe to inline: letters, digits and underscores, optionally schema qualified.
+fn is_text_search_config(config: &str) -> bool {
+    let parts: Vec<&str> = config.split('.').collect();
+    parts.len() <= 2
+        && parts.iter().all(|part| {
+            !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+/// Restricts an equality or IN/NOT IN filter condition to the allowed values of an
+/// enum column, returning `None` when no allowed value is left.
+fn restrict_to_allowed_values(
+    condition: &QueryFilterCondition,
+    allowed: &[String],
+) -> Option<QueryFilterCondition> {
+    let is_allowed = |value: &str| allowed.iter().any(|allowed| allowed == value);
+
+    match condition.operator {
+        QueryFilterOperator::Equal | QueryFilterOperator::NotEqual => condition
+            .value
+            .as_deref()
+            .is_some_and(is_allowed)
+            .then(|| condition.clone()),
+        QueryFilterOperator::In | QueryFilterOperator::NotIn => {
+            let values: Vec<String> = condition
+                .split_values()
+                .into_iter()
+                .filter(|value| is_allowed(value))
+                .collect();
+
+            (!values.is_empty()).then(|| QueryFilterCondition {
+                value: Some(values.join(",")),
+                ..condition.clone()
+            })
+        }
+        _ => Some(condition.clone()),
+    }
+}
+
+/// Combines the search conditions of each token into one condition, requiring at
+/// least `min_should_match` tokens to match, clamped to the number of tokens.
+/// Without `min_should_match` any token matching is enough.
+fn min_should_match_condition(
+    token_conditions: Vec<Vec<String>>,
+    min_should_match: Option<usize>,
+) -> Option<String> {
+    let token_count = token_conditions.len();
+    let min_should_match = min_should_match.unwrap_or(1).clamp(1, token_count.max(1));
+
+    if token_count == 0 {
+        None
+    } else if min_should_match == 1 {
+        Some(format!("({})", token_conditions.concat().join(" OR ")))
+    } else if min_should_match == token_count {
+        let tokens: Vec<String> = token_conditions
+            .iter()
+            .map(|conditions| format!("({})", conditions.join(" OR ")))
+            .collect();
+        Some(format!("({})", tokens.join(" AND ")))
+    } else {
+        let tokens: Vec<String> = token_conditions
+            .iter()
+            .map(|conditions| format!("CASE WHEN ({}) THEN 1 ELSE 0 END", conditions.join(" OR ")))
+            .collect();
+        Some(format!(
+            "(({}) >= {})",
+            tokens.join(" + "),
+            min_should_match
+        ))
+    }
 }
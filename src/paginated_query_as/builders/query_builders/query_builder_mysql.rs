@@ -0,0 +1,56 @@
+use crate::paginated_query_as::builders::query_builders::query_builder::bind_number;
+use crate::paginated_query_as::internal::{
+    get_struct_boolean_field_names, get_struct_field_names, ColumnProtection, MySqlDialect,
+};
+use crate::QueryBuilder;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+impl<'q, T> Default for QueryBuilder<'q, T, sqlx::MySql>
+where
+    T: Default + Serialize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'q, T> QueryBuilder<'q, T, sqlx::MySql>
+where
+    T: Default + Serialize,
+{
+    pub fn new() -> Self {
+        Self {
+            conditions: Vec::new(),
+            having: Vec::new(),
+            having_filters: Vec::new(),
+            arguments: sqlx::mysql::MySqlArguments::default(),
+            valid_columns: get_struct_field_names::<T>(),
+            boolean_columns: get_struct_boolean_field_names::<T>(),
+            protection: Some(ColumnProtection::for_mysql()),
+            protection_enabled: true,
+            boolean_null_flag: false,
+            drop_empty_filters: false,
+            indexed_search_columns: None,
+            require_indexed_search: false,
+            jsonb_columns: Vec::new(),
+            typed_search_enabled: false,
+            search_options: None,
+            search_properties: HashMap::new(),
+            aggregate_properties: HashMap::new(),
+            enum_columns: HashMap::new(),
+            strict_enum_columns: false,
+            column_mappings: HashMap::new(),
+            column_qualifier: None,
+            bound_values: HashMap::new(),
+            array_exclusions: None,
+            raw_value_binder: None,
+            number_binder: Some(bind_number::<sqlx::MySql>),
+            encode_error: None,
+            type_cast: None,
+            dialect: Box::new(MySqlDialect),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -1,8 +1,16 @@
+use crate::paginated_query_as::builders::query_builders::query_builder::bind_number;
 use crate::paginated_query_as::internal::{
-    get_struct_field_names, ColumnProtection, PostgresDialect,
+    get_postgres_array_casting, get_struct_boolean_field_names, get_struct_field_names,
+    ColumnProtection, FieldType, PostgresDialect,
 };
 use crate::QueryBuilder;
 use serde::Serialize;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::{PgArgumentBuffer, PgArguments, PgTypeInfo};
+use sqlx::{Arguments, Encode, Postgres, Type};
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 impl<T> Default for QueryBuilder<'_, T, sqlx::Postgres>
@@ -21,12 +29,141 @@ where
     pub fn new() -> Self {
         Self {
             conditions: Vec::new(),
+            having: Vec::new(),
+            having_filters: Vec::new(),
             arguments: sqlx::postgres::PgArguments::default(),
             valid_columns: get_struct_field_names::<T>(),
+            boolean_columns: get_struct_boolean_field_names::<T>(),
             protection: Some(ColumnProtection::for_postgres()),
             protection_enabled: true,
+            boolean_null_flag: false,
+            drop_empty_filters: false,
+            indexed_search_columns: None,
+            require_indexed_search: false,
+            jsonb_columns: Vec::new(),
+            typed_search_enabled: false,
+            search_options: None,
+            search_properties: HashMap::new(),
+            aggregate_properties: HashMap::new(),
+            enum_columns: HashMap::new(),
+            strict_enum_columns: false,
+            column_mappings: HashMap::new(),
+            column_qualifier: None,
+            bound_values: HashMap::new(),
+            array_exclusions: None,
+            raw_value_binder: Some(bind_postgres_raw_value),
+            number_binder: Some(bind_number::<sqlx::Postgres>),
+            encode_error: None,
+            type_cast: None,
             dialect: Box::new(PostgresDialect),
             _phantom: PhantomData,
         }
     }
+
+    /// Binds the values of `NOT IN` filters as a single array, emitting
+    /// `column NOT IN (SELECT x FROM unnest($1) x)` instead of a placeholder per
+    /// value. Large exclusion lists then take one argument, staying clear of the
+    /// bind parameter limit.
+    ///
+    /// Values of number fields bind as a `bigint[]` or `double precision[]` array.
+    /// Values of other fields bind as a `text[]` array, cast to the element type
+    /// shared by every value, e.g. `::uuid[]`, unless they read as numbers or
+    /// booleans on a text field. Raw values bind as a `text[]` array without a cast.
+    ///
+    /// An array that fails to encode is reported by `try_build`, or by
+    /// `fetch_paginated` with `with_having_query_builder`, instead of its condition.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::{Arguments, Postgres};
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     id: i64,
+    ///     name: String
+    /// }
+    ///
+    /// let initial_params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter_not_in("id", vec!["1", "2", "70000"])
+    ///     .build();
+    /// let (conditions, arguments) = QueryBuilder::<UserExample, Postgres>::new()
+    ///     .with_array_exclusions()
+    ///     .with_filters(&initial_params)
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["\"id\" NOT IN (SELECT x FROM unnest($1) x)"]
+    /// );
+    /// assert_eq!(arguments.len(), 1);
+    /// ```
+    pub fn with_array_exclusions(mut self) -> Self {
+        self.array_exclusions = Some(bind_postgres_array);
+        self
+    }
+}
+
+fn bind_postgres_array(
+    arguments: &mut PgArguments,
+    values: Vec<String>,
+    raw: bool,
+    field_type: FieldType,
+) -> Result<String, BoxDynError> {
+    if raw {
+        arguments.add(values)?;
+        return Ok(String::new());
+    }
+
+    if field_type == FieldType::Number {
+        let integers: Result<Vec<i64>, _> = values.iter().map(|value| value.parse()).collect();
+        if let Ok(integers) = integers {
+            arguments.add(integers)?;
+            return Ok(String::new());
+        }
+        let floats: Result<Vec<f64>, _> = values.iter().map(|value| value.parse()).collect();
+        if let Ok(floats) = floats {
+            arguments.add(floats)?;
+            return Ok(String::new());
+        }
+    }
+
+    let cast = match get_postgres_array_casting(&values).as_str() {
+        "::bigint[]" | "::real[]" | "::double precision[]" | "::boolean[]"
+            if field_type == FieldType::String =>
+        {
+            String::new()
+        }
+        cast => cast.to_string(),
+    };
+    arguments.add(values)?;
+    Ok(cast)
+}
+
+/// Text bound with an unspecified type, which PostgreSQL infers from where the
+/// placeholder is used, e.g. the type of the column it is compared with. The text
+/// is sent as is, the binary form of text, enum and text domain values.
+struct UntypedText(String);
+
+impl Type<Postgres> for UntypedText {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(Oid(0))
+    }
+}
+
+impl Encode<'_, Postgres> for UntypedText {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<Postgres>>::encode(self.0.as_str(), buf)
+    }
+}
+
+fn bind_postgres_raw_value(arguments: &mut PgArguments, value: String) {
+    arguments.add(UntypedText(value)).unwrap_or_default();
 }
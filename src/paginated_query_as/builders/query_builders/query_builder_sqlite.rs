@@ -1,8 +1,10 @@
+use crate::paginated_query_as::builders::query_builders::query_builder::bind_number;
 use crate::paginated_query_as::internal::{
-    get_struct_field_names, ColumnProtection, SqliteDialect,
+    get_struct_boolean_field_names, get_struct_field_names, ColumnProtection, SqliteDialect,
 };
 use crate::QueryBuilder;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 impl<'q, T> Default for QueryBuilder<'q, T, sqlx::Sqlite>
@@ -21,10 +23,32 @@ where
     pub fn new() -> Self {
         Self {
             conditions: Vec::new(),
+            having: Vec::new(),
+            having_filters: Vec::new(),
             arguments: sqlx::sqlite::SqliteArguments::default(),
             valid_columns: get_struct_field_names::<T>(),
+            boolean_columns: get_struct_boolean_field_names::<T>(),
             protection: Some(ColumnProtection::for_sqlite()),
             protection_enabled: true,
+            boolean_null_flag: false,
+            drop_empty_filters: false,
+            indexed_search_columns: None,
+            require_indexed_search: false,
+            jsonb_columns: Vec::new(),
+            typed_search_enabled: false,
+            search_options: None,
+            search_properties: HashMap::new(),
+            aggregate_properties: HashMap::new(),
+            enum_columns: HashMap::new(),
+            strict_enum_columns: false,
+            column_mappings: HashMap::new(),
+            column_qualifier: None,
+            bound_values: HashMap::new(),
+            array_exclusions: None,
+            raw_value_binder: None,
+            number_binder: Some(bind_number::<sqlx::Sqlite>),
+            encode_error: None,
+            type_cast: None,
             dialect: Box::new(SqliteDialect),
             _phantom: PhantomData,
         }
@@ -1,10 +1,13 @@
 use crate::paginated_query_as::internal::{
-    get_struct_field_names, QueryDateRangeParams, QueryPaginationParams, QuerySearchParams,
-    QuerySortParams, DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE,
-    DEFAULT_PAGE,
+    clamp_page, get_struct_field_meta, get_struct_field_names, FieldType, QueryDateRangeParams,
+    QueryPaginationParams, QuerySearchParams, QuerySort, QuerySortParams,
+    DEFAULT_DATE_RANGE_COLUMN_NAME,
 };
-use crate::paginated_query_as::models::QuerySortDirection;
-use crate::paginated_query_as::models::{QueryFilterCondition, QueryFilterOperator};
+use crate::paginated_query_as::models::{
+    FilterLogic, PageToken, PaginatedColumns, QueryFilterCondition, QueryFilterGroup,
+    QueryFilterOperator, SearchAnchor, SearchMode,
+};
+use crate::paginated_query_as::models::{QueryNullsPosition, QuerySortDirection};
 use crate::QueryParams;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
@@ -12,6 +15,7 @@ use std::collections::HashMap;
 
 pub struct QueryParamsBuilder<'q, T> {
     query: QueryParams<'q, T>,
+    max_page_size: Option<i64>,
 }
 
 impl<T: Default + Serialize> Default for QueryParamsBuilder<'_, T> {
@@ -26,7 +30,7 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     /// Default values include:
     /// - Page: 1
     /// - Page size: 10
-    /// - Sort column: "created_at"
+    /// - Sort column: "created_at", or "updated_at" or "id" for models without it
     /// - Sort direction: Descending
     ///
     /// # Examples
@@ -44,16 +48,21 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     pub fn new() -> Self {
         Self {
             query: QueryParams::default(),
+            max_page_size: None,
         }
     }
 
-    /// Creates a new `QueryParamsBuilder` with default values.
+    /// Sets pagination parameters.
     ///
-    /// Default values include:
-    /// - Page: 1
-    /// - Page size: 10
-    /// - Sort column: "created_at"
-    /// - Sort direction: Descending
+    /// Values are clamped exactly like the `page`/`page_size` query string
+    /// parameters: the page is at least 1 and the page size is clamped to the
+    /// range from 10 to the maximum page size, 50 unless set with
+    /// `with_max_page_size`, boundaries included.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number, starting at 1
+    /// * `page_size` - Number of records per page
     ///
     /// # Examples
     ///
@@ -65,23 +74,105 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     /// struct UserExample {
     ///     name: String
     /// }
-    /// let builder = QueryParamsBuilder::<UserExample>::new();
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_pagination(2, 500)
+    ///     .build();
+    ///
+    /// assert_eq!(params.pagination.page, 2);
+    /// assert_eq!(params.pagination.page_size, 50);
     /// ```
     pub fn with_pagination(mut self, page: i64, page_size: i64) -> Self {
-        self.query.pagination = QueryPaginationParams {
-            page: page.max(DEFAULT_PAGE),
-            page_size: page_size.clamp(DEFAULT_MIN_PAGE_SIZE, DEFAULT_MAX_PAGE_SIZE),
-        };
+        self.query.pagination = QueryPaginationParams::requested(clamp_page(page), page_size);
+        if let Some(max_page_size) = self.max_page_size {
+            self.query.pagination.clamp_to(max_page_size);
+        }
+        self
+    }
+
+    /// Replaces the maximum page size of 50 used to clamp the page size, for this
+    /// builder's parameters.
+    ///
+    /// The page size is clamped to the range from the minimum page size of 10 to
+    /// `max_page_size`, in whichever order the two are set. A maximum below the
+    /// minimum becomes the page size, and a maximum of 0 or less falls back to the
+    /// minimum page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_page_size` - Largest number of records per page
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_max_page_size(200)
+    ///     .with_pagination(1, 500)
+    ///     .build();
+    ///
+    /// assert_eq!(params.pagination.page_size, 200);
+    /// ```
+    pub fn with_max_page_size(mut self, max_page_size: i64) -> Self {
+        self.max_page_size = Some(max_page_size);
+        self.query.pagination.clamp_to(max_page_size);
         self
     }
 
-    /// Sets sorting parameters.
+    /// Starts the page at the offset of a `page_token`, reporting the page of the
+    /// current page size containing it.
+    ///
+    /// Call after `with_pagination` when setting a page size as well. Invalid
+    /// tokens leave the page unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Token produced by `PageToken::encode`, e.g. a `next_page_token`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{PageToken, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_pagination(1, 20)
+    ///     .with_page_token(&PageToken { offset: 40 }.encode())
+    ///     .build();
+    ///
+    /// assert_eq!(params.pagination.page, 3);
+    /// ```
+    pub fn with_page_token(mut self, token: &str) -> Self {
+        if let Some(token) = PageToken::decode(token) {
+            self.query.pagination.start_at(token.offset);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(token = %token, "Skipping invalid page token");
+        }
+        self
+    }
+
+    /// Sets sorting parameters, or adds a secondary sort column when called again.
     ///
     /// # Arguments
     ///
     /// * `sort_column` - Column name to sort by
     /// * `sort_direction` - Direction of sort (Ascending or Descending)
     ///
+    /// # Details
+    ///
+    /// Every call after the first appends a column to `sort.order_by`, in order of
+    /// precedence, so ties on the earlier columns are broken by the later ones.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -90,22 +181,139 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     ///
     /// #[derive(Serialize, Default)]
     /// struct UserExample {
-    ///     name: String
+    ///     name: String,
+    ///     status: String,
     /// }
     ///
     /// let params = QueryParamsBuilder::<UserExample>::new()
-    ///     .with_sort("updated_at", QuerySortDirection::Ascending)
+    ///     .with_sort("status", QuerySortDirection::Ascending)
+    ///     .with_sort("updated_at", QuerySortDirection::Descending)
     ///     .build();
+    ///
+    /// assert_eq!(params.sort.sorts().len(), 2);
     /// ```
     pub fn with_sort(
         mut self,
         sort_column: impl Into<String>,
         sort_direction: QuerySortDirection,
     ) -> Self {
-        self.query.sort = QuerySortParams {
-            sort_column: sort_column.into(),
-            sort_direction,
-        };
+        self.query.sort = append_sort(
+            self.query.sort,
+            QuerySort {
+                column: sort_column.into(),
+                direction: sort_direction,
+                nulls: QueryNullsPosition::Default,
+            },
+        );
+        self
+    }
+
+    /// Sets sorting parameters like `with_sort`, placing NULLs first or last.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort_column` - Column name to sort by
+    /// * `sort_direction` - Direction of sort (Ascending or Descending)
+    /// * `nulls` - Where NULLs are placed, whatever the direction
+    ///
+    /// # Details
+    ///
+    /// Postgres emits `NULLS FIRST`/`NULLS LAST`. Databases without that syntax,
+    /// such as SQLite, sort on `"column" IS NULL` first instead.
+    /// `QueryNullsPosition::Default` keeps the database default, like `with_sort`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryNullsPosition, QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     last_login: Option<String>,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_sort_nulls("last_login", QuerySortDirection::Descending, QueryNullsPosition::Last)
+    ///     .with_sort("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// assert_eq!(params.sort.sorts()[0].nulls, QueryNullsPosition::Last);
+    /// ```
+    pub fn with_sort_nulls(
+        mut self,
+        sort_column: impl Into<String>,
+        sort_direction: QuerySortDirection,
+        nulls: QueryNullsPosition,
+    ) -> Self {
+        self.query.sort = append_sort(
+            self.query.sort,
+            QuerySort {
+                column: sort_column.into(),
+                direction: sort_direction,
+                nulls,
+            },
+        );
+        self
+    }
+
+    /// Sets sorting parameters, sorting by the lowercased column value.
+    ///
+    /// Emits `ORDER BY LOWER("column")`, which can use a `LOWER(column)` expression
+    /// index, instead of sorting by the column itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort_column` - Text column name to sort by
+    /// * `sort_direction` - Direction of sort (Ascending or Descending)
+    ///
+    /// # Details
+    ///
+    /// Only sets the sort if the column exists in the model struct and holds text,
+    /// or values of unknown type such as `Option<String>`.
+    /// Logs a warning if tracing is enabled and the column is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder, QuerySortDirection};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_sort_case_insensitive("name", QuerySortDirection::Ascending)
+    ///     .build();
+    ///
+    /// assert!(params.sort.is_case_insensitive());
+    /// ```
+    pub fn with_sort_case_insensitive(
+        mut self,
+        sort_column: impl Into<String>,
+        sort_direction: QuerySortDirection,
+    ) -> Self {
+        let sort_column = sort_column.into();
+        let is_text = get_struct_field_meta::<T>().iter().any(|field| {
+            field.name == sort_column
+                && matches!(field.field_type, FieldType::String | FieldType::Unknown)
+        });
+
+        if is_text {
+            self.query.sort = QuerySortParams {
+                sort_column,
+                sort_direction,
+                order_by: Vec::new(),
+                explicit: true,
+                case_insensitive: true,
+            };
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %sort_column, "Skipping invalid case insensitive sort column");
+        }
         self
     }
 
@@ -139,10 +347,146 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self.query.search = QuerySearchParams {
             search: Some(search.into()),
             search_columns: Some(search_columns.into_iter().map(Into::into).collect()),
+            search_mode: self.query.search.search_mode,
+            search_anchor: self.query.search.search_anchor,
+            min_should_match: self.query.search.min_should_match,
+            full_text_config: self.query.search.full_text_config.take(),
         };
         self
     }
 
+    /// Sets the search term and columns, matched with Postgres full-text search
+    /// instead of LIKE patterns.
+    ///
+    /// On Postgres the columns are searched as a single document, as
+    /// `to_tsvector(config, document) @@ plainto_tsquery(config, term)`, with the term
+    /// bound as a parameter. A single column is the document as is; several columns
+    /// are concatenated as `coalesce(col1, '') || ' ' || coalesce(col2, '')`, the
+    /// expression a GIN index should be built on. Other dialects fall back to the
+    /// LIKE search of `with_search`, so the same parameters work everywhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - Search term to look for
+    /// * `search_columns` - Vector of column names to search in
+    /// * `config` - Text search configuration, e.g. `english` or `simple`. Names other
+    ///   than letters, digits and underscores, optionally schema qualified, fall back
+    ///   to LIKE search
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::QueryParamsBuilder;
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct ArticleExample {
+    ///     title: String,
+    ///     body: String,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<ArticleExample>::new()
+    ///     .with_full_text_search("rust ownership", vec!["title", "body"], "english")
+    ///     .build();
+    /// ```
+    pub fn with_full_text_search(
+        self,
+        search: impl Into<String>,
+        search_columns: Vec<impl Into<String>>,
+        config: impl Into<String>,
+    ) -> Self {
+        let mut builder = self.with_search(search, search_columns);
+        builder.query.search.full_text_config = Some(config.into());
+        builder
+    }
+
+    /// Sets how the search term is matched against the search columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `search_mode` - Matching strategy, defaults to `SearchMode::Phrase`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder, SearchMode};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     email: String,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("john doe", vec!["name", "email"])
+    ///     .with_search_mode(SearchMode::AnyTokenAnyColumn)
+    ///     .build();
+    /// ```
+    pub fn with_search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.query.search.search_mode = search_mode;
+        self
+    }
+
+    /// Sets where the `%` wildcards are placed around the search term.
+    ///
+    /// # Arguments
+    ///
+    /// * `search_anchor` - Wildcard placement, defaults to `SearchAnchor::Both` (`%term%`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder, SearchAnchor};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     email: String,
+    /// }
+    ///
+    /// // Index-friendly "starts with" search: `john%`
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("john", vec!["email"])
+    ///     .with_search_anchor(SearchAnchor::Prefix)
+    ///     .build();
+    /// ```
+    pub fn with_search_anchor(mut self, search_anchor: SearchAnchor) -> Self {
+        self.query.search.search_anchor = search_anchor;
+        self
+    }
+
+    /// Sets the minimum number of search tokens a row must match, on any of the
+    /// search columns. The search term is split into whitespace separated tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_should_match` - Tokens required to match, clamped between one and the
+    ///   number of tokens; one matches any token and the number of tokens all of them
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::QueryParamsBuilder;
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     email: String,
+    /// }
+    ///
+    /// // Rows matching at least two of the three tokens
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_search("john doe smith", vec!["name", "email"])
+    ///     .with_min_should_match(2)
+    ///     .build();
+    /// ```
+    pub fn with_min_should_match(mut self, min_should_match: usize) -> Self {
+        self.query.search.min_should_match = Some(min_should_match);
+        self
+    }
+
     /// Sets date range parameters for filtering by date.
     ///
     /// # Arguments
@@ -205,27 +549,348 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     ///
     /// ```rust
     /// use serde::{Serialize};
-    /// use sqlx_paginated::{QueryParamsBuilder, QueryFilterOperator};
+    /// use sqlx_paginated::{QueryParamsBuilder, QueryFilterOperator};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct Product {
+    ///     name: String,
+    ///     price: f64,
+    ///     stock: i32,
+    ///     status: String,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<Product>::new()
+    ///     .with_filter_operator("price", QueryFilterOperator::GreaterThan, "10.00")
+    ///     .with_filter_operator("stock", QueryFilterOperator::LessOrEqual, "100")
+    ///     .with_filter_operator("status", QueryFilterOperator::NotEqual, "deleted")
+    ///     .build();
+    /// ```
+    pub fn with_filter_operator(
+        mut self,
+        key: impl Into<String>,
+        operator: QueryFilterOperator,
+        value: impl Into<String>,
+    ) -> Self {
+        let key = key.into();
+        let valid_fields = get_struct_field_names::<T>();
+
+        if valid_fields.contains(&key) {
+            self.query
+                .filters
+                .entry(key)
+                .or_default()
+                .push(QueryFilterCondition::new(operator, Some(value)));
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping invalid filter column");
+        }
+        self
+    }
+
+    /// Adds a filter condition whose value is bound without a type cast.
+    ///
+    /// Filter values are normally cast based on their content, e.g. `42` as
+    /// `::smallint` on Postgres, which is wrong for columns with enum or custom
+    /// domain types. On Postgres a raw value is bound with no type, for the
+    /// database to infer from the column, and is sent as text, so the column
+    /// should be text-like, e.g. an enum or a domain over text. Other databases
+    /// bind it as text.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `operator` - The comparison operator to use
+    /// * `value` - Value to compare against
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{QueryFilterOperator, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct Ticket {
+    ///     priority: String,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<Ticket>::new()
+    ///     .with_filter_raw_value("priority", QueryFilterOperator::Equal, "1")
+    ///     .build();
+    ///
+    /// assert!(params.filters["priority"][0].is_raw_value());
+    /// ```
+    pub fn with_filter_raw_value(
+        mut self,
+        key: impl Into<String>,
+        operator: QueryFilterOperator,
+        value: impl Into<String>,
+    ) -> Self {
+        let key = key.into();
+        let valid_fields = get_struct_field_names::<T>();
+
+        if valid_fields.contains(&key) {
+            let condition = QueryFilterCondition {
+                raw_value: true,
+                ..QueryFilterCondition::new(operator, Some(value))
+            };
+            self.query.filters.entry(key).or_default().push(condition);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping invalid filter column");
+        }
+        self
+    }
+
+    /// Adds a filter condition for IS NULL or IS NOT NULL checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `is_null` - If true, checks IS NULL; if false, checks IS NOT NULL
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct User {
+    ///     name: String,
+    ///     deleted_at: Option<String>,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_filter_null("deleted_at", true)  // IS NULL
+    ///     .build();
+    /// ```
+    pub fn with_filter_null(mut self, key: impl Into<String>, is_null: bool) -> Self {
+        let key = key.into();
+        let valid_fields = get_struct_field_names::<T>();
+
+        if valid_fields.contains(&key) {
+            let condition = if is_null {
+                QueryFilterCondition::is_null()
+            } else {
+                QueryFilterCondition::is_not_null()
+            };
+            self.query.filters.entry(key).or_default().push(condition);
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping invalid filter column");
+        }
+        self
+    }
+
+    /// Adds an IS TRUE or IS FALSE filter condition on a boolean column.
+    ///
+    /// Like an equality filter, NULL values don't match. On SQLite the column is
+    /// compared with `1`/`0` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `value` - If true, checks IS TRUE; if false, checks IS FALSE
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct User {
+    ///     name: String,
+    ///     confirmed: Option<bool>,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_filter_is("confirmed", true)  // IS TRUE
+    ///     .build();
+    /// ```
+    pub fn with_filter_is(self, key: impl Into<String>, value: bool) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::is(value))
+    }
+
+    /// Adds an IS NOT TRUE or IS NOT FALSE filter condition on a boolean column.
+    ///
+    /// Unlike a not equal filter, NULL values match, so `IS NOT TRUE` selects the
+    /// rows that are false or unset. On SQLite the column is compared with
+    /// `IS NOT 1`/`IS NOT 0` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `value` - If true, checks IS NOT TRUE; if false, checks IS NOT FALSE
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct User {
+    ///     name: String,
+    ///     confirmed: Option<bool>,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_filter_is_not("confirmed", true)  // IS NOT TRUE
+    ///     .build();
+    /// ```
+    pub fn with_filter_is_not(self, key: impl Into<String>, value: bool) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::is_not(value))
+    }
+
+    /// Adds an IN filter condition with multiple values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `values` - Vector of values to check against
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct User {
+    ///     name: String,
+    ///     role: String,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_filter_in("role", vec!["admin", "moderator", "user"])
+    ///     .build();
+    /// ```
+    pub fn with_filter_in(
+        mut self,
+        key: impl Into<String>,
+        values: Vec<impl Into<String>>,
+    ) -> Self {
+        let key = key.into();
+        let valid_fields = get_struct_field_names::<T>();
+
+        if valid_fields.contains(&key) {
+            self.query
+                .filters
+                .entry(key)
+                .or_default()
+                .push(QueryFilterCondition::in_list(values));
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(column = %key, "Skipping invalid filter column");
+        }
+        self
+    }
+
+    /// Adds a BETWEEN filter condition, matching values from `low` to `high` inclusive.
+    ///
+    /// Both bounds are bound as parameters, with the same type casts as other
+    /// filter values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `low` - Lowest matching value
+    /// * `high` - Highest matching value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx::Postgres;
+    /// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct Product {
+    ///     name: String,
+    ///     price: f64,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<Product>::new()
+    ///     .with_filter_between("price", "10", "100")
+    ///     .build();
+    ///
+    /// let (conditions, _arguments) = QueryBuilder::<Product, Postgres>::new()
+    ///     .with_filters(&params)
+    ///     .build();
+    ///
+    /// assert_eq!(conditions, vec!["\"price\" BETWEEN $1::smallint AND $2::smallint"]);
+    /// ```
+    pub fn with_filter_between(
+        self,
+        key: impl Into<String>,
+        low: impl Into<String>,
+        high: impl Into<String>,
+    ) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::between(low, high))
+    }
+
+    /// Adds a NOT BETWEEN filter condition, matching values outside `low` to `high`
+    /// inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `low` - Lowest excluded value
+    /// * `high` - Highest excluded value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::QueryParamsBuilder;
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct Product {
+    ///     name: String,
+    ///     price: f64,
+    /// }
+    ///
+    /// let params = QueryParamsBuilder::<Product>::new()
+    ///     .with_filter_not_between("price", "10", "100")
+    ///     .build();
+    /// ```
+    pub fn with_filter_not_between(
+        self,
+        key: impl Into<String>,
+        low: impl Into<String>,
+        high: impl Into<String>,
+    ) -> Self {
+        self.with_filter_condition(key, QueryFilterCondition::not_between(low, high))
+    }
+
+    /// Adds a NOT IN filter condition with multiple values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column name to filter on
+    /// * `values` - Vector of values to exclude
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::{QueryParamsBuilder};
     ///
     /// #[derive(Serialize, Default)]
-    /// struct Product {
+    /// struct User {
     ///     name: String,
-    ///     price: f64,
-    ///     stock: i32,
-    ///     status: String,
+    ///     role: String,
     /// }
     ///
-    /// let params = QueryParamsBuilder::<Product>::new()
-    ///     .with_filter_operator("price", QueryFilterOperator::GreaterThan, "10.00")
-    ///     .with_filter_operator("stock", QueryFilterOperator::LessOrEqual, "100")
-    ///     .with_filter_operator("status", QueryFilterOperator::NotEqual, "deleted")
+    /// let params = QueryParamsBuilder::<User>::new()
+    ///     .with_filter_not_in("role", vec!["banned", "suspended"])
     ///     .build();
     /// ```
-    pub fn with_filter_operator(
+    pub fn with_filter_not_in(
         mut self,
         key: impl Into<String>,
-        operator: QueryFilterOperator,
-        value: impl Into<String>,
+        values: Vec<impl Into<String>>,
     ) -> Self {
         let key = key.into();
         let valid_fields = get_struct_field_names::<T>();
@@ -233,7 +898,9 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         if valid_fields.contains(&key) {
             self.query
                 .filters
-                .insert(key, QueryFilterCondition::new(operator, Some(value)));
+                .entry(key)
+                .or_default()
+                .push(QueryFilterCondition::not_in_list(values));
         } else {
             #[cfg(feature = "tracing")]
             tracing::warn!(column = %key, "Skipping invalid filter column");
@@ -241,40 +908,50 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self
     }
 
-    /// Adds a filter condition for IS NULL or IS NOT NULL checks.
+    /// Adds a simple equality filter condition (backward compatible).
     ///
     /// # Arguments
     ///
     /// * `key` - Column name to filter on
-    /// * `is_null` - If true, checks IS NULL; if false, checks IS NOT NULL
+    /// * `value` - Optional value to filter by
+    ///
+    /// # Details
+    ///
+    /// Only adds the filter if the column exists in the model struct.
+    /// Logs a warning if tracing is enabled and the column is invalid.
+    /// This method maintains backward compatibility with the original API.
     ///
     /// # Examples
     ///
     /// ```rust
+    /// use std::any::Any;
     /// use serde::{Serialize};
     /// use sqlx_paginated::{QueryParamsBuilder};
     ///
     /// #[derive(Serialize, Default)]
-    /// struct User {
+    /// struct UserExample {
     ///     name: String,
-    ///     deleted_at: Option<String>,
+    ///     status: String,
+    ///     role: String
     /// }
     ///
-    /// let params = QueryParamsBuilder::<User>::new()
-    ///     .with_filter_null("deleted_at", true)  // IS NULL
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .with_filter("status", Some("active"))
+    ///     .with_filter("role", Some("admin"))
     ///     .build();
     /// ```
-    pub fn with_filter_null(mut self, key: impl Into<String>, is_null: bool) -> Self {
+    pub fn with_filter(mut self, key: impl Into<String>, value: Option<impl Into<String>>) -> Self {
         let key = key.into();
         let valid_fields = get_struct_field_names::<T>();
 
         if valid_fields.contains(&key) {
-            let condition = if is_null {
-                QueryFilterCondition::is_null()
-            } else {
-                QueryFilterCondition::is_not_null()
-            };
-            self.query.filters.insert(key, condition);
+            if let Some(val) = value {
+                self.query
+                    .filters
+                    .entry(key)
+                    .or_default()
+                    .push(QueryFilterCondition::equal(val));
+            }
         } else {
             #[cfg(feature = "tracing")]
             tracing::warn!(column = %key, "Skipping invalid filter column");
@@ -282,41 +959,42 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self
     }
 
-    /// Adds an IN filter condition with multiple values.
+    /// Adds a single prebuilt filter condition for a column.
+    ///
+    /// Useful together with the `QueryFilterCondition` constructors when conditions
+    /// are added one at a time. Only adds the filter if the column exists in the model struct.
     ///
     /// # Arguments
     ///
     /// * `key` - Column name to filter on
-    /// * `values` - Vector of values to check against
+    /// * `condition` - The filter condition to apply
     ///
     /// # Examples
     ///
     /// ```rust
     /// use serde::{Serialize};
-    /// use sqlx_paginated::{QueryParamsBuilder};
+    /// use sqlx_paginated::{QueryParamsBuilder, QueryFilterCondition};
     ///
     /// #[derive(Serialize, Default)]
-    /// struct User {
+    /// struct Product {
     ///     name: String,
-    ///     role: String,
+    ///     price: f64,
     /// }
     ///
-    /// let params = QueryParamsBuilder::<User>::new()
-    ///     .with_filter_in("role", vec!["admin", "moderator", "user"])
+    /// let params = QueryParamsBuilder::<Product>::new()
+    ///     .with_filter_condition("price", QueryFilterCondition::greater_than("10.00"))
     ///     .build();
     /// ```
-    pub fn with_filter_in(
+    pub fn with_filter_condition(
         mut self,
         key: impl Into<String>,
-        values: Vec<impl Into<String>>,
+        condition: QueryFilterCondition,
     ) -> Self {
         let key = key.into();
         let valid_fields = get_struct_field_names::<T>();
 
         if valid_fields.contains(&key) {
-            self.query
-                .filters
-                .insert(key, QueryFilterCondition::in_list(values));
+            self.query.filters.entry(key).or_default().push(condition);
         } else {
             #[cfg(feature = "tracing")]
             tracing::warn!(column = %key, "Skipping invalid filter column");
@@ -324,93 +1002,124 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
         self
     }
 
-    /// Adds a NOT IN filter condition with multiple values.
+    /// Adds a group of filter conditions combined by `logic`, such as either of two
+    /// statuses, rendered as a single parenthesized condition.
+    ///
+    /// The group is combined with AND with the other filters and groups. Conditions
+    /// on columns that don't exist in the model struct are skipped, and a group left
+    /// without conditions is not added.
     ///
     /// # Arguments
     ///
-    /// * `key` - Column name to filter on
-    /// * `values` - Vector of values to exclude
+    /// * `logic` - How the conditions of the group are combined
+    /// * `conditions` - Column names and their filter conditions
     ///
     /// # Examples
     ///
     /// ```rust
     /// use serde::{Serialize};
-    /// use sqlx_paginated::{QueryParamsBuilder};
+    /// use sqlx::Postgres;
+    /// use sqlx_paginated::{FilterLogic, QueryBuilder, QueryFilterCondition, QueryParamsBuilder};
     ///
     /// #[derive(Serialize, Default)]
     /// struct User {
-    ///     name: String,
-    ///     role: String,
+    ///     status: String,
+    ///     deleted_at: Option<String>,
     /// }
     ///
     /// let params = QueryParamsBuilder::<User>::new()
-    ///     .with_filter_not_in("role", vec!["banned", "suspended"])
+    ///     .with_filter_group(
+    ///         FilterLogic::Or,
+    ///         vec![
+    ///             ("status", QueryFilterCondition::equal("active")),
+    ///             ("status", QueryFilterCondition::equal("pending")),
+    ///         ],
+    ///     )
+    ///     .with_filter_null("deleted_at", true)
+    ///     .build();
+    ///
+    /// let (conditions, _arguments) = QueryBuilder::<User, Postgres>::new()
+    ///     .with_filters(&params)
     ///     .build();
+    ///
+    /// assert_eq!(
+    ///     conditions,
+    ///     vec!["\"deleted_at\" IS NULL", "(\"status\" = $1 OR \"status\" = $2)"]
+    /// );
     /// ```
-    pub fn with_filter_not_in(
+    pub fn with_filter_group(
         mut self,
-        key: impl Into<String>,
-        values: Vec<impl Into<String>>,
+        logic: FilterLogic,
+        conditions: Vec<(impl Into<String>, QueryFilterCondition)>,
     ) -> Self {
-        let key = key.into();
         let valid_fields = get_struct_field_names::<T>();
 
-        if valid_fields.contains(&key) {
+        let conditions: Vec<(String, QueryFilterCondition)> = conditions
+            .into_iter()
+            .filter_map(|(key, condition)| {
+                let key = key.into();
+                if valid_fields.contains(&key) {
+                    Some((key, condition))
+                } else {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(column = %key, "Skipping invalid filter column");
+                    None
+                }
+            })
+            .collect();
+
+        if !conditions.is_empty() {
             self.query
-                .filters
-                .insert(key, QueryFilterCondition::not_in_list(values));
-        } else {
-            #[cfg(feature = "tracing")]
-            tracing::warn!(column = %key, "Skipping invalid filter column");
+                .filter_groups
+                .push(QueryFilterGroup { logic, conditions });
         }
         self
     }
 
-    /// Adds a simple equality filter condition (backward compatible).
+    /// Treats the listed fields as tri-state checkbox filters.
     ///
-    /// # Arguments
+    /// HTML checkboxes send `field=on` when checked and omit the key when unchecked.
+    /// Filters on these fields accept `on`/`off` as well as `true`/`false` and `1`/`0`,
+    /// and are bound using the boolean representation of the target database.
+    /// An absent, empty or unrecognized value adds no filter.
     ///
-    /// * `key` - Column name to filter on
-    /// * `value` - Optional value to filter by
+    /// Useful for fields whose type can't be inferred as boolean, such as `Option<bool>`.
+    /// Only fields that exist in the model struct are kept.
     ///
-    /// # Details
+    /// # Arguments
     ///
-    /// Only adds the filter if the column exists in the model struct.
-    /// Logs a warning if tracing is enabled and the column is invalid.
-    /// This method maintains backward compatibility with the original API.
+    /// * `fields` - Column names to treat as checkboxes
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use std::any::Any;
     /// use serde::{Serialize};
     /// use sqlx_paginated::{QueryParamsBuilder};
     ///
     /// #[derive(Serialize, Default)]
     /// struct UserExample {
     ///     name: String,
-    ///     status: String,
-    ///     role: String
+    ///     confirmed: Option<bool>,
     /// }
     ///
     /// let params = QueryParamsBuilder::<UserExample>::new()
-    ///     .with_filter("status", Some("active"))
-    ///     .with_filter("role", Some("admin"))
+    ///     .with_checkbox_filters(vec!["confirmed"])
+    ///     .with_filter("confirmed", Some("on"))
     ///     .build();
     /// ```
-    pub fn with_filter(mut self, key: impl Into<String>, value: Option<impl Into<String>>) -> Self {
-        let key = key.into();
+    pub fn with_checkbox_filters(mut self, fields: Vec<impl Into<String>>) -> Self {
         let valid_fields = get_struct_field_names::<T>();
 
-        if valid_fields.contains(&key) {
-            if let Some(val) = value {
-                self.query
-                    .filters
-                    .insert(key, QueryFilterCondition::equal(val));
+        for field in fields {
+            let field = field.into();
+            if valid_fields.contains(&field) {
+                if !self.query.checkbox_filters.contains(&field) {
+                    self.query.checkbox_filters.push(field);
+                }
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(column = %field, "Skipping invalid checkbox filter column");
             }
-        } else {
-            #[cfg(feature = "tracing")]
-            tracing::warn!(column = %key, "Skipping invalid filter column");
         }
         self
     }
@@ -454,18 +1163,21 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     ) -> Self {
         let valid_fields = get_struct_field_names::<T>();
 
-        self.query
-            .filters
-            .extend(filters.into_iter().filter_map(|(key, value)| {
-                let key = key.into();
-                if valid_fields.contains(&key) {
-                    value.map(|v| (key, QueryFilterCondition::equal(v)))
-                } else {
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!(column = %key, "Skipping invalid filter column");
-                    None
+        for (key, value) in filters {
+            let key = key.into();
+            if valid_fields.contains(&key) {
+                if let Some(value) = value {
+                    self.query
+                        .filters
+                        .entry(key)
+                        .or_default()
+                        .push(QueryFilterCondition::equal(value));
                 }
-            }));
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(column = %key, "Skipping invalid filter column");
+            }
+        }
 
         self
     }
@@ -509,22 +1221,54 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     ) -> Self {
         let valid_fields = get_struct_field_names::<T>();
 
-        self.query
-            .filters
-            .extend(filters.into_iter().filter_map(|(key, condition)| {
-                let key = key.into();
-                if valid_fields.contains(&key) {
-                    Some((key, condition))
-                } else {
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!(column = %key, "Skipping invalid filter column");
-                    None
-                }
-            }));
+        for (key, condition) in filters {
+            let key = key.into();
+            if valid_fields.contains(&key) {
+                self.query.filters.entry(key).or_default().push(condition);
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(column = %key, "Skipping invalid filter column");
+            }
+        }
 
         self
     }
 
+    /// Applies a closure to the builder only when a condition holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - Whether the closure should be applied
+    /// * `f` - Closure that receives the builder and returns it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Serialize};
+    /// use sqlx_paginated::QueryParamsBuilder;
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct UserExample {
+    ///     name: String,
+    ///     status: String,
+    /// }
+    ///
+    /// let is_admin = false;
+    /// let params = QueryParamsBuilder::<UserExample>::new()
+    ///     .when(!is_admin, |builder| builder.with_filter("status", Some("active")))
+    ///     .build();
+    /// ```
+    pub fn when<F>(self, condition: bool, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        if condition {
+            f(self)
+        } else {
+            self
+        }
+    }
+
     /// Builds and returns the final QueryParams.
     ///
     /// # Returns
@@ -558,12 +1302,83 @@ impl<'q, T: Default + Serialize> QueryParamsBuilder<'q, T> {
     }
 }
 
+impl<T: Default + Serialize + PaginatedColumns> QueryParamsBuilder<'_, T> {
+    /// Sets the sort column, like `with_sort`, from a typed column of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort_column` - Column of the model to sort by
+    /// * `sort_direction` - Direction of the sort
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_sort_by(self, sort_column: T::Column, sort_direction: QuerySortDirection) -> Self {
+        self.with_sort(sort_column, sort_direction)
+    }
+
+    /// Adds an equality filter, like `with_filter`, on a typed column of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Column of the model to filter on
+    /// * `value` - Value to filter by, nothing is added for `None`
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_filter_by(self, key: T::Column, value: Option<impl Into<String>>) -> Self {
+        self.with_filter(key, value)
+    }
+
+    /// Sets the search term and columns, like `with_search`, from typed columns of
+    /// the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - Term to search for
+    /// * `search_columns` - Columns of the model to search in
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_search_in(self, search: impl Into<String>, search_columns: Vec<T::Column>) -> Self {
+        self.with_search(search, search_columns)
+    }
+}
+
+/// Sets the sort column of defaulted sort parameters, or appends a secondary sort
+/// column to explicit ones.
+///
+/// A NULL placement can only be held by `order_by`, so a first sort column with
+/// one is also kept there.
+pub(crate) fn append_sort(sort: QuerySortParams, next: QuerySort) -> QuerySortParams {
+    if !sort.is_explicit() {
+        return QuerySortParams {
+            sort_column: next.column.clone(),
+            sort_direction: next.direction.clone(),
+            order_by: match next.nulls {
+                QueryNullsPosition::Default => Vec::new(),
+                _ => vec![next],
+            },
+            explicit: true,
+            case_insensitive: false,
+        };
+    }
+
+    let mut order_by = sort.sorts();
+    order_by.push(next);
+
+    QuerySortParams { order_by, ..sort }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
     use crate::paginated_query_as::internal::{
-        DEFAULT_SEARCH_COLUMN_NAMES, DEFAULT_SORT_COLUMN_NAME,
+        DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE, DEFAULT_SEARCH_COLUMN_NAMES,
+        DEFAULT_SORT_COLUMN_NAME,
     };
     use crate::paginated_query_as::models::QuerySortDirection;
     use chrono::{DateTime, Utc};
@@ -628,6 +1443,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_repeated_sort_appends_secondary_columns() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort("title", QuerySortDirection::Descending)
+            .build();
+        assert_eq!(params.sort.sort_column, "title");
+        assert!(params.sort.order_by.is_empty());
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort("title", QuerySortDirection::Ascending)
+            .with_sort("created_at", QuerySortDirection::Descending)
+            .with_sort("id", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            params.sort.order_by,
+            vec![
+                QuerySort {
+                    column: "title".to_string(),
+                    direction: QuerySortDirection::Ascending,
+                    nulls: QueryNullsPosition::Default,
+                },
+                QuerySort {
+                    column: "created_at".to_string(),
+                    direction: QuerySortDirection::Descending,
+                    nulls: QueryNullsPosition::Default,
+                },
+                QuerySort {
+                    column: "id".to_string(),
+                    direction: QuerySortDirection::Ascending,
+                    nulls: QueryNullsPosition::Default,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_sort_requires_known_column() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort_case_insensitive("title", QuerySortDirection::Descending)
+            .build();
+        assert_eq!(params.sort.sort_column, "title");
+        assert!(params.sort.is_case_insensitive());
+
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_sort_case_insensitive("unknown", QuerySortDirection::Descending)
+            .build();
+        assert_eq!(params.sort.sort_column, DEFAULT_SORT_COLUMN_NAME);
+        assert!(!params.sort.is_case_insensitive());
+    }
+
     #[test]
     fn test_date_range_defaults() {
         let params = QueryParamsBuilder::<TestModel>::new().build();
@@ -750,12 +1615,12 @@ mod tests {
             .build();
 
         assert!(params.filters.contains_key("status"));
-        let status_filter = params.filters.get("status").unwrap();
+        let status_filter = &params.filters["status"][0];
         assert_eq!(status_filter.operator, QueryFilterOperator::Equal);
         assert_eq!(status_filter.value, Some("active".to_string()));
 
         assert!(params.filters.contains_key("category"));
-        let category_filter = params.filters.get("category").unwrap();
+        let category_filter = &params.filters["category"][0];
         assert_eq!(category_filter.operator, QueryFilterOperator::Equal);
         assert_eq!(category_filter.value, Some("test".to_string()));
     }
@@ -811,11 +1676,11 @@ mod tests {
             .with_filter("category", Some("test"))
             .build();
 
-        let status_filter = params.filters.get("status").unwrap();
+        let status_filter = &params.filters["status"][0];
         assert_eq!(status_filter.operator, QueryFilterOperator::Equal);
         assert_eq!(status_filter.value, Some("active".to_string()));
 
-        let category_filter = params.filters.get("category").unwrap();
+        let category_filter = &params.filters["category"][0];
         assert_eq!(category_filter.operator, QueryFilterOperator::Equal);
         assert_eq!(category_filter.value, Some("test".to_string()));
     }
@@ -832,7 +1697,7 @@ mod tests {
         assert_eq!(params.pagination.page_size, 10);
         assert_eq!(params.search.search, Some("test".to_string()));
 
-        let status_filter = params.filters.get("status").unwrap();
+        let status_filter = &params.filters["status"][0];
         assert_eq!(status_filter.operator, QueryFilterOperator::Equal);
         assert_eq!(status_filter.value, Some("active".to_string()));
     }
@@ -844,11 +1709,11 @@ mod tests {
             .with_filter_operator("status", QueryFilterOperator::NotEqual, "deleted")
             .build();
 
-        let title_filter = params.filters.get("title").unwrap();
+        let title_filter = &params.filters["title"][0];
         assert_eq!(title_filter.operator, QueryFilterOperator::Like);
         assert_eq!(title_filter.value, Some("%test%".to_string()));
 
-        let status_filter = params.filters.get("status").unwrap();
+        let status_filter = &params.filters["status"][0];
         assert_eq!(status_filter.operator, QueryFilterOperator::NotEqual);
         assert_eq!(status_filter.value, Some("deleted".to_string()));
     }
@@ -859,7 +1724,7 @@ mod tests {
             .with_filter_null("description", true)
             .build();
 
-        let filter = params.filters.get("description").unwrap();
+        let filter = &params.filters["description"][0];
         assert_eq!(filter.operator, QueryFilterOperator::IsNull);
         assert_eq!(filter.value, None);
     }
@@ -870,11 +1735,106 @@ mod tests {
             .with_filter_in("status", vec!["active", "pending", "approved"])
             .build();
 
-        let filter = params.filters.get("status").unwrap();
+        let filter = &params.filters["status"][0];
         assert_eq!(filter.operator, QueryFilterOperator::In);
         assert_eq!(filter.value, Some("active,pending,approved".to_string()));
 
         let values = filter.split_values();
         assert_eq!(values, vec!["active", "pending", "approved"]);
     }
+
+    #[test]
+    fn test_filter_condition() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .with_filter_condition("description", QueryFilterCondition::is_null())
+            .with_filter_condition("nonexistent", QueryFilterCondition::is_null())
+            .build();
+
+        let filter = &params.filters["description"][0];
+        assert_eq!(filter.operator, QueryFilterOperator::IsNull);
+        assert_eq!(filter.value, None);
+        assert!(!params.filters.contains_key("nonexistent"));
+        assert_eq!(params.filters.len(), 1);
+    }
+
+    #[test]
+    fn test_when_applies_closure_only_when_condition_holds() {
+        let params = QueryParamsBuilder::<TestModel>::new()
+            .when(true, |builder| {
+                builder.with_filter("status", Some("active"))
+            })
+            .when(false, |builder| {
+                builder.with_filter("category", Some("books"))
+            })
+            .build();
+
+        assert!(params.filters.contains_key("status"));
+        assert!(!params.filters.contains_key("category"));
+    }
+
+    #[test]
+    fn test_max_page_size_replaces_page_size_clamp() {
+        let raised = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(1, 500)
+            .with_max_page_size(200)
+            .build();
+        assert_eq!(raised.pagination.page_size, 200);
+
+        let lowered = QueryParamsBuilder::<TestModel>::new()
+            .with_max_page_size(25)
+            .with_pagination(1, 40)
+            .build();
+        assert_eq!(lowered.pagination.page_size, 25);
+
+        let below_min = QueryParamsBuilder::<TestModel>::new()
+            .with_max_page_size(5)
+            .with_pagination(1, 40)
+            .build();
+        assert_eq!(below_min.pagination.page_size, 5);
+
+        let not_positive = QueryParamsBuilder::<TestModel>::new()
+            .with_max_page_size(0)
+            .with_pagination(1, 40)
+            .build();
+        assert_eq!(not_positive.pagination.page_size, DEFAULT_MIN_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_page_size_clamp_matches_query_string_path() {
+        use crate::FlatQueryParams;
+
+        for (page, page_size) in [(0, 9), (1, 10), (2, 11), (3, 49), (4, 50), (5, 51), (-1, 0)] {
+            let built = QueryParamsBuilder::<TestModel>::new()
+                .with_pagination(page, page_size)
+                .build();
+            let parsed: QueryParams<TestModel> = serde_json::from_value::<FlatQueryParams>(
+                serde_json::json!({ "page": page.to_string(), "page_size": page_size.to_string() }),
+            )
+            .unwrap()
+            .into();
+
+            assert_eq!(built.pagination.page, parsed.pagination.page);
+            assert_eq!(built.pagination.page_size, parsed.pagination.page_size);
+        }
+
+        let at_min = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(1, DEFAULT_MIN_PAGE_SIZE)
+            .build();
+        assert_eq!(at_min.pagination.page_size, DEFAULT_MIN_PAGE_SIZE);
+
+        let at_max = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(1, DEFAULT_MAX_PAGE_SIZE)
+            .build();
+        assert_eq!(at_max.pagination.page_size, DEFAULT_MAX_PAGE_SIZE);
+
+        let above_max = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(1, DEFAULT_MAX_PAGE_SIZE + 1)
+            .build();
+        assert_eq!(above_max.pagination.page_size, DEFAULT_MAX_PAGE_SIZE);
+
+        let below_min = QueryParamsBuilder::<TestModel>::new()
+            .with_pagination(1, DEFAULT_MIN_PAGE_SIZE - 1)
+            .build();
+        assert_eq!(below_min.pagination.page_size, DEFAULT_MIN_PAGE_SIZE);
+    }
 }
@@ -0,0 +1,165 @@
+use crate::paginated_query_as::internal::quote_identifier;
+use crate::{paginated_query_as, DatabaseQueryDefaults, PaginatedQueryBuilder};
+use serde::Serialize;
+use sqlx::{AssertSqlSafe, Database, FromRow, IntoArguments};
+
+/// Combines several queries into a single paginated feed, e.g. `articles` and
+/// `videos` ordered by a shared `created_at` column.
+///
+/// Every query must project the same columns. Each is tagged with its kind in an
+/// extra discriminator column, and the tagged queries are combined with
+/// `UNION ALL` as the base query, so that sorting, filters, search and counting
+/// apply to the combined rows. The model decodes the common columns plus the
+/// discriminator, and can be filtered on it like any other column.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{DateTime, Utc};
+/// use serde::Serialize;
+/// use sqlx::{FromRow, Postgres};
+/// use sqlx_paginated::{QueryParamsBuilder, QuerySortDirection, UnionPaginatedQuery};
+///
+/// #[derive(Serialize, FromRow, Default)]
+/// struct FeedItem {
+///     id: i32,
+///     title: String,
+///     created_at: DateTime<Utc>,
+///     kind: String,
+/// }
+///
+/// let union = UnionPaginatedQuery::new("kind")
+///     .with_query("article", "SELECT id, title, created_at FROM articles")
+///     .with_query("video", "SELECT id, name AS title, created_at FROM videos");
+///
+/// assert_eq!(
+///     union.sql(),
+///     "SELECT \"part\".*, 'article' AS \"kind\" FROM (SELECT id, title, created_at FROM articles) AS \"part\" \
+///      UNION ALL \
+///      SELECT \"part\".*, 'video' AS \"kind\" FROM (SELECT id, name AS title, created_at FROM videos) AS \"part\""
+/// );
+///
+/// let builder = union
+///     .into_paginated_query_as::<FeedItem, Postgres>()
+///     .with_params(
+///         QueryParamsBuilder::<FeedItem>::new()
+///             .with_sort("created_at", QuerySortDirection::Descending)
+///             .build(),
+///     );
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionPaginatedQuery {
+    discriminator: String,
+    queries: Vec<(String, String)>,
+}
+
+impl UnionPaginatedQuery {
+    /// Creates an empty union, tagging rows with their kind in the given column.
+    pub fn new(discriminator: impl Into<String>) -> Self {
+        Self {
+            discriminator: discriminator.into(),
+            queries: Vec::new(),
+        }
+    }
+
+    /// Adds a query whose rows are tagged with the given kind.
+    ///
+    /// A trailing statement terminator is removed. Empty queries, and queries
+    /// containing other statements, are skipped with a warning when tracing is
+    /// enabled.
+    pub fn with_query(mut self, kind: impl Into<String>, sql: impl Into<String>) -> Self {
+        let kind = kind.into();
+        let sql = sql.into();
+        let sql = sql.trim().trim_end_matches(';').trim_end();
+
+        if sql.is_empty() || sql.contains(';') {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(kind = %kind, "Skipping invalid union query");
+            return self;
+        }
+
+        self.queries.push((kind, sql.to_string()));
+        self
+    }
+
+    /// Returns the combined SQL used as the base query, with identifiers quoted
+    /// in double quotes.
+    pub fn sql(&self) -> String {
+        self.build_sql(quote_identifier)
+    }
+
+    /// Builds the combined SQL, quoting identifiers with the given function.
+    fn build_sql(&self, quote: impl Fn(&str) -> String) -> String {
+        let discriminator = quote(&self.discriminator);
+        let part = quote("part");
+
+        self.queries
+            .iter()
+            .map(|(kind, sql)| {
+                format!(
+                    "SELECT {}.*, '{}' AS {} FROM ({}) AS {}",
+                    part,
+                    kind.replace('\'', "''"),
+                    discriminator,
+                    sql,
+                    part
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" UNION ALL ")
+    }
+
+    /// Creates a `PaginatedQueryBuilder` over the combined rows, with the
+    /// database-specific defaults of `paginated_query_as` and identifiers quoted
+    /// for the database.
+    pub fn into_paginated_query_as<'q, T, DB>(
+        self,
+    ) -> PaginatedQueryBuilder<'q, T, DB, DB::Arguments>
+    where
+        DB: Database + DatabaseQueryDefaults,
+        T: for<'r> FromRow<'r, DB::Row> + Send + Unpin + Serialize + Default,
+        DB::Arguments: IntoArguments<DB>,
+        for<'c> &'c sqlx::Pool<DB>: sqlx::Executor<'c, Database = DB>,
+        usize: sqlx::ColumnIndex<DB::Row>,
+        i64: sqlx::Type<DB>
+            + for<'r> sqlx::Decode<'r, DB>
+            + for<'e> sqlx::Encode<'e, DB>
+            + Send
+            + Unpin,
+    {
+        let sql = self.build_sql(|identifier| DB::dialect().quote_identifier(identifier));
+        paginated_query_as::<T, DB>(AssertSqlSafe(sql))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_each_query_with_its_kind() {
+        let union = UnionPaginatedQuery::new("kind")
+            .with_query("article", "SELECT id FROM articles;")
+            .with_query("o'clock", "SELECT id FROM clocks");
+
+        assert_eq!(
+            union.sql(),
+            "SELECT \"part\".*, 'article' AS \"kind\" FROM (SELECT id FROM articles) AS \"part\" \
+             UNION ALL \
+             SELECT \"part\".*, 'o''clock' AS \"kind\" FROM (SELECT id FROM clocks) AS \"part\""
+        );
+    }
+
+    #[test]
+    fn test_skips_empty_and_multi_statement_queries() {
+        let union = UnionPaginatedQuery::new("kind")
+            .with_query("empty", " ; ")
+            .with_query("stacked", "SELECT id FROM a; DROP TABLE a")
+            .with_query("video", "SELECT id FROM videos");
+
+        assert_eq!(
+            union.sql(),
+            "SELECT \"part\".*, 'video' AS \"kind\" FROM (SELECT id FROM videos) AS \"part\""
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::QueryParams;
+use crate::paginated_query_as::internal::QueryDialect;
+use crate::{ComputedProperty, QueryBuilder, QueryParams};
 use serde::Serialize;
 use sqlx::Database;
 
@@ -19,10 +20,44 @@ pub trait DatabaseQueryDefaults: Database {
     fn build_default_query<T>(params: &QueryParams<T>) -> (Vec<String>, Self::Arguments)
     where
         T: Default + Serialize;
+    /// Builds the default query builder of `paginated_query_as` before its conditions
+    /// are taken, with the searchable and aggregate computed properties registered,
+    /// so that search matches the former and filters on the latter go to `HAVING`.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters containing search, filter, and date range settings
+    /// * `properties` - The computed properties of the paginated query
+    fn default_query_builder<'p, T>(
+        params: &'p QueryParams<T>,
+        properties: &[ComputedProperty],
+    ) -> QueryBuilder<'p, T, Self>
+    where
+        T: Default + Serialize;
+    /// Returns the dialect of the SQL generated for this database type.
+    fn dialect() -> &'static (dyn QueryDialect + Sync);
 }
 
 #[cfg(feature = "postgres")]
 impl DatabaseQueryDefaults for sqlx::Postgres {
+    fn default_query_builder<'p, T>(
+        params: &'p QueryParams<T>,
+        properties: &[ComputedProperty],
+    ) -> QueryBuilder<'p, T, Self>
+    where
+        T: Default + Serialize,
+    {
+        QueryBuilder::<T, sqlx::Postgres>::new()
+            .with_computed_properties(properties)
+            .with_search(params)
+            .with_filters(params)
+            .with_date_range(params)
+    }
+
+    fn dialect() -> &'static (dyn QueryDialect + Sync) {
+        &crate::paginated_query_as::internal::PostgresDialect
+    }
+
     fn build_default_query<T>(params: &QueryParams<T>) -> (Vec<String>, Self::Arguments)
     where
         T: Default + Serialize,
@@ -34,6 +69,24 @@ impl DatabaseQueryDefaults for sqlx::Postgres {
 
 #[cfg(feature = "sqlite")]
 impl DatabaseQueryDefaults for sqlx::Sqlite {
+    fn default_query_builder<'p, T>(
+        params: &'p QueryParams<T>,
+        properties: &[ComputedProperty],
+    ) -> QueryBuilder<'p, T, Self>
+    where
+        T: Default + Serialize,
+    {
+        QueryBuilder::<T, sqlx::Sqlite>::new()
+            .with_computed_properties(properties)
+            .with_search(params)
+            .with_filters(params)
+            .with_date_range(params)
+    }
+
+    fn dialect() -> &'static (dyn QueryDialect + Sync) {
+        &crate::paginated_query_as::internal::SqliteDialect
+    }
+
     fn build_default_query<T>(params: &QueryParams<T>) -> (Vec<String>, Self::Arguments)
     where
         T: Default + Serialize,
@@ -46,3 +99,36 @@ impl DatabaseQueryDefaults for sqlx::Sqlite {
             .build()
     }
 }
+
+#[cfg(feature = "mysql")]
+impl DatabaseQueryDefaults for sqlx::MySql {
+    fn default_query_builder<'p, T>(
+        params: &'p QueryParams<T>,
+        properties: &[ComputedProperty],
+    ) -> QueryBuilder<'p, T, Self>
+    where
+        T: Default + Serialize,
+    {
+        QueryBuilder::<T, sqlx::MySql>::new()
+            .with_computed_properties(properties)
+            .with_search(params)
+            .with_filters(params)
+            .with_date_range(params)
+    }
+
+    fn dialect() -> &'static (dyn QueryDialect + Sync) {
+        &crate::paginated_query_as::internal::MySqlDialect
+    }
+
+    fn build_default_query<T>(params: &QueryParams<T>) -> (Vec<String>, Self::Arguments)
+    where
+        T: Default + Serialize,
+    {
+        use crate::QueryBuilder;
+        QueryBuilder::<T, sqlx::MySql>::new()
+            .with_search(params)
+            .with_filters(params)
+            .with_date_range(params)
+            .build()
+    }
+}
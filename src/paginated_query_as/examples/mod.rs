@@ -2,4 +2,5 @@ mod paginated_query_builder_advanced_examples;
 mod query_builder_examples;
 mod query_filters_examples;
 
+#[cfg(feature = "postgres")]
 pub use query_builder_examples::*;
@@ -1,4 +1,6 @@
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
 use crate::{QueryBuilder, QueryParams};
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
 use serde::Serialize;
 
 #[cfg(feature = "postgres")]
@@ -301,6 +301,11 @@ pub mod postgres_examples {
     /// | `field[is_not_null]=` | `field IS NOT NULL` | `email[is_not_null]=` |
     /// | `field[like]=pattern` | `field LIKE 'pattern'` | `name[like]=%phone%` |
     /// | `field[not_like]=pattern` | `field NOT LIKE 'pattern'` | `email[not_like]=%spam%` |
+    /// | `field[regex]=pattern` | `field ~ 'pattern'` | `sku[regex]=^[A-Z]{3}-` |
+    /// | `field[iregex]=pattern` | `field ~* 'pattern'` | `name[iregex]=^jo` |
+    /// | `field[contains]=value` | `field @> 'value'` | `tags[contains]={sale,new}` |
+    /// | `field[contained_by]=value` | `field <@ 'value'` | `tags[contained_by]={sale,new}` |
+    /// | `field[overlaps]=value` | `field && 'value'` | `tags[overlaps]={sale,new}` |
     pub fn example_automatic_operator_parsing() {
         // No code needed here - this demonstrates URL query string usage
         // The actual parsing happens automatically when using FlatQueryParams
@@ -0,0 +1,772 @@
+use crate::paginated_query_as::internal::{
+    parse_boolean_value, DEFAULT_SORT_COLUMN_FALLBACK_NAMES, DEFAULT_SORT_COLUMN_NAME,
+};
+use crate::{
+    FilterLogic, PaginatedResponse, QueryFilterCondition, QueryFilterOperator, QueryNullsPosition,
+    QueryParams, QuerySortDirection, SearchAnchor,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+
+/// Filters, searches, sorts and paginates records already in memory.
+///
+/// Applies the same parameters as `PaginatedQueryBuilder` without a database, which
+/// is handy for tests and small lookup tables. Records are compared through their
+/// `serde_json` representation, mirroring the SQL semantics as closely as feasible:
+///
+/// - Columns missing from the serialized records are ignored, like invalid columns
+/// - `null` values never match comparisons, `IN` or `LIKE`, as with SQL `NULL`,
+///   and only match the negated `IS NOT TRUE`/`IS NOT FALSE` checks
+/// - Numbers compare numerically, booleans accept the same values as boolean
+///   filters (`true`/`false`, `1`/`0`, `on`/`off`), and text compares byte-wise
+///   rather than with a database collation
+/// - `LIKE` and search patterns match case-insensitively, for any text
+/// - Regular expression filters are skipped, as on dialects without regular
+///   expressions
+/// - Date ranges apply to RFC3339 text values; other values are excluded
+/// - Time function filter values such as `NOW()` compare as plain text
+/// - Sorting is stable and puts `null` values last ascending and first descending,
+///   as on Postgres; case insensitive sorts compare lowercased text, and computed
+///   properties are not supported
+///
+/// # Arguments
+///
+/// * `items` - Records to paginate
+/// * `params` - Query parameters to apply
+///
+/// # Returns
+///
+/// Returns the records of the requested page, with pagination and totals
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use sqlx_paginated::{paginate_in_memory, QueryParamsBuilder, QuerySortDirection};
+///
+/// #[derive(Serialize, Default)]
+/// struct Country {
+///     code: String,
+///     name: String,
+/// }
+///
+/// let countries = vec![
+///     Country { code: "NL".to_string(), name: "Netherlands".to_string() },
+///     Country { code: "NO".to_string(), name: "Norway".to_string() },
+///     Country { code: "RO".to_string(), name: "Romania".to_string() },
+/// ];
+///
+/// let params = QueryParamsBuilder::<Country>::new()
+///     .with_search("n", vec!["code"])
+///     .with_sort("name", QuerySortDirection::Descending)
+///     .build();
+///
+/// let response = paginate_in_memory(countries, &params);
+/// assert_eq!(response.total, Some(2));
+/// assert_eq!(response.records[0].name, "Norway");
+/// ```
+pub fn paginate_in_memory<T: Serialize>(
+    items: Vec<T>,
+    params: &QueryParams<T>,
+) -> PaginatedResponse<T> {
+    let mut rows: Vec<(T, Map<String, Value>)> = items
+        .into_iter()
+        .map(|item| {
+            let fields = match serde_json::to_value(&item) {
+                Ok(Value::Object(fields)) => fields,
+                _ => Map::new(),
+            };
+            (item, fields)
+        })
+        .filter(|(_, fields)| matches_params(fields, params))
+        .collect();
+
+    let sorts: Vec<(String, QuerySortDirection, QueryNullsPosition)> = params
+        .sort
+        .sorts()
+        .into_iter()
+        .filter_map(|sort| {
+            let column = rows.first().and_then(|(_, fields)| {
+                resolve_sort_column(fields, &sort.column, params.sort.is_explicit())
+            })?;
+            Some((column, sort.direction, sort.nulls))
+        })
+        .collect();
+
+    let case_insensitive = params.sort.is_case_insensitive();
+    rows.sort_by(|(_, a), (_, b)| {
+        sorts
+            .iter()
+            .map(|(column, direction, nulls)| {
+                let a = a.get(column).unwrap_or(&Value::Null);
+                let b = b.get(column).unwrap_or(&Value::Null);
+                let nulls_ordering = a.is_null().cmp(&b.is_null());
+                match nulls {
+                    QueryNullsPosition::First if nulls_ordering.is_ne() => {
+                        return nulls_ordering.reverse()
+                    }
+                    QueryNullsPosition::Last if nulls_ordering.is_ne() => return nulls_ordering,
+                    _ => {}
+                }
+
+                let ordering = compare_values(a, b, case_insensitive);
+                match direction {
+                    QuerySortDirection::Ascending => ordering,
+                    QuerySortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let pagination = params.pagination.clone();
+    let total = rows.len() as i64;
+    let total_pages = match total {
+        0 => 0,
+        _ => (total + pagination.page_size - 1) / pagination.page_size,
+    };
+    let offset = pagination.offset().max(0) as usize;
+
+    let records = rows
+        .into_iter()
+        .skip(offset)
+        .take(pagination.page_size.max(0) as usize)
+        .map(|(item, _)| item)
+        .collect();
+
+    let has_next = pagination.offset() + pagination.page_size < total;
+    let has_previous = pagination.offset() > 0;
+
+    PaginatedResponse {
+        records,
+        pagination: Some(pagination),
+        total: Some(total),
+        total_pages: Some(total_pages),
+        total_is_estimate: false,
+        total_active: None,
+        total_capped_at: None,
+        page_bounds: None,
+        next_page_token: None,
+        next_cursor: None,
+        previous_cursor: None,
+        has_next: Some(has_next),
+        has_previous: Some(has_previous),
+    }
+}
+
+/// Whether a serialized record matches the search, filters and date range.
+fn matches_params<T>(fields: &Map<String, Value>, params: &QueryParams<T>) -> bool {
+    matches_search(fields, params)
+        && params.filters.iter().all(|(column, conditions)| {
+            let is_checkbox = params.checkbox_filters.contains(column);
+            fields.get(column).is_none_or(|value| {
+                conditions
+                    .iter()
+                    .all(|condition| matches_filter(value, condition, is_checkbox))
+            })
+        })
+        && params.filter_groups.iter().all(|group| {
+            // Conditions on missing columns are left out of the group, as in SQL
+            let mut matches = group
+                .conditions
+                .iter()
+                .filter_map(|(column, condition)| {
+                    let is_checkbox = params.checkbox_filters.contains(column);
+                    let value = fields.get(column)?;
+                    Some(matches_filter(value, condition, is_checkbox))
+                })
+                .peekable();
+            match group.logic {
+                _ if matches.peek().is_none() => true,
+                FilterLogic::And => matches.all(|matches| matches),
+                FilterLogic::Or => matches.any(|matches| matches),
+            }
+        })
+        && matches_date_range(fields, params)
+}
+
+fn matches_search<T>(fields: &Map<String, Value>, params: &QueryParams<T>) -> bool {
+    let patterns = params.search.patterns();
+    let columns: Vec<&Value> = params
+        .search
+        .columns()
+        .into_iter()
+        .filter_map(|column| fields.get(column))
+        .collect();
+
+    if patterns.is_empty() || columns.is_empty() {
+        return true;
+    }
+
+    let matched_tokens = patterns
+        .iter()
+        .filter(|pattern| {
+            columns.iter().any(|value| match value {
+                Value::String(text) if params.search.search_anchor == SearchAnchor::Exact => {
+                    text.to_lowercase() == pattern.to_lowercase()
+                }
+                Value::String(text) => like(text, pattern),
+                _ => false,
+            })
+        })
+        .count();
+
+    matched_tokens
+        >= params
+            .search
+            .min_should_match
+            .unwrap_or(1)
+            .clamp(1, patterns.len())
+}
+
+fn matches_filter(value: &Value, condition: &QueryFilterCondition, is_checkbox: bool) -> bool {
+    let filter_value = condition.value.as_deref();
+
+    match condition.operator {
+        QueryFilterOperator::IsNull => value.is_null(),
+        QueryFilterOperator::IsNotNull => !value.is_null(),
+        QueryFilterOperator::IsTrue
+        | QueryFilterOperator::IsFalse
+        | QueryFilterOperator::IsNotTrue
+        | QueryFilterOperator::IsNotFalse => {
+            let Some((expected, negated)) = condition.operator.boolean_test() else {
+                return true;
+            };
+            let truth = match value {
+                Value::Bool(flag) => Some(*flag),
+                Value::Number(number) => number.as_f64().map(|number| number != 0.0),
+                _ => None,
+            };
+            (truth == Some(expected)) != negated
+        }
+        QueryFilterOperator::In | QueryFilterOperator::NotIn => {
+            let values = condition.split_values();
+            if values.is_empty() || value.is_null() {
+                return values.is_empty();
+            }
+            let found = values
+                .iter()
+                .any(|candidate| compare_filter(value, candidate) == Some(Ordering::Equal));
+            found == (condition.operator == QueryFilterOperator::In)
+        }
+        QueryFilterOperator::Between => {
+            let Some((low, high)) = condition.bounds() else {
+                return true;
+            };
+            compare_filter(value, &low).is_some_and(Ordering::is_ge)
+                && compare_filter(value, &high).is_some_and(Ordering::is_le)
+        }
+        QueryFilterOperator::NotBetween => {
+            let Some((low, high)) = condition.bounds() else {
+                return true;
+            };
+            compare_filter(value, &low).is_some_and(Ordering::is_lt)
+                || compare_filter(value, &high).is_some_and(Ordering::is_gt)
+        }
+        // Without a regular expression engine, regular expression filters are skipped
+        QueryFilterOperator::Regex | QueryFilterOperator::IRegex => true,
+        QueryFilterOperator::Like | QueryFilterOperator::NotLike => match (value, filter_value) {
+            (Value::String(text), Some(pattern)) => {
+                like(text, pattern) == (condition.operator != QueryFilterOperator::NotLike)
+            }
+            (_, None) => true,
+            _ => false,
+        },
+        QueryFilterOperator::Contains
+        | QueryFilterOperator::ContainedBy
+        | QueryFilterOperator::Overlaps => {
+            let Some(filter_value) = filter_value.map(parse_containment_value) else {
+                return true;
+            };
+            match condition.operator {
+                QueryFilterOperator::Contains => json_contains(value, &filter_value),
+                QueryFilterOperator::ContainedBy => json_contains(&filter_value, value),
+                _ => match (value, &filter_value) {
+                    (Value::Array(elements), Value::Array(others)) => elements
+                        .iter()
+                        .any(|element| others.iter().any(|other| json_contains(element, other))),
+                    _ => false,
+                },
+            }
+        }
+        ref operator => {
+            let Some(filter_value) = filter_value else {
+                return true;
+            };
+            // Checkbox filters with unrecognized values add no condition
+            if is_checkbox && parse_boolean_value(filter_value).is_none() {
+                return true;
+            }
+
+            compare_filter(value, filter_value).is_some_and(|ordering| match operator {
+                QueryFilterOperator::Equal => ordering.is_eq(),
+                QueryFilterOperator::NotEqual => ordering.is_ne(),
+                QueryFilterOperator::GreaterThan => ordering.is_gt(),
+                QueryFilterOperator::GreaterOrEqual => ordering.is_ge(),
+                QueryFilterOperator::LessThan => ordering.is_lt(),
+                _ => ordering.is_le(),
+            })
+        }
+    }
+}
+
+fn matches_date_range<T>(fields: &Map<String, Value>, params: &QueryParams<T>) -> bool {
+    let range = &params.date_range;
+    let Some(value) = range
+        .date_column
+        .as_ref()
+        .and_then(|column| fields.get(column))
+    else {
+        return true;
+    };
+    if range.date_after.is_none() && range.date_before.is_none() {
+        return true;
+    }
+
+    let Some(date) = value
+        .as_str()
+        .and_then(|text| DateTime::parse_from_rfc3339(text).ok())
+        .map(|date| date.with_timezone(&Utc))
+    else {
+        return false;
+    };
+
+    range.date_after.is_none_or(|after| date >= after)
+        && range.date_before.is_none_or(|before| date <= before)
+}
+
+/// Compares a record value with a textual filter value, or `None` when they
+/// can't be compared.
+fn compare_filter(value: &Value, filter_value: &str) -> Option<Ordering> {
+    match value {
+        Value::Number(number) => number
+            .as_f64()?
+            .partial_cmp(&filter_value.trim().parse::<f64>().ok()?),
+        Value::Bool(flag) => Some(flag.cmp(&parse_boolean_value(filter_value)?)),
+        Value::String(text) => Some(text.as_str().cmp(filter_value)),
+        _ => None,
+    }
+}
+
+/// Parses the value of a containment filter, a JSON document or an array
+/// literal such as `{sale,new}`, whose elements are strings.
+fn parse_containment_value(filter_value: &str) -> Value {
+    if let Ok(value) = serde_json::from_str::<Value>(filter_value) {
+        return value;
+    }
+
+    let trimmed = filter_value.trim();
+    let elements = trimmed
+        .strip_prefix('{')
+        .and_then(|value| value.strip_suffix('}'))
+        .unwrap_or(trimmed);
+    Value::Array(
+        elements
+            .split(',')
+            .map(|element| element.trim().trim_matches('"'))
+            .filter(|element| !element.is_empty())
+            .map(|element| Value::String(element.to_string()))
+            .collect(),
+    )
+}
+
+/// Whether `value` contains `other` as with the `@>` operator: objects hold every
+/// key of `other` with a contained value, arrays hold every element of `other`,
+/// and scalars are equal, strings comparing with other scalars by their text.
+fn json_contains(value: &Value, other: &Value) -> bool {
+    match (value, other) {
+        (Value::Object(fields), Value::Object(others)) => others.iter().all(|(key, other)| {
+            fields
+                .get(key)
+                .is_some_and(|field| json_contains(field, other))
+        }),
+        (Value::Array(elements), Value::Array(others)) => others
+            .iter()
+            .all(|other| elements.iter().any(|element| json_contains(element, other))),
+        (Value::Array(elements), other) if !other.is_object() => {
+            elements.iter().any(|element| json_contains(element, other))
+        }
+        (value, Value::String(text)) if !value.is_array() && !value.is_object() => {
+            compare_filter(value, text) == Some(Ordering::Equal)
+        }
+        (Value::String(text), other) if !other.is_array() && !other.is_object() => {
+            compare_filter(other, text) == Some(Ordering::Equal)
+        }
+        (value, other) => value == other,
+    }
+}
+
+/// Orders two record values, with `null` sorting after every other value.
+fn compare_values(a: &Value, b: &Value, case_insensitive: bool) -> Ordering {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Greater,
+        (_, Value::Null) => Ordering::Less,
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) if case_insensitive => {
+            a.to_lowercase().cmp(&b.to_lowercase())
+        }
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Resolves a sort column against the record fields, applying the same default
+/// sort column fallbacks as the SQL path unless the sort is `explicit`. Unknown
+/// columns are skipped.
+fn resolve_sort_column(
+    fields: &Map<String, Value>,
+    column: &str,
+    explicit: bool,
+) -> Option<String> {
+    if fields.contains_key(column) {
+        return Some(column.to_string());
+    }
+    if explicit || column != DEFAULT_SORT_COLUMN_NAME {
+        return None;
+    }
+
+    DEFAULT_SORT_COLUMN_FALLBACK_NAMES
+        .iter()
+        .find(|name| fields.contains_key(**name))
+        .map(|name| name.to_string())
+}
+
+/// Case-insensitive SQL `LIKE`: `%` matches any characters and `_` a single one.
+fn like(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+
+    // matches[j]: whether the text consumed so far matches pattern[..j]
+    let mut matches = vec![false; pattern.len() + 1];
+    matches[0] = true;
+    for (j, p) in pattern.iter().enumerate() {
+        matches[j + 1] = matches[j] && *p == '%';
+    }
+
+    for c in &text {
+        let mut next = vec![false; pattern.len() + 1];
+        for (j, p) in pattern.iter().enumerate() {
+            next[j + 1] = match p {
+                '%' => next[j] || matches[j + 1],
+                '_' => matches[j],
+                _ => matches[j] && p == c,
+            };
+        }
+        matches = next;
+    }
+
+    matches[pattern.len()]
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::QueryParamsBuilder;
+
+    #[derive(Serialize, Default, Debug, Clone)]
+    struct Product {
+        id: i32,
+        name: String,
+        price: f64,
+        active: bool,
+        discontinued_at: Option<String>,
+        created_at: String,
+    }
+
+    fn products() -> Vec<Product> {
+        [
+            ("Laptop Pro", 1299.99, true, None, "2024-01-10T00:00:00Z"),
+            ("Wireless Mouse", 29.99, true, None, "2024-02-10T00:00:00Z"),
+            (
+                "Mechanical Keyboard",
+                149.0,
+                false,
+                Some("2024-06-01"),
+                "2024-03-10T00:00:00Z",
+            ),
+            ("USB-C Hub", 49.5, true, None, "2024-04-10T00:00:00Z"),
+            (
+                "Laptop Stand",
+                59.0,
+                false,
+                Some("2024-07-01"),
+                "2024-05-10T00:00:00Z",
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(
+            |(idx, (name, price, active, discontinued_at, created_at))| Product {
+                id: idx as i32 + 1,
+                name: name.to_string(),
+                price,
+                active,
+                discontinued_at: discontinued_at.map(str::to_string),
+                created_at: created_at.to_string(),
+            },
+        )
+        .collect()
+    }
+
+    fn names(response: &PaginatedResponse<Product>) -> Vec<&str> {
+        response
+            .records
+            .iter()
+            .map(|product| product.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_filters() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_operator("price", QueryFilterOperator::GreaterThan, "40")
+            .with_filter("active", Some("on"))
+            .with_sort("price", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["USB-C Hub", "Laptop Pro"]
+        );
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_null("discontinued_at", false)
+            .with_filter_operator("name", QueryFilterOperator::Like, "laptop%")
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["Laptop Stand"]
+        );
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_not_in("id", vec!["1", "2", "3"])
+            .with_filter("unknown", Some("ignored"))
+            .with_sort("id", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["USB-C Hub", "Laptop Stand"]
+        );
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_operator("name", QueryFilterOperator::Regex, "^Laptop")
+            .with_filter("active", Some("on"))
+            .build();
+        assert_eq!(paginate_in_memory(products(), &params).total, Some(3));
+    }
+
+    #[test]
+    fn test_between() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_between("price", "49.5", "149")
+            .with_sort("price", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["USB-C Hub", "Laptop Stand", "Mechanical Keyboard"]
+        );
+    }
+
+    #[test]
+    fn test_not_between() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_not_between("price", "49.5", "149")
+            .with_sort("price", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["Wireless Mouse", "Laptop Pro"]
+        );
+    }
+
+    #[test]
+    fn test_filter_groups() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_filter_group(
+                FilterLogic::Or,
+                vec![
+                    ("price", QueryFilterCondition::less_than("40")),
+                    ("price", QueryFilterCondition::greater_than("1000")),
+                ],
+            )
+            .with_filter("active", Some("true"))
+            .with_sort("price", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["Wireless Mouse", "Laptop Pro"]
+        );
+    }
+
+    #[test]
+    fn test_search_is_case_insensitive() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_search("LAPTOP", vec!["name"])
+            .with_sort("name", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["Laptop Pro", "Laptop Stand"]
+        );
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_search("mouse", vec!["name"])
+            .with_search_anchor(SearchAnchor::Prefix)
+            .build();
+        assert_eq!(paginate_in_memory(products(), &params).total, Some(0));
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_search("laptop_pro", vec!["name"])
+            .with_search_anchor(SearchAnchor::Exact)
+            .build();
+        assert_eq!(paginate_in_memory(products(), &params).total, Some(0));
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_search("LAPTOP PRO", vec!["name"])
+            .with_search_anchor(SearchAnchor::Exact)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["Laptop Pro"]
+        );
+    }
+
+    #[test]
+    fn test_search_min_should_match() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_search("laptop mouse stand", vec!["name"])
+            .with_min_should_match(2)
+            .with_sort("name", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec!["Laptop Stand"]
+        );
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_search("laptop mouse stand", vec!["name"])
+            .with_min_should_match(1)
+            .build();
+        assert_eq!(paginate_in_memory(products(), &params).total, Some(3));
+    }
+
+    #[test]
+    fn test_sort_puts_nulls_last_ascending() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_sort("discontinued_at", QuerySortDirection::Ascending)
+            .build();
+        let response = paginate_in_memory(products(), &params);
+
+        assert_eq!(
+            names(&response)[..2],
+            ["Mechanical Keyboard", "Laptop Stand"]
+        );
+        // Ties keep their original order
+        assert_eq!(
+            names(&response)[2..],
+            ["Laptop Pro", "Wireless Mouse", "USB-C Hub"]
+        );
+
+        let default_sort = QueryParamsBuilder::<Product>::new().build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &default_sort))[0],
+            "Laptop Stand"
+        );
+    }
+
+    #[test]
+    fn test_sort_nulls_position_ignores_direction() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_sort_nulls(
+                "discontinued_at",
+                QuerySortDirection::Ascending,
+                QueryNullsPosition::First,
+            )
+            .with_sort("id", QuerySortDirection::Ascending)
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params)),
+            vec![
+                "Laptop Pro",
+                "Wireless Mouse",
+                "USB-C Hub",
+                "Mechanical Keyboard",
+                "Laptop Stand"
+            ]
+        );
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_sort_nulls(
+                "discontinued_at",
+                QuerySortDirection::Descending,
+                QueryNullsPosition::Last,
+            )
+            .build();
+        assert_eq!(
+            names(&paginate_in_memory(products(), &params))[..2],
+            ["Laptop Stand", "Mechanical Keyboard"]
+        );
+    }
+
+    #[test]
+    fn test_pagination() {
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_pagination(2, 10)
+            .build();
+        let response = paginate_in_memory((0..25).map(|_| Product::default()).collect(), &params);
+
+        assert_eq!(response.records.len(), 10);
+        assert_eq!(response.total, Some(25));
+        assert_eq!(response.total_pages, Some(3));
+        assert_eq!(response.pagination.unwrap().page, 2);
+        assert_eq!(response.has_next, Some(true));
+        assert_eq!(response.has_previous, Some(true));
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_pagination(4, 10)
+            .build();
+        let response = paginate_in_memory(products(), &params);
+        assert!(response.records.is_empty());
+        assert_eq!(response.has_next, Some(false));
+    }
+
+    #[test]
+    fn test_json_contains() {
+        let tags = serde_json::json!(["sale", "new", 3]);
+        assert!(json_contains(&tags, &parse_containment_value("{sale,3}")));
+        assert!(!json_contains(
+            &tags,
+            &parse_containment_value("{sale,old}")
+        ));
+        assert!(json_contains(
+            &parse_containment_value(r#"["sale","new",3,"featured"]"#),
+            &tags
+        ));
+
+        let metadata = serde_json::json!({"color": "red", "sizes": ["s", "m"]});
+        assert!(json_contains(
+            &metadata,
+            &parse_containment_value(r#"{"sizes":["m"]}"#)
+        ));
+        assert!(!json_contains(
+            &metadata,
+            &parse_containment_value(r#"{"color":"blue"}"#)
+        ));
+    }
+
+    #[test]
+    fn test_like() {
+        assert!(like("Laptop Pro", "%PRO"));
+        assert!(like("Laptop", "l_ptop"));
+        assert!(!like("Laptop", "l_top"));
+        assert!(like("", "%"));
+        assert!(!like("abc", "ab"));
+    }
+}
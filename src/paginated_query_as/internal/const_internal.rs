@@ -6,5 +6,28 @@ pub static DEFAULT_PAGE: i64 = 1;
 pub static DEFAULT_MAX_FIELD_LENGTH: i64 = 100;
 pub static DEFAULT_SEARCH_COLUMN_NAMES: [&str; 2] = ["name", "description"];
 pub static DEFAULT_SEARCH_COLUMN_NAME_SEPARATOR_SYMBOL: &str = ",";
+pub static DEFAULT_ORDER_BY_SEPARATOR_SYMBOL: &str = ",";
+pub static DEFAULT_SORT_DIRECTION_SEPARATOR_SYMBOL: &str = ":";
 pub static DEFAULT_SORT_COLUMN_NAME: &str = "created_at";
+pub static DEFAULT_SORT_COLUMN_FALLBACK_NAMES: [&str; 3] = ["created_at", "updated_at", "id"];
 pub static DEFAULT_DATE_RANGE_COLUMN_NAME: &str = "created_at";
+pub static DEFAULT_TIME_FUNCTIONS: [&str; 3] = ["NOW()", "CURRENT_DATE", "CURRENT_TIMESTAMP"];
+pub static DEFAULT_RESERVED_PARAM_NAMES: [&str; 17] = [
+    "page",
+    "page_size",
+    "page_token",
+    "sort_column",
+    "sort_direction",
+    "order_by",
+    "sort",
+    "search",
+    "search_columns",
+    "search_mode",
+    "search_anchor",
+    "min_should_match",
+    "date_column",
+    "date_after",
+    "date_before",
+    "checkbox_filters",
+    "filter_groups",
+];
@@ -19,30 +19,46 @@ use std::fmt;
 /// - lte: Less or Equal
 /// - in: In List
 /// - nin, not_in: Not In List
+/// - between: Between two comma-separated bounds, inclusive
 /// - is_null, null: Is Null
 /// - is_not_null, not_null: Is Not Null
+/// - is_true, is_false: Is True, Is False
+/// - is_not_true, not_true: Is Not True
+/// - is_not_false, not_false: Is Not False
 /// - like: Like Pattern
 /// - not_like, nlike: Not Like Pattern
+/// - regex, iregex: Regular Expression, case-sensitive or not
+///
+/// Conditions are grouped by field, so repeated fields such as
+/// `price[gte]=10&price[lte]=100` keep every condition, in the order given.
+///
+/// Unrecognised operators keep their raw `field[op]` key (with an Equal condition)
+/// so that `FlatQueryParams::validate` can report them. Converting into
+/// `QueryParams` treats them as equality on `field`.
 ///
 /// # Examples
 ///
 /// ```text
 /// ?age=25                      → age = 25
 /// ?price[gt]=10                → price > 10
+/// ?price[gte]=10&price[lte]=100 → price >= 10 AND price <= 100
 /// ?status[ne]=deleted          → status != 'deleted'
 /// ?role[in]=admin,moderator    → role IN ('admin', 'moderator')
+/// ?price[between]=10,100       → price BETWEEN 10 AND 100
+/// ?price[not_between]=10,100   → price NOT BETWEEN 10 AND 100
 /// ?deleted_at[is_null]=        → deleted_at IS NULL
+/// ?deleted_at[is_null]=false   → deleted_at IS NOT NULL (with `QueryBuilder::with_boolean_null_flag`)
 /// ```
 pub fn deserialize_filter_map<'de, D>(
     deserializer: D,
-) -> Result<Option<HashMap<String, QueryFilterCondition>>, D::Error>
+) -> Result<Option<HashMap<String, Vec<QueryFilterCondition>>>, D::Error>
 where
     D: Deserializer<'de>,
 {
     struct FilterMapVisitor;
 
     impl<'de> Visitor<'de> for FilterMapVisitor {
-        type Value = Option<HashMap<String, QueryFilterCondition>>;
+        type Value = Option<HashMap<String, Vec<QueryFilterCondition>>>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
             formatter.write_str("a map of filter conditions")
@@ -52,33 +68,11 @@ where
         where
             M: MapAccess<'de>,
         {
-            let mut filter_map = HashMap::new();
+            let mut filter_map: HashMap<String, Vec<QueryFilterCondition>> = HashMap::new();
 
             while let Some((key, value)) = access.next_entry::<String, Option<String>>()? {
-                // Check if the key contains an operator specification: field[op]
-                if let Some(start_bracket) = key.find('[') {
-                    if let Some(end_bracket) = key.find(']') {
-                        if start_bracket < end_bracket {
-                            let field = &key[..start_bracket];
-                            let operator_str = &key[start_bracket + 1..end_bracket];
-                            let operator = QueryFilterOperator::from_str(operator_str);
-
-                            let condition = if operator.requires_value() {
-                                QueryFilterCondition::new(operator, value)
-                            } else {
-                                // For IS NULL/IS NOT NULL, value is ignored
-                                QueryFilterCondition::new(operator, None::<String>)
-                            };
-
-                            filter_map.insert(field.to_string(), condition);
-                            continue;
-                        }
-                    }
-                }
-
-                // Simple format without operator → defaults to Equal
-                if let Some(val) = value {
-                    filter_map.insert(key, QueryFilterCondition::equal(val));
+                if let Some((key, condition)) = parse_filter_entry(key, value) {
+                    filter_map.entry(key).or_default().push(condition);
                 }
             }
 
@@ -93,6 +87,43 @@ where
     deserializer.deserialize_map(FilterMapVisitor)
 }
 
+/// Parses one `field` or `field[op]` filter entry into the key its condition is
+/// grouped under and the condition, as `deserialize_filter_map` does.
+pub(crate) fn parse_filter_entry(
+    key: String,
+    value: Option<String>,
+) -> Option<(String, QueryFilterCondition)> {
+    // Check if the key contains an operator specification: field[op]
+    if let Some(start_bracket) = key.find('[') {
+        if let Some(end_bracket) = key.find(']') {
+            if start_bracket < end_bracket {
+                let field = &key[..start_bracket];
+                let operator_str = &key[start_bracket + 1..end_bracket];
+                let Some(operator) = QueryFilterOperator::parse(operator_str) else {
+                    return Some((
+                        key,
+                        QueryFilterCondition::new(QueryFilterOperator::Equal, value),
+                    ));
+                };
+
+                let condition = if operator.requires_value() {
+                    QueryFilterCondition::new(operator, value)
+                } else {
+                    // For IS NULL/IS NOT NULL, empty values are dropped; others are
+                    // kept for `QueryBuilder::with_boolean_null_flag`
+                    QueryFilterCondition::new(operator, value.filter(|value| !value.is_empty()))
+                };
+
+                return Some((field.to_string(), condition));
+            }
+        }
+    }
+
+    // Simple format without operator → defaults to Equal. Empty values are
+    // kept, matching '' unless `QueryBuilder::with_drop_empty_filters` is set
+    value.map(|value| (key, QueryFilterCondition::equal(value)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,7 +133,7 @@ mod tests {
     #[derive(Deserialize, Debug)]
     struct TestQuery {
         #[serde(flatten, deserialize_with = "deserialize_filter_map")]
-        filters: Option<HashMap<String, QueryFilterCondition>>,
+        filters: Option<HashMap<String, Vec<QueryFilterCondition>>>,
     }
 
     // Note: These tests require serde_urlencoded dependency
@@ -168,6 +199,12 @@ mod tests {
         let confirmed = filters.get("confirmed_at").unwrap();
         assert_eq!(confirmed.operator, QueryFilterOperator::IsNotNull);
         assert_eq!(confirmed.value, None);
+
+        let query = "deleted_at[is_null]=false";
+        let parsed: TestQuery = serde_urlencoded::from_str(query).unwrap();
+        let deleted = parsed.filters.as_ref().unwrap().get("deleted_at").unwrap();
+        assert_eq!(deleted.operator, QueryFilterOperator::IsNull);
+        assert_eq!(deleted.value, Some("false".to_string()));
     }
 
     #[test]
@@ -181,6 +218,25 @@ mod tests {
         assert_eq!(email.value, Some("%@example.com".to_string()));
     }
 
+    #[test]
+    fn test_containment_operators() {
+        let query = "tags[contains]={sale,new}&labels[contained_by]={a,b}&sizes[overlaps]={s,m}";
+        let parsed: TestQuery = serde_urlencoded::from_str(query).unwrap();
+
+        let filters = parsed.filters.as_ref().unwrap();
+        let tags = filters.get("tags").unwrap();
+        assert_eq!(tags.operator, QueryFilterOperator::Contains);
+        assert_eq!(tags.value, Some("{sale,new}".to_string()));
+        assert_eq!(
+            filters.get("labels").unwrap().operator,
+            QueryFilterOperator::ContainedBy
+        );
+        assert_eq!(
+            filters.get("sizes").unwrap().operator,
+            QueryFilterOperator::Overlaps
+        );
+    }
+
     #[test]
     fn test_mixed_formats() {
         let query = "status=active&age[gte]=18&role[in]=admin,user&deleted_at[is_null]=";
@@ -198,7 +254,7 @@ mod tests {
         let role = filters.get("role").unwrap();
         assert_eq!(role.operator, QueryFilterOperator::In);
 
-        let deleted = parsed.filters.get("deleted_at").unwrap();
+        let deleted = &parsed.filters["deleted_at"][0];
         assert_eq!(deleted.operator, QueryFilterOperator::IsNull);
     }
     */
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes the `min_should_match` query string parameter.
+///
+/// Missing, zero, negative or non-numeric values are `None`, so any search token
+/// matching is enough.
+pub fn min_should_match_deserialize<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    Ok(value
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|min_should_match| *min_should_match > 0))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn deserialize(json: &str) -> Option<usize> {
+        let value: Value = serde_json::from_str(json).unwrap();
+        min_should_match_deserialize(value).unwrap()
+    }
+
+    #[test]
+    fn test_min_should_match_deserialize() {
+        assert_eq!(deserialize(r#""2""#), Some(2));
+        assert_eq!(deserialize(r#"" 3 ""#), Some(3));
+
+        assert_eq!(deserialize(r#"null"#), None);
+        assert_eq!(deserialize(r#""""#), None);
+        assert_eq!(deserialize(r#""0""#), None);
+        assert_eq!(deserialize(r#""-1""#), None);
+        assert_eq!(deserialize(r#""two""#), None);
+    }
+}
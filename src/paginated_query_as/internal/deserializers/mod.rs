@@ -1,10 +1,14 @@
 mod filter_deserialize;
+mod min_should_match_deserialize;
+mod order_by_deserialize;
 mod page_deserialize;
 mod page_size_deserialize;
 mod search_columns_deserialize;
 mod search_deserialize;
 
 pub use filter_deserialize::*;
+pub use min_should_match_deserialize::*;
+pub use order_by_deserialize::*;
 pub use page_deserialize::*;
 pub use page_size_deserialize::*;
 pub use search_columns_deserialize::*;
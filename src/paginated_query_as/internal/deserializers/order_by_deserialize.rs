@@ -0,0 +1,235 @@
+use crate::paginated_query_as::internal::{
+    QuerySort, DEFAULT_ORDER_BY_SEPARATOR_SYMBOL, DEFAULT_SORT_DIRECTION_SEPARATOR_SYMBOL,
+};
+use crate::{QueryNullsPosition, QuerySortDirection};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Deserializes a comma-separated `order_by` list into sort columns.
+///
+/// Each entry is either:
+/// - `column.asc` / `column.desc`: dot-suffixed direction
+/// - `-column`: descending
+/// - `column`: ascending
+///
+/// Unknown direction suffixes (e.g. `name.up`) are rejected, so qualified
+/// column names are not supported in this form.
+///
+/// # Examples
+///
+/// ```text
+/// ?order_by=name.asc,created_at.desc   → "name" ASC, "created_at" DESC
+/// ?order_by=-created_at,name           → "created_at" DESC, "name" ASC
+/// ```
+pub fn order_by_deserialize<'de, D>(deserializer: D) -> Result<Vec<QuerySort>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(Vec::new());
+    };
+
+    value
+        .split(DEFAULT_ORDER_BY_SEPARATOR_SYMBOL)
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| parse_order_by_entry(entry).map_err(D::Error::custom))
+        .collect()
+}
+
+/// Deserializes a comma-separated `sort` list into sort columns.
+///
+/// Each entry is either:
+/// - `column:asc` / `column:desc`: colon-suffixed direction
+/// - `column`: ascending
+///
+/// # Examples
+///
+/// ```text
+/// ?sort=status:asc,created_at:desc     → "status" ASC, "created_at" DESC
+/// ```
+pub fn sort_deserialize<'de, D>(deserializer: D) -> Result<Vec<QuerySort>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(Vec::new());
+    };
+
+    value
+        .split(DEFAULT_ORDER_BY_SEPARATOR_SYMBOL)
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| parse_sort_entry(entry).map_err(D::Error::custom))
+        .collect()
+}
+
+/// Serializes sort columns back into the dot-suffixed `order_by` form.
+pub fn order_by_serialize<S>(sorts: &[QuerySort], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format_order_by(sorts))
+}
+
+/// Formats sort columns in the dot-suffixed `order_by` form, e.g. `name.asc,created_at.desc`.
+pub fn format_order_by(sorts: &[QuerySort]) -> String {
+    sorts
+        .iter()
+        .map(|sort| {
+            let direction = match sort.direction {
+                QuerySortDirection::Ascending => "asc",
+                QuerySortDirection::Descending => "desc",
+            };
+            format!("{}.{}", sort.column, direction)
+        })
+        .collect::<Vec<_>>()
+        .join(DEFAULT_ORDER_BY_SEPARATOR_SYMBOL)
+}
+
+fn parse_order_by_entry(entry: &str) -> Result<QuerySort, String> {
+    if let Some(column) = entry.strip_prefix('-') {
+        return build_sort(column, QuerySortDirection::Descending, entry);
+    }
+
+    parse_suffixed_entry(entry, ".")
+}
+
+fn parse_sort_entry(entry: &str) -> Result<QuerySort, String> {
+    parse_suffixed_entry(entry, DEFAULT_SORT_DIRECTION_SEPARATOR_SYMBOL)
+}
+
+fn parse_suffixed_entry(entry: &str, separator: &str) -> Result<QuerySort, String> {
+    match entry.rsplit_once(separator) {
+        Some((column, suffix)) => {
+            let direction = match suffix.to_lowercase().as_str() {
+                "asc" => QuerySortDirection::Ascending,
+                "desc" => QuerySortDirection::Descending,
+                _ => {
+                    return Err(format!(
+                        "unknown sort direction `{}` in `{}`, expected `asc` or `desc`",
+                        suffix, entry
+                    ))
+                }
+            };
+            build_sort(column, direction, entry)
+        }
+        None => build_sort(entry, QuerySortDirection::Ascending, entry),
+    }
+}
+
+fn build_sort(
+    column: &str,
+    direction: QuerySortDirection,
+    entry: &str,
+) -> Result<QuerySort, String> {
+    let column = column.trim();
+    if column.is_empty() {
+        return Err(format!("missing sort column in `{}`", entry));
+    }
+
+    Ok(QuerySort {
+        column: column.to_string(),
+        direction,
+        nulls: QueryNullsPosition::Default,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn deserialize_test<T, F>(json: &str, deserialize_fn: F) -> Result<T, serde_json::Error>
+    where
+        F: FnOnce(Value) -> Result<T, serde_json::Error>,
+    {
+        let value: Value = serde_json::from_str(json)?;
+        deserialize_fn(value)
+    }
+
+    fn sort(column: &str, direction: QuerySortDirection) -> QuerySort {
+        QuerySort {
+            column: column.to_string(),
+            direction,
+            nulls: QueryNullsPosition::Default,
+        }
+    }
+
+    #[test]
+    fn test_order_by_dotted_form() {
+        assert_eq!(
+            deserialize_test(r#""name.asc,created_at.DESC""#, order_by_deserialize).unwrap(),
+            vec![
+                sort("name", QuerySortDirection::Ascending),
+                sort("created_at", QuerySortDirection::Descending),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_mixed_default_and_explicit() {
+        assert_eq!(
+            deserialize_test(r#""status, -created_at ,name.desc,""#, order_by_deserialize).unwrap(),
+            vec![
+                sort("status", QuerySortDirection::Ascending),
+                sort("created_at", QuerySortDirection::Descending),
+                sort("name", QuerySortDirection::Descending),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_empty() {
+        assert!(deserialize_test(r#"null"#, order_by_deserialize)
+            .unwrap()
+            .is_empty());
+        assert!(deserialize_test(r#""""#, order_by_deserialize)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_order_by_rejects_unknown_direction() {
+        let error = deserialize_test(r#""name.up""#, order_by_deserialize).unwrap_err();
+        assert!(error.to_string().contains("unknown sort direction `up`"));
+
+        assert!(deserialize_test(r#""-""#, order_by_deserialize).is_err());
+        assert!(deserialize_test(r#"".asc""#, order_by_deserialize).is_err());
+    }
+
+    #[test]
+    fn test_sort_colon_form() {
+        assert_eq!(
+            deserialize_test(r#""status:asc, created_at:DESC,name""#, sort_deserialize).unwrap(),
+            vec![
+                sort("status", QuerySortDirection::Ascending),
+                sort("created_at", QuerySortDirection::Descending),
+                sort("name", QuerySortDirection::Ascending),
+            ]
+        );
+        assert!(deserialize_test(r#"null"#, sort_deserialize)
+            .unwrap()
+            .is_empty());
+
+        let error = deserialize_test(r#""name:up""#, sort_deserialize).unwrap_err();
+        assert!(error.to_string().contains("unknown sort direction `up`"));
+        assert!(deserialize_test(r#"":desc""#, sort_deserialize).is_err());
+    }
+
+    #[test]
+    fn test_order_by_serialize_round_trip() {
+        let sorts = vec![
+            sort("name", QuerySortDirection::Ascending),
+            sort("created_at", QuerySortDirection::Descending),
+        ];
+        let value = order_by_serialize(&sorts, serde_json::value::Serializer).unwrap();
+
+        assert_eq!(value, Value::String("name.asc,created_at.desc".to_string()));
+        assert_eq!(
+            deserialize_test(&value.to_string(), order_by_deserialize).unwrap(),
+            sorts
+        );
+    }
+}
@@ -1,6 +1,11 @@
-use crate::paginated_query_as::internal::{extract_digits_from_strings, DEFAULT_PAGE};
+use crate::paginated_query_as::internal::{clamp_page, extract_digits_from_strings, DEFAULT_PAGE};
 use serde::{Deserialize, Deserializer};
 
+/// Deserializes the `page` query string parameter.
+///
+/// Valid pages are clamped with `clamp_page`, the same clamp used by
+/// `QueryParamsBuilder::with_pagination`. Missing, negative or non-numeric
+/// values fall back to the first page.
 pub fn page_deserialize<'de, D>(deserializer: D) -> Result<i64, D::Error>
 where
     D: Deserializer<'de>,
@@ -14,13 +19,7 @@ where
 
     value_with_fallbacks
         .parse::<i64>()
-        .map(|digit| {
-            if digit < DEFAULT_PAGE {
-                DEFAULT_PAGE
-            } else {
-                digit
-            }
-        })
+        .map(clamp_page)
         .or(Ok(DEFAULT_PAGE))
 }
 
@@ -1,33 +1,41 @@
-use crate::paginated_query_as::internal::{
-    extract_digits_from_strings, DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE,
-};
+use crate::paginated_query_as::internal::extract_digits_from_strings;
 use serde::{Deserialize, Deserializer};
 
-pub fn page_size_deserialize<'de, D>(deserializer: D) -> Result<i64, D::Error>
+/// Deserializes the `page_size` query string parameter as requested, before it is
+/// clamped.
+///
+/// `QueryPaginationParams` clamps the size with `clamp_page_size`, the same clamp
+/// used by `QueryParamsBuilder::with_pagination`, and keeps the requested size for
+/// `with_max_page_size`. Missing, negative or non-numeric values are `None`, and
+/// fall back to the minimum page size.
+pub fn requested_page_size_deserialize<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
 where
     D: Deserializer<'de>,
 {
     let value = Option::<String>::deserialize(deserializer)?;
     let value_with_fallbacks = match value {
-        None => return Ok(DEFAULT_MIN_PAGE_SIZE),
-        Some(s) if s.trim().is_empty() || s.trim().starts_with('-') => {
-            return Ok(DEFAULT_MIN_PAGE_SIZE)
-        }
+        None => return Ok(None),
+        Some(s) if s.trim().is_empty() || s.trim().starts_with('-') => return Ok(None),
         Some(s) => extract_digits_from_strings(s),
     };
 
-    value_with_fallbacks
-        .parse::<i64>()
-        .map(|digit| digit.clamp(DEFAULT_MIN_PAGE_SIZE, DEFAULT_MAX_PAGE_SIZE))
-        .or(Ok(DEFAULT_MIN_PAGE_SIZE))
+    Ok(value_with_fallbacks.parse::<i64>().ok())
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::paginated_query_as::internal::{
+        clamp_page_size, DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE,
+    };
     use serde_json::Value;
 
+    /// The page size as `QueryPaginationParams` clamps it.
+    fn page_size_deserialize(value: Value) -> Result<i64, serde_json::Error> {
+        Ok(requested_page_size_deserialize(value)?.map_or(DEFAULT_MIN_PAGE_SIZE, clamp_page_size))
+    }
+
     fn deserialize_test<T, F>(json: &str, deserialize_fn: F) -> Result<T, serde_json::Error>
     where
         F: FnOnce(Value) -> Result<T, serde_json::Error>,
@@ -64,6 +72,20 @@ mod tests {
             DEFAULT_MAX_PAGE_SIZE
         );
 
+        // Boundaries are kept as-is
+        assert_eq!(
+            deserialize_test(r#""10""#, page_size_deserialize).unwrap(),
+            DEFAULT_MIN_PAGE_SIZE
+        );
+        assert_eq!(
+            deserialize_test(r#""50""#, page_size_deserialize).unwrap(),
+            DEFAULT_MAX_PAGE_SIZE
+        );
+        assert_eq!(
+            deserialize_test(r#""51""#, page_size_deserialize).unwrap(),
+            DEFAULT_MAX_PAGE_SIZE
+        );
+
         // Invalid/Edge cases
         assert_eq!(
             deserialize_test(r#""size25""#, page_size_deserialize).unwrap(),
@@ -74,4 +96,24 @@ mod tests {
             DEFAULT_MIN_PAGE_SIZE
         );
     }
+
+    #[test]
+    fn test_requested_page_size_deserialize_keeps_unclamped_size() {
+        assert_eq!(
+            deserialize_test(r#""500""#, requested_page_size_deserialize).unwrap(),
+            Some(500)
+        );
+        assert_eq!(
+            deserialize_test(r#""5""#, requested_page_size_deserialize).unwrap(),
+            Some(5)
+        );
+        assert_eq!(
+            deserialize_test(r#""-5""#, requested_page_size_deserialize).unwrap(),
+            None
+        );
+        assert_eq!(
+            deserialize_test(r#"null"#, requested_page_size_deserialize).unwrap(),
+            None
+        );
+    }
 }
@@ -10,6 +10,12 @@ mod sqlite_dialect;
 #[cfg(feature = "sqlite")]
 mod sqlite_dialect_utils;
 
+#[cfg(feature = "mysql")]
+mod mysql_dialect;
+
+#[cfg(feature = "mysql")]
+mod mysql_dialect_utils;
+
 #[cfg(feature = "postgres")]
 pub use postgres_dialect::*;
 
@@ -21,3 +27,9 @@ pub use sqlite_dialect::*;
 
 #[cfg(feature = "sqlite")]
 pub use sqlite_dialect_utils::*;
+
+#[cfg(feature = "mysql")]
+pub use mysql_dialect::*;
+
+#[cfg(feature = "mysql")]
+pub use mysql_dialect_utils::*;
@@ -0,0 +1,164 @@
+use crate::paginated_query_as::internal::{get_mysql_type_casting, QueryDialect};
+
+pub struct MySqlDialect;
+
+impl QueryDialect for MySqlDialect {
+    fn quote_identifier(&self, ident: &str) -> String {
+        format!("`{}`", ident.replace('`', "``"))
+    }
+
+    fn placeholder(&self, _position: usize) -> String {
+        "?".to_string()
+    }
+
+    fn type_cast(&self, value: &str) -> String {
+        get_mysql_type_casting(value).to_string()
+    }
+
+    /// MySQL casts with a function, `CAST(? AS SIGNED)`, rather than a suffix.
+    fn cast_placeholder(&self, placeholder: &str, value: &str) -> String {
+        match get_mysql_type_casting(value) {
+            "" => placeholder.to_string(),
+            cast => format!("CAST({} AS {})", placeholder, cast),
+        }
+    }
+
+    /// RFC3339 values are bound as `DateTime<Utc>`, which MySQL compares with
+    /// `DATETIME` and `TIMESTAMP` columns without a cast.
+    fn binds_native_timestamps(&self) -> bool {
+        true
+    }
+
+    /// `BOOLEAN` is a synonym of `TINYINT(1)`, holding `0`/`1`.
+    fn binds_integer_booleans(&self) -> bool {
+        true
+    }
+
+    fn boolean_literal(&self, value: bool) -> &'static str {
+        if value {
+            "1"
+        } else {
+            "0"
+        }
+    }
+
+    /// Window functions such as `ROW_NUMBER()` return `BIGINT UNSIGNED`.
+    fn signed_integer(&self, expression: &str) -> String {
+        format!("CAST({} AS SIGNED)", expression)
+    }
+
+    /// `REGEXP_LIKE` takes the case sensitivity as a flag, ignoring the collation
+    /// of the column.
+    fn regex_match(
+        &self,
+        column: &str,
+        pattern_placeholder: &str,
+        case_insensitive: bool,
+    ) -> Option<String> {
+        let flags = if case_insensitive { "i" } else { "c" };
+        Some(format!(
+            "REGEXP_LIKE({}, {}, '{}')",
+            column, pattern_placeholder, flags
+        ))
+    }
+
+    /// `?` placeholders refer to arguments by their order in the statement.
+    fn numbers_placeholders(&self) -> bool {
+        false
+    }
+
+    fn offset_placeholders(&self, sql: &str, _offset: usize) -> String {
+        sql.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_identifier() {
+        let dialect = MySqlDialect;
+
+        assert_eq!(dialect.quote_identifier("column_name"), "`column_name`");
+        assert_eq!(dialect.quote_identifier("my column"), "`my column`");
+        assert_eq!(dialect.quote_identifier("table.column"), "`table.column`");
+
+        // Backticks are escaped by doubling them
+        assert_eq!(dialect.quote_identifier("column`name"), "`column``name`");
+        assert_eq!(dialect.quote_identifier(""), "``");
+    }
+
+    #[test]
+    fn test_placeholder() {
+        let dialect = MySqlDialect;
+
+        assert_eq!(dialect.placeholder(1), "?");
+        assert_eq!(dialect.placeholder(100), "?");
+        assert!(!dialect.reuses_placeholders());
+        assert!(!dialect.numbers_placeholders());
+        assert_eq!(
+            dialect.offset_placeholders("total > ? AND name <> ?", 3),
+            "total > ? AND name <> ?"
+        );
+    }
+
+    #[test]
+    fn test_cast_placeholder() {
+        let dialect = MySqlDialect;
+
+        assert_eq!(dialect.cast_placeholder("?", "42"), "CAST(? AS SIGNED)");
+        assert_eq!(
+            dialect.cast_placeholder("?", "3.14"),
+            "CAST(? AS DECIMAL(65,30))"
+        );
+        assert_eq!(
+            dialect.cast_placeholder("?", "2024-01-01 12:34:56"),
+            "CAST(? AS DATETIME)"
+        );
+        assert_eq!(dialect.cast_placeholder("?", "hello"), "?");
+    }
+
+    #[test]
+    fn test_boolean_literal() {
+        assert_eq!(MySqlDialect.boolean_literal(true), "1");
+        assert_eq!(MySqlDialect.boolean_literal(false), "0");
+        assert!(MySqlDialect.binds_integer_booleans());
+        assert!(MySqlDialect.binds_native_timestamps());
+        assert!(!MySqlDialect.supports_nulls_ordering());
+    }
+
+    #[test]
+    fn test_boolean_test() {
+        assert_eq!(MySqlDialect.boolean_test("`a`", true, false), "`a` IS TRUE");
+        assert_eq!(
+            MySqlDialect.boolean_test("`a`", false, true),
+            "`a` IS NOT FALSE"
+        );
+    }
+
+    #[test]
+    fn test_regex_match() {
+        assert_eq!(
+            MySqlDialect.regex_match("`sku`", "?", true).as_deref(),
+            Some("REGEXP_LIKE(`sku`, ?, 'i')")
+        );
+    }
+
+    #[test]
+    fn test_signed_integer() {
+        assert_eq!(
+            MySqlDialect.signed_integer("ROW_NUMBER() OVER (ORDER BY `id`)"),
+            "CAST(ROW_NUMBER() OVER (ORDER BY `id`) AS SIGNED)"
+        );
+    }
+
+    #[test]
+    fn test_time_function() {
+        assert_eq!(
+            MySqlDialect.time_function("NOW()").as_deref(),
+            Some("NOW()")
+        );
+        assert_eq!(MySqlDialect.time_function("now()"), None);
+    }
+}
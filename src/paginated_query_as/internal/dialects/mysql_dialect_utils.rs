@@ -0,0 +1,101 @@
+use crate::paginated_query_as::internal::DEFAULT_EMPTY_VALUE;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// MySQL type casting utility
+/// Note: MySQL casts with `CAST(value AS type)` rather than a suffix, so this returns
+/// the target type only, wrapped around the placeholder by `MySqlDialect`.
+/// Values MySQL converts implicitly when compared (text, UUIDs, booleans bound as
+/// integers) return no type.
+pub fn get_mysql_type_casting(value: &str) -> &'static str {
+    match value.trim().to_string().to_lowercase().as_str() {
+        // Special values
+        value
+            if value.eq_ignore_ascii_case("null")
+                || value.eq_ignore_ascii_case("nan")
+                || value.eq_ignore_ascii_case("infinity")
+                || value.eq_ignore_ascii_case("-infinity") =>
+        {
+            DEFAULT_EMPTY_VALUE
+        }
+
+        // JSON
+        value if value.starts_with('{') || value.starts_with('[') => {
+            match serde_json::from_str::<serde_json::Value>(value) {
+                Ok(_) => "JSON",
+                Err(_) => DEFAULT_EMPTY_VALUE,
+            }
+        }
+
+        // Dates and Times
+        value if NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").is_ok() => "DATETIME",
+        value if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok() => "DATE",
+        value if NaiveTime::parse_from_str(value, "%H:%M:%S").is_ok() => "TIME",
+
+        // Numbers
+        value if value.parse::<i64>().is_ok() => "SIGNED",
+        value if value.parse::<u64>().is_ok() => "UNSIGNED",
+        value if !value.contains('e') && value.parse::<f64>().is_ok_and(f64::is_finite) => {
+            "DECIMAL(65,30)"
+        }
+
+        // Default - no type cast
+        _ => DEFAULT_EMPTY_VALUE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(get_mysql_type_casting("NULL"), "");
+        assert_eq!(get_mysql_type_casting("nan"), "");
+        assert_eq!(get_mysql_type_casting("Infinity"), "");
+        assert_eq!(get_mysql_type_casting("-infinity"), "");
+        assert_eq!(get_mysql_type_casting("inf"), "");
+    }
+
+    #[test]
+    fn test_numeric_types() {
+        assert_eq!(get_mysql_type_casting("42"), "SIGNED");
+        assert_eq!(get_mysql_type_casting("-42"), "SIGNED");
+        assert_eq!(get_mysql_type_casting("9223372036854775807"), "SIGNED");
+        assert_eq!(get_mysql_type_casting("18446744073709551615"), "UNSIGNED");
+        assert_eq!(get_mysql_type_casting("3.14"), "DECIMAL(65,30)");
+        assert_eq!(get_mysql_type_casting("-0.5"), "DECIMAL(65,30)");
+
+        // Exponent forms are left for MySQL to convert
+        assert_eq!(get_mysql_type_casting("1.23e-4"), "");
+        assert_eq!(get_mysql_type_casting("1.23E+4"), "");
+    }
+
+    #[test]
+    fn test_date_time_types() {
+        assert_eq!(get_mysql_type_casting("2024-01-01"), "DATE");
+        assert_eq!(get_mysql_type_casting("12:34:56"), "TIME");
+        assert_eq!(get_mysql_type_casting("2024-01-01 12:34:56"), "DATETIME");
+
+        // RFC 3339 timestamps are bound natively instead
+        assert_eq!(get_mysql_type_casting("2024-01-01T12:34:56Z"), "");
+        assert_eq!(get_mysql_type_casting("2024-13-01"), "");
+    }
+
+    #[test]
+    fn test_json_types() {
+        assert_eq!(get_mysql_type_casting("{\"key\":\"value\"}"), "JSON");
+        assert_eq!(get_mysql_type_casting("[1,2,3]"), "JSON");
+        assert_eq!(get_mysql_type_casting("{invalid json}"), "");
+    }
+
+    #[test]
+    fn test_text_types() {
+        assert_eq!(get_mysql_type_casting(""), "");
+        assert_eq!(get_mysql_type_casting("hello world"), "");
+        assert_eq!(get_mysql_type_casting("true"), "");
+        assert_eq!(
+            get_mysql_type_casting("550e8400-e29b-41d4-a716-446655440000"),
+            ""
+        );
+    }
+}
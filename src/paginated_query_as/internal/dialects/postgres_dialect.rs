@@ -1,4 +1,6 @@
-use crate::paginated_query_as::internal::{get_postgres_type_casting, QueryDialect};
+use crate::paginated_query_as::internal::{
+    get_postgres_containment_casting, get_postgres_type_casting, rewrite_placeholders, QueryDialect,
+};
 
 pub struct PostgresDialect;
 
@@ -14,12 +16,107 @@ impl QueryDialect for PostgresDialect {
     fn type_cast(&self, value: &str) -> String {
         get_postgres_type_casting(value).to_string()
     }
+
+    fn reuses_placeholders(&self) -> bool {
+        true
+    }
+
+    fn binds_native_timestamps(&self) -> bool {
+        true
+    }
+
+    fn supports_nulls_ordering(&self) -> bool {
+        true
+    }
+
+    fn supports_full_text_search(&self) -> bool {
+        true
+    }
+
+    fn regex_match(
+        &self,
+        column: &str,
+        pattern_placeholder: &str,
+        case_insensitive: bool,
+    ) -> Option<String> {
+        let operator = if case_insensitive { "~*" } else { "~" };
+        Some(format!("{} {} {}", column, operator, pattern_placeholder))
+    }
+
+    fn containment_casts(&self, value: &str) -> Option<(&'static str, &'static str)> {
+        Some(get_postgres_containment_casting(value))
+    }
+
+    fn numbers_placeholders(&self) -> bool {
+        true
+    }
+
+    fn offset_placeholders(&self, sql: &str, offset: usize) -> String {
+        rewrite_placeholders(sql, '$', |position| {
+            format!("${}", position.unwrap_or_default() + offset)
+        })
+    }
+
+    /// `reltuples` is -1 for tables never vacuumed or analyzed.
+    fn table_row_estimate(&self, table: &str) -> Option<String> {
+        Some(format!(
+            "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass('{}')",
+            table.replace('\'', "''")
+        ))
+    }
+
+    fn explain_json(&self, sql: &str) -> Option<String> {
+        Some(format!("EXPLAIN (FORMAT JSON) {}", sql))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_full_text_search() {
+        assert!(PostgresDialect.supports_full_text_search());
+        assert_eq!(
+            PostgresDialect.full_text_search("english", "\"title\"", "$1"),
+            "to_tsvector('english', \"title\") @@ plainto_tsquery('english', $1)"
+        );
+    }
+
+    #[test]
+    fn test_regex_match() {
+        assert_eq!(
+            PostgresDialect
+                .regex_match("\"sku\"", "$1", false)
+                .as_deref(),
+            Some("\"sku\" ~ $1")
+        );
+        assert_eq!(
+            PostgresDialect
+                .regex_match("\"sku\"", "$1", true)
+                .as_deref(),
+            Some("\"sku\" ~* $1")
+        );
+    }
+
+    #[test]
+    fn test_count_estimates() {
+        assert_eq!(
+            PostgresDialect
+                .table_row_estimate("public.user's")
+                .as_deref(),
+            Some(
+                "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass('public.user''s')"
+            )
+        );
+        assert_eq!(
+            PostgresDialect
+                .explain_json("SELECT COUNT(*) FROM users")
+                .as_deref(),
+            Some("EXPLAIN (FORMAT JSON) SELECT COUNT(*) FROM users")
+        );
+    }
+
     #[test]
     fn test_quote_identifier() {
         let dialect = PostgresDialect;
@@ -100,4 +197,36 @@ mod tests {
         assert_eq!(dialect.type_cast("NULL"), "");
         assert_eq!(dialect.type_cast("invalid"), "");
     }
+
+    #[test]
+    fn test_binds_native_timestamps() {
+        assert!(PostgresDialect.binds_native_timestamps());
+    }
+
+    #[test]
+    fn test_boolean_literal() {
+        let dialect = PostgresDialect;
+        assert_eq!(dialect.boolean_literal(true), "true");
+        assert_eq!(dialect.boolean_literal(false), "false");
+        assert!(!dialect.binds_integer_booleans());
+        assert!(dialect.reuses_placeholders());
+        assert_eq!(
+            dialect.type_cast(dialect.boolean_literal(true)),
+            "::boolean"
+        );
+    }
+
+    #[test]
+    fn test_offset_placeholders() {
+        let dialect = PostgresDialect;
+
+        assert_eq!(
+            dialect.offset_placeholders("total > $1 AND name <> $2", 3),
+            "total > $4 AND name <> $5"
+        );
+        assert_eq!(
+            dialect.offset_placeholders("data ? 'key' AND bio = '$1'", 3),
+            "data ? 'key' AND bio = '$1'"
+        );
+    }
 }
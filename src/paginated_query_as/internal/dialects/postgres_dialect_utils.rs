@@ -65,10 +65,78 @@ pub fn get_postgres_type_casting(value: &str) -> &'static str {
     }
 }
 
+/// Returns the cast of a text array holding the values, to an array of the type
+/// inferred for every value, or an empty string when the values differ in type.
+/// Integers of different widths share the `bigint` type.
+pub fn get_postgres_array_casting(values: &[String]) -> String {
+    let widened = |value: &String| match get_postgres_type_casting(value) {
+        "::smallint" | "::integer" => "::bigint",
+        cast => cast,
+    };
+    let mut casts = values.iter().map(widened);
+
+    match casts.next() {
+        Some(cast) if !cast.is_empty() && casts.all(|other| other == cast) => {
+            format!("{}[]", cast)
+        }
+        _ => DEFAULT_EMPTY_VALUE.to_string(),
+    }
+}
+
+/// Returns the casts of a column and of a value compared with the containment
+/// operators: the value is cast to `::jsonb` for JSON documents. The element type
+/// of an array column is unknown, e.g. `integer[]` or `text[]` for `{1,2}`, so
+/// array literals are compared as text, by casting both sides to `::text[]`.
+pub fn get_postgres_containment_casting(value: &str) -> (&'static str, &'static str) {
+    match get_postgres_type_casting(value.trim()) {
+        "::jsonb" => ("", "::jsonb"),
+        _ => ("::text[]", "::text[]"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_containment_casting() {
+        assert_eq!(
+            get_postgres_containment_casting(r#"{"color":"red"}"#),
+            ("", "::jsonb")
+        );
+        assert_eq!(
+            get_postgres_containment_casting(r#"["sale"]"#),
+            ("", "::jsonb")
+        );
+        assert_eq!(
+            get_postgres_containment_casting("{1, 2, 70000}"),
+            ("::text[]", "::text[]")
+        );
+        assert_eq!(
+            get_postgres_containment_casting("{sale,new}"),
+            ("::text[]", "::text[]")
+        );
+    }
+
+    #[test]
+    fn test_array_casting() {
+        let values = |values: &[&str]| values.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert_eq!(
+            get_postgres_array_casting(&values(&["1", "70000", "9000000000"])),
+            "::bigint[]"
+        );
+        assert_eq!(
+            get_postgres_array_casting(&values(&[
+                "550e8400-e29b-41d4-a716-446655440000",
+                "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+            ])),
+            "::uuid[]"
+        );
+        assert_eq!(get_postgres_array_casting(&values(&["1", "draft"])), "");
+        assert_eq!(get_postgres_array_casting(&values(&["draft", "done"])), "");
+    }
+
     #[test]
     fn test_boolean_types() {
         // Standard boolean values
@@ -1,5 +1,185 @@
+use crate::paginated_query_as::internal::DEFAULT_TIME_FUNCTIONS;
+
+/// SQL syntax differences between databases used when building query conditions.
+///
+/// The crate ships dialects for its supported databases. Databases it doesn't ship
+/// can be supported by implementing this trait and passing the dialect to
+/// `QueryBuilder::with_dialect`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::QueryDialect;
+///
+/// struct BacktickDialect;
+///
+/// impl QueryDialect for BacktickDialect {
+///     fn quote_identifier(&self, ident: &str) -> String {
+///         format!("`{}`", ident.replace('`', "``"))
+///     }
+///
+///     fn placeholder(&self, _position: usize) -> String {
+///         "?".to_string()
+///     }
+///
+///     fn type_cast(&self, _value: &str) -> String {
+///         String::new()
+///     }
+///
+///     fn numbers_placeholders(&self) -> bool {
+///         false
+///     }
+///
+///     fn offset_placeholders(&self, sql: &str, _offset: usize) -> String {
+///         sql.to_string()
+///     }
+/// }
+///
+/// assert_eq!(BacktickDialect.quote_identifier("name"), "`name`");
+/// ```
 pub trait QueryDialect {
+    /// Quotes a column name, escaping any embedded quote characters.
     fn quote_identifier(&self, ident: &str) -> String;
+
+    /// Returns the bind parameter placeholder for the 1-based argument position.
     fn placeholder(&self, position: usize) -> String;
+
+    /// Returns the cast of a text bound placeholder for the given value, or an
+    /// empty string when no cast is needed. The cast is appended to the
+    /// placeholder unless the dialect overrides `cast_placeholder`.
     fn type_cast(&self, value: &str) -> String;
+
+    /// Returns a text bound placeholder with the type cast for its value, the
+    /// placeholder followed by `type_cast` by default.
+    fn cast_placeholder(&self, placeholder: &str, value: &str) -> String {
+        format!("{}{}", placeholder, self.type_cast(value))
+    }
+
+    /// Whether a placeholder can appear several times in a statement, so that a
+    /// value used in several places is bound once. Only numbered placeholders
+    /// (e.g. `$1`) can be reused.
+    fn reuses_placeholders(&self) -> bool {
+        false
+    }
+
+    /// Whether RFC3339 filter values are bound as native timestamps instead of
+    /// text, skipping the text to timestamp cast.
+    fn binds_native_timestamps(&self) -> bool {
+        false
+    }
+
+    /// Whether `ORDER BY` terms accept `NULLS FIRST`/`NULLS LAST`. Without it, NULL
+    /// placement is emulated with a `term IS NULL` term sorted first.
+    fn supports_nulls_ordering(&self) -> bool {
+        false
+    }
+
+    /// Whether boolean filter values are bound as `0`/`1` integers instead of text,
+    /// for databases storing booleans as integers.
+    fn binds_integer_booleans(&self) -> bool {
+        false
+    }
+
+    /// Returns the SQL inlined in place of a filter value naming an allowed time
+    /// function (`NOW()`, `CURRENT_DATE` or `CURRENT_TIMESTAMP`, matched exactly),
+    /// or `None` for values to bind.
+    fn time_function(&self, value: &str) -> Option<String> {
+        DEFAULT_TIME_FUNCTIONS
+            .contains(&value)
+            .then(|| value.to_string())
+    }
+
+    /// Textual form of a boolean filter value for this database.
+    fn boolean_literal(&self, value: bool) -> &'static str {
+        if value {
+            "true"
+        } else {
+            "false"
+        }
+    }
+
+    /// Checks a boolean column with `IS [NOT] TRUE/FALSE`. The negated forms match
+    /// NULL values, the others don't.
+    fn boolean_test(&self, column: &str, value: bool, negated: bool) -> String {
+        format!(
+            "{} IS {}{}",
+            column,
+            if negated { "NOT " } else { "" },
+            if value { "TRUE" } else { "FALSE" }
+        )
+    }
+
+    /// Whether search parameters with a full-text configuration are matched with
+    /// `full_text_search`. Without it, they fall back to LIKE search.
+    fn supports_full_text_search(&self) -> bool {
+        false
+    }
+
+    /// Matches a text document against a search term with full-text search, using
+    /// the given text search configuration, in the Postgres form
+    /// `to_tsvector(config, document) @@ plainto_tsquery(config, term)` by default.
+    ///
+    /// The configuration is a validated name inlined as a literal, so that
+    /// expression indexes with the same configuration apply.
+    fn full_text_search(&self, config: &str, document: &str, term_placeholder: &str) -> String {
+        format!(
+            "to_tsvector('{0}', {1}) @@ plainto_tsquery('{0}', {2})",
+            config, document, term_placeholder
+        )
+    }
+
+    /// Matches a column against the regular expression bound at the placeholder,
+    /// or returns `None` when the database has no regular expressions, in which
+    /// case `QueryFilterOperator::Regex` filters are skipped.
+    fn regex_match(
+        &self,
+        _column: &str,
+        _pattern_placeholder: &str,
+        _case_insensitive: bool,
+    ) -> Option<String> {
+        None
+    }
+
+    /// Returns the type casts of the column and of the value compared with the
+    /// `@>`, `<@` and `&&` containment operators, or `None` when the database has
+    /// no such operators, in which case `QueryFilterOperator::Contains` filters
+    /// and the like are skipped.
+    fn containment_casts(&self, _value: &str) -> Option<(&'static str, &'static str)> {
+        None
+    }
+
+    /// Wraps an integer expression of the query, e.g. a row number, so that it
+    /// decodes as `i64`. Expressions are left unchanged by default.
+    fn signed_integer(&self, expression: &str) -> String {
+        expression.to_string()
+    }
+
+    /// Whether placeholders can refer to arguments bound out of statement order,
+    /// either natively (e.g. `$1`) or once renumbered by `offset_placeholders`.
+    ///
+    /// Dialects whose placeholders refer to arguments by their order in the
+    /// statement (e.g. MySQL `?`) return `false`, and builder features binding
+    /// arguments out of statement order fail with
+    /// `PaginationError::UnorderedArguments`.
+    fn numbers_placeholders(&self) -> bool;
+
+    /// Returns the query selecting the number of rows of a table estimated from the
+    /// statistics of the database, as a single `BIGINT`, or `None` when the
+    /// database keeps no such estimate. Unknown estimates are negative.
+    fn table_row_estimate(&self, _table: &str) -> Option<String> {
+        None
+    }
+
+    /// Returns the statement explaining a query as a JSON plan, from which the
+    /// number of rows estimated by the planner is read, or `None` when the
+    /// database can't explain queries as JSON.
+    fn explain_json(&self, _sql: &str) -> Option<String> {
+        None
+    }
+
+    /// Rewrites the placeholders of a fragment written as if its arguments were
+    /// bound first, for arguments bound after `offset` others. Dialects whose
+    /// placeholders refer to arguments by their order in the statement return the
+    /// fragment unchanged.
+    fn offset_placeholders(&self, sql: &str, offset: usize) -> String;
 }
@@ -1,4 +1,6 @@
-use crate::paginated_query_as::internal::{get_sqlite_type_casting, QueryDialect};
+use crate::paginated_query_as::internal::{
+    get_sqlite_type_casting, rewrite_placeholders, QueryDialect,
+};
 
 pub struct SqliteDialect;
 
@@ -14,6 +16,71 @@ impl QueryDialect for SqliteDialect {
     fn type_cast(&self, value: &str) -> String {
         get_sqlite_type_casting(value).to_string()
     }
+
+    /// Booleans are stored as integers, which text bound values don't match when
+    /// compared without column affinity (e.g. computed columns).
+    fn binds_integer_booleans(&self) -> bool {
+        true
+    }
+
+    /// SQLite has no boolean type and stores booleans as `0`/`1` integers.
+    fn boolean_literal(&self, value: bool) -> &'static str {
+        if value {
+            "1"
+        } else {
+            "0"
+        }
+    }
+
+    /// Compared with the `0`/`1` integers, with the NULL-safe `IS NOT` for the
+    /// negated forms.
+    fn boolean_test(&self, column: &str, value: bool, negated: bool) -> String {
+        let operator = if negated { "IS NOT" } else { "=" };
+        format!("{} {} {}", column, operator, self.boolean_literal(value))
+    }
+
+    /// SQLite has no `NOW()`, `CURRENT_TIMESTAMP` is used in its place.
+    fn time_function(&self, value: &str) -> Option<String> {
+        match value {
+            "NOW()" => Some("CURRENT_TIMESTAMP".to_string()),
+            "CURRENT_DATE" | "CURRENT_TIMESTAMP" => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    /// `REGEXP` calls the application defined `regexp()` function, which SQLite
+    /// doesn't ship: register one on each connection, e.g. with the `regexp`
+    /// feature of SQLx and `SqliteConnectOptions::with_regexp`. Case-insensitive
+    /// matches prefix the pattern with the `(?i)` flag.
+    fn regex_match(
+        &self,
+        column: &str,
+        pattern_placeholder: &str,
+        case_insensitive: bool,
+    ) -> Option<String> {
+        Some(if case_insensitive {
+            format!("{} REGEXP ('(?i)' || {})", column, pattern_placeholder)
+        } else {
+            format!("{} REGEXP {}", column, pattern_placeholder)
+        })
+    }
+
+    fn numbers_placeholders(&self) -> bool {
+        true
+    }
+
+    /// Numbers the placeholders as `?NNN` so that they keep referring to the
+    /// right arguments whatever their position in the statement.
+    fn offset_placeholders(&self, sql: &str, offset: usize) -> String {
+        let mut next = offset;
+        rewrite_placeholders(sql, '?', |position| match position {
+            Some(position) => format!("?{}", position + offset),
+            None => {
+                next += 1;
+                format!("?{}", next)
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +139,44 @@ mod tests {
         assert_eq!(dialect.type_cast("2024-01-01"), "");
         assert_eq!(dialect.type_cast("hello"), "");
     }
+
+    #[test]
+    fn test_binds_timestamps_as_text() {
+        assert!(!SqliteDialect.binds_native_timestamps());
+    }
+
+    #[test]
+    fn test_boolean_literal() {
+        assert_eq!(SqliteDialect.boolean_literal(true), "1");
+        assert_eq!(SqliteDialect.boolean_literal(false), "0");
+        assert!(SqliteDialect.binds_integer_booleans());
+        assert!(!SqliteDialect.reuses_placeholders());
+    }
+
+    #[test]
+    fn test_time_function() {
+        assert_eq!(
+            SqliteDialect.time_function("NOW()").as_deref(),
+            Some("CURRENT_TIMESTAMP")
+        );
+        assert_eq!(
+            SqliteDialect.time_function("CURRENT_DATE").as_deref(),
+            Some("CURRENT_DATE")
+        );
+        assert_eq!(SqliteDialect.time_function("now()"), None);
+    }
+
+    #[test]
+    fn test_offset_placeholders() {
+        let dialect = SqliteDialect;
+
+        assert_eq!(
+            dialect.offset_placeholders("total > ? AND name <> ?", 3),
+            "total > ?4 AND name <> ?5"
+        );
+        assert_eq!(
+            dialect.offset_placeholders("total > ?2 AND bio = '?'", 3),
+            "total > ?5 AND bio = '?'"
+        );
+    }
 }
@@ -1,8 +1,9 @@
 use crate::paginated_query_as::internal::{
-    DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE,
-    DEFAULT_SEARCH_COLUMN_NAMES, DEFAULT_SORT_COLUMN_NAME,
+    DEFAULT_DATE_RANGE_COLUMN_NAME, DEFAULT_MAX_PAGE_SIZE, DEFAULT_MIN_PAGE_SIZE, DEFAULT_PAGE,
+    DEFAULT_SEARCH_COLUMN_NAMES, DEFAULT_SORT_COLUMN_FALLBACK_NAMES, DEFAULT_SORT_COLUMN_NAME,
 };
 use crate::QuerySortDirection;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -35,6 +36,29 @@ pub fn default_date_range_column() -> Option<String> {
     Some(DEFAULT_DATE_RANGE_COLUMN_NAME.to_string())
 }
 
+/// Clamps a page number to the first page. Shared by the query string and builder paths.
+pub fn clamp_page(page: i64) -> i64 {
+    page.max(DEFAULT_PAGE)
+}
+
+/// Clamps a page size to the `DEFAULT_MIN_PAGE_SIZE..=DEFAULT_MAX_PAGE_SIZE` range.
+/// Shared by the query string and builder paths.
+pub fn clamp_page_size(page_size: i64) -> i64 {
+    clamp_page_size_to(page_size, DEFAULT_MAX_PAGE_SIZE)
+}
+
+/// Clamps a page size to `DEFAULT_MIN_PAGE_SIZE` and the given maximum page size.
+///
+/// A maximum of 0 or less falls back to `DEFAULT_MIN_PAGE_SIZE`, and a maximum
+/// below the minimum page size is used as the page size.
+pub fn clamp_page_size_to(page_size: i64, max_page_size: i64) -> i64 {
+    let max_page_size = match max_page_size {
+        max_page_size if max_page_size > 0 => max_page_size,
+        _ => DEFAULT_MIN_PAGE_SIZE,
+    };
+    page_size.clamp(DEFAULT_MIN_PAGE_SIZE.min(max_page_size), max_page_size)
+}
+
 pub fn quote_identifier(identifier: &str) -> String {
     identifier
         .split('.')
@@ -61,6 +85,389 @@ where
     }
 }
 
+/// Value type of a model field, inferred from its serialized default value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    /// The default value serializes to `null` (e.g. `Option<T>`), so the type is unknown
+    Unknown,
+}
+
+/// Name and inferred value type of a model field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMeta {
+    pub name: String,
+    pub field_type: FieldType,
+}
+
+pub fn get_struct_field_meta<T>() -> Vec<FieldMeta>
+where
+    T: Default + Serialize,
+{
+    let default_value = T::default();
+    let Ok(Value::Object(map)) = serde_json::to_value(default_value) else {
+        return vec![];
+    };
+
+    map.into_iter()
+        .map(|(name, value)| {
+            let field_type = match value {
+                Value::String(_) => FieldType::String,
+                Value::Number(_) => FieldType::Number,
+                Value::Bool(_) => FieldType::Boolean,
+                Value::Array(_) => FieldType::Array,
+                Value::Object(_) => FieldType::Object,
+                Value::Null => FieldType::Unknown,
+            };
+            FieldMeta { name, field_type }
+        })
+        .collect()
+}
+
+/// Resolves the column to sort by for the model `T`.
+///
+/// The default sort column (`created_at`) falls back to the first conventional
+/// column present on the model: `created_at`, then `updated_at`, then `id`.
+/// Any other column, or a model without any of these columns, is returned unchanged.
+/// Callers only resolve the sort column when no sort was requested, so that an
+/// explicit `created_at` sort is never rewritten.
+pub fn resolve_sort_column<T>(sort_column: &str) -> String
+where
+    T: Default + Serialize,
+{
+    if sort_column != DEFAULT_SORT_COLUMN_NAME {
+        return sort_column.to_string();
+    }
+
+    let fields = get_struct_field_meta::<T>();
+    DEFAULT_SORT_COLUMN_FALLBACK_NAMES
+        .iter()
+        .find(|name| fields.iter().any(|field| field.name == **name))
+        .map_or_else(|| sort_column.to_string(), |name| name.to_string())
+}
+
+/// Builds a `CASE` expression ranking the values of a sort term in the given
+/// order, with values missing from the order ranked last. The values are inlined
+/// as quoted string literals. An empty order leaves the term as is, since a
+/// `CASE` needs at least one branch.
+pub fn build_sort_order_case(term: &str, order: &[String]) -> String {
+    if order.is_empty() {
+        return term.to_string();
+    }
+
+    let branches: String = order
+        .iter()
+        .enumerate()
+        .map(|(rank, value)| format!(" WHEN '{}' THEN {}", value.replace('\'', "''"), rank))
+        .collect();
+
+    format!("CASE {}{} ELSE {} END", term, branches, order.len())
+}
+
+/// Names of the model fields whose default value serializes to a boolean.
+pub fn get_struct_boolean_field_names<T>() -> Vec<String>
+where
+    T: Default + Serialize,
+{
+    get_struct_field_meta::<T>()
+        .into_iter()
+        .filter(|field| field.field_type == FieldType::Boolean)
+        .map(|field| field.name)
+        .collect()
+}
+
+/// Whether a model field may hold a date or time: its default value serializes
+/// to a date, time or timestamp, or to `null` (e.g. `Option<DateTime<Utc>>`), when
+/// its type is unknown.
+pub fn is_struct_date_time_field<T>(field: &str) -> bool
+where
+    T: Default + Serialize,
+{
+    let Ok(Value::Object(map)) = serde_json::to_value(T::default()) else {
+        return false;
+    };
+
+    match map.get(field) {
+        Some(Value::Null) => true,
+        Some(Value::String(value)) => {
+            DateTime::parse_from_rfc3339(value).is_ok()
+                || NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+                || NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+                || NaiveTime::parse_from_str(value, "%H:%M:%S%.f").is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Hex encodes a string, for opaque tokens handed to clients.
+pub fn encode_hex(value: &str) -> String {
+    value.bytes().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes a string hex encoded by `encode_hex`, returning `None` for invalid
+/// hex or UTF-8.
+pub fn decode_hex(value: &str) -> Option<String> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let bytes = (0..value.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(value.get(idx..idx + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+/// Parses the common textual forms of a boolean filter value, including the
+/// `on`/`off` values sent by HTML checkboxes. Matching is case-insensitive.
+pub fn parse_boolean_value(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "t" | "1" | "on" | "yes" => Some(true),
+        "false" | "f" | "0" | "off" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Number of single character insertions, deletions, substitutions or adjacent
+/// transpositions needed to turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// Rewrites the bind placeholders of `sql` starting with `marker` (`$` or `?`),
+/// leaving string literals and quoted identifiers untouched.
+///
+/// `rewrite` receives the position written after the marker, if any, and returns
+/// the replacement placeholder. `$` markers without a position are kept as is,
+/// since they start dollar-quoted strings rather than placeholders.
+pub fn rewrite_placeholders(
+    sql: &str,
+    marker: char,
+    mut rewrite: impl FnMut(Option<usize>) -> String,
+) -> String {
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if let Some(open) = quote {
+            if c == open {
+                quote = None;
+            }
+            rewritten.push(c);
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            quote = Some(c);
+            rewritten.push(c);
+            continue;
+        }
+
+        if c != marker {
+            rewritten.push(c);
+            continue;
+        }
+
+        let mut digits = String::new();
+        while let Some(digit) = chars.peek().filter(|next| next.is_ascii_digit()) {
+            digits.push(*digit);
+            chars.next();
+        }
+
+        match digits.parse::<usize>().ok() {
+            None if marker == '$' => rewritten.push(c),
+            position => rewritten.push_str(&rewrite(position)),
+        }
+    }
+
+    rewritten
+}
+
+/// Rewrites the references to columns of `table` in `sql`, e.g. `users.id` or
+/// `"users".id`, to columns of `alias`, leaving string literals untouched.
+///
+/// Identifiers are compared case-insensitively unless quoted, and only
+/// identifiers followed by a `.` are references. Schema-qualified names, e.g.
+/// `public.users.id`, are kept as is. A clause joining `table` itself without an
+/// alias, e.g. `JOIN users ON users.id = base_query.parent_id`, is kept as is too,
+/// since its references are to the joined rows.
+pub fn requalify_table_references(sql: &str, table: &str, alias: &str) -> String {
+    if joins_table_unaliased(sql, table) {
+        return sql.to_string();
+    }
+
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut after_dot = false;
+
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            rewritten.push(c);
+            for next in chars.by_ref() {
+                rewritten.push(next);
+                if next == '\'' {
+                    break;
+                }
+            }
+            after_dot = false;
+            continue;
+        }
+
+        let (identifier, quoted) = if c == '"' || c == '`' {
+            let mut identifier = String::new();
+            let mut closed = false;
+            for next in chars.by_ref() {
+                if next == c {
+                    closed = true;
+                    break;
+                }
+                identifier.push(next);
+            }
+            if !closed {
+                rewritten.push(c);
+                rewritten.push_str(&identifier);
+                break;
+            }
+            (identifier, Some(c))
+        } else if c.is_alphanumeric() || c == '_' {
+            let mut identifier = c.to_string();
+            while let Some(next) = chars
+                .peek()
+                .filter(|next| next.is_alphanumeric() || **next == '_' || **next == '$')
+            {
+                identifier.push(*next);
+                chars.next();
+            }
+            (identifier, None)
+        } else {
+            rewritten.push(c);
+            after_dot = c == '.';
+            continue;
+        };
+
+        let matches = match quoted {
+            Some(_) => identifier == table,
+            None => identifier.eq_ignore_ascii_case(table),
+        };
+        if matches && !after_dot && chars.peek() == Some(&'.') {
+            rewritten.push_str(alias);
+        } else if let Some(quote) = quoted {
+            rewritten.push(quote);
+            rewritten.push_str(&identifier);
+            rewritten.push(quote);
+        } else {
+            rewritten.push_str(&identifier);
+        }
+        after_dot = false;
+    }
+
+    rewritten
+}
+
+/// Whether `sql` joins `table`, optionally schema-qualified, without an alias,
+/// so that `table.column` refers to the joined rows.
+fn joins_table_unaliased(sql: &str, table: &str) -> bool {
+    let tokens = sql_tokens(sql);
+    let is_word = |token: &SqlToken, word: &str| matches!(token, SqlToken::Word(value, false) if value.eq_ignore_ascii_case(word));
+
+    tokens.iter().enumerate().any(|(index, token)| {
+        if !is_word(token, "JOIN") {
+            return false;
+        }
+
+        // The joined name is the last part of a dotted name
+        let mut position = index + 1;
+        while matches!(tokens.get(position + 1), Some(SqlToken::Dot)) {
+            position += 2;
+        }
+        let joined = match tokens.get(position) {
+            Some(SqlToken::Word(name, true)) => name == table,
+            Some(SqlToken::Word(name, false)) => name.eq_ignore_ascii_case(table),
+            _ => false,
+        };
+
+        joined
+            && match tokens.get(position + 1) {
+                Some(next @ SqlToken::Word(_, false)) => {
+                    is_word(next, "ON") || is_word(next, "USING")
+                }
+                Some(SqlToken::Word(_, true)) => false,
+                _ => true,
+            }
+    })
+}
+
+/// A token of `sql_tokens`: an identifier or keyword, quoted or not, a `.`, or
+/// any other character outside string literals.
+enum SqlToken {
+    Word(String, bool),
+    Dot,
+    Other,
+}
+
+/// Splits SQL into words, dots and other characters, skipping whitespace and
+/// string literals.
+fn sql_tokens(sql: &str) -> Vec<SqlToken> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                for next in chars.by_ref() {
+                    if next == '\'' {
+                        break;
+                    }
+                }
+                tokens.push(SqlToken::Other);
+            }
+            '"' | '`' => {
+                let identifier: String = chars.by_ref().take_while(|next| *next != c).collect();
+                tokens.push(SqlToken::Word(identifier, true));
+            }
+            '.' => tokens.push(SqlToken::Dot),
+            _ if c.is_alphanumeric() || c == '_' => {
+                let mut word = c.to_string();
+                while let Some(next) = chars
+                    .peek()
+                    .filter(|next| next.is_alphanumeric() || **next == '_' || **next == '$')
+                {
+                    word.push(*next);
+                    chars.next();
+                }
+                tokens.push(SqlToken::Word(word, false));
+            }
+            _ if c.is_whitespace() => {}
+            _ => tokens.push(SqlToken::Other),
+        }
+    }
+
+    tokens
+}
+
 pub fn extract_digits_from_strings(val: impl Into<String>) -> String {
     val.into().chars().filter(|c| c.is_ascii_digit()).collect()
 }
@@ -69,10 +476,58 @@ pub fn extract_digits_from_strings(val: impl Into<String>) -> String {
 #[allow(clippy::unwrap_used, clippy::indexing_slicing)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_is_struct_date_time_field() {
+        #[derive(Serialize, Default)]
+        struct Event {
+            name: String,
+            starts_at: chrono::DateTime<chrono::Utc>,
+            day: NaiveDate,
+            ended_at: Option<String>,
+            attendees: i64,
+        }
+
+        assert!(is_struct_date_time_field::<Event>("starts_at"));
+        assert!(is_struct_date_time_field::<Event>("day"));
+        assert!(is_struct_date_time_field::<Event>("ended_at"));
+        assert!(!is_struct_date_time_field::<Event>("name"));
+        assert!(!is_struct_date_time_field::<Event>("attendees"));
+        assert!(!is_struct_date_time_field::<Event>("missing"));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("page_size", "page_size"), 0);
+        assert_eq!(edit_distance("page_siez", "page_size"), 1);
+        assert_eq!(edit_distance("pag", "page"), 1);
+        assert_eq!(edit_distance("name", "page"), 2);
+        assert_eq!(edit_distance("", "page"), 4);
+    }
     use crate::paginated_query_as::internal::DEFAULT_MIN_PAGE_SIZE;
     use crate::paginated_query_as::models::QuerySortDirection;
     use serde::Serialize;
 
+    #[test]
+    fn test_hex_round_trip() {
+        assert_eq!(encode_hex("offset:20"), "6f66667365743a3230");
+        assert_eq!(decode_hex(&encode_hex("héllo")).as_deref(), Some("héllo"));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+        assert_eq!(decode_hex("ff"), None);
+    }
+
+    #[test]
+    fn test_build_sort_order_case() {
+        let order = vec!["high".to_string(), "it's".to_string()];
+
+        assert_eq!(
+            build_sort_order_case("\"priority\"", &order),
+            "CASE \"priority\" WHEN 'high' THEN 0 WHEN 'it''s' THEN 1 ELSE 2 END"
+        );
+        assert_eq!(build_sort_order_case("\"priority\"", &[]), "\"priority\"");
+    }
+
     #[test]
     fn test_default_page() {
         assert_eq!(default_page(), DEFAULT_PAGE);
@@ -85,6 +540,17 @@ mod tests {
         assert_eq!(default_page_size(), 10);
     }
 
+    #[test]
+    fn test_clamp_page_size_to() {
+        assert_eq!(clamp_page_size_to(500, 200), 200);
+        assert_eq!(clamp_page_size_to(75, 200), 75);
+        assert_eq!(clamp_page_size_to(5, 200), DEFAULT_MIN_PAGE_SIZE);
+        assert_eq!(clamp_page_size_to(40, 5), 5);
+        assert_eq!(clamp_page_size_to(40, 0), DEFAULT_MIN_PAGE_SIZE);
+        assert_eq!(clamp_page_size_to(40, -3), DEFAULT_MIN_PAGE_SIZE);
+        assert_eq!(clamp_page_size(500), DEFAULT_MAX_PAGE_SIZE);
+    }
+
     #[test]
     fn test_default_search_columns() {
         let columns = default_search_columns();
@@ -211,6 +677,70 @@ mod tests {
         assert!(get_struct_field_names::<UnitStruct>().is_empty());
     }
 
+    #[test]
+    fn test_get_struct_field_meta() {
+        #[derive(Default, Serialize)]
+        struct MetaStruct {
+            id: i32,
+            name: String,
+            active: bool,
+            tags: Vec<String>,
+            deleted_at: Option<String>,
+        }
+
+        let meta = get_struct_field_meta::<MetaStruct>();
+        let field_type = |name: &str| {
+            meta.iter()
+                .find(|field| field.name == name)
+                .map(|field| field.field_type)
+        };
+
+        assert_eq!(meta.len(), 5);
+        assert_eq!(field_type("id"), Some(FieldType::Number));
+        assert_eq!(field_type("name"), Some(FieldType::String));
+        assert_eq!(field_type("active"), Some(FieldType::Boolean));
+        assert_eq!(field_type("tags"), Some(FieldType::Array));
+        assert_eq!(field_type("deleted_at"), Some(FieldType::Unknown));
+        assert!(get_struct_field_meta::<EmptyStruct>().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_sort_column_fallbacks() {
+        #[derive(Default, Serialize)]
+        struct WithCreatedAt {
+            id: i32,
+            created_at: String,
+            updated_at: String,
+        }
+
+        #[derive(Default, Serialize)]
+        struct WithUpdatedAt {
+            id: i32,
+            updated_at: String,
+        }
+
+        #[derive(Default, Serialize)]
+        struct WithId {
+            id: i32,
+            name: String,
+        }
+
+        assert_eq!(
+            resolve_sort_column::<WithCreatedAt>("created_at"),
+            "created_at"
+        );
+        assert_eq!(
+            resolve_sort_column::<WithUpdatedAt>("created_at"),
+            "updated_at"
+        );
+        assert_eq!(resolve_sort_column::<WithId>("created_at"), "id");
+        assert_eq!(
+            resolve_sort_column::<EmptyStruct>("created_at"),
+            "created_at"
+        );
+        assert_eq!(resolve_sort_column::<WithId>("name"), "name");
+    }
+
     #[test]
     fn test_extract_digits_from_strings() {
         assert_eq!(extract_digits_from_strings("123abc456"), "123456");
@@ -219,4 +749,95 @@ mod tests {
         assert_eq!(extract_digits_from_strings(String::from("12.34")), "1234");
         assert_eq!(extract_digits_from_strings("page=5"), "5");
     }
+
+    #[test]
+    fn test_parse_boolean_value() {
+        for value in ["true", "T", "1", "on", "ON", "yes"] {
+            assert_eq!(parse_boolean_value(value), Some(true), "{}", value);
+        }
+        for value in ["false", "f", "0", "off", "Off", "no"] {
+            assert_eq!(parse_boolean_value(value), Some(false), "{}", value);
+        }
+        assert_eq!(parse_boolean_value(""), None);
+        assert_eq!(parse_boolean_value("maybe"), None);
+    }
+
+    #[test]
+    fn test_get_struct_boolean_field_names() {
+        #[derive(Default, Serialize)]
+        struct Flags {
+            name: String,
+            active: bool,
+            confirmed: bool,
+            archived: Option<bool>,
+        }
+
+        assert_eq!(
+            get_struct_boolean_field_names::<Flags>(),
+            vec!["active".to_string(), "confirmed".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_placeholders() {
+        assert_eq!(
+            rewrite_placeholders("a = $1 AND b = '$2' AND c = $2", '$', |position| {
+                format!("${}", position.unwrap() + 3)
+            }),
+            "a = $4 AND b = '$2' AND c = $5"
+        );
+        assert_eq!(
+            rewrite_placeholders("$$body$$ = $1", '$', |_| "$9".to_string()),
+            "$$body$$ = $9"
+        );
+
+        let mut next = 0;
+        assert_eq!(
+            rewrite_placeholders("a = ? AND \"b?\" = ? AND c = '?'", '?', |_| {
+                next += 1;
+                format!("?{}", next)
+            }),
+            "a = ?1 AND \"b?\" = ?2 AND c = '?'"
+        );
+    }
+
+    #[test]
+    fn test_requalify_table_references() {
+        assert_eq!(
+            requalify_table_references(
+                "LEFT JOIN orders ON orders.user_id = users.id AND \"users\".tenant = 'users.x'",
+                "users",
+                "base_query"
+            ),
+            "LEFT JOIN orders ON orders.user_id = base_query.id AND base_query.tenant = 'users.x'"
+        );
+        assert_eq!(
+            requalify_table_references(
+                "JOIN users managers ON managers.id = USERS.manager_id AND public.users.id = my_users.id",
+                "users",
+                "base_query"
+            ),
+            "JOIN users managers ON managers.id = base_query.manager_id AND public.users.id = my_users.id"
+        );
+
+        // The references of a clause joining the table unaliased are to the joined rows
+        for join in [
+            "LEFT JOIN users ON users.id = base_query.parent_id",
+            "JOIN public.\"users\" USING (id)",
+            "JOIN users",
+        ] {
+            assert_eq!(
+                requalify_table_references(join, "users", "base_query"),
+                join
+            );
+        }
+        assert_eq!(
+            requalify_table_references(
+                "JOIN users AS parents ON parents.id = users.parent_id",
+                "users",
+                "base_query"
+            ),
+            "JOIN users AS parents ON parents.id = base_query.parent_id"
+        );
+    }
 }
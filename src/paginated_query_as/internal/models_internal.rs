@@ -1,23 +1,64 @@
 use crate::paginated_query_as::internal::{
-    default_date_range_column, default_page, default_page_size, default_search_columns,
-    default_sort_column, default_sort_direction, page_deserialize, page_size_deserialize,
-    search_columns_deserialize, search_deserialize,
+    clamp_page_size, clamp_page_size_to, default_date_range_column, default_page,
+    default_page_size, default_search_columns, default_sort_column, default_sort_direction,
+    min_should_match_deserialize, order_by_deserialize, order_by_serialize, page_deserialize,
+    requested_page_size_deserialize, search_columns_deserialize, search_deserialize,
+    sort_deserialize,
 };
 
-use crate::QuerySortDirection;
+use crate::{QueryNullsPosition, QuerySortDirection, SearchAnchor, SearchMode};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", from = "RequestedPaginationParams")]
 pub struct QueryPaginationParams {
-    #[serde(deserialize_with = "page_deserialize", default = "default_page")]
     pub page: i64,
-    #[serde(
-        deserialize_with = "page_size_deserialize",
-        default = "default_page_size"
-    )]
     pub page_size: i64,
+
+    /// Page size as requested, before clamping, so that `with_max_page_size` can
+    /// clamp it to another maximum
+    #[serde(skip)]
+    pub(crate) requested_page_size: Option<i64>,
+
+    /// Offset of the first record, set from a `page_token`, taking precedence
+    /// over the offset of `page`
+    #[serde(skip)]
+    pub(crate) token_offset: Option<i64>,
+}
+
+impl QueryPaginationParams {
+    /// Pagination for the requested page size, clamped with `clamp_page_size`.
+    pub(crate) fn requested(page: i64, page_size: i64) -> Self {
+        Self {
+            page,
+            page_size: clamp_page_size(page_size),
+            requested_page_size: Some(page_size),
+            token_offset: None,
+        }
+    }
+
+    /// Starts the page at the offset of a `page_token`, reporting the page
+    /// containing it.
+    pub(crate) fn start_at(&mut self, offset: i64) {
+        self.token_offset = Some(offset);
+        self.page = offset / self.page_size.max(1) + 1;
+    }
+
+    /// Returns the offset of the first record of the page.
+    pub(crate) fn offset(&self) -> i64 {
+        self.token_offset
+            .unwrap_or((self.page - 1) * self.page_size)
+    }
+
+    /// Clamps the requested page size with `clamp_page_size_to` instead.
+    pub(crate) fn clamp_to(&mut self, max_page_size: i64) {
+        let page_size = self.requested_page_size.unwrap_or(self.page_size);
+        self.page_size = clamp_page_size_to(page_size, max_page_size);
+        if let Some(offset) = self.token_offset {
+            self.start_at(offset);
+        }
+    }
 }
 
 impl Default for QueryPaginationParams {
@@ -25,17 +66,122 @@ impl Default for QueryPaginationParams {
         Self {
             page: default_page(),
             page_size: default_page_size(),
+            requested_page_size: None,
+            token_offset: None,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// `page`/`page_size` query string parameters, before the page size is clamped.
+#[derive(Deserialize)]
 #[serde(rename_all = "snake_case")]
+struct RequestedPaginationParams {
+    #[serde(deserialize_with = "page_deserialize", default = "default_page")]
+    page: i64,
+    #[serde(deserialize_with = "requested_page_size_deserialize", default)]
+    page_size: Option<i64>,
+}
+
+impl From<RequestedPaginationParams> for QueryPaginationParams {
+    fn from(params: RequestedPaginationParams) -> Self {
+        match params.page_size {
+            Some(page_size) => Self::requested(params.page, page_size),
+            None => Self {
+                page: params.page,
+                ..Self::default()
+            },
+        }
+    }
+}
+
+/// A single column of a multi-column sort.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct QuerySort {
+    pub column: String,
+    pub direction: QuerySortDirection,
+    /// Where NULLs are placed, set by `QueryParamsBuilder::with_sort_nulls`
+    #[serde(default)]
+    pub nulls: QueryNullsPosition,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case", from = "RawQuerySortParams")]
 pub struct QuerySortParams {
-    #[serde(default = "default_sort_direction")]
     pub sort_direction: QuerySortDirection,
-    #[serde(default = "default_sort_column")]
     pub sort_column: String,
+    /// Ordered sort columns parsed from `order_by`, or else from `sort`, or set by
+    /// repeated `QueryParamsBuilder::with_sort` calls, taking precedence over
+    /// `sort_column`/`sort_direction` when not empty
+    #[serde(
+        serialize_with = "order_by_serialize",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub order_by: Vec<QuerySort>,
+    /// Whether the sort was supplied by the caller rather than defaulted
+    #[serde(skip)]
+    pub(crate) explicit: bool,
+    /// Whether `sort_column` is sorted by its lowercased value
+    #[serde(skip)]
+    pub(crate) case_insensitive: bool,
+}
+
+/// Sort parameters as received, keeping track of which ones were supplied.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct RawQuerySortParams {
+    sort_direction: Option<QuerySortDirection>,
+    sort_column: Option<String>,
+    #[serde(default, deserialize_with = "order_by_deserialize")]
+    order_by: Vec<QuerySort>,
+    #[serde(default, deserialize_with = "sort_deserialize")]
+    sort: Vec<QuerySort>,
+}
+
+impl From<RawQuerySortParams> for QuerySortParams {
+    fn from(raw: RawQuerySortParams) -> Self {
+        let order_by = if raw.order_by.is_empty() {
+            raw.sort
+        } else {
+            raw.order_by
+        };
+        let explicit = raw.sort_column.is_some() || !order_by.is_empty();
+
+        Self {
+            sort_direction: raw.sort_direction.unwrap_or_else(default_sort_direction),
+            sort_column: raw.sort_column.unwrap_or_else(default_sort_column),
+            order_by,
+            explicit,
+            case_insensitive: false,
+        }
+    }
+}
+
+impl QuerySortParams {
+    /// Whether a sort column was supplied by the caller, through `sort_column`,
+    /// `order_by`, `sort` or `QueryParamsBuilder::with_sort`, rather than defaulted.
+    pub fn is_explicit(&self) -> bool {
+        self.explicit
+    }
+
+    /// Whether `sort_column` is sorted by its lowercased value, as set by
+    /// `QueryParamsBuilder::with_sort_case_insensitive`. Never applies to `order_by`.
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive && self.order_by.is_empty()
+    }
+
+    /// Returns the sort columns in order of precedence.
+    pub fn sorts(&self) -> Vec<QuerySort> {
+        if self.order_by.is_empty() {
+            vec![QuerySort {
+                column: self.sort_column.clone(),
+                direction: self.sort_direction.clone(),
+                nulls: QueryNullsPosition::Default,
+            }]
+        } else {
+            self.order_by.clone()
+        }
+    }
 }
 
 impl Default for QuerySortParams {
@@ -43,6 +189,9 @@ impl Default for QuerySortParams {
         Self {
             sort_direction: default_sort_direction(),
             sort_column: default_sort_column(),
+            order_by: Vec::new(),
+            explicit: false,
+            case_insensitive: false,
         }
     }
 }
@@ -57,6 +206,58 @@ pub struct QuerySearchParams {
         default = "default_search_columns"
     )]
     pub search_columns: Option<Vec<String>>,
+    #[serde(default)]
+    pub search_mode: SearchMode,
+    #[serde(default)]
+    pub search_anchor: SearchAnchor,
+    /// Minimum number of search tokens a row must match, on any column
+    #[serde(default, deserialize_with = "min_should_match_deserialize")]
+    pub min_should_match: Option<usize>,
+    /// Postgres text search configuration enabling full-text search, e.g. `english`
+    #[serde(skip)]
+    pub full_text_config: Option<String>,
+}
+
+impl QuerySearchParams {
+    /// Returns the distinct search columns, in the order they were given, so a
+    /// column listed twice is only searched once.
+    pub fn columns(&self) -> Vec<&str> {
+        let mut columns: Vec<&str> = Vec::new();
+        for column in self.search_columns.iter().flatten() {
+            if !columns.contains(&column.as_str()) {
+                columns.push(column);
+            }
+        }
+        columns
+    }
+
+    /// Returns the terms searched for: the whole search term for
+    /// `SearchMode::Phrase`, or every whitespace separated token for
+    /// `SearchMode::AnyTokenAnyColumn`. With `min_should_match` the term is always
+    /// split into tokens. Blank or missing terms have no terms.
+    pub fn terms(&self) -> Vec<&str> {
+        let Some(search) = self
+            .search
+            .as_deref()
+            .filter(|search| !search.trim().is_empty())
+        else {
+            return Vec::new();
+        };
+
+        match self.search_mode {
+            SearchMode::Phrase if self.min_should_match.is_none() => vec![search],
+            SearchMode::Phrase => search.split_whitespace().collect(),
+            SearchMode::AnyTokenAnyColumn => search.split_whitespace().collect(),
+        }
+    }
+
+    /// Returns the LIKE patterns to bind for the search terms, in the order of `terms`.
+    pub fn patterns(&self) -> Vec<String> {
+        self.terms()
+            .into_iter()
+            .map(|term| self.search_anchor.pattern(term))
+            .collect()
+    }
 }
 
 impl Default for QuerySearchParams {
@@ -64,6 +265,10 @@ impl Default for QuerySearchParams {
         Self {
             search: None,
             search_columns: default_search_columns(),
+            search_mode: SearchMode::default(),
+            search_anchor: SearchAnchor::default(),
+            min_should_match: None,
+            full_text_config: None,
         }
     }
 }
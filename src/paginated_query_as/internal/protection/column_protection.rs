@@ -4,6 +4,9 @@ use crate::paginated_query_as::internal::protection::COLUMN_PROTECTION_BLOCKED_P
 #[cfg(feature = "sqlite")]
 use crate::paginated_query_as::internal::protection::COLUMN_PROTECTION_BLOCKED_SQLITE;
 
+#[cfg(feature = "mysql")]
+use crate::paginated_query_as::internal::protection::COLUMN_PROTECTION_BLOCKED_MYSQL;
+
 use std::collections::HashSet;
 
 /// Protects columns against SQL injection and system table access
@@ -24,7 +27,11 @@ impl Default for ColumnProtection {
         {
             Self::for_sqlite()
         }
-        #[cfg(not(any(feature = "postgres", feature = "sqlite")))]
+        #[cfg(all(feature = "mysql", not(any(feature = "postgres", feature = "sqlite"))))]
+        {
+            Self::for_mysql()
+        }
+        #[cfg(not(any(feature = "postgres", feature = "sqlite", feature = "mysql")))]
         {
             Self::new()
         }
@@ -64,6 +71,18 @@ impl ColumnProtection {
         protection
     }
 
+    #[cfg(feature = "mysql")]
+    /// Creates protection with MySQL-specific blocked patterns
+    pub fn for_mysql() -> Self {
+        let mut protection = Self::new();
+        protection.blocked_patterns.extend(
+            COLUMN_PROTECTION_BLOCKED_MYSQL
+                .iter()
+                .map(|&s| s.to_string()),
+        );
+        protection
+    }
+
     #[allow(dead_code)]
     pub fn block_pattern(&mut self, pattern: impl Into<String>) {
         self.blocked_patterns.insert(pattern.into());
@@ -192,6 +211,23 @@ mod tests {
         assert!(protection.is_safe("ctid"));
     }
 
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn test_mysql_specific_protection() {
+        let protection = ColumnProtection::for_mysql();
+
+        // Should block MySQL system schemas
+        assert!(!protection.is_safe("information_schema.tables"));
+        assert!(!protection.is_safe("performance_schema.threads"));
+        assert!(!protection.is_safe("mysql.user"));
+        assert!(!protection.is_safe("sys.processlist"));
+
+        // Should allow regular columns
+        assert!(protection.is_safe("user_id"));
+        assert!(protection.is_safe("users.created_at"));
+        assert!(protection.is_safe("mysql_version"));
+    }
+
     #[test]
     fn test_custom_patterns() {
         let mut protection = ColumnProtection::new();
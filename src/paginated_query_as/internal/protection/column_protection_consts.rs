@@ -31,3 +31,12 @@ pub static COLUMN_PROTECTION_BLOCKED_SQLITE: [&str; 7] = [
     "rowid",
     "_rowid_",
 ];
+
+#[cfg(feature = "mysql")]
+pub static COLUMN_PROTECTION_BLOCKED_MYSQL: [&str; 4] = [
+    // System schemas
+    "information_schema.",
+    "performance_schema.",
+    "mysql.",
+    "sys.",
+];
@@ -40,3 +40,63 @@ macro_rules! paginated_query_as {
         $crate::paginated_query_as::<$type, $db>($query)
     }};
 }
+
+/// Creates a column name that is checked against the model at compile time.
+///
+/// Expands to the stringified field name, but fails to compile when the field
+/// does not exist on the model, catching typos in `with_sort`/`with_filter`
+/// column names before they reach the database.
+///
+/// Note that the Rust field name is used, so fields renamed with
+/// `#[serde(rename = "...")]` should still be referenced by their serialized name.
+/// `#[derive(PaginatedColumns)]`, with the `derive` feature, generates typed
+/// columns named as the model serializes them instead.
+///
+/// # Syntax
+///
+/// ```ignore
+/// col!(Type, field)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use sqlx_paginated::{col, QueryParamsBuilder, QuerySortDirection};
+///
+/// #[derive(Serialize, Default)]
+/// struct User {
+///     name: String,
+///     created_at: String,
+/// }
+///
+/// assert_eq!(col!(User, created_at), "created_at");
+///
+/// let params = QueryParamsBuilder::<User>::new()
+///     .with_sort(col!(User, created_at), QuerySortDirection::Ascending)
+///     .with_filter(col!(User, name), Some("John"))
+///     .build();
+/// ```
+///
+/// Referencing a field that does not exist fails to compile:
+///
+/// ```compile_fail
+/// use sqlx_paginated::col;
+///
+/// #[derive(Default)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// let column = col!(User, created_at);
+/// ```
+#[macro_export]
+macro_rules! col {
+    ($type:ty, $field:ident) => {{
+        // Never called: only type-checks that `$field` exists on `$type`
+        let _ = |value: &$type| {
+            let _ = &value.$field;
+        };
+        stringify!($field)
+    }};
+}
@@ -1,12 +1,19 @@
+#[cfg(feature = "axum")]
+pub mod axum;
 mod builders;
 mod database_query_defaults;
 mod examples;
+mod in_memory;
 mod internal;
 mod r#macro;
 mod models;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod utils;
 
 pub use builders::*;
 pub use database_query_defaults::*;
+pub use in_memory::*;
+pub use internal::{FieldType, QueryDialect};
 pub use models::*;
 pub use utils::*;
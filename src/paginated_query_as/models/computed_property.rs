@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+/// A named SQL expression that can be selected into results or used as a sort key.
+///
+/// Computed properties let a listing sort by a derived value, such as the most
+/// recent of several timestamps (`GREATEST(updated_at, created_at)`), without
+/// adding that value to the base query. The expression is trusted SQL supplied
+/// by the developer and is never built from request input; requests only
+/// reference a computed property by its registered name.
+///
+/// Use `ComputedPropertyBuilder` to attach joins, tie breakers or aggregation.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::ComputedProperty;
+///
+/// let last_activity = ComputedProperty::new("last_activity", "GREATEST(updated_at, created_at)");
+///
+/// assert_eq!(last_activity.name, "last_activity");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedProperty {
+    /// Name used to reference the property from `sort_column` and as the selected column alias
+    pub name: String,
+
+    /// SQL expression evaluated against `base_query` rows
+    pub expression: String,
+
+    /// JOIN clauses required by the expression, added only when the property is active
+    pub joins: Vec<String>,
+
+    /// Values bound to the `?` tokens of JOIN clauses, keyed by clause
+    pub join_values: HashMap<String, Vec<String>>,
+
+    /// Column used to order rows whose computed values are equal
+    pub tie_breaker: Option<String>,
+
+    /// Whether the expression is added to the records query selection
+    pub selected: bool,
+
+    /// Whether the expression is an aggregate, requiring the records to be grouped
+    pub aggregate: bool,
+
+    /// Whether clients can search the property by naming it in `search_columns`
+    pub searchable: bool,
+}
+
+impl ComputedProperty {
+    /// Creates a computed property from a name and a SQL expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name used to reference the property from sort parameters
+    /// * `expression` - SQL expression producing the value, e.g. `GREATEST(updated_at, created_at)`
+    pub fn new(name: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            expression: expression.into(),
+            joins: Vec::new(),
+            join_values: HashMap::new(),
+            tie_breaker: None,
+            selected: false,
+            aggregate: false,
+            searchable: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_computed_property() {
+        let property = ComputedProperty::new("last_activity", "GREATEST(updated_at, created_at)");
+
+        assert_eq!(property.name, "last_activity");
+        assert_eq!(property.expression, "GREATEST(updated_at, created_at)");
+        assert!(property.joins.is_empty());
+        assert!(property.join_values.is_empty());
+        assert!(property.tie_breaker.is_none());
+        assert!(!property.selected);
+        assert!(!property.aggregate);
+        assert!(!property.searchable);
+    }
+}
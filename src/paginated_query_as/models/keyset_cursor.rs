@@ -0,0 +1,80 @@
+use crate::paginated_query_as::internal::{decode_hex, encode_hex};
+
+/// The sort key of the last record of a page in keyset pagination, handed to
+/// clients as the opaque `PaginatedResponse::next_cursor`, or of the first record
+/// as the `previous_cursor`.
+///
+/// The key holds the values of the sort column and of the tie breaker column,
+/// in their textual form, and is hex encoded so clients treat it as opaque.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::KeysetCursor;
+///
+/// let cursor = KeysetCursor {
+///     value: "2024-01-01T00:00:00Z".to_string(),
+///     tie_breaker: "42".to_string(),
+/// };
+///
+/// assert_eq!(KeysetCursor::decode(&cursor.encode()), Some(cursor));
+/// assert_eq!(KeysetCursor::decode("not a cursor"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetCursor {
+    /// Value of the sort column
+    pub value: String,
+
+    /// Value of the tie breaker column
+    pub tie_breaker: String,
+}
+
+/// Which side of the cursor a keyset page is fetched from, set with
+/// `PaginatedQueryBuilder::with_cursor_direction`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeysetDirection {
+    /// The records sorted after the cursor, e.g. from a `next_cursor`
+    #[default]
+    Forward,
+
+    /// The records sorted before the cursor, e.g. from a `previous_cursor`
+    Backward,
+}
+
+impl KeysetCursor {
+    /// Encodes the cursor for a `next_cursor` or `previous_cursor` response field.
+    pub fn encode(&self) -> String {
+        encode_hex(&serde_json::json!([self.value, self.tie_breaker]).to_string())
+    }
+
+    /// Decodes a cursor, returning `None` for cursors not produced by `encode`.
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let (value, tie_breaker): (String, String) =
+            serde_json::from_str(&decode_hex(cursor)?).ok()?;
+
+        Some(Self { value, tie_breaker })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_values() {
+        let cursor = KeysetCursor {
+            value: "O'Brien, \"Jr\"".to_string(),
+            tie_breaker: "7".to_string(),
+        };
+
+        assert_eq!(KeysetCursor::decode(&cursor.encode()), Some(cursor));
+    }
+
+    #[test]
+    fn test_rejects_foreign_cursors() {
+        assert_eq!(KeysetCursor::decode(""), None);
+        assert_eq!(KeysetCursor::decode("zz"), None);
+        assert_eq!(KeysetCursor::decode(&encode_hex("[\"a\"]")), None);
+        assert_eq!(KeysetCursor::decode(&encode_hex("[1, 2]")), None);
+    }
+}
@@ -1,9 +1,31 @@
+mod computed_property;
+mod keyset_cursor;
+mod page_token;
+mod paginated_columns;
+mod pagination_context;
+mod pagination_error;
 mod query_filter;
+mod query_param_error;
 mod query_params;
 mod query_response;
+mod query_search;
 mod query_sort;
+mod where_tree;
 
-pub use query_filter::{QueryFilterCondition, QueryFilterOperator};
-pub use query_params::{FlatQueryParams, QueryParams};
-pub use query_response::PaginatedResponse;
-pub use query_sort::QuerySortDirection;
+pub use computed_property::ComputedProperty;
+pub use keyset_cursor::{KeysetCursor, KeysetDirection};
+pub use page_token::PageToken;
+pub use paginated_columns::PaginatedColumns;
+pub use pagination_context::{CountEvent, PaginationContext};
+pub use pagination_error::PaginationError;
+pub use query_filter::{
+    FilterLogic, QueryFilterCondition, QueryFilterGroup, QueryFilterOperator, RangeBoundCheck,
+};
+pub use query_param_error::{ParamError, ValidationConfig};
+pub use query_params::{FlatFilterGroup, FlatQueryParams, QueryParams};
+pub use query_response::{
+    ExecutedSql, KeyedPaginatedResponse, MatchInfo, PageMeta, PaginatedResponse,
+};
+pub use query_search::{SearchAnchor, SearchMode, SearchOptions};
+pub use query_sort::{QueryNullsPosition, QuerySortDirection};
+pub use where_tree::WhereTree;
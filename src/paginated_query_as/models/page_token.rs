@@ -0,0 +1,81 @@
+use crate::paginated_query_as::internal::{decode_hex, encode_hex};
+
+/// A `page_token`, for APIs following AIP-158 style pagination with
+/// `page_token`/`page_size` request fields and a `next_page_token` response field.
+///
+/// The token holds the offset of the first record of the page, hex encoded.
+/// Pages start at that offset, so changing `page_size` between requests
+/// continues from the same record.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::PageToken;
+///
+/// let token = PageToken { offset: 40 }.encode();
+///
+/// assert_eq!(PageToken::decode(&token), Some(PageToken { offset: 40 }));
+/// assert_eq!(PageToken::decode("not a token"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageToken {
+    /// Offset of the first record of the page
+    pub offset: i64,
+}
+
+const PAGE_TOKEN_PREFIX: &str = "offset:";
+
+impl PageToken {
+    /// Encodes the token for a `next_page_token` response field.
+    pub fn encode(&self) -> String {
+        encode_hex(&format!("{}{}", PAGE_TOKEN_PREFIX, self.offset))
+    }
+
+    /// Decodes a `page_token` request field, returning `None` for tokens not
+    /// produced by `encode` or holding a negative offset.
+    pub fn decode(token: &str) -> Option<Self> {
+        let offset = decode_hex(token)?
+            .strip_prefix(PAGE_TOKEN_PREFIX)?
+            .parse::<i64>()
+            .ok()?;
+
+        (offset >= 0).then_some(Self { offset })
+    }
+
+    /// Returns the page of the given size starting at or containing the offset.
+    pub fn page(&self, page_size: i64) -> i64 {
+        self.offset / page_size.max(1) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_offsets() {
+        for offset in [0, 1, 20, i64::MAX] {
+            let token = PageToken { offset }.encode();
+            assert_eq!(PageToken::decode(&token), Some(PageToken { offset }));
+        }
+    }
+
+    #[test]
+    fn test_rejects_foreign_and_negative_tokens() {
+        assert_eq!(PageToken::decode(""), None);
+        assert_eq!(PageToken::decode("abc"), None);
+        assert_eq!(PageToken::decode("6f66667365743a"), None);
+        assert_eq!(PageToken::decode("zz"), None);
+        assert_eq!(PageToken::decode("3230"), None);
+        assert_eq!(PageToken::decode(&PageToken { offset: -1 }.encode()), None);
+    }
+
+    #[test]
+    fn test_page_for_page_size() {
+        let token = PageToken { offset: 20 };
+
+        assert_eq!(token.page(10), 3);
+        assert_eq!(token.page(20), 2);
+        assert_eq!(token.page(30), 1);
+    }
+}
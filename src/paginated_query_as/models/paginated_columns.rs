@@ -0,0 +1,62 @@
+/// A model whose columns are referenced through a typed `Column`, rather than by
+/// their names as strings.
+///
+/// With the `derive` feature, `#[derive(PaginatedColumns)]` generates a
+/// `{Model}Columns` enum holding a variant per serialized field, named as the
+/// model serializes it (honouring `#[serde(rename)]`, `#[serde(rename_all)]` and
+/// `#[serde(skip)]`), and implements the trait with it. The columns are then handed
+/// to `QueryParamsBuilder::with_sort_by`, `with_filter_by` and `with_search_in`, so
+/// a misspelled or removed column fails to compile instead of being skipped. As
+/// they convert into `String`, they're also accepted by every builder method taking
+/// a column name.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use serde::Serialize;
+/// use sqlx_paginated::{PaginatedColumns, QueryParamsBuilder, QuerySortDirection};
+///
+/// #[derive(Serialize, Default, PaginatedColumns)]
+/// struct User {
+///     #[serde(rename = "full_name")]
+///     name: String,
+///     status: String,
+///     created_at: String,
+/// }
+///
+/// assert_eq!(UserColumns::Name.as_str(), "full_name");
+///
+/// let params = QueryParamsBuilder::<User>::new()
+///     .with_sort_by(UserColumns::CreatedAt, QuerySortDirection::Descending)
+///     .with_filter_by(UserColumns::Status, Some("active"))
+///     .with_search_in("john", vec![UserColumns::Name])
+///     .build();
+///
+/// assert_eq!(params.sort.sort_column, "created_at");
+/// assert!(params.filters.contains_key("status"));
+/// # }
+/// ```
+///
+/// Fields serialized conditionally, with `#[serde(skip_serializing_if)]`, fail to
+/// compile, as the query parameters only accept them when the default value of the
+/// model serializes them:
+///
+/// ```compile_fail
+/// use serde::Serialize;
+/// use sqlx_paginated::PaginatedColumns;
+///
+/// #[derive(Serialize, Default, PaginatedColumns)]
+/// struct User {
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     nickname: Option<String>,
+/// }
+/// ```
+pub trait PaginatedColumns {
+    /// The typed column of the model
+    type Column: Copy + Into<String> + 'static;
+
+    /// Returns every column of the model, in field order.
+    fn columns() -> &'static [Self::Column];
+}
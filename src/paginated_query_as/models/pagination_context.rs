@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A total counted, or reused, by a builder sharing a `PaginationContext`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountEvent<'a> {
+    /// The count query the total is for
+    pub count_sql: &'a str,
+
+    /// Number of records matching the filters
+    pub total: i64,
+
+    /// Whether the total was reused from the context instead of counted
+    pub cached: bool,
+}
+
+type CountObserver = Arc<dyn Fn(&CountEvent) + Send + Sync>;
+
+/// Request-scoped state shared by several paginated queries, set on each builder
+/// with `PaginatedQueryBuilder::with_context`.
+///
+/// The context caches the totals counted by its builders, so that a builder running
+/// the same count query with the same search, filters and date range reuses the
+/// total instead of counting again. Pages and sorts don't change the total and are
+/// ignored. Values bound by the query building function from outside the
+/// parameters aren't part of the cache key, so a context should not outlive the
+/// request they belong to.
+///
+/// An optional observer is told of every total, counted or reused.
+///
+/// Clones share the same cache and observer.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx::{FromRow, Postgres};
+/// use serde::Serialize;
+/// use sqlx_paginated::{paginated_query_as, PaginationContext};
+///
+/// #[derive(Serialize, FromRow, Default)]
+/// struct UserExample {
+///     name: String
+/// }
+///
+/// let context = PaginationContext::new().with_observer(|event| {
+///     println!("total {} (cached: {})", event.total, event.cached);
+/// });
+///
+/// let users = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+///     .with_context(&context);
+/// let active_users = paginated_query_as::<UserExample, Postgres>("SELECT * FROM users")
+///     .with_context(&context);
+/// ```
+#[derive(Clone, Default)]
+pub struct PaginationContext {
+    totals: Arc<Mutex<HashMap<String, i64>>>,
+    observer: Option<CountObserver>,
+}
+
+impl PaginationContext {
+    /// Creates a context with an empty total cache and no observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the function told of every total counted or reused by the builders
+    /// sharing the context.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - Function receiving each `CountEvent`
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_observer<F>(mut self, observer: F) -> Self
+    where
+        F: Fn(&CountEvent) + Send + Sync + 'static,
+    {
+        self.observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Returns the total cached for the key, if any.
+    pub(crate) fn cached_total(&self, key: &str) -> Option<i64> {
+        self.totals
+            .lock()
+            .ok()
+            .and_then(|totals| totals.get(key).copied())
+    }
+
+    /// Caches the total counted for the key.
+    pub(crate) fn cache_total(&self, key: String, total: i64) {
+        if let Ok(mut totals) = self.totals.lock() {
+            totals.insert(key, total);
+        }
+    }
+
+    /// Tells the observer, if any, of a total.
+    pub(crate) fn observe(&self, event: &CountEvent) {
+        if let Some(observer) = &self.observer {
+            observer(event);
+        }
+    }
+}
+
+impl fmt::Debug for PaginationContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PaginationContext")
+            .field("totals", &self.totals)
+            .field("observer", &self.observer.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_totals_and_observer() {
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&observed);
+        let context = PaginationContext::new()
+            .with_observer(move |event| sink.lock().unwrap().push((event.total, event.cached)));
+        let sibling = context.clone();
+
+        context.cache_total("count".to_string(), 42);
+        assert_eq!(sibling.cached_total("count"), Some(42));
+        assert_eq!(sibling.cached_total("other"), None);
+
+        sibling.observe(&CountEvent {
+            count_sql: "SELECT COUNT(*) FROM users",
+            total: 42,
+            cached: true,
+        });
+        assert_eq!(*observed.lock().unwrap(), vec![(42, true)]);
+    }
+}
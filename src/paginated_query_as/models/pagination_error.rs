@@ -0,0 +1,87 @@
+use std::fmt;
+
+/// A guardrail configured on `PaginatedQueryBuilder` rejected the query parameters.
+///
+/// These checks run before any query is sent. The fetch methods return
+/// `sqlx::Error`, so the error is carried as `sqlx::Error::Configuration` and can
+/// be recovered with `PaginationError::from_sqlx_error`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::PaginationError;
+///
+/// let error: sqlx::Error = PaginationError::MissingSort.into();
+/// assert_eq!(
+///     PaginationError::from_sqlx_error(&error),
+///     Some(&PaginationError::MissingSort)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaginationError {
+    /// No sort column was supplied while an explicit sort is required
+    MissingSort,
+    /// The requested page starts past the maximum offset set with `with_max_offset`
+    OffsetTooLarge { offset: i64, max_offset: i64 },
+    /// A builder feature binds arguments out of statement order, which the
+    /// positional placeholders of the database can't refer to
+    UnorderedArguments { feature: &'static str },
+    /// The `with_distinct_on` columns aren't the leading sort columns
+    UnsortedDistinctOn { columns: Vec<String> },
+    /// No condition was supplied on a field set with `require_filter`
+    MissingRequiredFilter { field: String },
+    /// A [NOT] BETWEEN condition has a low bound greater than its high bound while
+    /// `with_range_bound_check` rejects inverted ranges
+    InvalidRange { column: String },
+}
+
+impl PaginationError {
+    /// Returns the pagination error carried by a `sqlx::Error`, if any.
+    pub fn from_sqlx_error(error: &sqlx::Error) -> Option<&PaginationError> {
+        match error {
+            sqlx::Error::Configuration(source) => source.downcast_ref::<PaginationError>(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PaginationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginationError::MissingSort => write!(f, "an explicit sort column is required"),
+            PaginationError::OffsetTooLarge { offset, max_offset } => write!(
+                f,
+                "offset {} exceeds the maximum of {}, use keyset pagination for deep pages",
+                offset, max_offset
+            ),
+            PaginationError::UnorderedArguments { feature } => write!(
+                f,
+                "{} binds arguments out of statement order, which this database doesn't support",
+                feature
+            ),
+            PaginationError::UnsortedDistinctOn { columns } => write!(
+                f,
+                "DISTINCT ON columns {} must lead the sort",
+                columns.join(", ")
+            ),
+            PaginationError::MissingRequiredFilter { field } => {
+                write!(f, "a filter on {} is required", field)
+            }
+            PaginationError::InvalidRange { column } => {
+                write!(
+                    f,
+                    "the range on {} has a low bound above its high bound",
+                    column
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PaginationError {}
+
+impl From<PaginationError> for sqlx::Error {
+    fn from(error: PaginationError) -> Self {
+        sqlx::Error::Configuration(Box::new(error))
+    }
+}
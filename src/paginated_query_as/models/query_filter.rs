@@ -84,6 +84,20 @@ pub enum QueryFilterOperator {
     #[serde(alias = "nin", alias = "not_in")]
     NotIn,
 
+    /// BETWEEN clause - value within an inclusive range
+    ///
+    /// The bounds should be two comma-separated strings.
+    /// Example: `price BETWEEN 10 AND 100`
+    #[serde(alias = "between")]
+    Between,
+
+    /// NOT BETWEEN clause - value outside an inclusive range
+    ///
+    /// The bounds should be two comma-separated strings.
+    /// Example: `price NOT BETWEEN 10 AND 100`
+    #[serde(alias = "not_between")]
+    NotBetween,
+
     /// IS NULL check
     ///
     /// Example: `deleted_at IS NULL`
@@ -96,6 +110,30 @@ pub enum QueryFilterOperator {
     #[serde(alias = "is_not_null", alias = "not_null")]
     IsNotNull,
 
+    /// IS TRUE check, excluding NULL values
+    ///
+    /// Example: `confirmed IS TRUE` (`confirmed = 1` on SQLite)
+    #[serde(alias = "is_true")]
+    IsTrue,
+
+    /// IS FALSE check, excluding NULL values
+    ///
+    /// Example: `confirmed IS FALSE` (`confirmed = 0` on SQLite)
+    #[serde(alias = "is_false")]
+    IsFalse,
+
+    /// IS NOT TRUE check, including NULL values
+    ///
+    /// Example: `confirmed IS NOT TRUE` (`confirmed IS NOT 1` on SQLite)
+    #[serde(alias = "is_not_true", alias = "not_true")]
+    IsNotTrue,
+
+    /// IS NOT FALSE check, including NULL values
+    ///
+    /// Example: `confirmed IS NOT FALSE` (`confirmed IS NOT 0` on SQLite)
+    #[serde(alias = "is_not_false", alias = "not_false")]
+    IsNotFalse,
+
     /// LIKE pattern matching (case-insensitive)
     ///
     /// Supports SQL wildcards: `%` (any characters) and `_` (single character)
@@ -108,6 +146,35 @@ pub enum QueryFilterOperator {
     /// Example: `email NOT LIKE '%@spam.com'`
     #[serde(alias = "not_like", alias = "nlike")]
     NotLike,
+
+    /// Regular expression match (case-sensitive)
+    ///
+    /// Example: `sku ~ '^[A-Z]{3}-\d+$'` (`sku REGEXP ...` on SQLite, which needs
+    /// a `REGEXP` function registered by the application)
+    Regex,
+
+    /// Regular expression match (case-insensitive)
+    ///
+    /// Example: `sku ~* '^[a-z]{3}-\d+$'`
+    IRegex,
+
+    /// Contains all the elements of an array, or the keys and values of a JSON
+    /// document (Postgres only)
+    ///
+    /// Example: `tags::text[] @> '{sale,new}'::text[]`, `metadata @> '{"color":"red"}'::jsonb`
+    Contains,
+
+    /// Is contained by an array or a JSON document (Postgres only)
+    ///
+    /// Example: `tags::text[] <@ '{sale,new,featured}'::text[]`
+    #[serde(alias = "contained_by")]
+    ContainedBy,
+
+    /// Shares at least one element with an array (Postgres only)
+    ///
+    /// Example: `tags::text[] && '{sale,new}'::text[]`
+    #[serde(alias = "overlap")]
+    Overlaps,
 }
 
 impl QueryFilterOperator {
@@ -122,24 +189,98 @@ impl QueryFilterOperator {
             QueryFilterOperator::LessOrEqual => "<=",
             QueryFilterOperator::In => "IN",
             QueryFilterOperator::NotIn => "NOT IN",
+            QueryFilterOperator::Between => "BETWEEN",
+            QueryFilterOperator::NotBetween => "NOT BETWEEN",
             QueryFilterOperator::IsNull => "IS NULL",
             QueryFilterOperator::IsNotNull => "IS NOT NULL",
+            QueryFilterOperator::IsTrue => "IS TRUE",
+            QueryFilterOperator::IsFalse => "IS FALSE",
+            QueryFilterOperator::IsNotTrue => "IS NOT TRUE",
+            QueryFilterOperator::IsNotFalse => "IS NOT FALSE",
             QueryFilterOperator::Like => "LIKE",
             QueryFilterOperator::NotLike => "NOT LIKE",
+            QueryFilterOperator::Regex => "~",
+            QueryFilterOperator::IRegex => "~*",
+            QueryFilterOperator::Contains => "@>",
+            QueryFilterOperator::ContainedBy => "<@",
+            QueryFilterOperator::Overlaps => "&&",
         }
     }
 
-    /// Returns true if the operator requires a value (excludes IS NULL/IS NOT NULL).
+    /// Returns the operator as written in `field[op]` query string keys.
+    pub fn to_query_key(&self) -> &'static str {
+        match self {
+            QueryFilterOperator::Equal => "eq",
+            QueryFilterOperator::NotEqual => "ne",
+            QueryFilterOperator::GreaterThan => "gt",
+            QueryFilterOperator::GreaterOrEqual => "gte",
+            QueryFilterOperator::LessThan => "lt",
+            QueryFilterOperator::LessOrEqual => "lte",
+            QueryFilterOperator::In => "in",
+            QueryFilterOperator::NotIn => "nin",
+            QueryFilterOperator::Between => "between",
+            QueryFilterOperator::NotBetween => "not_between",
+            QueryFilterOperator::IsNull => "is_null",
+            QueryFilterOperator::IsNotNull => "is_not_null",
+            QueryFilterOperator::IsTrue => "is_true",
+            QueryFilterOperator::IsFalse => "is_false",
+            QueryFilterOperator::IsNotTrue => "is_not_true",
+            QueryFilterOperator::IsNotFalse => "is_not_false",
+            QueryFilterOperator::Like => "like",
+            QueryFilterOperator::NotLike => "not_like",
+            QueryFilterOperator::Regex => "regex",
+            QueryFilterOperator::IRegex => "iregex",
+            QueryFilterOperator::Contains => "contains",
+            QueryFilterOperator::ContainedBy => "contained_by",
+            QueryFilterOperator::Overlaps => "overlaps",
+        }
+    }
+
+    /// Returns true if the operator requires a value (excludes IS NULL/IS NOT NULL
+    /// and the IS [NOT] TRUE/FALSE checks).
     pub fn requires_value(&self) -> bool {
         !matches!(
             self,
-            QueryFilterOperator::IsNull | QueryFilterOperator::IsNotNull
+            QueryFilterOperator::IsNull
+                | QueryFilterOperator::IsNotNull
+                | QueryFilterOperator::IsTrue
+                | QueryFilterOperator::IsFalse
+                | QueryFilterOperator::IsNotTrue
+                | QueryFilterOperator::IsNotFalse
         )
     }
 
-    /// Returns true if the operator accepts multiple values (IN/NOT IN).
+    /// Returns the truth value and whether it is negated, for the IS [NOT]
+    /// TRUE/FALSE checks.
+    pub(crate) fn boolean_test(&self) -> Option<(bool, bool)> {
+        match self {
+            QueryFilterOperator::IsTrue => Some((true, false)),
+            QueryFilterOperator::IsFalse => Some((false, false)),
+            QueryFilterOperator::IsNotTrue => Some((true, true)),
+            QueryFilterOperator::IsNotFalse => Some((false, true)),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the operator compares arrays or JSON documents (`@>`, `<@`, `&&`).
+    pub fn is_containment(&self) -> bool {
+        matches!(
+            self,
+            QueryFilterOperator::Contains
+                | QueryFilterOperator::ContainedBy
+                | QueryFilterOperator::Overlaps
+        )
+    }
+
+    /// Returns true if the operator accepts multiple values (IN/NOT IN/[NOT] BETWEEN).
     pub fn accepts_multiple_values(&self) -> bool {
-        matches!(self, QueryFilterOperator::In | QueryFilterOperator::NotIn)
+        matches!(
+            self,
+            QueryFilterOperator::In
+                | QueryFilterOperator::NotIn
+                | QueryFilterOperator::Between
+                | QueryFilterOperator::NotBetween
+        )
     }
 
     /// Parses an operator from a string representation.
@@ -153,20 +294,44 @@ impl QueryFilterOperator {
     /// Returns the corresponding `QueryFilterOperator` or defaults to `Equal` if not recognized.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
+        Self::parse(s).unwrap_or_default()
+    }
+
+    /// Parses an operator from a string representation, rejecting unknown operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - String representation of the operator
+    ///
+    /// # Returns
+    ///
+    /// Returns the corresponding `QueryFilterOperator`, or `None` if not recognized.
+    pub fn parse(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
-            "eq" | "equal" => QueryFilterOperator::Equal,
-            "ne" | "neq" | "not_equal" => QueryFilterOperator::NotEqual,
-            "gt" | "greater_than" => QueryFilterOperator::GreaterThan,
-            "gte" | "greater_or_equal" => QueryFilterOperator::GreaterOrEqual,
-            "lt" | "less_than" => QueryFilterOperator::LessThan,
-            "lte" | "less_or_equal" => QueryFilterOperator::LessOrEqual,
-            "in" => QueryFilterOperator::In,
-            "nin" | "not_in" => QueryFilterOperator::NotIn,
-            "is_null" | "null" => QueryFilterOperator::IsNull,
-            "is_not_null" | "not_null" => QueryFilterOperator::IsNotNull,
-            "like" => QueryFilterOperator::Like,
-            "not_like" | "nlike" => QueryFilterOperator::NotLike,
-            _ => QueryFilterOperator::Equal,
+            "eq" | "equal" => Some(QueryFilterOperator::Equal),
+            "ne" | "neq" | "not_equal" => Some(QueryFilterOperator::NotEqual),
+            "gt" | "greater_than" => Some(QueryFilterOperator::GreaterThan),
+            "gte" | "greater_or_equal" => Some(QueryFilterOperator::GreaterOrEqual),
+            "lt" | "less_than" => Some(QueryFilterOperator::LessThan),
+            "lte" | "less_or_equal" => Some(QueryFilterOperator::LessOrEqual),
+            "in" => Some(QueryFilterOperator::In),
+            "nin" | "not_in" => Some(QueryFilterOperator::NotIn),
+            "between" => Some(QueryFilterOperator::Between),
+            "not_between" | "nbetween" => Some(QueryFilterOperator::NotBetween),
+            "is_null" | "null" => Some(QueryFilterOperator::IsNull),
+            "is_not_null" | "not_null" => Some(QueryFilterOperator::IsNotNull),
+            "is_true" => Some(QueryFilterOperator::IsTrue),
+            "is_false" => Some(QueryFilterOperator::IsFalse),
+            "is_not_true" | "not_true" => Some(QueryFilterOperator::IsNotTrue),
+            "is_not_false" | "not_false" => Some(QueryFilterOperator::IsNotFalse),
+            "like" => Some(QueryFilterOperator::Like),
+            "not_like" | "nlike" => Some(QueryFilterOperator::NotLike),
+            "regex" => Some(QueryFilterOperator::Regex),
+            "iregex" => Some(QueryFilterOperator::IRegex),
+            "contains" => Some(QueryFilterOperator::Contains),
+            "contained_by" | "containedby" => Some(QueryFilterOperator::ContainedBy),
+            "overlaps" | "overlap" => Some(QueryFilterOperator::Overlaps),
+            _ => None,
         }
     }
 }
@@ -187,6 +352,11 @@ pub struct QueryFilterCondition {
     /// - For IN/NOT IN: comma-separated values (e.g., "admin,moderator,user")
     /// - For IS NULL/IS NOT NULL: ignored (can be None)
     pub value: Option<String>,
+
+    /// Whether the value is bound without a type cast, set by
+    /// `QueryParamsBuilder::with_filter_raw_value`
+    #[serde(skip)]
+    pub(crate) raw_value: bool,
 }
 
 impl QueryFilterCondition {
@@ -200,9 +370,15 @@ impl QueryFilterCondition {
         Self {
             operator,
             value: value.map(Into::into),
+            raw_value: false,
         }
     }
 
+    /// Whether the value is bound as is, without a type cast.
+    pub fn is_raw_value(&self) -> bool {
+        self.raw_value
+    }
+
     /// Creates an equality filter condition.
     pub fn equal(value: impl Into<String>) -> Self {
         Self::new(QueryFilterOperator::Equal, Some(value))
@@ -257,6 +433,24 @@ impl QueryFilterCondition {
         Self::new(QueryFilterOperator::NotIn, Some(value))
     }
 
+    /// Creates a BETWEEN filter condition, matching values from `low` to `high`
+    /// inclusive.
+    pub fn between(low: impl Into<String>, high: impl Into<String>) -> Self {
+        Self::new(
+            QueryFilterOperator::Between,
+            Some(format!("{},{}", low.into(), high.into())),
+        )
+    }
+
+    /// Creates a NOT BETWEEN filter condition, matching values outside `low` to
+    /// `high` inclusive.
+    pub fn not_between(low: impl Into<String>, high: impl Into<String>) -> Self {
+        Self::new(
+            QueryFilterOperator::NotBetween,
+            Some(format!("{},{}", low.into(), high.into())),
+        )
+    }
+
     /// Creates an IS NULL filter condition.
     pub fn is_null() -> Self {
         Self::new(QueryFilterOperator::IsNull, None::<String>)
@@ -267,6 +461,27 @@ impl QueryFilterCondition {
         Self::new(QueryFilterOperator::IsNotNull, None::<String>)
     }
 
+    /// Creates an IS TRUE filter condition, or IS FALSE when `value` is false.
+    pub fn is(value: bool) -> Self {
+        let operator = if value {
+            QueryFilterOperator::IsTrue
+        } else {
+            QueryFilterOperator::IsFalse
+        };
+        Self::new(operator, None::<String>)
+    }
+
+    /// Creates an IS NOT TRUE filter condition, or IS NOT FALSE when `value` is
+    /// false. Unlike `not_equal`, it matches NULL values.
+    pub fn is_not(value: bool) -> Self {
+        let operator = if value {
+            QueryFilterOperator::IsNotTrue
+        } else {
+            QueryFilterOperator::IsNotFalse
+        };
+        Self::new(operator, None::<String>)
+    }
+
     /// Creates a LIKE filter condition.
     pub fn like(pattern: impl Into<String>) -> Self {
         Self::new(QueryFilterOperator::Like, Some(pattern))
@@ -277,7 +492,44 @@ impl QueryFilterCondition {
         Self::new(QueryFilterOperator::NotLike, Some(pattern))
     }
 
-    /// Splits the value into a vector for IN/NOT IN operations.
+    /// Creates a regular expression filter condition, case-insensitive when
+    /// `case_insensitive` is true.
+    pub fn regex(pattern: impl Into<String>, case_insensitive: bool) -> Self {
+        let operator = if case_insensitive {
+            QueryFilterOperator::IRegex
+        } else {
+            QueryFilterOperator::Regex
+        };
+        Self::new(operator, Some(pattern))
+    }
+
+    /// Creates a containment filter condition, matching arrays holding every
+    /// element of `value`, e.g. `{sale,new}`, or JSON documents holding it, e.g.
+    /// `{"color":"red"}`.
+    pub fn contains(value: impl Into<String>) -> Self {
+        Self::new(QueryFilterOperator::Contains, Some(value))
+    }
+
+    /// Creates a filter condition matching arrays or JSON documents contained by `value`.
+    pub fn contained_by(value: impl Into<String>) -> Self {
+        Self::new(QueryFilterOperator::ContainedBy, Some(value))
+    }
+
+    /// Creates a filter condition matching arrays sharing an element with `value`.
+    pub fn overlaps(value: impl Into<String>) -> Self {
+        Self::new(QueryFilterOperator::Overlaps, Some(value))
+    }
+
+    /// Returns the low and high bounds of a [NOT] BETWEEN condition, or `None` unless
+    /// the value splits into exactly two values.
+    pub fn bounds(&self) -> Option<(String, String)> {
+        match <[String; 2]>::try_from(self.split_values()) {
+            Ok([low, high]) => Some((low, high)),
+            Err(_) => None,
+        }
+    }
+
+    /// Splits the value into a vector for IN/NOT IN/[NOT] BETWEEN operations.
     pub fn split_values(&self) -> Vec<String> {
         if let Some(ref value) = self.value {
             value
@@ -304,6 +556,54 @@ impl From<&str> for QueryFilterCondition {
     }
 }
 
+/// How the conditions of a `QueryFilterGroup` are combined.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterLogic {
+    /// Matches when every condition matches
+    #[default]
+    And,
+
+    /// Matches when any condition matches
+    Or,
+}
+
+impl FilterLogic {
+    /// Returns the SQL operator joining the conditions.
+    pub(crate) fn separator(&self) -> &'static str {
+        match self {
+            FilterLogic::And => " AND ",
+            FilterLogic::Or => " OR ",
+        }
+    }
+}
+
+/// How `PaginatedQueryBuilder::with_range_bound_check` handles a [NOT] BETWEEN condition
+/// whose low bound is greater than its high bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBoundCheck {
+    /// Swaps the bounds, so the condition matches the values between them
+    Swap,
+
+    /// Rejects the parameters with `PaginationError::InvalidRange`
+    Reject,
+}
+
+/// Filter conditions on one or more columns combined by a `FilterLogic`, added
+/// with `QueryParamsBuilder::with_filter_group`.
+///
+/// A group is rendered as a single parenthesized condition, combined with AND
+/// with the other filters, e.g. `("status" = $1 OR "status" = $2)`. Every value
+/// is bound as for the other filters.
+#[derive(Debug, Clone)]
+pub struct QueryFilterGroup {
+    /// How the conditions are combined
+    pub logic: FilterLogic,
+
+    /// The conditions, by column, in the order they were given
+    pub conditions: Vec<(String, QueryFilterCondition)>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,6 +614,7 @@ mod tests {
         assert_eq!(QueryFilterOperator::GreaterThan.to_sql(), ">");
         assert_eq!(QueryFilterOperator::In.to_sql(), "IN");
         assert_eq!(QueryFilterOperator::IsNull.to_sql(), "IS NULL");
+        assert_eq!(QueryFilterOperator::IsNotTrue.to_sql(), "IS NOT TRUE");
     }
 
     #[test]
@@ -322,6 +623,8 @@ mod tests {
         assert!(QueryFilterOperator::GreaterThan.requires_value());
         assert!(!QueryFilterOperator::IsNull.requires_value());
         assert!(!QueryFilterOperator::IsNotNull.requires_value());
+        assert!(!QueryFilterOperator::IsTrue.requires_value());
+        assert!(!QueryFilterOperator::IsNotFalse.requires_value());
     }
 
     #[test]
@@ -347,6 +650,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_operator_parse() {
+        assert_eq!(
+            QueryFilterOperator::parse("NIN"),
+            Some(QueryFilterOperator::NotIn)
+        );
+        assert_eq!(
+            QueryFilterOperator::parse("not_true"),
+            Some(QueryFilterOperator::IsNotTrue)
+        );
+        assert_eq!(
+            QueryFilterOperator::parse("overlap"),
+            Some(QueryFilterOperator::Overlaps)
+        );
+        assert_eq!(QueryFilterOperator::parse("invalid"), None);
+    }
+
+    #[test]
+    fn test_operator_query_key_parses_back() {
+        for operator in [
+            QueryFilterOperator::Equal,
+            QueryFilterOperator::NotEqual,
+            QueryFilterOperator::GreaterThan,
+            QueryFilterOperator::GreaterOrEqual,
+            QueryFilterOperator::LessThan,
+            QueryFilterOperator::LessOrEqual,
+            QueryFilterOperator::In,
+            QueryFilterOperator::NotIn,
+            QueryFilterOperator::Between,
+            QueryFilterOperator::NotBetween,
+            QueryFilterOperator::IsNull,
+            QueryFilterOperator::IsNotNull,
+            QueryFilterOperator::IsTrue,
+            QueryFilterOperator::IsFalse,
+            QueryFilterOperator::IsNotTrue,
+            QueryFilterOperator::IsNotFalse,
+            QueryFilterOperator::Like,
+            QueryFilterOperator::NotLike,
+            QueryFilterOperator::Regex,
+            QueryFilterOperator::IRegex,
+            QueryFilterOperator::Contains,
+            QueryFilterOperator::ContainedBy,
+            QueryFilterOperator::Overlaps,
+        ] {
+            assert_eq!(
+                QueryFilterOperator::parse(operator.to_query_key()),
+                Some(operator)
+            );
+        }
+    }
+
     #[test]
     fn test_filter_condition_constructors() {
         let cond = QueryFilterCondition::equal("test");
@@ -356,6 +710,15 @@ mod tests {
         let cond = QueryFilterCondition::is_null();
         assert_eq!(cond.operator, QueryFilterOperator::IsNull);
         assert_eq!(cond.value, None);
+
+        assert_eq!(
+            QueryFilterCondition::is(false).operator,
+            QueryFilterOperator::IsFalse
+        );
+        assert_eq!(
+            QueryFilterCondition::is_not(true).operator,
+            QueryFilterOperator::IsNotTrue
+        );
     }
 
     #[test]
@@ -368,6 +731,23 @@ mod tests {
         assert_eq!(values, vec!["admin", "moderator", "user"]);
     }
 
+    #[test]
+    fn test_filter_condition_between_bounds() {
+        let cond = QueryFilterCondition::between("10", "100");
+        assert_eq!(cond.operator, QueryFilterOperator::Between);
+        assert_eq!(cond.value, Some("10,100".to_string()));
+        assert_eq!(cond.bounds(), Some(("10".to_string(), "100".to_string())));
+
+        let cond = QueryFilterCondition::not_between("10", "100");
+        assert_eq!(cond.operator, QueryFilterOperator::NotBetween);
+        assert_eq!(cond.bounds(), Some(("10".to_string(), "100".to_string())));
+
+        for value in ["", "10", "10,", "10,20,30"] {
+            let cond = QueryFilterCondition::new(QueryFilterOperator::Between, Some(value));
+            assert_eq!(cond.bounds(), None, "{}", value);
+        }
+    }
+
     #[test]
     fn test_filter_condition_from_string() {
         let cond: QueryFilterCondition = "test_value".into();
@@ -0,0 +1,98 @@
+use std::fmt;
+
+/// A problem found while validating query parameters against a model.
+///
+/// Returned by `FlatQueryParams::validate` so that web handlers can reject
+/// invalid requests with specific messages before touching the database.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::ParamError;
+///
+/// let error = ParamError::UnknownColumn { column: "secret".to_string() };
+/// assert_eq!(error.to_string(), "unknown column `secret`");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// A filter, search or sort column that does not exist on the model
+    UnknownColumn { column: String },
+
+    /// A filter operator (`field[op]=...`) that is not recognized
+    UnknownOperator { column: String, operator: String },
+
+    /// A filter value that cannot be compared with a numeric column
+    InvalidNumericValue { column: String, value: String },
+
+    /// An unknown key resembling a pagination, sort, search or date range
+    /// parameter, such as `page_siez`, rather than a filter column
+    MisspelledParam { key: String, expected: String },
+
+    /// A `page_token` that was not produced by `PageToken::encode`
+    InvalidPageToken { token: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::UnknownColumn { column } => write!(f, "unknown column `{}`", column),
+            ParamError::UnknownOperator { column, operator } => {
+                write!(f, "unknown operator `{}` for column `{}`", operator, column)
+            }
+            ParamError::InvalidNumericValue { column, value } => {
+                write!(
+                    f,
+                    "non-numeric value `{}` for numeric column `{}`",
+                    value, column
+                )
+            }
+            ParamError::MisspelledParam { key, expected } => {
+                write!(
+                    f,
+                    "unknown parameter `{}`, did you mean `{}`?",
+                    key, expected
+                )
+            }
+            ParamError::InvalidPageToken { token } => write!(f, "invalid page token `{}`", token),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// Settings of the validating conversion from `FlatQueryParams` into `QueryParams`,
+/// `QueryParams::try_from((&params, config))`.
+///
+/// The default reports every problem `FlatQueryParams::validate` does.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use sqlx_paginated::{FlatQueryParams, QueryParams, ValidationConfig};
+///
+/// #[derive(Serialize, Default)]
+/// struct Product {
+///     name: String,
+///     price: f64,
+///     created_at: String,
+/// }
+///
+/// let flat: FlatQueryParams =
+///     serde_json::from_str(r#"{"price[gt]": "10", "utm_source": "newsletter"}"#).unwrap();
+/// let config = ValidationConfig {
+///     ignore_unknown_filters: true,
+/// };
+///
+/// let params = QueryParams::<Product>::try_from((&flat, config)).unwrap();
+/// assert!(params.filters.contains_key("price"));
+/// assert!(!params.filters.contains_key("utm_source"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidationConfig {
+    /// Whether filters on columns missing from the model are dropped instead of
+    /// reported, e.g. for tracking parameters sent along with the query string.
+    /// Misspelled pagination, sort, search and date range parameters are still
+    /// reported.
+    pub ignore_unknown_filters: bool,
+}
@@ -1,9 +1,18 @@
 use crate::paginated_query_as::internal::{
-    deserialize_filter_map, QueryDateRangeParams, QueryPaginationParams, QuerySearchParams,
-    QuerySortParams,
+    deserialize_filter_map, edit_distance, format_order_by, get_struct_field_meta,
+    get_struct_field_names, parse_filter_entry, resolve_sort_column, FieldType,
+    QueryDateRangeParams, QueryPaginationParams, QuerySearchParams, QuerySortParams,
+    DEFAULT_RESERVED_PARAM_NAMES, DEFAULT_SEARCH_COLUMN_NAME_SEPARATOR_SYMBOL,
 };
-use crate::paginated_query_as::models::QueryFilterCondition;
-use serde::{Deserialize, Serialize};
+use crate::paginated_query_as::models::{
+    FilterLogic, PageToken, ParamError, QueryFilterCondition, QueryFilterGroup,
+    QueryFilterOperator, ValidationConfig,
+};
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -43,6 +52,12 @@ pub struct FlatQueryParams {
     #[serde(flatten)]
     pub pagination: Option<QueryPaginationParams>,
 
+    /// AIP-158 style page token, starting the page at its offset instead of `page`
+    ///
+    /// See `PageToken`. Invalid tokens are ignored, and reported by `validate`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<String>,
+
     /// Sort parameters (sort_column, sort_direction)
     #[serde(flatten)]
     pub sort: Option<QuerySortParams>,
@@ -55,17 +70,349 @@ pub struct FlatQueryParams {
     #[serde(flatten)]
     pub date_range: Option<QueryDateRangeParams>,
 
-    /// Filter conditions with operators
+    /// Comma separated fields filtered as tri-state checkboxes
+    ///
+    /// See `QueryParamsBuilder::with_checkbox_filters`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkbox_filters: Option<String>,
+
+    /// Groups of filter conditions, each combined by its own logic
+    ///
+    /// Only available in JSON and the compact form, e.g.
+    /// `{"logic": "or", "conditions": [{"status": "active"}, {"status": "pending"}]}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_groups: Option<Vec<FlatFilterGroup>>,
+
+    /// Filter conditions with operators, by column
     ///
     /// Supports operator syntax in query strings:
     /// - `field=value` → Equality
     /// - `field[gt]=value` → Greater than
     /// - `field[in]=val1,val2` → In list
     /// - `field[is_null]=` → Is null
+    /// - `field[gte]=val1&field[lte]=val2` → Both conditions, combined with AND
     ///
     /// See `QueryFilterOperator` for all supported operators.
     #[serde(flatten, deserialize_with = "deserialize_filter_map")]
-    pub filters: Option<HashMap<String, QueryFilterCondition>>,
+    pub filters: Option<HashMap<String, Vec<QueryFilterCondition>>>,
+}
+
+/// A group of filter conditions in the flat form, see `QueryFilterGroup`.
+///
+/// Every condition is an object keyed like a filter, `field` or `field[op]`, holding
+/// a single condition each.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FlatFilterGroup {
+    /// How the conditions are combined, AND unless given
+    #[serde(default)]
+    pub logic: FilterLogic,
+
+    /// The conditions of the group, by `field` or `field[op]` key
+    #[serde(default)]
+    pub conditions: Vec<HashMap<String, Option<String>>>,
+}
+
+/// URL safe base64 of the compact form, encoded without padding and decoded
+/// with or without it.
+const COMPACT_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+impl FlatQueryParams {
+    /// Parses parameters packed into a single compact string, the base64 encoded
+    /// JSON object of the flat parameters, e.g. `?q=eyJwYWdlIjoiMiJ9`.
+    ///
+    /// The JSON is parsed like a JSON body, so it holds the same keys as a query
+    /// string. Both the URL safe and the standard base64 alphabets are accepted,
+    /// with or without padding. See `QueryParams::to_compact` for the encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `serde_json::Error` when the string isn't base64, or doesn't
+    /// decode to a JSON object of parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::{FlatQueryParams, QueryParams};
+    ///
+    /// // {"page":"2","status[ne]":"deleted"}
+    /// let flat = FlatQueryParams::from_compact("eyJwYWdlIjoiMiIsInN0YXR1c1tuZV0iOiJkZWxldGVkIn0")
+    ///     .unwrap();
+    /// let params: QueryParams<()> = flat.into();
+    ///
+    /// assert_eq!(params.pagination.page, 2);
+    /// assert_eq!(params.filters["status"][0].value.as_deref(), Some("deleted"));
+    /// assert!(FlatQueryParams::from_compact("not base64!").is_err());
+    /// ```
+    pub fn from_compact(compact: &str) -> Result<Self, serde_json::Error> {
+        let normalized = compact.trim().replace('+', "-").replace('/', "_");
+        let json = COMPACT_ENGINE
+            .decode(normalized)
+            .map_err(serde::de::Error::custom)?;
+
+        serde_json::from_slice(&json)
+    }
+
+    /// Converts into `QueryParams` like `From`, without building the deprecated
+    /// `simple_filters`, for callers that have migrated to `filters`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::{FlatQueryParams, QueryParams};
+    ///
+    /// let params: FlatQueryParams = serde_json::from_str(r#"{"status": "active"}"#).unwrap();
+    /// let params: QueryParams<()> = params.without_legacy_filters();
+    ///
+    /// assert!(params.filters.contains_key("status"));
+    /// ```
+    pub fn without_legacy_filters<'q, T>(self) -> QueryParams<'q, T> {
+        into_query_params(self, false)
+    }
+
+    /// Validates the parameters against the columns of a model without touching the database.
+    ///
+    /// Checks that every filter, search and sort column exists on `T`, that every
+    /// filter operator is recognized, and that filter values for numeric columns
+    /// are numeric. Search columns are only checked when a search term is present.
+    ///
+    /// Unknown keys are collected as filters when deserializing, so a misspelled
+    /// parameter such as `page_siez` would otherwise silently become a filter on a
+    /// missing column. Unknown filter columns resembling a reserved parameter are
+    /// reported as `ParamError::MisspelledParam`, other ones as `UnknownColumn`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the parameters are valid, or every problem found otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{FlatQueryParams, ParamError};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct Product {
+    ///     name: String,
+    ///     price: f64,
+    ///     created_at: String,
+    /// }
+    ///
+    /// let params: FlatQueryParams = serde_json::from_str(r#"{"price[gt]": "cheap"}"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     params.validate::<Product>(),
+    ///     Err(vec![ParamError::InvalidNumericValue {
+    ///         column: "price".to_string(),
+    ///         value: "cheap".to_string(),
+    ///     }])
+    /// );
+    /// ```
+    pub fn validate<T>(&self) -> Result<(), Vec<ParamError>>
+    where
+        T: Default + Serialize,
+    {
+        self.validate_with::<T>(&ValidationConfig::default())
+    }
+
+    /// Validates the parameters like `validate`, according to the given settings.
+    fn validate_with<T>(&self, config: &ValidationConfig) -> Result<(), Vec<ParamError>>
+    where
+        T: Default + Serialize,
+    {
+        let fields = get_struct_field_meta::<T>();
+        let field_type = |column: &str| {
+            fields
+                .iter()
+                .find(|field| field.name == column)
+                .map(|field| field.field_type)
+        };
+        let mut errors = Vec::new();
+        let check_column = |column: &str, errors: &mut Vec<ParamError>| {
+            let known = field_type(column).is_some();
+            if !known {
+                errors.push(ParamError::UnknownColumn {
+                    column: column.to_string(),
+                });
+            }
+            known
+        };
+
+        if let Some(sort) = &self.sort {
+            for column in sort.sorts().into_iter().map(|sort| sort.column) {
+                if sort.is_explicit() {
+                    check_column(&column, &mut errors);
+                } else {
+                    check_column(&resolve_sort_column::<T>(&column), &mut errors);
+                }
+            }
+        }
+
+        if let Some(search) = &self.search {
+            if search.search.is_some() {
+                for column in search.columns() {
+                    check_column(column, &mut errors);
+                }
+            }
+        }
+
+        if let Some(token) = &self.page_token {
+            if PageToken::decode(token).is_none() {
+                errors.push(ParamError::InvalidPageToken {
+                    token: token.clone(),
+                });
+            }
+        }
+
+        for column in self.checkbox_columns() {
+            check_column(column, &mut errors);
+        }
+
+        let mut filters: Vec<(&str, Vec<&QueryFilterCondition>)> = self
+            .filters
+            .iter()
+            .flatten()
+            .map(|(key, conditions)| (key.as_str(), conditions.iter().collect()))
+            .collect();
+        filters.sort_by_key(|(key, _)| *key);
+
+        let groups = self.group_conditions();
+        filters.extend(
+            groups
+                .iter()
+                .map(|(key, condition)| (key.as_str(), vec![condition])),
+        );
+
+        for (key, conditions) in filters {
+            let (column, operator) = split_filter_key(key);
+
+            if field_type(column).is_none() {
+                if let Some(expected) = misspelled_reserved_param(column) {
+                    errors.push(ParamError::MisspelledParam {
+                        key: key.to_string(),
+                        expected: expected.to_string(),
+                    });
+                    continue;
+                }
+                if config.ignore_unknown_filters {
+                    continue;
+                }
+            }
+
+            if !check_column(column, &mut errors) {
+                continue;
+            }
+
+            if let Some(operator) = operator {
+                if QueryFilterOperator::parse(operator).is_none() {
+                    errors.push(ParamError::UnknownOperator {
+                        column: column.to_string(),
+                        operator: operator.to_string(),
+                    });
+                    continue;
+                }
+            }
+
+            for condition in conditions {
+                let compares_numbers = !matches!(
+                    condition.operator,
+                    QueryFilterOperator::Like
+                        | QueryFilterOperator::NotLike
+                        | QueryFilterOperator::Regex
+                        | QueryFilterOperator::IRegex
+                ) && !condition.operator.is_containment()
+                    && condition.operator.requires_value();
+
+                if compares_numbers && field_type(column) == Some(FieldType::Number) {
+                    let values = if condition.operator.accepts_multiple_values() {
+                        condition.split_values()
+                    } else {
+                        condition.value.iter().cloned().collect()
+                    };
+
+                    for value in values {
+                        if value.trim().parse::<f64>().is_err() {
+                            errors.push(ParamError::InvalidNumericValue {
+                                column: column.to_string(),
+                                value,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the distinct, non-empty fields of `checkbox_filters`.
+    fn checkbox_columns(&self) -> Vec<&str> {
+        let mut columns: Vec<&str> = Vec::new();
+        for column in self
+            .checkbox_filters
+            .iter()
+            .flat_map(|list| list.split(','))
+        {
+            let column = column.trim();
+            if !column.is_empty() && !columns.contains(&column) {
+                columns.push(column);
+            }
+        }
+        columns
+    }
+
+    /// Returns the conditions of every filter group, by the key they are grouped
+    /// under, as the filters are.
+    fn group_conditions(&self) -> Vec<(String, QueryFilterCondition)> {
+        self.filter_groups
+            .iter()
+            .flatten()
+            .flat_map(|group| group.parsed_conditions())
+            .collect()
+    }
+}
+
+impl FlatFilterGroup {
+    /// Parses the conditions like filters, in key order within every object.
+    fn parsed_conditions(&self) -> Vec<(String, QueryFilterCondition)> {
+        self.conditions
+            .iter()
+            .flat_map(|condition| {
+                let mut entries: Vec<_> = condition.iter().collect();
+                entries.sort_by_key(|(key, _)| *key);
+                entries
+                    .into_iter()
+                    .filter_map(|(key, value)| parse_filter_entry(key.clone(), value.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Returns the reserved parameter a key is a likely misspelling of: one edit away,
+/// or two for longer parameters.
+fn misspelled_reserved_param(key: &str) -> Option<&'static str> {
+    DEFAULT_RESERVED_PARAM_NAMES
+        .iter()
+        .map(|name| (*name, edit_distance(key, name)))
+        .filter(|(name, distance)| *distance > 0 && *distance <= name.len().min(8) / 4)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(name, _)| name)
+}
+
+/// Splits a filter key into its column and the raw operator of the `field[op]` form.
+fn split_filter_key(key: &str) -> (&str, Option<&str>) {
+    match (key.find('['), key.find(']')) {
+        (Some(start), Some(end)) if start < end => (&key[..start], Some(&key[start + 1..end])),
+        _ => (key, None),
+    }
 }
 
 /// Complete query parameters with all configuration options.
@@ -110,11 +457,25 @@ pub struct QueryParams<'q, T> {
     /// Date range filtering configuration
     pub date_range: QueryDateRangeParams,
 
-    /// Filter conditions with operators
-    pub filters: HashMap<String, QueryFilterCondition>,
+    /// Filter conditions with operators, by column
+    ///
+    /// Every condition on a column applies, combined with AND, so a column can be
+    /// filtered by a range with a lower and an upper bound.
+    pub filters: HashMap<String, Vec<QueryFilterCondition>>,
+
+    /// Groups of filter conditions, each combined by its own logic, e.g. with OR,
+    /// and with AND with the other filters
+    pub filter_groups: Vec<QueryFilterGroup>,
+
+    /// Fields filtered as tri-state checkboxes: `on`/`true` and `off`/`false` filter
+    /// by the boolean value, while an absent or empty value adds no filter
+    pub checkbox_filters: Vec<String>,
 
     /// Legacy simple filters (backward compatibility)
     ///
+    /// Only filled when converting from `FlatQueryParams` with the `legacy-filters`
+    /// feature enabled, and left empty by `FlatQueryParams::without_legacy_filters`.
+    ///
     /// Deprecated: Use `filters` with QueryFilterCondition instead
     #[deprecated(
         since = "0.3.0",
@@ -122,30 +483,857 @@ pub struct QueryParams<'q, T> {
     )]
     pub simple_filters: HashMap<String, Option<String>>,
 
+    /// Database columns of renamed model fields, by serialized field name, set by
+    /// `PaginatedQueryBuilder::with_column_mapping` for its query building function
+    pub(crate) column_mappings: HashMap<String, String>,
+
+    /// Table qualifying the columns of the conditions, set by `PaginatedQueryBuilder`
+    /// to `base_query` when joined tables could make the names ambiguous
+    pub(crate) column_qualifier: Option<String>,
+
     /// Phantom data to hold the type parameter
     pub(crate) _phantom: PhantomData<&'q T>,
 }
 
+/// Serializes the parameters in the flat query string form, e.g. for audit logs.
+///
+/// The output deserializes back into `FlatQueryParams`. Keys come in a fixed order,
+/// with filters sorted by column, so equal parameters always produce the same JSON.
+/// Values are strings as in a query string, filters with an operator other than
+/// equality use the `field[op]` key, and the effective sort is always included.
+/// Checkbox filters are joined with commas and filter groups hold one condition per
+/// object. The deprecated `simple_filters` are left out, and the output does not
+/// depend on `T`.
+impl<T> Serialize for QueryParams<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry("page", &self.pagination.page.to_string())?;
+        map.serialize_entry("page_size", &self.pagination.page_size.to_string())?;
+        if let Some(offset) = self.pagination.token_offset {
+            map.serialize_entry("page_token", &PageToken { offset }.encode())?;
+        }
+
+        map.serialize_entry("sort_column", &self.sort.sort_column)?;
+        map.serialize_entry("sort_direction", &self.sort.sort_direction)?;
+        if !self.sort.order_by.is_empty() {
+            map.serialize_entry("order_by", &format_order_by(&self.sort.order_by))?;
+        }
+
+        if let Some(search) = &self.search.search {
+            map.serialize_entry("search", search)?;
+        }
+        if let Some(columns) = &self.search.search_columns {
+            map.serialize_entry(
+                "search_columns",
+                &columns.join(DEFAULT_SEARCH_COLUMN_NAME_SEPARATOR_SYMBOL),
+            )?;
+        }
+        map.serialize_entry("search_mode", &self.search.search_mode)?;
+        map.serialize_entry("search_anchor", &self.search.search_anchor)?;
+        if let Some(min_should_match) = self.search.min_should_match {
+            map.serialize_entry("min_should_match", &min_should_match.to_string())?;
+        }
+
+        if let Some(date_column) = &self.date_range.date_column {
+            map.serialize_entry("date_column", date_column)?;
+        }
+        if let Some(date_after) = &self.date_range.date_after {
+            map.serialize_entry("date_after", date_after)?;
+        }
+        if let Some(date_before) = &self.date_range.date_before {
+            map.serialize_entry("date_before", date_before)?;
+        }
+
+        let mut filters: Vec<_> = self.filters.iter().collect();
+        filters.sort_by_key(|(column, _)| *column);
+
+        for (column, conditions) in filters {
+            for condition in conditions {
+                map.serialize_entry(&filter_key(column, condition), &condition.value)?;
+            }
+        }
+
+        if !self.checkbox_filters.is_empty() {
+            map.serialize_entry("checkbox_filters", &self.checkbox_filters.join(","))?;
+        }
+
+        if !self.filter_groups.is_empty() {
+            let groups: Vec<FlatFilterGroup> = self
+                .filter_groups
+                .iter()
+                .map(|group| FlatFilterGroup {
+                    logic: group.logic,
+                    conditions: group
+                        .conditions
+                        .iter()
+                        .map(|(column, condition)| {
+                            HashMap::from([(
+                                filter_key(column, condition),
+                                condition.value.clone(),
+                            )])
+                        })
+                        .collect(),
+                })
+                .collect();
+            map.serialize_entry("filter_groups", &groups)?;
+        }
+
+        map.end()
+    }
+}
+
+/// Returns the flat key of a filter condition: the column for equality, or the
+/// `field[op]` form.
+fn filter_key(column: &str, condition: &QueryFilterCondition) -> String {
+    match condition.operator {
+        QueryFilterOperator::Equal => column.to_string(),
+        ref operator => format!("{}[{}]", column, operator.to_query_key()),
+    }
+}
+
+impl<T> QueryParams<'_, T> {
+    /// Packs the parameters into a single compact string, the URL safe base64
+    /// of their flat JSON form, read back with `FlatQueryParams::from_compact`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `serde_json::Error` when the parameters can't be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use sqlx_paginated::{FlatQueryParams, QueryParams, QueryParamsBuilder};
+    ///
+    /// #[derive(Serialize, Default)]
+    /// struct Product {
+    ///     status: String,
+    /// }
+    ///
+    /// let compact = QueryParamsBuilder::<Product>::new()
+    ///     .with_filter("status", Some("active"))
+    ///     .build()
+    ///     .to_compact()
+    ///     .unwrap();
+    ///
+    /// let params: QueryParams<Product> = FlatQueryParams::from_compact(&compact).unwrap().into();
+    /// assert_eq!(params.filters["status"][0].value.as_deref(), Some("active"));
+    /// ```
+    pub fn to_compact(&self) -> Result<String, serde_json::Error> {
+        Ok(COMPACT_ENGINE.encode(serde_json::to_vec(self)?))
+    }
+}
+
 impl<'q, T> From<FlatQueryParams> for QueryParams<'q, T> {
     fn from(params: FlatQueryParams) -> Self {
-        let filters = params.filters.unwrap_or_default();
+        into_query_params(params, cfg!(feature = "legacy-filters"))
+    }
+}
 
-        // Build simple_filters for backward compatibility (deprecated)
+/// Converts the parameters after validating them against the columns of `T`, as
+/// `FlatQueryParams::validate` does, instead of silently ignoring what doesn't
+/// apply to the model.
+///
+/// # Errors
+///
+/// Returns every problem found, as `validate` does.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Serialize;
+/// use sqlx_paginated::{FlatQueryParams, ParamError, QueryParams, ValidationConfig};
+///
+/// #[derive(Serialize, Default)]
+/// struct Product {
+///     name: String,
+///     price: f64,
+///     created_at: String,
+/// }
+///
+/// let flat: FlatQueryParams = serde_json::from_str(r#"{"secret": "1"}"#).unwrap();
+///
+/// assert_eq!(
+///     QueryParams::<Product>::try_from((&flat, ValidationConfig::default())).err(),
+///     Some(vec![ParamError::UnknownColumn {
+///         column: "secret".to_string(),
+///     }])
+/// );
+/// ```
+impl<'q, T> TryFrom<(&FlatQueryParams, ValidationConfig)> for QueryParams<'q, T>
+where
+    T: Default + Serialize,
+{
+    type Error = Vec<ParamError>;
+
+    fn try_from(
+        (params, config): (&FlatQueryParams, ValidationConfig),
+    ) -> Result<Self, Self::Error> {
+        params.validate_with::<T>(&config)?;
+
+        let mut params = params.clone();
+        if config.ignore_unknown_filters {
+            let fields = get_struct_field_names::<T>();
+            if let Some(filters) = &mut params.filters {
+                filters.retain(|key, _| {
+                    let column = split_filter_key(key).0;
+                    fields.iter().any(|field| field == column)
+                });
+            }
+            for group in params.filter_groups.iter_mut().flatten() {
+                for condition in &mut group.conditions {
+                    condition.retain(|key, _| {
+                        let column = split_filter_key(key).0;
+                        fields.iter().any(|field| field == column)
+                    });
+                }
+            }
+        }
+
+        Ok(params.into())
+    }
+}
+
+fn into_query_params<'q, T>(params: FlatQueryParams, legacy_filters: bool) -> QueryParams<'q, T> {
+    let checkbox_filters = params
+        .checkbox_columns()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+
+    let filter_groups = params
+        .filter_groups
+        .iter()
+        .flatten()
+        .filter_map(|group| {
+            let conditions: Vec<_> = group
+                .parsed_conditions()
+                .into_iter()
+                .map(|(key, condition)| (split_filter_key(&key).0.to_string(), condition))
+                .collect();
+            (!conditions.is_empty()).then_some(QueryFilterGroup {
+                logic: group.logic,
+                conditions,
+            })
+        })
+        .collect();
+
+    // Filters with unrecognised operators keep their raw `field[op]` key and are
+    // treated as equality on `field`
+    let mut filters: HashMap<String, Vec<QueryFilterCondition>> = HashMap::new();
+    for (key, conditions) in params.filters.unwrap_or_default() {
+        filters
+            .entry(split_filter_key(&key).0.to_string())
+            .or_default()
+            .extend(conditions);
+    }
+
+    // Build simple_filters for backward compatibility (deprecated), holding the
+    // last condition of every column
+    let simple_filters = if legacy_filters {
+        filters
+            .iter()
+            .filter_map(|(k, v)| Some((k.clone(), v.last()?.value.clone())))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let mut pagination = params.pagination.unwrap_or_default();
+    if let Some(token) = params.page_token.as_deref().and_then(PageToken::decode) {
+        pagination.start_at(token.offset);
+    }
+
+    QueryParams {
+        pagination,
+        sort: params.sort.unwrap_or_default(),
+        search: params.search.unwrap_or_default(),
+        date_range: params.date_range.unwrap_or_default(),
+        filters,
+        filter_groups,
+        checkbox_filters,
         #[allow(deprecated)]
-        let simple_filters = filters
+        simple_filters,
+        column_mappings: HashMap::new(),
+        column_qualifier: None,
+        _phantom: PhantomData::<&'q T>,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{QuerySortDirection, SearchMode};
+
+    #[derive(Serialize, Default)]
+    struct Product {
+        name: String,
+        description: String,
+        price: f64,
+        stock: i32,
+        featured: Option<bool>,
+        created_at: String,
+    }
+
+    fn parse(json: &str) -> FlatQueryParams {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_without_legacy_filters_leaves_simple_filters_empty() {
+        let json = r#"{"name": "widget", "price[gt]": "10"}"#;
+
+        let params: QueryParams<Product> = parse(json).without_legacy_filters();
+        assert_eq!(params.filters.len(), 2);
+        assert!(params.simple_filters.is_empty());
+
+        let params: QueryParams<Product> = parse(json).into();
+        assert_eq!(params.filters.len(), 2);
+        assert_eq!(
+            params.simple_filters.len(),
+            if cfg!(feature = "legacy-filters") {
+                2
+            } else {
+                0
+            }
+        );
+    }
+
+    #[test]
+    fn test_keeps_every_filter_on_a_column() {
+        let flat = parse(r#"{"price[gte]": "10", "price[lte]": "100", "price[foo]": "5"}"#);
+        assert_eq!(flat.filters.as_ref().unwrap()["price"].len(), 2);
+
+        let params: QueryParams<Product> = flat.into();
+        let mut conditions: Vec<_> = params.filters["price"]
             .iter()
-            .map(|(k, v)| (k.clone(), v.value.clone()))
+            .map(|condition| (condition.operator.clone(), condition.value.as_deref()))
             .collect();
+        conditions.sort_by_key(|(_, value)| *value);
+
+        assert_eq!(params.filters.len(), 1);
+        assert_eq!(
+            conditions,
+            vec![
+                (QueryFilterOperator::GreaterOrEqual, Some("10")),
+                (QueryFilterOperator::LessOrEqual, Some("100")),
+                (QueryFilterOperator::Equal, Some("5")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_params() {
+        let params = parse(
+            r#"{
+                "page": "2",
+                "sort_column": "price",
+                "search": "laptop",
+                "search_columns": "name,description",
+                "name": "Laptop",
+                "price[gte]": "10.5",
+                "stock[in]": "1, 2,3",
+                "description[like]": "%fast%",
+                "created_at[is_null]": ""
+            }"#,
+        );
+
+        assert_eq!(params.validate::<Product>(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_unknown_columns() {
+        let params = parse(
+            r#"{
+                "sort_column": "rank",
+                "search": "laptop",
+                "search_columns": "name,secret",
+                "owner[eq]": "me"
+            }"#,
+        );
+
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![
+                ParamError::UnknownColumn {
+                    column: "rank".to_string()
+                },
+                ParamError::UnknownColumn {
+                    column: "secret".to_string()
+                },
+                ParamError::UnknownColumn {
+                    column: "owner".to_string()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_misspelled_reserved_params() {
+        let params = parse(
+            r#"{
+                "page_siez": "50",
+                "serach": "laptop",
+                "sort_colunm[eq]": "price",
+                "pgae": "2",
+                "owner": "me"
+            }"#,
+        );
+
+        // Misspelled keys would otherwise become filters on missing columns
+        assert!(params.filters.as_ref().unwrap().contains_key("page_siez"));
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![
+                ParamError::UnknownColumn {
+                    column: "owner".to_string()
+                },
+                ParamError::MisspelledParam {
+                    key: "page_siez".to_string(),
+                    expected: "page_size".to_string()
+                },
+                ParamError::MisspelledParam {
+                    key: "pgae".to_string(),
+                    expected: "page".to_string()
+                },
+                ParamError::MisspelledParam {
+                    key: "serach".to_string(),
+                    expected: "search".to_string()
+                },
+                ParamError::MisspelledParam {
+                    key: "sort_colunm".to_string(),
+                    expected: "sort_column".to_string()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_every_order_by_column() {
+        let params = parse(r#"{"order_by": "name.asc,-rank"}"#);
+
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![ParamError::UnknownColumn {
+                column: "rank".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_resolves_only_the_default_sort_column() {
+        #[derive(Serialize, Default)]
+        struct Event {
+            id: i64,
+            updated_at: String,
+        }
 
-        QueryParams {
-            pagination: params.pagination.unwrap_or_default(),
-            sort: params.sort.unwrap_or_default(),
-            search: params.search.unwrap_or_default(),
-            date_range: params.date_range.unwrap_or_default(),
-            filters,
-            #[allow(deprecated)]
-            simple_filters,
-            _phantom: PhantomData::<&'q T>,
+        assert_eq!(parse(r#"{"page": "2"}"#).validate::<Event>(), Ok(()));
+        assert_eq!(
+            parse(r#"{"sort_column": "created_at"}"#).validate::<Event>(),
+            Err(vec![ParamError::UnknownColumn {
+                column: "created_at".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_ignores_search_columns_without_search_term() {
+        let params = parse(r#"{"search_columns": "secret"}"#);
+
+        assert_eq!(params.validate::<Product>(), Ok(()));
+    }
+
+    #[test]
+    fn test_try_from_converts_valid_params() {
+        let flat = parse(
+            r#"{
+                "page": "2",
+                "sort_column": "price",
+                "search": "laptop",
+                "price[gte]": "10.5",
+                "utm_source": "newsletter"
+            }"#,
+        );
+        let config = ValidationConfig {
+            ignore_unknown_filters: true,
+        };
+
+        let params = QueryParams::<Product>::try_from((&flat, config)).unwrap();
+
+        assert_eq!(params.pagination.page, 2);
+        assert_eq!(params.sort.sort_column, "price");
+        assert_eq!(params.search.search.as_deref(), Some("laptop"));
+        assert_eq!(
+            params.filters["price"][0].operator,
+            QueryFilterOperator::GreaterOrEqual
+        );
+        assert!(!params.filters.contains_key("utm_source"));
+    }
+
+    #[test]
+    fn test_try_from_collects_every_error() {
+        let flat = parse(
+            r#"{
+                "sort_column": "rank",
+                "price[gt]": "cheap",
+                "stock[near]": "1",
+                "page_siez": "50",
+                "owner": "me"
+            }"#,
+        );
+
+        assert_eq!(
+            QueryParams::<Product>::try_from((&flat, ValidationConfig::default())).err(),
+            Some(vec![
+                ParamError::UnknownColumn {
+                    column: "rank".to_string()
+                },
+                ParamError::UnknownColumn {
+                    column: "owner".to_string()
+                },
+                ParamError::MisspelledParam {
+                    key: "page_siez".to_string(),
+                    expected: "page_size".to_string()
+                },
+                ParamError::InvalidNumericValue {
+                    column: "price".to_string(),
+                    value: "cheap".to_string()
+                },
+                ParamError::UnknownOperator {
+                    column: "stock".to_string(),
+                    operator: "near".to_string()
+                },
+            ])
+        );
+
+        // Unknown filters can be ignored, misspelled parameters are still reported
+        let config = ValidationConfig {
+            ignore_unknown_filters: true,
+        };
+        assert_eq!(
+            QueryParams::<Product>::try_from((&flat, config)).err(),
+            Some(vec![
+                ParamError::UnknownColumn {
+                    column: "rank".to_string()
+                },
+                ParamError::MisspelledParam {
+                    key: "page_siez".to_string(),
+                    expected: "page_size".to_string()
+                },
+                ParamError::InvalidNumericValue {
+                    column: "price".to_string(),
+                    value: "cheap".to_string()
+                },
+                ParamError::UnknownOperator {
+                    column: "stock".to_string(),
+                    operator: "near".to_string()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_is_explicit_only_when_supplied() {
+        let params: QueryParams<Product> = parse(r#"{"sort_direction": "ascending"}"#).into();
+        assert!(!params.sort.is_explicit());
+        assert_eq!(params.sort.sort_column, "created_at");
+
+        let params: QueryParams<Product> = parse(r#"{"sort_column": "name"}"#).into();
+        assert!(params.sort.is_explicit());
+
+        let params: QueryParams<Product> = parse(r#"{"order_by": "-name"}"#).into();
+        assert!(params.sort.is_explicit());
+    }
+
+    #[test]
+    fn test_sort_colon_list_from_query_string() {
+        let params: QueryParams<Product> = parse(r#"{"sort": "name:asc,created_at:desc"}"#).into();
+        assert!(params.sort.is_explicit());
+        assert_eq!(
+            params
+                .sort
+                .sorts()
+                .into_iter()
+                .map(|sort| (sort.column, sort.direction))
+                .collect::<Vec<_>>(),
+            vec![
+                ("name".to_string(), QuerySortDirection::Ascending),
+                ("created_at".to_string(), QuerySortDirection::Descending),
+            ]
+        );
+        assert!(params.filters.is_empty());
+
+        let params: QueryParams<Product> =
+            parse(r#"{"sort": "name:asc", "order_by": "-created_at"}"#).into();
+        assert_eq!(params.sort.sorts().len(), 1);
+        assert_eq!(params.sort.sorts()[0].column, "created_at");
+    }
+
+    #[test]
+    fn test_search_mode_from_query_string() {
+        let params: QueryParams<Product> =
+            parse(r#"{"search": "a b", "search_mode": "any_token_any_column"}"#).into();
+        assert_eq!(params.search.search_mode, SearchMode::AnyTokenAnyColumn);
+
+        let params: QueryParams<Product> = parse(r#"{"search": "a b"}"#).into();
+        assert_eq!(params.search.search_mode, SearchMode::Phrase);
+    }
+
+    #[test]
+    fn test_min_should_match_from_query_string() {
+        let params: QueryParams<Product> =
+            parse(r#"{"search": "a b c", "min_should_match": "2"}"#).into();
+        assert_eq!(params.search.min_should_match, Some(2));
+        assert_eq!(params.search.terms(), vec!["a", "b", "c"]);
+
+        let params: QueryParams<Product> = parse(r#"{"search": "a b c"}"#).into();
+        assert_eq!(params.search.min_should_match, None);
+        assert_eq!(params.search.terms(), vec!["a b c"]);
+    }
+
+    #[test]
+    fn test_page_token_from_query_string() {
+        let token = PageToken { offset: 40 }.encode();
+        let params = parse(&format!(
+            r#"{{"page": "1", "page_size": "20", "page_token": "{}"}}"#,
+            token
+        ));
+
+        assert!(params
+            .filters
+            .as_ref()
+            .is_none_or(|filters| filters.is_empty()));
+        assert!(params.validate::<Product>().is_ok());
+
+        let params = QueryParams::<Product>::from(params);
+        assert_eq!(params.pagination.page, 3);
+        assert_eq!(params.pagination.page_size, 20);
+    }
+
+    #[test]
+    fn test_page_token_keeps_unaligned_offsets() {
+        let token = PageToken { offset: 25 }.encode();
+        let params = QueryParams::<Product>::from(parse(&format!(
+            r#"{{"page_size": "10", "page_token": "{}"}}"#,
+            token
+        )));
+
+        assert_eq!(params.pagination.page, 3);
+        assert_eq!(params.pagination.offset(), 25);
+        assert_eq!(
+            serde_json::to_value(&params).unwrap()["page_token"],
+            serde_json::json!(token)
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_invalid_page_tokens() {
+        let params = parse(r#"{"page": "2", "page_token": "garbage"}"#);
+
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![ParamError::InvalidPageToken {
+                token: "garbage".to_string()
+            }])
+        );
+        assert_eq!(QueryParams::<Product>::from(params).pagination.page, 2);
+    }
+
+    #[test]
+    fn test_validate_reports_unknown_operators() {
+        let params = parse(r#"{"price[near]": "1,2"}"#);
+
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![ParamError::UnknownOperator {
+                column: "price".to_string(),
+                operator: "near".to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_non_numeric_values() {
+        let params = parse(r#"{"price[lt]": "cheap", "stock[in]": "1,many"}"#);
+
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![
+                ParamError::InvalidNumericValue {
+                    column: "price".to_string(),
+                    value: "cheap".to_string(),
+                },
+                ParamError::InvalidNumericValue {
+                    column: "stock".to_string(),
+                    value: "many".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_filter_groups_and_checkbox_columns() {
+        let params = parse(
+            r#"{
+                "checkbox_filters": "featured,secret",
+                "filter_groups": [{"logic": "or", "conditions": [{"price[lt]": "cheap"}, {"owner": "me"}]}]
+            }"#,
+        );
+
+        assert_eq!(
+            params.validate::<Product>(),
+            Err(vec![
+                ParamError::UnknownColumn {
+                    column: "secret".to_string(),
+                },
+                ParamError::InvalidNumericValue {
+                    column: "price".to_string(),
+                    value: "cheap".to_string(),
+                },
+                ParamError::UnknownColumn {
+                    column: "owner".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_unknown_operator_converts_to_equality() {
+        let params: QueryParams<Product> = parse(r#"{"name[unknown]": "Laptop"}"#).into();
+
+        let filter = &params.filters["name"][0];
+        assert_eq!(filter.operator, QueryFilterOperator::Equal);
+        assert_eq!(filter.value, Some("Laptop".to_string()));
+    }
+
+    #[test]
+    fn test_serialize_round_trips_through_flat_params() {
+        use crate::{QueryParamsBuilder, QuerySortDirection};
+        use chrono::{DateTime, Utc};
+
+        let date_after: DateTime<Utc> = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .into();
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_pagination(2, 20)
+            .with_sort("price", QuerySortDirection::Ascending)
+            .with_search("laptop", vec!["name", "description"])
+            .with_date_range(Some(date_after), None, Some("created_at"))
+            .with_filter("name", Some("Laptop"))
+            .with_filter_operator("price", QueryFilterOperator::GreaterOrEqual, "10.5")
+            .with_filter_in("stock", vec!["1", "2"])
+            .with_filter_null("description", true)
+            .with_filter_group(
+                FilterLogic::Or,
+                vec![
+                    ("name", QueryFilterCondition::like("%Pro%")),
+                    ("stock", QueryFilterCondition::equal("0")),
+                ],
+            )
+            .with_checkbox_filters(vec!["featured"])
+            .build();
+
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "page": "2",
+                "page_size": "20",
+                "sort_column": "price",
+                "sort_direction": "ascending",
+                "search": "laptop",
+                "search_columns": "name,description",
+                "search_mode": "phrase",
+                "search_anchor": "both",
+                "date_column": "created_at",
+                "date_after": "2024-01-01T00:00:00Z",
+                "description[is_null]": null,
+                "name": "Laptop",
+                "price[gte]": "10.5",
+                "stock[in]": "1,2",
+                "checkbox_filters": "featured",
+                "filter_groups": [{
+                    "logic": "or",
+                    "conditions": [{"name[like]": "%Pro%"}, {"stock": "0"}]
+                }]
+            })
+        );
+        assert!(serde_json::to_string(&params)
+            .unwrap()
+            .starts_with(r#"{"page":"2","page_size":"20","sort_column":"price""#));
+
+        let flat: FlatQueryParams = serde_json::from_value(json.clone()).unwrap();
+        let round_tripped: QueryParams<Product> = flat.into();
+        assert_eq!(round_tripped.checkbox_filters, vec!["featured"]);
+        assert_eq!(round_tripped.filter_groups.len(), 1);
+        assert_eq!(round_tripped.filter_groups[0].conditions[0].0, "name");
+        assert_eq!(serde_json::to_value(&round_tripped).unwrap(), json);
+    }
+
+    #[test]
+    fn test_compact_round_trips_params() {
+        use crate::{QueryParamsBuilder, QuerySortDirection};
+
+        let params = QueryParamsBuilder::<Product>::new()
+            .with_pagination(3, 25)
+            .with_sort("price", QuerySortDirection::Descending)
+            .with_search("laptop", vec!["name", "description"])
+            .with_filter("name", Some("Läptop \"Pro\"/+"))
+            .with_filter_operator("price", QueryFilterOperator::LessThan, "99.9")
+            .with_filter_in("stock", vec!["1", "2", "3"])
+            .with_filter_null("description", false)
+            .with_filter_group(
+                FilterLogic::And,
+                vec![
+                    ("price", QueryFilterCondition::greater_or_equal("10")),
+                    ("name", QueryFilterCondition::not_equal("Basic")),
+                ],
+            )
+            .with_checkbox_filters(vec!["featured"])
+            .build();
+
+        let compact = params.to_compact().unwrap();
+        assert!(compact
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+        let round_tripped: QueryParams<Product> =
+            FlatQueryParams::from_compact(&compact).unwrap().into();
+        assert_eq!(
+            serde_json::to_value(&round_tripped).unwrap(),
+            serde_json::to_value(&params).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_compact_accepts_standard_alphabet_and_padding() {
+        let json = r#"{"page":"2","name":"a?b>c"}"#;
+        let flat = |compact: String| -> QueryParams<Product> {
+            FlatQueryParams::from_compact(&compact).unwrap().into()
+        };
+
+        for compact in [
+            base64::engine::general_purpose::STANDARD.encode(json),
+            base64::engine::general_purpose::URL_SAFE.encode(json),
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json),
+        ] {
+            let params = flat(compact);
+            assert_eq!(params.pagination.page, 2);
+            assert_eq!(params.filters["name"][0].value.as_deref(), Some("a?b>c"));
         }
     }
+
+    #[test]
+    fn test_from_compact_rejects_invalid_input() {
+        assert!(FlatQueryParams::from_compact("not base64!").is_err());
+        assert!(FlatQueryParams::from_compact(&COMPACT_ENGINE.encode("not json")).is_err());
+        assert!(FlatQueryParams::from_compact(&COMPACT_ENGINE.encode("[1, 2]")).is_err());
+    }
 }
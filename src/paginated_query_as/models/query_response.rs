@@ -1,5 +1,7 @@
 use crate::paginated_query_as::internal::QueryPaginationParams;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use std::hash::Hash;
 
 /// Represents a paginated response with records and metadata.
 ///
@@ -29,7 +31,9 @@ use serde::{Deserialize, Serialize};
 /// //   "page": 1,
 /// //   "page_size": 10,
 /// //   "total": 100,
-/// //   "total_pages": 10
+/// //   "total_pages": 10,
+/// //   "has_next": true,
+/// //   "has_previous": false
 /// // }
 /// ```
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -48,4 +52,376 @@ pub struct PaginatedResponse<T> {
     /// Total number of pages
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_pages: Option<i64>,
+
+    /// Whether `total` is an approximate count, read from a counter table
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub total_is_estimate: bool,
+
+    /// Records of `total` that are not soft-deleted, when the total includes them
+    /// with `with_count_includes_trashed`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_active: Option<i64>,
+
+    /// The cap of `with_count_capped` when `total` exceeds it, in which case
+    /// `total` is only a lower bound, e.g. shown as "99+"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_capped_at: Option<i64>,
+
+    /// Sort column values of the first and last records of the page, when
+    /// requested with `with_page_bounds` and the page is not empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_bounds: Option<(String, String)>,
+
+    /// Token of the next page, when requested with `with_page_tokens`
+    /// and there may be more records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+
+    /// Opaque cursor of the next page in keyset mode, set by `with_cursor`,
+    /// when there are more records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    /// Opaque cursor of the previous page in keyset mode, fetched with
+    /// `KeysetDirection::Backward`, when there are records before the page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_cursor: Option<String>,
+
+    /// Whether there is a page after this one, known from the totals, or in
+    /// keyset mode from an extra record fetched past the page, and unknown once
+    /// the page reaches a capped total
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_next: Option<bool>,
+
+    /// Whether there is a page before this one, known from the totals, or in
+    /// keyset mode from a cursor being given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_previous: Option<bool>,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Converts the records into a map keyed by the given function, in record
+    /// order, keeping the pagination metadata.
+    ///
+    /// Keys are expected to be unique. When several records share a key, the last
+    /// one wins, at the position of the first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::PaginatedResponse;
+    ///
+    /// let response = PaginatedResponse {
+    ///     records: vec![("US", "United States"), ("CA", "Canada")],
+    ///     pagination: None,
+    ///     total: Some(2),
+    ///     total_pages: Some(1),
+    ///     total_is_estimate: false,
+    ///     total_active: None,
+    ///     total_capped_at: None,
+    ///     page_bounds: None,
+    ///     next_page_token: None,
+    ///     next_cursor: None,
+    ///     previous_cursor: None,
+    ///     has_next: Some(false),
+    ///     has_previous: Some(false),
+    /// };
+    ///
+    /// let keyed = response.into_keyed(|(code, _)| code.to_string());
+    ///
+    /// assert_eq!(keyed.records.keys().collect::<Vec<_>>(), ["US", "CA"]);
+    /// assert_eq!(keyed.total, Some(2));
+    /// ```
+    pub fn into_keyed<K, F>(self, key_fn: F) -> KeyedPaginatedResponse<K, T>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        KeyedPaginatedResponse {
+            records: self
+                .records
+                .into_iter()
+                .map(|record| (key_fn(&record), record))
+                .collect(),
+            pagination: self.pagination,
+            total: self.total,
+            total_pages: self.total_pages,
+            total_is_estimate: self.total_is_estimate,
+            total_active: self.total_active,
+            total_capped_at: self.total_capped_at,
+            page_bounds: self.page_bounds,
+            next_page_token: self.next_page_token,
+            next_cursor: self.next_cursor,
+            previous_cursor: self.previous_cursor,
+            has_next: self.has_next,
+            has_previous: self.has_previous,
+        }
+    }
+}
+
+/// A paginated response whose records are keyed by a column, e.g. for lookup
+/// tables serialized as `{ "records": { "US": {...}, "CA": {...} } }`.
+///
+/// Returned by `fetch_paginated_keyed` and `PaginatedResponse::into_keyed`. The
+/// records keep the order of the query, and the metadata is the same as for
+/// `PaginatedResponse`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(bound(
+    serialize = "K: Serialize + Hash + Eq, T: Serialize",
+    deserialize = "K: Deserialize<'de> + Hash + Eq, T: Deserialize<'de>"
+))]
+pub struct KeyedPaginatedResponse<K, T> {
+    /// The records for the current page, by key, in query order
+    pub records: IndexMap<K, T>,
+
+    /// Pagination metadata (page and page_size)
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<QueryPaginationParams>,
+
+    /// Total number of records across all pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+
+    /// Total number of pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<i64>,
+
+    /// Whether `total` is an approximate count, read from a counter table
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub total_is_estimate: bool,
+
+    /// Records of `total` that are not soft-deleted, when the total includes them
+    /// with `with_count_includes_trashed`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_active: Option<i64>,
+
+    /// The cap of `with_count_capped` when `total` exceeds it, in which case
+    /// `total` is only a lower bound, e.g. shown as "99+"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_capped_at: Option<i64>,
+
+    /// Sort column values of the first and last records of the page, when
+    /// requested with `with_page_bounds` and the page is not empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_bounds: Option<(String, String)>,
+
+    /// Token of the next page, when requested with `with_page_tokens`
+    /// and there may be more records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+
+    /// Opaque cursor of the next page in keyset mode, set by `with_cursor`,
+    /// when there are more records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    /// Opaque cursor of the previous page in keyset mode, fetched with
+    /// `KeysetDirection::Backward`, when there are records before the page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_cursor: Option<String>,
+
+    /// Whether there is a page after this one, known from the totals, or in
+    /// keyset mode from an extra record fetched past the page, and unknown once
+    /// the page reaches a capped total
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_next: Option<bool>,
+
+    /// Whether there is a page before this one, known from the totals, or in
+    /// keyset mode from a cursor being given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_previous: Option<bool>,
+}
+
+#[cfg(feature = "content-hash")]
+impl<T: Serialize> PaginatedResponse<T> {
+    /// Returns a hex-encoded SHA-256 hash of the records and metadata, suitable
+    /// for an `ETag` header.
+    ///
+    /// The response is hashed in its JSON form with object keys sorted, so the
+    /// hash only depends on the data and not on field or map iteration order.
+    /// Records that cannot be serialized to JSON hash like `null`.
+    ///
+    /// Requires the `content-hash` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sqlx_paginated::PaginatedResponse;
+    ///
+    /// let response = PaginatedResponse {
+    ///     records: vec!["a", "b"],
+    ///     pagination: None,
+    ///     total: Some(2),
+    ///     total_pages: Some(1),
+    ///     total_is_estimate: false,
+    ///     total_active: None,
+    ///     total_capped_at: None,
+    ///     page_bounds: None,
+    ///     next_page_token: None,
+    ///     next_cursor: None,
+    ///     previous_cursor: None,
+    ///     has_next: Some(false),
+    ///     has_previous: Some(false),
+    /// };
+    ///
+    /// assert_eq!(response.content_hash().len(), 64);
+    /// assert_eq!(response.content_hash(), response.clone().content_hash());
+    /// ```
+    pub fn content_hash(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        let value = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+        format!("{:x}", Sha256::digest(value.to_string().as_bytes()))
+    }
+}
+
+/// Pagination metadata of a query, without its records.
+///
+/// Returned by `fetch_meta`, to render a paginator before the records arrive.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::PageMeta;
+///
+/// let meta = PageMeta {
+///     page: 2,
+///     page_size: 10,
+///     total: 25,
+///     total_pages: 3,
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_string(&meta).unwrap(),
+///     r#"{"page":2,"page_size":10,"total":25,"total_pages":3}"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PageMeta {
+    /// The requested page
+    pub page: i64,
+
+    /// Number of records per page
+    pub page_size: i64,
+
+    /// Total number of records across all pages
+    pub total: i64,
+
+    /// Total number of pages
+    pub total_pages: i64,
+}
+
+/// The SQL of the queries `fetch_paginated_debug` executed, for logging.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::ExecutedSql;
+///
+/// let executed = ExecutedSql {
+///     records_sql: "SELECT * FROM users WHERE \"name\" = $1 LIMIT $2".to_string(),
+///     count_sql: Some("SELECT COUNT(*) FROM users WHERE \"name\" = $1".to_string()),
+///     arguments: 2,
+/// };
+///
+/// assert!(executed.count_sql.is_some());
+/// ```
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutedSql {
+    /// The records query, with its placeholders
+    pub records_sql: String,
+
+    /// The count query, or `None` when no count query ran
+    pub count_sql: Option<String>,
+
+    /// The number of arguments bound to the records query
+    pub arguments: usize,
+}
+
+/// Search match metadata for a single record.
+///
+/// Returned alongside each record by `fetch_paginated_with_matches`, listing the
+/// search columns whose value matched the search term.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::MatchInfo;
+///
+/// let info = MatchInfo {
+///     matched_columns: vec!["email".to_string()],
+/// };
+///
+/// assert!(info.matched("email"));
+/// assert!(!info.matched("name"));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchInfo {
+    /// Search columns that matched, in search column order
+    pub matched_columns: Vec<String>,
+}
+
+impl MatchInfo {
+    /// Whether the given search column matched the search term.
+    pub fn matched(&self, column: &str) -> bool {
+        self.matched_columns.iter().any(|matched| matched == column)
+    }
+}
+
+#[cfg(all(test, feature = "content-hash"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn response(
+        records: Vec<HashMap<&'static str, i64>>,
+        page: i64,
+    ) -> PaginatedResponse<HashMap<&'static str, i64>> {
+        PaginatedResponse {
+            records,
+            pagination: Some(QueryPaginationParams {
+                page,
+                page_size: 10,
+                requested_page_size: None,
+                token_offset: None,
+            }),
+            total: Some(1),
+            total_pages: Some(1),
+            total_is_estimate: false,
+            total_active: None,
+            total_capped_at: None,
+            page_bounds: None,
+            next_page_token: None,
+            next_cursor: None,
+            previous_cursor: None,
+            has_next: Some(false),
+            has_previous: Some(page > 1),
+        }
+    }
+
+    fn record(id: i64, score: i64) -> HashMap<&'static str, i64> {
+        HashMap::from([("id", id), ("score", score)])
+    }
+
+    #[test]
+    fn test_content_hash_is_stable_for_identical_content() {
+        let first = response(vec![record(1, 10)], 1);
+        let second = response(vec![record(1, 10)], 1);
+
+        assert_eq!(first.content_hash(), second.content_hash());
+    }
+
+    #[test]
+    fn test_content_hash_changes_with_records_or_metadata() {
+        let base = response(vec![record(1, 10)], 1);
+
+        assert_ne!(
+            base.content_hash(),
+            response(vec![record(1, 11)], 1).content_hash()
+        );
+        assert_ne!(
+            base.content_hash(),
+            response(vec![record(1, 10)], 2).content_hash()
+        );
+    }
 }
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+/// Controls how the search term is matched against the search columns.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::{QueryParamsBuilder, SearchMode};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, Default)]
+/// struct User {
+///     name: String,
+///     email: String,
+/// }
+///
+/// let params = QueryParamsBuilder::<User>::new()
+///     .with_search("john smith", vec!["name", "email"])
+///     .with_search_mode(SearchMode::AnyTokenAnyColumn)
+///     .build();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// The whole search term must appear in any of the columns
+    #[default]
+    Phrase,
+
+    /// The term is split on whitespace and any token appearing in any column is a match
+    AnyTokenAnyColumn,
+}
+
+/// Controls where the `%` wildcards are placed around the search term.
+///
+/// Patterns with a leading wildcard can't use btree indexes, so `Prefix`
+/// allows index-friendly "starts with" searches, and `Exact` equality searches.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::{QueryParamsBuilder, SearchAnchor};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, Default)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// let params = QueryParamsBuilder::<User>::new()
+///     .with_search("jo", vec!["name"])
+///     .with_search_anchor(SearchAnchor::Prefix)
+///     .build();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchAnchor {
+    /// Matches the term anywhere: `%term%`
+    #[default]
+    Both,
+
+    /// Matches values starting with the term: `term%`
+    Prefix,
+
+    /// Matches values ending with the term: `%term`
+    Suffix,
+
+    /// Matches values equal to the term, compared with `=` rather than `LIKE`, so
+    /// `%` and `_` in the term match themselves
+    Exact,
+}
+
+impl SearchAnchor {
+    /// Wraps the term in a LIKE pattern according to the anchor, or returns it as
+    /// is for `Exact`, which compares it with `=`.
+    pub fn pattern(&self, term: &str) -> String {
+        match self {
+            SearchAnchor::Both => format!("%{}%", term),
+            SearchAnchor::Prefix => format!("{}%", term),
+            SearchAnchor::Suffix => format!("%{}", term),
+            SearchAnchor::Exact => term.to_string(),
+        }
+    }
+
+    /// Returns the SQL operator comparing a column with the pattern.
+    pub(crate) fn operator(&self) -> &'static str {
+        match self {
+            SearchAnchor::Exact => "=",
+            _ => "LIKE",
+        }
+    }
+}
+
+/// Controls how search terms are matched when set with
+/// `QueryBuilder::with_search_options`, overriding the `SearchAnchor` of the
+/// parameters.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::{SearchAnchor, SearchOptions};
+///
+/// let options = SearchOptions {
+///     case_sensitive: true,
+///     anchor: SearchAnchor::Prefix,
+/// };
+///
+/// assert_eq!(options.anchor.pattern("jo"), "jo%");
+/// assert_eq!(SearchOptions::default().anchor, SearchAnchor::Both);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchOptions {
+    /// Whether the column and the term are compared as is, without `LOWER()`
+    pub case_sensitive: bool,
+    /// Where the term has to appear in the column
+    pub anchor: SearchAnchor,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_anchor_pattern_shape() {
+        assert_eq!(SearchAnchor::Both.pattern("john"), "%john%");
+        assert_eq!(SearchAnchor::Prefix.pattern("john"), "john%");
+        assert_eq!(SearchAnchor::Suffix.pattern("john"), "%john");
+        assert_eq!(SearchAnchor::Exact.pattern("jo_n%"), "jo_n%");
+        assert_eq!(SearchAnchor::default(), SearchAnchor::Both);
+    }
+}
@@ -30,3 +30,38 @@ pub enum QuerySortDirection {
     #[default]
     Descending,
 }
+
+/// Where NULL values are placed when sorting on a column.
+///
+/// Databases differ in their default: Postgres sorts NULLs as larger than any
+/// value, while SQLite sorts them as smaller.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx_paginated::{QueryNullsPosition, QueryParamsBuilder, QuerySortDirection};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, Default)]
+/// struct User {
+///     name: String,
+///     last_login: Option<String>,
+/// }
+///
+/// let params = QueryParamsBuilder::<User>::new()
+///     .with_sort_nulls("last_login", QuerySortDirection::Descending, QueryNullsPosition::Last)
+///     .build();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryNullsPosition {
+    /// NULLs sort before every value
+    First,
+
+    /// NULLs sort after every value
+    Last,
+
+    /// NULLs sort where the database places them by default
+    #[default]
+    Default,
+}
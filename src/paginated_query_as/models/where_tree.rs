@@ -0,0 +1,124 @@
+use crate::QueryParams;
+
+/// How the search, filter and date range conditions are combined.
+///
+/// By default every condition is combined with AND. A tree passed to
+/// `QueryBuilder::with_where` combines them explicitly instead, for instance to
+/// match either the search term or one specific record.
+///
+/// Leaves without a search term, filter or date leave their node unconstrained:
+/// they are left out of `And` groups, and an `Or` group with such a leaf adds no
+/// condition, since it matches every row. Leaves whose conditions are all
+/// skipped, e.g. for invalid values, are left out of their group, and groups
+/// left empty are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx::Postgres;
+/// use serde::Serialize;
+/// use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, WhereTree};
+///
+/// #[derive(Serialize, Default)]
+/// struct User {
+///     id: i64,
+///     name: String,
+/// }
+///
+/// let params = QueryParamsBuilder::<User>::new()
+///     .with_search("john", vec!["name"])
+///     .with_filter("id", Some("42"))
+///     .build();
+///
+/// let (conditions, _arguments) = QueryBuilder::<User, Postgres>::new()
+///     .with_where(&params, WhereTree::or(vec![WhereTree::Search, WhereTree::filter("id")]))
+///     .build();
+///
+/// assert_eq!(
+///     conditions,
+///     vec!["((LOWER(\"name\") LIKE LOWER($1)) OR \"id\" = $2::smallint)"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum WhereTree {
+    /// The search condition, as added by `QueryBuilder::with_search`
+    Search,
+
+    /// Every filter not named by a `Filter` leaf of the tree, and every filter
+    /// group, combined with AND
+    Filters,
+
+    /// The filter on a single column
+    Filter(String),
+
+    /// The date range conditions, combined with AND
+    DateRange,
+
+    /// Matches when every child matches
+    And(Vec<WhereTree>),
+
+    /// Matches when any child matches
+    Or(Vec<WhereTree>),
+}
+
+impl WhereTree {
+    /// Creates a group matching when every child matches.
+    pub fn and(children: Vec<WhereTree>) -> Self {
+        WhereTree::And(children)
+    }
+
+    /// Creates a group matching when any child matches.
+    pub fn or(children: Vec<WhereTree>) -> Self {
+        WhereTree::Or(children)
+    }
+
+    /// Creates a leaf for the filter on a single column.
+    pub fn filter(column: impl Into<String>) -> Self {
+        WhereTree::Filter(column.into())
+    }
+
+    /// Returns the columns named by `Filter` leaves of the tree.
+    pub(crate) fn filter_columns(&self) -> Vec<&str> {
+        match self {
+            WhereTree::Filter(column) => vec![column.as_str()],
+            WhereTree::And(children) | WhereTree::Or(children) => children
+                .iter()
+                .flat_map(|child| child.filter_columns())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns whether the parameters leave the node unconstrained: leaves
+    /// without a search term, filter or date, `And` groups of such nodes only,
+    /// and `Or` groups with any such node, since it matches every row.
+    pub(crate) fn is_absent<T>(&self, params: &QueryParams<T>, excluded: &[String]) -> bool {
+        match self {
+            WhereTree::Search => params
+                .search
+                .search
+                .as_deref()
+                .is_none_or(|search| search.trim().is_empty()),
+            WhereTree::Filters => {
+                params.filter_groups.is_empty()
+                    && params
+                        .filters
+                        .iter()
+                        .all(|(key, conditions)| excluded.contains(key) || conditions.is_empty())
+            }
+            WhereTree::Filter(column) => params
+                .filters
+                .get(column)
+                .is_none_or(|conditions| conditions.is_empty()),
+            WhereTree::DateRange => {
+                params.date_range.date_after.is_none() && params.date_range.date_before.is_none()
+            }
+            WhereTree::And(children) => children
+                .iter()
+                .all(|child| child.is_absent(params, excluded)),
+            WhereTree::Or(children) => children
+                .iter()
+                .any(|child| child.is_absent(params, excluded)),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Helpers for asserting the SQL generated by a `PaginatedQueryBuilder` without a
+//! database, enabled with the `testing` feature.
+
+use crate::PaginatedQueryBuilder;
+use serde::Serialize;
+use sqlx::{AssertSqlSafe, Executor, FromRow, IntoArguments, SqlSafeStr};
+
+/// The SQL a `PaginatedQueryBuilder` executes, split into its clauses.
+///
+/// Every string has its whitespace collapsed to single spaces and is trimmed, so
+/// assertions don't depend on formatting. Clauses that are absent are empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedSql {
+    /// The `WHERE` clause of the records and count queries, e.g. `WHERE "name" = $1`
+    pub where_clause: String,
+
+    /// The `ORDER BY` clause of the records query, e.g. `ORDER BY "created_at" DESC`
+    pub order_by: String,
+
+    /// The `LIMIT` and `OFFSET` clause of the records query, e.g. `LIMIT $2 OFFSET $3`,
+    /// or only `LIMIT $2` on the first page
+    pub limit_offset: String,
+
+    /// The complete records query
+    pub records_sql: String,
+
+    /// The complete count query, or `None` when totals are disabled
+    pub count_sql: Option<String>,
+}
+
+impl GeneratedSql {
+    pub(crate) fn new(
+        where_clause: &str,
+        order_by: &str,
+        limit_offset: &str,
+        records_sql: &str,
+        count_sql: Option<&str>,
+    ) -> Self {
+        Self {
+            where_clause: normalize_sql(where_clause),
+            order_by: normalize_sql(order_by),
+            limit_offset: normalize_sql(limit_offset),
+            records_sql: normalize_sql(records_sql),
+            count_sql: count_sql.map(normalize_sql),
+        }
+    }
+}
+
+/// Builders whose generated SQL can be inspected, implemented for the
+/// `PaginatedQueryBuilder` of every supported database.
+pub trait GeneratedSqlSource {
+    /// Builds the SQL the builder would execute, binding the same arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `sqlx::Error` when fetching would fail before reaching the
+    /// database, e.g. for invalid parameters or an offset past `with_max_offset`.
+    fn generated_sql(self) -> Result<GeneratedSql, sqlx::Error>;
+}
+
+#[cfg(feature = "postgres")]
+impl<T, A> GeneratedSqlSource for PaginatedQueryBuilder<'_, T, sqlx::Postgres, A>
+where
+    T: for<'r> FromRow<'r, <sqlx::Postgres as sqlx::Database>::Row>
+        + Send
+        + Unpin
+        + Serialize
+        + Default,
+    A: IntoArguments<sqlx::Postgres> + Send,
+{
+    fn generated_sql(self) -> Result<GeneratedSql, sqlx::Error> {
+        use crate::paginated_query_as::internal::PostgresDialect;
+
+        self.generated_sql_from_dialect(&PostgresDialect)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, A> GeneratedSqlSource for PaginatedQueryBuilder<'_, T, sqlx::Sqlite, A>
+where
+    T: for<'r> FromRow<'r, <sqlx::Sqlite as sqlx::Database>::Row>
+        + Send
+        + Unpin
+        + Serialize
+        + Default,
+    A: IntoArguments<sqlx::Sqlite> + Send,
+{
+    fn generated_sql(self) -> Result<GeneratedSql, sqlx::Error> {
+        use crate::paginated_query_as::internal::SqliteDialect;
+
+        self.generated_sql_from_dialect(&SqliteDialect)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T, A> GeneratedSqlSource for PaginatedQueryBuilder<'_, T, sqlx::MySql, A>
+where
+    T: for<'r> FromRow<'r, <sqlx::MySql as sqlx::Database>::Row>
+        + Send
+        + Unpin
+        + Serialize
+        + Default,
+    A: IntoArguments<sqlx::MySql> + Send,
+{
+    fn generated_sql(self) -> Result<GeneratedSql, sqlx::Error> {
+        use crate::paginated_query_as::internal::MySqlDialect;
+
+        self.generated_sql_from_dialect(&MySqlDialect)
+    }
+}
+
+/// Returns the SQL generated by a builder, for asserting on its shape in tests.
+///
+/// # Panics
+///
+/// Panics when the SQL can't be generated, see `GeneratedSqlSource::generated_sql`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sqlx::{FromRow, Postgres};
+/// use serde::Serialize;
+/// use sqlx_paginated::testing::assert_generated_sql;
+/// use sqlx_paginated::{paginated_query_as, QueryParamsBuilder, QuerySortDirection};
+///
+/// #[derive(Serialize, FromRow, Default)]
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// let sql = assert_generated_sql(
+///     paginated_query_as::<User, Postgres>("SELECT * FROM users").with_params(
+///         QueryParamsBuilder::<User>::new()
+///             .with_filter("name", Some("john"))
+///             .with_sort("name", QuerySortDirection::Ascending)
+///             .build(),
+///     ),
+/// );
+///
+/// assert_eq!(sql.where_clause, "WHERE \"name\" = $1");
+/// assert_eq!(sql.order_by, "ORDER BY \"name\" ASC");
+/// assert_eq!(sql.limit_offset, "LIMIT $2");
+/// ```
+pub fn assert_generated_sql(builder: impl GeneratedSqlSource) -> GeneratedSql {
+    match builder.generated_sql() {
+        Ok(sql) => sql,
+        Err(error) => panic!("failed to generate SQL: {}", error),
+    }
+}
+
+/// Prepares the records and count queries of the generated SQL on a connection,
+/// without executing them, returning the first error of the database.
+///
+/// Preparing has the database parse and plan the statements, so syntax errors and
+/// unknown tables or columns are reported, much like the `query!` macros check
+/// their SQL at compile time. This is a testing aid for catching invalid SQL of a
+/// dialect: fetching prepares the same statements anyway.
+///
+/// # Errors
+///
+/// Returns the `sqlx::Error` of the first statement the database rejects
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sqlx::{Connection, FromRow, PgConnection, Postgres};
+/// use serde::Serialize;
+/// use sqlx_paginated::paginated_query_as;
+/// use sqlx_paginated::testing::{assert_generated_sql, prepare_generated_sql};
+///
+/// #[derive(Serialize, FromRow, Default)]
+/// struct User {
+///     id: i32,
+///     name: String,
+///     created_at: String,
+/// }
+///
+/// # async fn example() -> Result<(), sqlx::Error> {
+/// let mut connection = PgConnection::connect("postgres://localhost/test").await?;
+/// let sql = assert_generated_sql(paginated_query_as::<User, Postgres>("SELECT * FROM users"));
+///
+/// prepare_generated_sql(&mut connection, &sql).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn prepare_generated_sql<C>(
+    connection: &mut C,
+    sql: &GeneratedSql,
+) -> Result<(), sqlx::Error>
+where
+    for<'c> &'c mut C: Executor<'c>,
+{
+    connection
+        .prepare(AssertSqlSafe(sql.records_sql.clone()).into_sql_str())
+        .await?;
+
+    if let Some(count_sql) = &sql.count_sql {
+        connection
+            .prepare(AssertSqlSafe(count_sql.clone()).into_sql_str())
+            .await?;
+    }
+    Ok(())
+}
+
+/// Prepares the generated SQL like `prepare_generated_sql`, on a throwaway
+/// in-memory SQLite database created with the given schema.
+///
+/// # Errors
+///
+/// Returns `sqlx::Error` when the schema can't be created, or for the first
+/// statement SQLite rejects
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sqlx::{FromRow, Sqlite};
+/// use serde::Serialize;
+/// use sqlx_paginated::paginated_query_as;
+/// use sqlx_paginated::testing::{assert_generated_sql, prepare_generated_sql_in_memory};
+///
+/// #[derive(Serialize, FromRow, Default)]
+/// struct User {
+///     id: i32,
+///     name: String,
+///     created_at: String,
+/// }
+///
+/// # async fn example() -> Result<(), sqlx::Error> {
+/// let sql = assert_generated_sql(paginated_query_as::<User, Sqlite>("SELECT * FROM users"));
+///
+/// prepare_generated_sql_in_memory(
+///     "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, created_at TEXT)",
+///     &sql,
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "sqlite")]
+pub async fn prepare_generated_sql_in_memory(
+    schema: &str,
+    sql: &GeneratedSql,
+) -> Result<(), sqlx::Error> {
+    use sqlx::Connection;
+
+    let mut connection = sqlx::SqliteConnection::connect("sqlite::memory:").await?;
+    sqlx::raw_sql(AssertSqlSafe(schema.to_string()))
+        .execute(&mut connection)
+        .await?;
+
+    prepare_generated_sql(&mut connection, sql).await
+}
+
+/// Collapses runs of whitespace to single spaces and trims the ends.
+fn normalize_sql(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_sql() {
+        assert_eq!(
+            normalize_sql(" SELECT *\n    FROM  users\tWHERE id = $1 "),
+            "SELECT * FROM users WHERE id = $1"
+        );
+        assert_eq!(normalize_sql(""), "");
+    }
+}
@@ -53,9 +53,11 @@ where
     DB::Arguments: IntoArguments<DB>,
     for<'c> &'c sqlx::Pool<DB>: sqlx::Executor<'c, Database = DB>,
     usize: sqlx::ColumnIndex<DB::Row>,
-    i64: sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB> + Send + Unpin,
+    i64:
+        sqlx::Type<DB> + for<'r> sqlx::Decode<'r, DB> + for<'e> sqlx::Encode<'e, DB> + Send + Unpin,
 {
     PaginatedQueryBuilder::new(sqlx::query_as::<DB, T>(sql), |params| {
         DB::build_default_query(params)
     })
+    .with_default_query_builder(DB::default_query_builder::<T>)
 }
@@ -8,6 +8,17 @@
 //! cargo test --test end_to_end --features sqlite
 //! ```
 //!
+//! ### mysql (requires DB)
+//! ```bash
+//! docker run --name sqlx-test-mysql \
+//!   -e MYSQL_ROOT_PASSWORD=mysql \
+//!   -e MYSQL_DATABASE=sqlx_paginated_test \
+//!   -p 3306:3306 -d mysql:8
+//!
+//! # Run tests
+//! cargo test --test end_to_end --features mysql -- --ignored --test-threads=1
+//! ```
+//!
 //! ### postgres (requires DB)
 //! ```bash
 //! docker run --name sqlx-test-postgres \
@@ -26,3 +37,7 @@ mod postgres;
 #[cfg(feature = "sqlite")]
 #[path = "end_to_end/sqlite.rs"]
 mod sqlite;
+
+#[cfg(feature = "mysql")]
+#[path = "end_to_end/mysql.rs"]
+mod mysql;
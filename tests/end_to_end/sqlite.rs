@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::sqlite::{Sqlite, SqlitePool, SqlitePoolOptions};
 use sqlx::FromRow;
 use sqlx_paginated::{
-    paginated_query_as, PaginatedResponse, QueryFilterOperator, QueryParamsBuilder,
+    paginated_query_as, FlatQueryParamsBuilder, KeysetDirection, PageMeta, PageToken,
+    PaginatedResponse, PaginationContext, QueryFilterOperator, QueryParams, QueryParamsBuilder,
     QuerySortDirection,
 };
 
@@ -31,6 +32,13 @@ struct TestProduct {
     created_at: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Default)]
+struct TestCatalogItem {
+    id: String,
+    name: String,
+    kind: String,
+}
+
 async fn setup_test_db() -> Result<SqlitePool, sqlx::Error> {
     let pool = SqlitePoolOptions::new()
         .max_connections(1)
@@ -673,6 +681,8 @@ async fn test_disable_totals_count() {
     assert!(!result.records.is_empty());
     assert_eq!(result.total, None);
     assert_eq!(result.total_pages, None);
+    assert_eq!(result.has_next, None);
+    assert_eq!(result.has_previous, None);
 }
 
 #[tokio::test]
@@ -709,6 +719,22 @@ async fn test_large_result_set() {
     assert_eq!(result.records.len(), 50);
     assert_eq!(result.total, Some(100));
     assert_eq!(result.total_pages, Some(2));
+    assert_eq!(result.has_next, Some(true));
+    assert_eq!(result.has_previous, Some(false));
+
+    let result: PaginatedResponse<TestUser> =
+        paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestUser>::new()
+                    .with_pagination(2, 50)
+                    .build(),
+            )
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+    assert_eq!(result.has_next, Some(false));
+    assert_eq!(result.has_previous, Some(true));
 }
 
 #[tokio::test]
@@ -722,3 +748,1342 @@ async fn test_max_page_size_clamping() {
 
     assert!(params.pagination.page_size <= 100);
 }
+
+#[tokio::test]
+async fn test_union_all_base_query() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+    seed_products(&pool).await.unwrap();
+
+    let params = QueryParamsBuilder::<TestCatalogItem>::new()
+        .with_filter_in("id", vec!["1", "2", "3", "p1", "p2", "p3"])
+        .with_sort("name", QuerySortDirection::Ascending)
+        .build();
+
+    let result = paginated_query_as::<TestCatalogItem, Sqlite>(
+        "SELECT id, first_name AS name, 'user' AS kind FROM users \
+         UNION ALL \
+         SELECT id, name, 'product' AS kind FROM products;",
+    )
+    .with_params(params)
+    .fetch_paginated(&pool)
+    .await
+    .unwrap();
+
+    let names: Vec<&str> = result.records.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "Jane",
+            "John",
+            "Johnny",
+            "Laptop Pro",
+            "Mechanical Keyboard",
+            "Wireless Mouse"
+        ]
+    );
+    assert_eq!(
+        result.records.iter().filter(|r| r.kind == "user").count(),
+        3
+    );
+    assert_eq!(result.total, Some(6));
+    assert_eq!(result.total_pages, Some(1));
+}
+
+#[tokio::test]
+async fn test_fetch_page_of_record() {
+    let pool = setup_test_db().await.unwrap();
+
+    for i in 1..=25 {
+        sqlx::query(
+            "INSERT INTO users (id, first_name, last_name, email, confirmed, created_at) 
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("user_{}", i))
+        .bind(format!("User{:02}", i))
+        .bind(format!("Last{}", i))
+        .bind(format!("user{}@example.com", i))
+        .bind(i % 2 == 0)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let params = || {
+        QueryParamsBuilder::<TestUser>::new()
+            .with_pagination(1, 10)
+            .with_sort("first_name", QuerySortDirection::Ascending)
+    };
+
+    let page = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params().build())
+        .fetch_page_of(&pool, "id", "user_21")
+        .await
+        .unwrap();
+    assert_eq!(page, Some(3));
+
+    let page = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params().with_filter("confirmed", Some("on")).build())
+        .fetch_page_of(&pool, "id", "user_21")
+        .await
+        .unwrap();
+    assert_eq!(page, None);
+
+    let page = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params().build())
+        .fetch_page_of(&pool, "id", "missing")
+        .await
+        .unwrap();
+    assert_eq!(page, None);
+
+    let error = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params().build())
+        .fetch_page_of(&pool, "secret", "user_21")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, sqlx::Error::ColumnNotFound(_)));
+}
+
+#[tokio::test]
+async fn test_search_anchor_pattern_shape() {
+    use sqlx_paginated::SearchAnchor;
+
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let mut matches = Vec::new();
+    for anchor in [
+        SearchAnchor::Both,
+        SearchAnchor::Prefix,
+        SearchAnchor::Suffix,
+    ] {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("n", vec!["first_name"])
+            .with_search_anchor(anchor)
+            .with_sort("first_name", QuerySortDirection::Ascending)
+            .build();
+
+        let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+            .with_params(params)
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        matches.push(
+            result
+                .records
+                .into_iter()
+                .map(|user| user.first_name)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    assert_eq!(matches[0], vec!["Diana", "Jane", "John", "Johnny"]);
+    assert!(matches[1].is_empty());
+    assert_eq!(matches[2], vec!["John"]);
+}
+
+#[tokio::test]
+async fn test_require_explicit_sort() {
+    use sqlx_paginated::{FlatQueryParams, PaginationError, QueryParams};
+
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let error = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(QueryParamsBuilder::<TestUser>::new().build())
+        .require_explicit_sort()
+        .fetch_paginated(&pool)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        PaginationError::from_sqlx_error(&error),
+        Some(&PaginationError::MissingSort)
+    );
+
+    let params = QueryParamsBuilder::<TestUser>::new()
+        .with_sort("first_name", QuerySortDirection::Ascending)
+        .build();
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params)
+        .require_explicit_sort()
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.records[0].first_name, "Alice");
+
+    let flat: FlatQueryParams =
+        serde_json::from_value(serde_json::json!({ "order_by": "-first_name" })).unwrap();
+    let params: QueryParams<TestUser> = flat.into();
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params)
+        .require_explicit_sort()
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.records[0].first_name, "Johnny");
+}
+
+#[tokio::test]
+async fn test_max_page_size_overrides_page_size_clamp() {
+    use sqlx_paginated::FlatQueryParams;
+
+    let pool = setup_test_db().await.unwrap();
+
+    for i in 1..=80 {
+        sqlx::query(
+            "INSERT INTO users (id, first_name, last_name, email, confirmed, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("user_{}", i))
+        .bind(format!("First{}", i))
+        .bind(format!("Last{}", i))
+        .bind(format!("user{}@example.com", i))
+        .bind(true)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let fetch = |max_page_size: Option<i64>| {
+        let pool = pool.clone();
+        async move {
+            let flat: FlatQueryParams =
+                serde_json::from_value(serde_json::json!({ "page_size": "75" })).unwrap();
+            let mut builder =
+                paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users").with_params(flat);
+            if let Some(max_page_size) = max_page_size {
+                builder = builder.with_max_page_size(max_page_size);
+            }
+            builder.fetch_paginated(&pool).await.unwrap()
+        }
+    };
+
+    let result = fetch(None).await;
+    assert_eq!(result.records.len(), 50);
+
+    let result = fetch(Some(100)).await;
+    assert_eq!(result.records.len(), 75);
+    assert_eq!(result.pagination.as_ref().unwrap().page_size, 75);
+    assert_eq!(result.total_pages, Some(2));
+
+    let result = fetch(Some(20)).await;
+    assert_eq!(result.records.len(), 20);
+    assert_eq!(result.total_pages, Some(4));
+
+    let result = fetch(Some(0)).await;
+    assert_eq!(result.records.len(), 10);
+}
+
+#[tokio::test]
+async fn test_prepended_cte_arguments() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let params = || {
+        QueryParamsBuilder::<TestUser>::new()
+            .with_search("o", vec!["first_name"])
+            .with_sort("first_name", QuerySortDirection::Ascending)
+            .build()
+    };
+    let query = || {
+        paginated_query_as::<TestUser, Sqlite>(
+            "SELECT * FROM users WHERE id IN (SELECT id FROM picked)",
+        )
+        .with_prepended_cte(
+            "picked",
+            "SELECT id FROM users WHERE first_name LIKE ? OR first_name = ?",
+            vec!["J%".to_string(), "Alice".to_string()],
+        )
+    };
+
+    let result = query()
+        .with_params(params())
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    let names: Vec<&str> = result
+        .records
+        .iter()
+        .map(|user| user.first_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["John", "Johnny"]);
+    assert_eq!(result.total, Some(2));
+
+    let page = query()
+        .with_params(params())
+        .fetch_page_of(&pool, "id", "3")
+        .await
+        .unwrap();
+    assert_eq!(page, Some(1));
+}
+
+#[tokio::test]
+async fn test_boolean_filter_matches_integer_storage() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    // `confirmed + 0` has no column affinity, so only integer bound values match it
+    let sql = "SELECT id, first_name, last_name, email, confirmed + 0 AS confirmed, created_at \
+               FROM users";
+
+    for value in ["1", "true", "on"] {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_filter("confirmed", Some(value))
+            .build();
+        let result = paginated_query_as::<TestUser, Sqlite>(sql)
+            .with_params(params)
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, Some(5), "confirmed={}", value);
+        assert!(result.records.iter().all(|user| user.confirmed));
+    }
+
+    let params = QueryParamsBuilder::<TestUser>::new()
+        .with_filter("confirmed", Some("0"))
+        .build();
+    let result = paginated_query_as::<TestUser, Sqlite>(sql)
+        .with_params(params)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.total, Some(3));
+}
+
+#[tokio::test]
+async fn test_max_offset() {
+    use sqlx_paginated::PaginationError;
+
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let params = |page| {
+        QueryParamsBuilder::<TestUser>::new()
+            .with_pagination(page, 10)
+            .build()
+    };
+
+    let error = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params(3))
+        .with_max_offset(19)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        PaginationError::from_sqlx_error(&error),
+        Some(PaginationError::OffsetTooLarge { offset: 20, .. })
+    ));
+
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params(3))
+        .with_max_offset(20)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert!(result.records.is_empty());
+
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params(3))
+        .with_clamped_max_offset(5)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.pagination.unwrap().page, 1);
+    assert_eq!(result.records.len(), 8);
+}
+
+#[tokio::test]
+async fn test_cached_total_skips_count() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let params = || {
+        QueryParamsBuilder::<TestUser>::new()
+            .with_pagination(1, 10)
+            .with_sort("first_name", QuerySortDirection::Ascending)
+            .build()
+    };
+
+    let counted = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params())
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+
+    let cached = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params())
+        .with_cached_total(counted.total.unwrap())
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&cached).unwrap(),
+        serde_json::to_value(&counted).unwrap()
+    );
+
+    // The cached total is reported as is, proving no count ran
+    let cached = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(params())
+        .with_cached_total(95)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(cached.total, Some(95));
+    assert_eq!(cached.total_pages, Some(10));
+    assert_eq!(cached.records.len(), 8);
+}
+
+#[tokio::test]
+async fn test_page_bounds() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let fetch = |page| {
+        let pool = pool.clone();
+        async move {
+            paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+                .with_params(
+                    QueryParamsBuilder::<TestUser>::new()
+                        .with_pagination(page, 10)
+                        .with_sort("first_name", QuerySortDirection::Ascending)
+                        .build(),
+                )
+                .with_page_bounds("first_name")
+                .fetch_paginated(&pool)
+                .await
+                .unwrap()
+        }
+    };
+
+    let result = fetch(1).await;
+    let first = &result.records[0].first_name;
+    let last = &result.records[result.records.len() - 1].first_name;
+    assert_eq!(result.page_bounds, Some((first.clone(), last.clone())));
+    assert_eq!(
+        result.page_bounds,
+        Some(("Alice".to_string(), "Johnny".to_string()))
+    );
+
+    let result = fetch(2).await;
+    assert!(result.records.is_empty());
+    assert_eq!(result.page_bounds, None);
+}
+
+#[tokio::test]
+async fn test_sort_coalesce_binds_sentinel() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(
+            QueryParamsBuilder::<TestUser>::new()
+                .with_search("jo", vec!["first_name"])
+                .with_sort("first_name", QuerySortDirection::Ascending)
+                .build(),
+        )
+        .with_sort_coalesce("first_name", String::new())
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = result
+        .records
+        .iter()
+        .map(|user| user.first_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["John", "Johnny"]);
+    assert_eq!(result.total, Some(2));
+}
+
+#[tokio::test]
+async fn test_page_tokens() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+    for idx in 0..7 {
+        sqlx::query(
+            "INSERT INTO users (id, first_name, last_name, email, confirmed, created_at) \
+             VALUES (?, ?, 'Extra', ?, 0, '2024-01-01T00:00:00Z')",
+        )
+        .bind(format!("extra-{}", idx))
+        .bind(format!("Zed {}", idx))
+        .bind(format!("extra{}@example.com", idx))
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let fetch = |token: Option<String>| {
+        let pool = pool.clone();
+        async move {
+            let mut flat = FlatQueryParamsBuilder::new()
+                .with_pagination(1, 10)
+                .with_sort("first_name", QuerySortDirection::Ascending);
+            if let Some(token) = token {
+                flat = flat.with_page_token(token);
+            }
+
+            paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+                .with_params(QueryParams::<TestUser>::from(flat.build()))
+                .with_page_tokens()
+                .fetch_paginated(&pool)
+                .await
+                .unwrap()
+        }
+    };
+
+    let first = fetch(None).await;
+    assert_eq!(first.records.len(), 10);
+    let token = first.next_page_token.unwrap();
+    assert_eq!(PageToken::decode(&token), Some(PageToken { offset: 10 }));
+
+    let second = fetch(Some(token)).await;
+    assert_eq!(second.pagination.as_ref().unwrap().page, 2);
+    assert_eq!(second.records.len(), 5);
+    assert_eq!(second.records[0].first_name, "Zed 2");
+    assert_eq!(second.next_page_token, None);
+
+    let unaligned = fetch(Some(PageToken { offset: 12 }.encode())).await;
+    assert_eq!(unaligned.records.len(), 3);
+    assert_eq!(unaligned.records[0].first_name, "Zed 4");
+    assert_eq!(unaligned.has_previous, Some(true));
+    assert_eq!(unaligned.has_next, Some(false));
+}
+
+#[tokio::test]
+async fn test_cursor_pagination_breaks_ties_on_id() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+    for idx in 0..7 {
+        sqlx::query(
+            "INSERT INTO users (id, first_name, last_name, email, confirmed, created_at) \
+             VALUES (?, 'Zed', 'Extra', ?, 0, '2024-01-01T00:00:00Z')",
+        )
+        .bind(format!("extra-{}", idx))
+        .bind(format!("extra{}@example.com", idx))
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let fetch = |cursor: Option<String>| {
+        let pool = pool.clone();
+        async move {
+            paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+                .with_params(
+                    QueryParamsBuilder::<TestUser>::new()
+                        .with_pagination(1, 10)
+                        .build(),
+                )
+                .with_cursor(
+                    "first_name",
+                    "id",
+                    cursor.as_deref(),
+                    QuerySortDirection::Ascending,
+                )
+                .fetch_paginated(&pool)
+                .await
+                .unwrap()
+        }
+    };
+
+    let first = fetch(None).await;
+    assert_eq!(first.records.len(), 10);
+    assert_eq!(first.total, None);
+    assert_eq!(first.records[0].first_name, "Alice");
+    assert_eq!(first.records[9].id, "extra-1");
+    assert_eq!(first.has_next, Some(true));
+    assert_eq!(first.has_previous, Some(false));
+
+    let second = fetch(first.next_cursor).await;
+    let ids: Vec<&str> = second.records.iter().map(|user| user.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["extra-2", "extra-3", "extra-4", "extra-5", "extra-6"]
+    );
+    assert_eq!(second.next_cursor, None);
+    assert_eq!(second.has_next, Some(false));
+    assert_eq!(second.has_previous, Some(true));
+}
+
+#[tokio::test]
+async fn test_cursor_pagination_pages_backward_to_the_same_rows() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+    for idx in 0..7 {
+        sqlx::query(
+            "INSERT INTO users (id, first_name, last_name, email, confirmed, created_at) \
+             VALUES (?, 'Zed', 'Extra', ?, 0, '2024-01-01T00:00:00Z')",
+        )
+        .bind(format!("extra-{}", idx))
+        .bind(format!("extra{}@example.com", idx))
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let fetch = |cursor: Option<String>, direction: KeysetDirection| {
+        let pool = pool.clone();
+        async move {
+            paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+                .with_params(
+                    QueryParamsBuilder::<TestUser>::new()
+                        .with_pagination(1, 10)
+                        .build(),
+                )
+                .with_cursor(
+                    "first_name",
+                    "id",
+                    cursor.as_deref(),
+                    QuerySortDirection::Ascending,
+                )
+                .with_cursor_direction(direction)
+                .fetch_paginated(&pool)
+                .await
+                .unwrap()
+        }
+    };
+    let ids = |page: &PaginatedResponse<TestUser>| -> Vec<String> {
+        page.records.iter().map(|user| user.id.clone()).collect()
+    };
+
+    let first = fetch(None, KeysetDirection::Forward).await;
+    assert_eq!(first.previous_cursor, None);
+    let second = fetch(first.next_cursor.clone(), KeysetDirection::Forward).await;
+    assert_eq!(second.records.len(), 5);
+    assert!(second.previous_cursor.is_some());
+
+    let back = fetch(second.previous_cursor.clone(), KeysetDirection::Backward).await;
+    assert_eq!(ids(&back), ids(&first));
+    assert_eq!(back.has_next, Some(true));
+    assert_eq!(back.has_previous, Some(false));
+    assert_eq!(back.previous_cursor, None);
+
+    let forward_again = fetch(back.next_cursor, KeysetDirection::Forward).await;
+    assert_eq!(ids(&forward_again), ids(&second));
+
+    // Without a cursor, paging backward fetches the last page
+    let last = fetch(None, KeysetDirection::Backward).await;
+    let mut expected = ids(&first)[5..].to_vec();
+    expected.extend(ids(&second));
+    assert_eq!(ids(&last), expected);
+    assert_eq!(last.has_next, Some(false));
+    assert_eq!(last.has_previous, Some(true));
+}
+
+#[tokio::test]
+async fn test_fetch_paginated_with_row_numbers() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_params(
+            QueryParamsBuilder::<TestUser>::new()
+                .with_filter("confirmed", Some("true"))
+                .with_sort("first_name", QuerySortDirection::Descending)
+                .build(),
+        )
+        .fetch_paginated_with_row_numbers(&pool)
+        .await
+        .unwrap();
+
+    let records: Vec<(&str, i64)> = result
+        .records
+        .iter()
+        .map(|(user, number)| (user.first_name.as_str(), *number))
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            ("John", 1),
+            ("Jane", 2),
+            ("Diana", 3),
+            ("Charlie", 4),
+            ("Alice", 5)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_meta_runs_only_the_count_query() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let builder = || {
+        paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestUser>::new()
+                    .with_pagination(2, 10)
+                    .with_filter("confirmed", Some("true"))
+                    .build(),
+            )
+            // Makes the records query fail if it were executed
+            .with_records_select("missing_column")
+            .disable_totals_count()
+    };
+
+    assert!(builder().fetch_paginated(&pool).await.is_err());
+
+    let meta = builder().fetch_meta(&pool).await.unwrap();
+    assert_eq!(
+        meta,
+        PageMeta {
+            page: 2,
+            page_size: 10,
+            total: 5,
+            total_pages: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_counter_table_total_is_estimate() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+    sqlx::query(
+        "CREATE TABLE table_counts (table_name TEXT NOT NULL, approx_count INTEGER NOT NULL)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO table_counts VALUES ('users', 1000)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_counter_table("table_counts", "table_name", "approx_count", "users")
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.total, Some(1000));
+    assert_eq!(result.total_pages, Some(100));
+    assert!(result.total_is_estimate);
+    assert_eq!(result.records.len(), 8);
+
+    // Without a counter row the rows are counted
+    let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+        .with_counter_table("table_counts", "table_name", "approx_count", "orders")
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(result.total, Some(8));
+    assert!(!result.total_is_estimate);
+}
+
+#[cfg(feature = "sqlite-inline-base-query")]
+#[tokio::test]
+async fn test_inline_base_query_matches_cte() {
+    let pool = setup_test_db().await.unwrap();
+
+    for i in 1..=25 {
+        sqlx::query(
+            "INSERT INTO users (id, first_name, last_name, email, confirmed, created_at) 
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("user_{}", i))
+        .bind(format!("User{:02}", i % 7))
+        .bind(format!("Last{}", i))
+        .bind(format!("user{}@example.com", i))
+        .bind(i % 2 == 0)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let params = || {
+        QueryParamsBuilder::<TestUser>::new()
+            .with_pagination(2, 10)
+            .with_search("user", vec!["email"])
+            .with_filter("confirmed", Some("true"))
+            .with_sort("first_name", QuerySortDirection::Descending)
+            .with_sort("id", QuerySortDirection::Ascending)
+            .build()
+    };
+
+    let cte = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users;")
+        .with_params(params())
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    let inline = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users;")
+        .with_params(params())
+        .with_inline_base_query()
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+
+    let ids = |response: &PaginatedResponse<TestUser>| {
+        response
+            .records
+            .iter()
+            .map(|user| user.id.clone())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(ids(&inline).len(), 2);
+    assert_eq!(ids(&inline), ids(&cte));
+    assert_eq!(inline.total, cte.total);
+    assert_eq!(inline.total_pages, cte.total_pages);
+
+    for id in ["user_2", "user_24", "user_3"] {
+        let cte_page = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+            .with_params(params())
+            .fetch_page_of(&pool, "id", id)
+            .await
+            .unwrap();
+        let inline_page = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+            .with_params(params())
+            .with_inline_base_query()
+            .fetch_page_of(&pool, "id", id)
+            .await
+            .unwrap();
+
+        assert_eq!(inline_page, cte_page);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Default)]
+struct TestConfirmation {
+    first_name: String,
+    confirmed: Option<bool>,
+}
+
+#[tokio::test]
+async fn test_boolean_tests_on_nullable_column() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    // John (confirmed) and Johnny (unconfirmed) have no confirmation
+    let sql = "SELECT first_name, \
+               CASE WHEN first_name IN ('John', 'Johnny') THEN NULL ELSE confirmed END AS confirmed \
+               FROM users";
+
+    for (params, expected) in [
+        (
+            QueryParamsBuilder::new().with_filter_is("confirmed", true),
+            4,
+        ),
+        (
+            QueryParamsBuilder::new().with_filter_is("confirmed", false),
+            2,
+        ),
+        (
+            QueryParamsBuilder::new().with_filter_is_not("confirmed", true),
+            4,
+        ),
+        (
+            QueryParamsBuilder::new().with_filter_is_not("confirmed", false),
+            6,
+        ),
+    ] {
+        let params = params
+            .with_pagination(1, 10)
+            .with_sort("first_name", QuerySortDirection::Ascending)
+            .build();
+        let result = paginated_query_as::<TestConfirmation, Sqlite>(sql)
+            .with_params(params)
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, Some(expected));
+    }
+
+    let params = QueryParamsBuilder::<TestConfirmation>::new()
+        .with_filter_is_not("confirmed", true)
+        .with_sort("first_name", QuerySortDirection::Ascending)
+        .build();
+    let result = paginated_query_as::<TestConfirmation, Sqlite>(sql)
+        .with_params(params)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        result
+            .records
+            .iter()
+            .map(|user| (user.first_name.as_str(), user.confirmed))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Bob", Some(false)),
+            ("Eve", Some(false)),
+            ("John", None),
+            ("Johnny", None),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_paginated_debug_returns_the_executed_sql() {
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let builder = || {
+        paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users").with_params(
+            QueryParamsBuilder::<TestUser>::new()
+                .with_filter("confirmed", Some("true"))
+                .with_pagination(1, 10)
+                .build(),
+        )
+    };
+    let (records_sql, count_sql) = builder().to_sql().unwrap();
+
+    let (result, executed) = builder().fetch_paginated_debug(&pool).await.unwrap();
+
+    assert_eq!(executed.records_sql, records_sql);
+    assert_eq!(executed.count_sql, Some(count_sql));
+    assert_eq!(executed.arguments, 2);
+    assert_eq!(result.total, Some(result.records.len() as i64));
+
+    let (_, executed) = builder()
+        .disable_totals_count()
+        .fetch_paginated_debug(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(executed.count_sql, None);
+
+    let (result, executed) = builder()
+        .with_cached_total(42)
+        .fetch_paginated_debug(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(executed.records_sql, records_sql);
+    assert_eq!(executed.count_sql, None);
+    assert_eq!(result.total, Some(42));
+
+    let context = PaginationContext::new();
+    let (_, first) = builder()
+        .with_context(&context)
+        .fetch_paginated_debug(&pool)
+        .await
+        .unwrap();
+    let (_, reused) = builder()
+        .with_context(&context)
+        .fetch_paginated_debug(&pool)
+        .await
+        .unwrap();
+
+    assert!(first.count_sql.is_some());
+    assert_eq!(reused.count_sql, None);
+}
+
+#[tokio::test]
+async fn test_require_filter_rejects_filters_emitting_no_condition() {
+    use sqlx_paginated::PaginationError;
+
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    let fetch = |operator, value| {
+        paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+            .with_params(
+                QueryParamsBuilder::<TestUser>::new()
+                    .with_filter_operator("first_name", operator, value)
+                    .build(),
+            )
+            .require_filter("first_name")
+            .fetch_paginated(&pool)
+    };
+
+    for (operator, value) in [
+        (QueryFilterOperator::In, ""),
+        (QueryFilterOperator::IsNotNull, ""),
+    ] {
+        let error = fetch(operator, value).await.err().unwrap();
+        assert_eq!(
+            PaginationError::from_sqlx_error(&error),
+            Some(&PaginationError::MissingRequiredFilter {
+                field: "first_name".to_string()
+            })
+        );
+    }
+
+    let result = fetch(QueryFilterOperator::Equal, "John").await.unwrap();
+    assert_eq!(result.total, Some(1));
+}
+
+#[tokio::test]
+async fn test_bound_join_values_bind_with_filters() {
+    use sqlx_paginated::ComputedPropertyBuilder;
+
+    let pool = setup_test_db().await.unwrap();
+    seed_users(&pool).await.unwrap();
+
+    sqlx::query(
+        "CREATE TABLE user_roles (user_id TEXT NOT NULL, tenant TEXT NOT NULL, role TEXT NOT NULL)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO user_roles (user_id, tenant, role) VALUES
+         ('1', 'acme', 'c'), ('2', 'acme', 'a'), ('4', 'acme', 'b'),
+         ('1', 'globex', 'a'), ('2', 'globex', 'b'), ('4', 'globex', 'c')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Both properties bind the same clause, to different tenants
+    let tenant_role = |name: &str, tenant: &str| {
+        ComputedPropertyBuilder::new(name, "user_roles.role")
+            .with_join_bound(
+                "LEFT JOIN user_roles ON user_roles.user_id = base_query.id \
+                 AND user_roles.tenant = ?",
+                vec![tenant],
+            )
+            .build()
+    };
+    let sorted_names = |sort_column: &'static str| {
+        let pool = pool.clone();
+        let tenant_role = &tenant_role;
+        async move {
+            let result = paginated_query_as::<TestUser, Sqlite>("SELECT * FROM users")
+                .with_computed_property(tenant_role("acme_role", "acme"))
+                .with_computed_property(tenant_role("globex_role", "globex"))
+                .with_params(
+                    QueryParamsBuilder::<TestUser>::new()
+                        .with_filter_operator(
+                            "first_name",
+                            QueryFilterOperator::In,
+                            "John,Jane,Alice",
+                        )
+                        .with_sort(sort_column, QuerySortDirection::Ascending)
+                        .build(),
+                )
+                .fetch_paginated(&pool)
+                .await
+                .unwrap();
+
+            assert_eq!(result.total, Some(3));
+            result
+                .records
+                .into_iter()
+                .map(|user| user.first_name)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(
+        sorted_names("acme_role").await,
+        vec!["Jane", "Alice", "John"]
+    );
+    assert_eq!(
+        sorted_names("globex_role").await,
+        vec!["John", "Jane", "Alice"]
+    );
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Default)]
+struct TestCustomerStatus {
+    id: String,
+    name: String,
+    region: String,
+    last_status: String,
+}
+
+#[tokio::test]
+async fn test_default_scope_binds_before_having_conditions() {
+    use sqlx_paginated::{ComputedPropertyBuilder, QueryBuilder};
+
+    let pool = setup_test_db().await.unwrap();
+
+    sqlx::query(
+        "CREATE TABLE customers (id TEXT PRIMARY KEY, name TEXT NOT NULL, region TEXT NOT NULL)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("CREATE TABLE orders (customer_id TEXT NOT NULL, status TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO customers (id, name, region) VALUES ('1', 'Ann', 'eu'), ('2', 'Ben', 'eu'), ('3', 'Cid', 'us')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO orders (customer_id, status) VALUES
+         ('1', 'paid'), ('1', 'shipped'), ('2', 'paid'), ('3', 'shipped')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let last_status = ComputedPropertyBuilder::new("last_status", "MAX(orders.status)")
+        .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+        .as_aggregate()
+        .selected()
+        .build();
+    let having_property = last_status.clone();
+
+    let result =
+        paginated_query_as::<TestCustomerStatus, Sqlite>("SELECT id, name, region FROM customers")
+            .with_computed_property(last_status)
+            .with_having_query_builder(move |params| {
+                QueryBuilder::<TestCustomerStatus, Sqlite>::new()
+                    .with_aggregate_property(&having_property)
+                    .with_filters(params)
+            })
+            .with_default_scope(|query_builder| {
+                query_builder.with_condition("region", "=", "eu".to_string())
+            })
+            .with_params(
+                QueryParamsBuilder::<TestCustomerStatus>::new()
+                    .with_filter("last_status", Some("shipped"))
+                    .build(),
+            )
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+
+    let names: Vec<&str> = result
+        .records
+        .iter()
+        .map(|customer| customer.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Ann"]);
+    assert_eq!(result.total, Some(1));
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Default)]
+struct TestCustomerOrders {
+    id: String,
+    name: String,
+    order_count: i64,
+}
+
+#[tokio::test]
+async fn test_aggregate_filters_compare_numbers_with_the_default_builder() {
+    use sqlx_paginated::ComputedPropertyBuilder;
+
+    let pool = setup_test_db().await.unwrap();
+
+    sqlx::query("CREATE TABLE customers (id TEXT PRIMARY KEY, name TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE orders (customer_id TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO customers (id, name) VALUES ('1', 'Ann'), ('2', 'Ben'), ('3', 'Cid')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO orders (customer_id) VALUES ('1'), ('1'), ('1'), ('2'), ('2'), ('3')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let fetch = |operator: QueryFilterOperator, value: &str| {
+        let params = QueryParamsBuilder::<TestCustomerOrders>::new()
+            .with_filter_operator("order_count", operator, value)
+            .with_sort("name", QuerySortDirection::Ascending)
+            .build();
+
+        paginated_query_as::<TestCustomerOrders, Sqlite>("SELECT id, name FROM customers")
+            .with_computed_property(
+                ComputedPropertyBuilder::new("order_count", "COUNT(orders.customer_id)")
+                    .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+                    .as_aggregate()
+                    .selected()
+                    .build(),
+            )
+            .with_params(params)
+            .fetch_paginated(&pool)
+    };
+
+    let result = fetch(QueryFilterOperator::GreaterOrEqual, "2")
+        .await
+        .unwrap();
+    let names: Vec<&str> = result
+        .records
+        .iter()
+        .map(|customer| customer.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Ann", "Ben"]);
+    assert_eq!(result.total, Some(2));
+
+    // Compared as text, '10' would sort before every count
+    let result = fetch(QueryFilterOperator::LessThan, "10").await.unwrap();
+    assert_eq!(result.records.len(), 3);
+    assert_eq!(result.records[0].order_count, 3);
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Default)]
+struct TestDatedCustomerOrders {
+    id: String,
+    name: String,
+    created_at: String,
+    order_count: i64,
+}
+
+async fn seed_dated_customer_orders(pool: &SqlitePool) {
+    sqlx::query(
+        "CREATE TABLE customers (id TEXT PRIMARY KEY, name TEXT NOT NULL, created_at TEXT NOT NULL)",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("CREATE TABLE orders (customer_id TEXT NOT NULL)")
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO customers (id, name, created_at) VALUES
+         ('1', 'Ann', '2024-01-01T00:00:00+00:00'), ('2', 'Ben', '2024-01-02T00:00:00+00:00'),
+         ('3', 'Cid', '2024-01-03T00:00:00+00:00'), ('4', 'Dan', '2024-06-01T00:00:00+00:00'),
+         ('5', 'Eve', '2024-06-02T00:00:00+00:00'), ('6', 'Fay', '2024-06-03T00:00:00+00:00'),
+         ('7', 'Gus', '2024-06-04T00:00:00+00:00'), ('8', 'Hal', '2024-06-05T00:00:00+00:00')",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO orders (customer_id) VALUES
+         ('1'), ('1'), ('1'), ('2'), ('2'), ('3'), ('4'), ('4'),
+         ('6'), ('6'), ('6'), ('6'), ('7'), ('7'), ('8')",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+fn order_count_property() -> sqlx_paginated::ComputedProperty {
+    sqlx_paginated::ComputedPropertyBuilder::new("order_count", "COUNT(orders.customer_id)")
+        .with_join("LEFT JOIN orders ON orders.customer_id = base_query.id")
+        .as_aggregate()
+        .selected()
+        .build()
+}
+
+fn order_count_params(excluded_name: &str) -> QueryParams<'static, TestDatedCustomerOrders> {
+    QueryParamsBuilder::<TestDatedCustomerOrders>::new()
+        .with_filter_operator("name", QueryFilterOperator::NotEqual, excluded_name)
+        .with_filter_operator("order_count", QueryFilterOperator::GreaterOrEqual, "2")
+        .with_sort("name", QuerySortDirection::Ascending)
+        .build()
+}
+
+fn customer_names(records: &[TestDatedCustomerOrders]) -> Vec<&str> {
+    records
+        .iter()
+        .map(|customer| customer.name.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_having_conditions_with_prepended_cte_arguments() {
+    let pool = setup_test_db().await.unwrap();
+    seed_dated_customer_orders(&pool).await;
+
+    let result = paginated_query_as::<TestDatedCustomerOrders, Sqlite>(
+        "SELECT * FROM customers WHERE id IN (SELECT id FROM picked)",
+    )
+    .with_prepended_cte(
+        "picked",
+        "SELECT id FROM customers WHERE name <> ?",
+        vec!["Fay".to_string()],
+    )
+    .with_computed_property(order_count_property())
+    .with_params(order_count_params("Ben"))
+    .fetch_paginated(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(customer_names(&result.records), vec!["Ann", "Dan", "Gus"]);
+    assert_eq!(result.total, Some(3));
+}
+
+#[tokio::test]
+async fn test_having_conditions_with_cursor_pagination() {
+    let pool = setup_test_db().await.unwrap();
+    seed_dated_customer_orders(&pool).await;
+    for idx in 10..30 {
+        sqlx::query(
+            "INSERT INTO customers (id, name, created_at) VALUES (?, ?, '2024-07-01T00:00:00+00:00')",
+        )
+        .bind(idx.to_string())
+        .bind(format!("Zed {}", idx))
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO orders (customer_id) VALUES (?), (?)")
+            .bind(idx.to_string())
+            .bind(idx.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let mut names = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = paginated_query_as::<TestDatedCustomerOrders, Sqlite>("SELECT * FROM customers")
+            .with_computed_property(order_count_property())
+            .with_params(order_count_params("Dan"))
+            .with_cursor(
+                "name",
+                "id",
+                cursor.as_deref(),
+                QuerySortDirection::Ascending,
+            )
+            .fetch_paginated(&pool)
+            .await
+            .unwrap();
+        names.extend(page.records.into_iter().map(|customer| customer.name));
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    let expected: Vec<String> = ["Ann", "Ben", "Fay", "Gus"]
+        .into_iter()
+        .map(str::to_string)
+        .chain((10..30).map(|idx| format!("Zed {}", idx)))
+        .collect();
+    assert_eq!(names, expected);
+}
+
+#[tokio::test]
+async fn test_having_conditions_with_count_window() {
+    let pool = setup_test_db().await.unwrap();
+    seed_dated_customer_orders(&pool).await;
+    let since = chrono::DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00")
+        .unwrap()
+        .with_timezone(&Utc);
+
+    let result = paginated_query_as::<TestDatedCustomerOrders, Sqlite>("SELECT * FROM customers")
+        .with_computed_property(order_count_property())
+        .with_params(order_count_params("Ann"))
+        .with_count_window("created_at", since)
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        customer_names(&result.records),
+        vec!["Ben", "Dan", "Fay", "Gus"]
+    );
+    assert_eq!(result.total, Some(3));
+
+    let result = paginated_query_as::<TestDatedCustomerOrders, Sqlite>("SELECT * FROM customers")
+        .with_computed_property(order_count_property())
+        .with_params(order_count_params("Ann"))
+        .with_count_window("created_at", since)
+        .with_windowed_records()
+        .fetch_paginated(&pool)
+        .await
+        .unwrap();
+    assert_eq!(customer_names(&result.records), vec!["Dan", "Fay", "Gus"]);
+    assert_eq!(result.total, Some(3));
+}
@@ -5,3 +5,14 @@ mod query_filter_operators;
 
 #[path = "integration/pagination.rs"]
 mod pagination;
+
+#[path = "integration/custom_dialect.rs"]
+mod custom_dialect;
+
+#[cfg(feature = "testing")]
+#[path = "integration/generated_sql.rs"]
+mod generated_sql;
+
+#[cfg(feature = "derive")]
+#[path = "integration/paginated_columns.rs"]
+mod paginated_columns;
@@ -0,0 +1,145 @@
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+// Most tests target PostgreSQL and share the models with the other databases
+#![cfg_attr(not(feature = "postgres"), allow(unused_imports, dead_code))]
+
+use serde::Serialize;
+use sqlx_paginated::{QueryBuilder, QueryDialect, QueryFilterOperator, QueryParamsBuilder};
+
+#[derive(Serialize, Default, Debug)]
+struct TestEvent {
+    id: i64,
+    name: String,
+    kind: String,
+    active: bool,
+}
+
+/// A ClickHouse-like dialect: backtick quoting, numbered `{pN}` placeholders
+/// and integer booleans.
+struct BacktickDialect;
+
+impl QueryDialect for BacktickDialect {
+    fn quote_identifier(&self, ident: &str) -> String {
+        format!("`{}`", ident.replace('`', "``"))
+    }
+
+    fn placeholder(&self, position: usize) -> String {
+        format!("{{p{}}}", position)
+    }
+
+    fn type_cast(&self, _value: &str) -> String {
+        String::new()
+    }
+
+    fn boolean_literal(&self, value: bool) -> &'static str {
+        if value {
+            "1"
+        } else {
+            "0"
+        }
+    }
+
+    fn numbers_placeholders(&self) -> bool {
+        true
+    }
+
+    fn offset_placeholders(&self, sql: &str, offset: usize) -> String {
+        let mut rewritten = String::new();
+        let mut rest = sql;
+        while let Some(start) = rest.find("{p") {
+            rewritten.push_str(&rest[..start]);
+            let tail = &rest[start + 2..];
+            let end = tail.find('}').unwrap_or(tail.len());
+            match tail[..end].parse::<usize>() {
+                Ok(position) => {
+                    rewritten.push_str(&format!("{{p{}}}", position + offset));
+                    rest = &tail[end + 1..];
+                }
+                Err(_) => {
+                    rewritten.push_str("{p");
+                    rest = tail;
+                }
+            }
+        }
+        rewritten.push_str(rest);
+        rewritten
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres_tests {
+    use super::*;
+    use sqlx::{Arguments, Postgres};
+
+    #[test]
+    fn test_custom_dialect_builds_where_clause() {
+        let params = QueryParamsBuilder::<TestEvent>::new()
+            .with_search("deploy", vec!["name"])
+            .with_filter_operator("kind", QueryFilterOperator::NotEqual, "internal")
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestEvent, Postgres>::with_dialect(BacktickDialect)
+            .with_search(&params)
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(`name`) LIKE LOWER({p1}))", "`kind` != {p2}"]
+        );
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_custom_dialect_boolean_literal_and_raw_condition() {
+        let params = QueryParamsBuilder::<TestEvent>::new()
+            .with_filter("active", Some("on"))
+            .build();
+
+        let (conditions, _args) =
+            QueryBuilder::<TestEvent, Postgres>::with_dialect(BacktickDialect)
+                .with_filters(&params)
+                .with_raw_condition("`kind` IS NOT NULL")
+                .build();
+
+        assert_eq!(conditions, vec!["`active` = {p1}", "`kind` IS NOT NULL"]);
+    }
+
+    #[test]
+    fn test_custom_dialect_without_regex_skips_regex_filters() {
+        let params = QueryParamsBuilder::<TestEvent>::new()
+            .with_filter_operator("name", QueryFilterOperator::Regex, "^deploy-")
+            .with_filter("active", Some("true"))
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestEvent, Postgres>::with_dialect(BacktickDialect)
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["`active` = {p1}"]);
+        assert_eq!(args.len(), 1);
+    }
+
+    #[test]
+    fn test_custom_dialect_offsets_exists_condition_placeholders() {
+        let params = QueryParamsBuilder::<TestEvent>::new()
+            .with_filter("kind", Some("deploy"))
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestEvent, Postgres>::with_dialect(BacktickDialect)
+            .with_filters(&params)
+            .with_exists_condition(
+                "SELECT 1 FROM audits a WHERE a.event_id = base_query.id AND a.actor = {p1}",
+                vec!["ci".to_string()],
+            )
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "`kind` = {p1}",
+                "EXISTS (SELECT 1 FROM audits a WHERE a.event_id = base_query.id AND a.actor = {p2})"
+            ]
+        );
+        assert_eq!(args.len(), 2);
+    }
+}
@@ -0,0 +1,761 @@
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+use serde::Serialize;
+use sqlx::FromRow;
+use sqlx_paginated::testing::assert_generated_sql;
+use sqlx_paginated::{
+    paginated_query_as, ComputedPropertyBuilder, QueryBuilder, QueryFilterOperator,
+    QueryNullsPosition, QueryParamsBuilder, QuerySortDirection, UnionPaginatedQuery,
+};
+
+#[derive(Serialize, FromRow, Default, Debug)]
+struct TestOrder {
+    id: i64,
+    customer: String,
+    status: String,
+    total: f64,
+    created_at: String,
+}
+
+#[cfg(feature = "postgres")]
+#[derive(Serialize, FromRow, Default, Debug)]
+struct TestCustomer {
+    id: i64,
+    #[serde(rename = "emailAddress")]
+    email: String,
+    created_at: String,
+}
+
+#[cfg(feature = "postgres")]
+#[derive(Serialize, FromRow, Default, Debug)]
+struct TestListing {
+    id: i64,
+    tags: Vec<String>,
+    category_ids: Vec<i64>,
+    metadata: String,
+}
+
+#[cfg(feature = "postgres")]
+mod postgres_tests {
+    use super::*;
+    use sqlx::Postgres;
+
+    #[test]
+    fn test_generated_sql_of_representative_query() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_pagination(3, 20)
+            .with_search("acme", vec!["customer"])
+            .with_filter("status", Some("paid"))
+            .with_filter_operator("total", QueryFilterOperator::GreaterThan, "100")
+            .with_sort("total", QuerySortDirection::Descending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT *\n  FROM orders")
+                .with_params(params),
+        );
+
+        assert_eq!(
+            sql.where_clause,
+            "WHERE (LOWER(\"customer\") LIKE LOWER($1)) AND \"status\" = $2 AND \"total\" > $3::smallint"
+        );
+        assert_eq!(sql.order_by, "ORDER BY \"total\" DESC");
+        assert_eq!(sql.limit_offset, "LIMIT $4 OFFSET $5");
+        assert_eq!(
+            sql.records_sql,
+            format!(
+                "WITH base_query AS (SELECT * FROM orders) SELECT * FROM base_query {} {} {}",
+                sql.where_clause, sql.order_by, sql.limit_offset
+            )
+        );
+        assert_eq!(
+            sql.count_sql.as_deref(),
+            Some(
+                format!(
+                    "WITH base_query AS (SELECT * FROM orders) SELECT COUNT(*) FROM base_query {}",
+                    sql.where_clause
+                )
+                .as_str()
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_binds_regex_patterns() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter_operator("customer", QueryFilterOperator::IRegex, "^acme")
+            .with_filter_operator("status", QueryFilterOperator::Regex, r"^[A-Z]{3}-\d+$")
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders").with_params(params),
+        );
+
+        assert_eq!(
+            sql.where_clause,
+            "WHERE \"customer\" ~* $1 AND \"status\" ~ $2"
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_casts_containment_values() {
+        let params = QueryParamsBuilder::<TestListing>::new()
+            .with_filter_operator("tags", QueryFilterOperator::Overlaps, "{sale,new}")
+            .with_filter_operator(
+                "category_ids",
+                QueryFilterOperator::ContainedBy,
+                "{1,2,70000}",
+            )
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestListing, Postgres>("SELECT * FROM listings")
+                .with_params(params),
+        );
+        let mut conditions: Vec<&str> = sql
+            .where_clause
+            .trim_start_matches("WHERE ")
+            .split(" AND ")
+            .collect();
+        conditions.sort();
+
+        assert_eq!(conditions.len(), 2);
+        assert!(conditions[0].starts_with("\"category_ids\"::text[] <@ $"));
+        assert!(conditions[0].ends_with("::text[]"));
+        assert!(conditions[1].starts_with("\"tags\"::text[] && $"));
+        assert!(conditions[1].ends_with("::text[]"));
+
+        let params = QueryParamsBuilder::<TestListing>::new()
+            .with_filter_operator(
+                "metadata",
+                QueryFilterOperator::Contains,
+                r#"{"color":"red"}"#,
+            )
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestListing, Postgres>("SELECT * FROM listings")
+                .with_params(params),
+        );
+
+        assert_eq!(sql.where_clause, "WHERE \"metadata\" @> $1::jsonb");
+    }
+
+    #[test]
+    fn test_generated_sql_without_conditions_or_totals() {
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .disable_totals_count(),
+        );
+
+        assert_eq!(sql.where_clause, "");
+        assert_eq!(sql.order_by, "ORDER BY \"created_at\" DESC");
+        assert_eq!(sql.limit_offset, "LIMIT $1");
+        assert_eq!(sql.count_sql, None);
+    }
+
+    #[test]
+    fn test_generated_sql_with_nulls_position() {
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders").with_params(
+                QueryParamsBuilder::<TestOrder>::new()
+                    .with_sort_nulls(
+                        "total",
+                        QuerySortDirection::Descending,
+                        QueryNullsPosition::Last,
+                    )
+                    .build(),
+            ),
+        );
+
+        assert_eq!(sql.order_by, "ORDER BY \"total\" DESC NULLS LAST");
+    }
+
+    #[test]
+    fn test_generated_sql_with_count_window() {
+        let since = "2024-01-01T00:00:00Z".parse().unwrap();
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter("status", Some("paid"))
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_params(params)
+                .with_count_window("created_at", since),
+        );
+
+        assert_eq!(sql.where_clause, "WHERE \"status\" = $1");
+        assert_eq!(
+            sql.count_sql.as_deref(),
+            Some(
+                "WITH base_query AS (SELECT * FROM orders) SELECT COUNT(*) FROM base_query \
+                 WHERE \"status\" = $1 AND \"created_at\" >= $2"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_qualifies_the_count_window_column_of_joined_queries() {
+        let since = "2024-01-01T00:00:00Z".parse().unwrap();
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_sort("shipped_at", QuerySortDirection::Descending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("shipped_at", "shipments.created_at")
+                        .with_join("LEFT JOIN shipments ON shipments.order_id = base_query.id")
+                        .build(),
+                )
+                .with_params(params)
+                .with_windowed_records()
+                .with_count_window("created_at", since),
+        );
+
+        assert_eq!(sql.where_clause, "WHERE base_query.\"created_at\" >= $1");
+    }
+
+    #[test]
+    fn test_generated_sql_with_count_capped() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter("status", Some("paid"))
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_params(params)
+                .with_count_capped(99),
+        );
+
+        assert_eq!(
+            sql.count_sql.as_deref(),
+            Some(
+                "WITH base_query AS (SELECT * FROM orders) SELECT COUNT(*) FROM (SELECT 1 \
+                 FROM base_query WHERE \"status\" = $1 LIMIT 100) AS capped_rows"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_with_window_count() {
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders").with_window_count(),
+        );
+
+        assert_eq!(
+            sql.records_sql,
+            "WITH base_query AS (SELECT * FROM orders) SELECT *, COUNT(*) OVER () AS \"__total\" \
+             FROM base_query ORDER BY \"created_at\" DESC LIMIT $1"
+        );
+
+        // A cached total is not counted at all
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_window_count()
+                .with_cached_total(42),
+        );
+
+        assert!(!sql.records_sql.contains("__total"));
+    }
+
+    #[test]
+    fn test_generated_sql_numbers_bound_join_values_after_conditions() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_pagination(2, 10)
+            .with_filter("status", Some("paid"))
+            .with_sort("carrier_rank", QuerySortDirection::Ascending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("carrier_rank", "carriers.rank")
+                        .with_join_bound(
+                            "LEFT JOIN carriers ON carriers.order_id = base_query.id \
+                             AND carriers.tenant_id = ?::integer AND carriers.region = $?",
+                            vec!["42", "eu"],
+                        )
+                        .build(),
+                )
+                .with_params(params),
+        );
+
+        assert_eq!(
+            sql.records_sql,
+            "WITH base_query AS (SELECT * FROM orders) SELECT base_query.* FROM base_query \
+             LEFT JOIN carriers ON carriers.order_id = base_query.id \
+             AND carriers.tenant_id = $2::integer AND carriers.region = $3 \
+             WHERE base_query.\"status\" = $1 ORDER BY (carriers.rank) ASC LIMIT $4 OFFSET $5"
+        );
+
+        // The count query doesn't join the sorted property
+        assert_eq!(
+            sql.count_sql.as_deref(),
+            Some(
+                "WITH base_query AS (SELECT * FROM orders) SELECT COUNT(*) FROM base_query \
+                 WHERE \"status\" = $1"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_requalifies_base_table_references_in_joins() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_sort("carrier", QuerySortDirection::Ascending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_base_table("orders")
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("carrier", "carriers.name")
+                        .with_join_bound(
+                            "LEFT JOIN carriers ON carriers.order_id = orders.id \
+                             AND carriers.tenant_id = ? AND carriers.note <> 'orders.id'",
+                            vec!["42"],
+                        )
+                        .build(),
+                )
+                .with_params(params),
+        );
+
+        assert_eq!(
+            sql.records_sql,
+            "WITH base_query AS (SELECT * FROM orders) SELECT base_query.* FROM base_query \
+             LEFT JOIN carriers ON carriers.order_id = base_query.id \
+             AND carriers.tenant_id = $1 AND carriers.note <> 'orders.id' \
+             ORDER BY (carriers.name) ASC LIMIT $2"
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_with_windowed_records() {
+        let since = "2024-01-01T00:00:00Z".parse().unwrap();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_windowed_records()
+                .with_count_window("created_at", since)
+                .with_count_window("unknown", since),
+        );
+
+        assert_eq!(sql.where_clause, "WHERE \"created_at\" >= $1");
+        assert_eq!(sql.limit_offset, "LIMIT $2");
+        assert!(sql
+            .count_sql
+            .as_deref()
+            .unwrap()
+            .ends_with("WHERE \"created_at\" >= $1"));
+    }
+
+    #[test]
+    fn test_column_mapping_uses_database_column_of_renamed_field() {
+        let params = QueryParamsBuilder::<TestCustomer>::new()
+            .with_search("acme", vec!["emailAddress"])
+            .with_filter_operator("emailAddress", QueryFilterOperator::NotEqual, "x@y.z")
+            .with_sort("emailAddress", QuerySortDirection::Ascending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestCustomer, Postgres>("SELECT * FROM customers")
+                .with_column_mapping("emailAddress", "email")
+                .with_column_mapping("unknown", "secret")
+                .with_params(params),
+        );
+
+        assert_eq!(
+            sql.where_clause,
+            "WHERE (LOWER(\"email\") LIKE LOWER($1)) AND \"email\" != $2"
+        );
+        assert_eq!(sql.order_by, "ORDER BY \"email\" ASC");
+        assert!(!sql.records_sql.contains("emailAddress"));
+    }
+
+    #[test]
+    fn test_to_sql_returns_the_generated_sql_with_placeholders() {
+        let params = || {
+            QueryParamsBuilder::<TestOrder>::new()
+                .with_pagination(2, 10)
+                .with_filter("status", Some("paid"))
+                .with_sort("total", QuerySortDirection::Ascending)
+                .build()
+        };
+
+        let (records_sql, count_sql) =
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders")
+                .with_params(params())
+                .to_sql()
+                .unwrap();
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Postgres>("SELECT * FROM orders").with_params(params()),
+        );
+
+        let normalize = |sql: &str| sql.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert_eq!(normalize(&records_sql), sql.records_sql);
+        assert_eq!(Some(normalize(&count_sql)), sql.count_sql);
+        assert!(records_sql.contains("WHERE \"status\" = $1"));
+        assert!(!records_sql.contains("paid") && !count_sql.contains("paid"));
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_tests {
+    use super::*;
+    use sqlx::Sqlite;
+    use sqlx_paginated::testing::prepare_generated_sql_in_memory;
+
+    const SCHEMA: &str = "
+        CREATE TABLE orders (
+            id INTEGER PRIMARY KEY,
+            customer TEXT NOT NULL,
+            status TEXT NOT NULL,
+            total REAL NOT NULL,
+            created_at TEXT NOT NULL
+        );
+        CREATE TABLE carriers (
+            order_id INTEGER NOT NULL,
+            tenant_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            rank INTEGER NOT NULL
+        );
+    ";
+
+    #[tokio::test]
+    async fn test_generated_sql_prepares_with_search_filters_and_sort() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_pagination(2, 20)
+            .with_search("acme", vec!["customer", "status"])
+            .with_filter("status", Some("paid"))
+            .with_filter_operator("total", QueryFilterOperator::GreaterThan, "100")
+            .with_sort("total", QuerySortDirection::Descending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders").with_params(params),
+        );
+
+        prepare_generated_sql_in_memory(SCHEMA, &sql).await.unwrap();
+    }
+
+    #[test]
+    fn test_generated_sql_matches_regex_with_regexp() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter_operator("customer", QueryFilterOperator::IRegex, "^acme")
+            .with_filter_operator("status", QueryFilterOperator::Regex, "^paid$")
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders").with_params(params),
+        );
+
+        assert_eq!(
+            sql.where_clause,
+            "WHERE \"customer\" REGEXP ('(?i)' || ?) AND \"status\" REGEXP ?"
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_skips_containment_filters() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter_operator("status", QueryFilterOperator::Overlaps, "{paid,shipped}")
+            .with_filter("id", Some("1"))
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders").with_params(params),
+        );
+
+        assert_eq!(sql.where_clause, "WHERE \"id\" = ?");
+    }
+
+    #[tokio::test]
+    async fn test_generated_sql_prepares_with_computed_property_joins() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_search("express", vec!["carrier"])
+            .with_filter("status", Some("paid"))
+            .with_sort("carrier_rank", QuerySortDirection::Ascending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders")
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("carrier_rank", "MIN(ranked.rank)")
+                        .with_join_bound(
+                            "LEFT JOIN carriers AS ranked ON ranked.order_id = base_query.id \
+                             AND ranked.tenant_id = ?",
+                            vec!["42"],
+                        )
+                        .as_aggregate()
+                        .build(),
+                )
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("carrier", "carriers.name")
+                        .with_join("LEFT JOIN carriers ON carriers.order_id = base_query.id")
+                        .searchable()
+                        .build(),
+                )
+                .with_distinct()
+                .with_params(params),
+        );
+
+        assert!(sql.records_sql.contains("JOIN carriers AS ranked"));
+        prepare_generated_sql_in_memory(SCHEMA, &sql).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_invalid_generated_sql_fails_to_prepare() {
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders")
+                .with_records_select("*, missing_column"),
+        );
+
+        assert!(prepare_generated_sql_in_memory(SCHEMA, &sql).await.is_err());
+    }
+
+    #[test]
+    fn test_generated_sql_numbers_sqlite_placeholders() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter("status", Some("paid"))
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders").with_params(params),
+        );
+
+        assert_eq!(sql.where_clause, "WHERE \"status\" = ?");
+        assert_eq!(sql.limit_offset, "LIMIT ?2");
+    }
+
+    #[cfg(feature = "sqlite-inline-base-query")]
+    #[test]
+    fn test_generated_sql_inlines_base_query() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter("status", Some("paid"))
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders;")
+                .with_params(params)
+                .with_inline_base_query(),
+        );
+
+        assert_eq!(
+            sql.records_sql,
+            "SELECT * FROM (SELECT * FROM orders) AS base_query \
+             WHERE \"status\" = ? ORDER BY \"created_at\" DESC LIMIT ?2"
+        );
+        assert_eq!(
+            sql.count_sql.as_deref(),
+            Some("SELECT COUNT(*) FROM (SELECT * FROM orders) AS base_query WHERE \"status\" = ?")
+        );
+    }
+
+    #[test]
+    fn test_generated_sql_emulates_nulls_position() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_sort_nulls(
+                "total",
+                QuerySortDirection::Ascending,
+                QueryNullsPosition::Last,
+            )
+            .with_sort_nulls(
+                "created_at",
+                QuerySortDirection::Descending,
+                QueryNullsPosition::First,
+            )
+            .with_sort("id", QuerySortDirection::Ascending)
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders").with_params(params),
+        );
+
+        assert_eq!(
+            sql.order_by,
+            "ORDER BY \"total\" IS NULL ASC, \"total\" ASC, \
+             \"created_at\" IS NULL DESC, \"created_at\" DESC, \"id\" ASC"
+        );
+    }
+
+    #[test]
+    fn test_to_sql_can_be_called_before_fetching() {
+        let mut builder = paginated_query_as::<TestOrder, Sqlite>("SELECT * FROM orders")
+            .with_params(
+                QueryParamsBuilder::<TestOrder>::new()
+                    .with_filter("status", Some("paid"))
+                    .build(),
+            );
+
+        let (records_sql, count_sql) = builder.to_sql().unwrap();
+
+        assert!(records_sql.contains("WHERE \"status\" = ?"));
+        assert!(records_sql.ends_with("LIMIT ?2"));
+        assert!(count_sql.contains("SELECT COUNT(*)"));
+
+        // Building again, as fetching does, gives the same SQL
+        assert_eq!(builder.to_sql().unwrap(), (records_sql, count_sql));
+    }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_tests {
+    use super::*;
+    use sqlx::MySql;
+    use sqlx_paginated::testing::GeneratedSqlSource;
+    use sqlx_paginated::PaginationError;
+
+    #[test]
+    fn test_generated_sql_of_representative_query() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_pagination(3, 20)
+            .with_search("acme", vec!["customer"])
+            .with_filter("status", Some("paid"))
+            .with_filter_operator("total", QueryFilterOperator::GreaterThan, "100")
+            .with_filter_operator("created_at", QueryFilterOperator::LessThan, "2024-01-01")
+            .with_sort_nulls(
+                "total",
+                QuerySortDirection::Descending,
+                QueryNullsPosition::Last,
+            )
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, MySql>("SELECT * FROM orders").with_params(params),
+        );
+
+        assert_eq!(
+            sql.where_clause,
+            "WHERE (LOWER(`customer`) LIKE LOWER(?)) AND `created_at` < CAST(? AS DATE) \
+             AND `status` = ? AND `total` > CAST(? AS SIGNED)"
+        );
+        assert_eq!(sql.order_by, "ORDER BY `total` IS NULL ASC, `total` DESC");
+        assert_eq!(sql.limit_offset, "LIMIT ? OFFSET ?");
+        assert!(!sql.records_sql.contains('"'));
+    }
+
+    #[test]
+    fn test_coalesced_sort_column_skips_the_nulls_term() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter("status", Some("paid"))
+            .with_sort_nulls(
+                "total",
+                QuerySortDirection::Ascending,
+                QueryNullsPosition::Last,
+            )
+            .build();
+
+        let sql = assert_generated_sql(
+            paginated_query_as::<TestOrder, MySql>("SELECT * FROM orders")
+                .with_sort_coalesce("total", 0_i64)
+                .with_params(params),
+        );
+
+        // The sentinel is bound once, so its placeholder can't be repeated
+        assert_eq!(sql.order_by, "ORDER BY COALESCE(`total`, ?) ASC");
+        assert_eq!(sql.records_sql.matches('?').count(), 3);
+    }
+
+    #[test]
+    fn test_generated_sql_quotes_every_identifier_with_backticks() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_sort("total", QuerySortDirection::Descending)
+            .build();
+
+        let sql = assert_generated_sql(
+            UnionPaginatedQuery::new("status")
+                .with_query("open", "SELECT id, customer, total, created_at FROM orders")
+                .into_paginated_query_as::<TestOrder, MySql>()
+                .with_computed_property(
+                    ComputedPropertyBuilder::new("doubled_total", "total * 2")
+                        .selected()
+                        .build(),
+                )
+                .with_params(params),
+        );
+
+        assert!(sql
+            .records_sql
+            .contains("SELECT `part`.*, 'open' AS `status` FROM"));
+        assert!(sql.records_sql.contains("(total * 2) AS `doubled_total`"));
+        assert_eq!(sql.order_by, "ORDER BY `total` DESC");
+        assert!(!sql.records_sql.contains('"'));
+    }
+
+    #[test]
+    fn test_bound_join_values_are_rejected() {
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_sort("carrier_rank", QuerySortDirection::Ascending)
+            .build();
+
+        let error = paginated_query_as::<TestOrder, MySql>("SELECT * FROM orders")
+            .with_computed_property(
+                ComputedPropertyBuilder::new("carrier_rank", "carriers.rank")
+                    .with_join_bound(
+                        "LEFT JOIN carriers ON carriers.order_id = base_query.id \
+                         AND carriers.tenant_id = ?",
+                        vec!["42"],
+                    )
+                    .build(),
+            )
+            .with_params(params)
+            .generated_sql()
+            .unwrap_err();
+
+        assert_eq!(
+            PaginationError::from_sqlx_error(&error),
+            Some(&PaginationError::UnorderedArguments {
+                feature: "with_join_bound"
+            })
+        );
+    }
+
+    #[test]
+    fn test_having_conditions_are_rejected() {
+        let paid_total = ComputedPropertyBuilder::new("total", "SUM(payments.amount)")
+            .with_join("LEFT JOIN payments ON payments.order_id = base_query.id")
+            .as_aggregate()
+            .build();
+        let having_property = paid_total.clone();
+        let params = QueryParamsBuilder::<TestOrder>::new()
+            .with_filter_operator("total", QueryFilterOperator::GreaterThan, "100")
+            .build();
+
+        let error = paginated_query_as::<TestOrder, MySql>("SELECT * FROM orders")
+            .with_computed_property(paid_total)
+            .with_having_query_builder(move |params| {
+                QueryBuilder::<TestOrder, MySql>::new()
+                    .with_aggregate_property(&having_property)
+                    .with_filters(params)
+            })
+            .with_params(params)
+            .generated_sql()
+            .unwrap_err();
+
+        assert_eq!(
+            PaginationError::from_sqlx_error(&error),
+            Some(&PaginationError::UnorderedArguments {
+                feature: "with_having_query_builder"
+            })
+        );
+    }
+
+    #[test]
+    fn test_prepended_cte_arguments_are_rejected() {
+        let error = paginated_query_as::<TestOrder, MySql>("SELECT * FROM orders")
+            .with_prepended_cte(
+                "recent",
+                "SELECT id FROM orders WHERE total > ?",
+                vec![100_i64],
+            )
+            .generated_sql()
+            .unwrap_err();
+
+        assert_eq!(
+            PaginationError::from_sqlx_error(&error),
+            Some(&PaginationError::UnorderedArguments {
+                feature: "with_prepended_cte"
+            })
+        );
+    }
+}
@@ -0,0 +1,94 @@
+#![allow(clippy::unwrap_used)]
+
+use serde::Serialize;
+use sqlx_paginated::{PaginatedColumns, QueryParamsBuilder, QuerySortDirection};
+
+#[derive(Serialize, Default, PaginatedColumns)]
+struct Order {
+    id: i64,
+    #[serde(rename = "order_status")]
+    status: String,
+    #[serde(rename(serialize = "total_cents", deserialize = "total"))]
+    total: i64,
+    #[serde(skip)]
+    #[allow(dead_code)]
+    internal_note: String,
+    r#type: String,
+    created_at: String,
+}
+
+#[derive(Serialize, Default, PaginatedColumns)]
+#[serde(rename_all = "camelCase")]
+struct Carrier {
+    carrier_name: String,
+    #[serde(rename = "code")]
+    tracking_code: String,
+    created_at: String,
+}
+
+/// Names `get_struct_field_meta` reads off the serialized default of the model.
+fn serialized_names<T: Default + Serialize>() -> Vec<String> {
+    let value = serde_json::to_value(T::default()).unwrap();
+    let mut names: Vec<String> = value.as_object().unwrap().keys().cloned().collect();
+    names.sort();
+    names
+}
+
+fn derived_names<T: PaginatedColumns>() -> Vec<String> {
+    let mut names: Vec<String> = T::columns().iter().map(|&column| column.into()).collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_columns_match_the_serialized_field_names() {
+    assert_eq!(derived_names::<Carrier>(), serialized_names::<Carrier>());
+    assert_eq!(derived_names::<Order>(), serialized_names::<Order>());
+}
+
+#[test]
+fn test_columns_follow_serde_renames() {
+    assert_eq!(OrderColumns::Status.as_str(), "order_status");
+    assert_eq!(OrderColumns::Total.as_str(), "total_cents");
+    assert_eq!(OrderColumns::Type.as_str(), "type");
+    assert_eq!(OrderColumns::CreatedAt.to_string(), "created_at");
+    assert_eq!(CarrierColumns::CarrierName.as_str(), "carrierName");
+    assert_eq!(CarrierColumns::TrackingCode.as_str(), "code");
+    assert_eq!(
+        CarrierColumns::ALL,
+        &[
+            CarrierColumns::CarrierName,
+            CarrierColumns::TrackingCode,
+            CarrierColumns::CreatedAt,
+        ]
+    );
+}
+
+#[test]
+fn test_typed_builder_methods_use_the_column_names() {
+    let params = QueryParamsBuilder::<Order>::new()
+        .with_sort_by(OrderColumns::CreatedAt, QuerySortDirection::Descending)
+        .with_sort_by(OrderColumns::Total, QuerySortDirection::Ascending)
+        .with_filter_by(OrderColumns::Status, Some("shipped"))
+        .with_search_in("express", vec![OrderColumns::Type])
+        .build();
+
+    let sorts: Vec<String> = params
+        .sort
+        .sorts()
+        .into_iter()
+        .map(|sort| sort.column)
+        .collect();
+    assert_eq!(sorts, vec!["created_at", "total_cents"]);
+    assert!(params.filters.contains_key("order_status"));
+    assert_eq!(params.search.search_columns, Some(vec!["type".to_string()]));
+}
+
+#[test]
+fn test_columns_are_accepted_by_name_taking_methods() {
+    let params = QueryParamsBuilder::<Carrier>::new()
+        .with_filter(CarrierColumns::TrackingCode, Some("1Z999"))
+        .build();
+
+    assert!(params.filters.contains_key("code"));
+}
@@ -1,4 +1,6 @@
 #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+// Most tests target PostgreSQL and share the models with the other databases
+#![cfg_attr(not(feature = "postgres"), allow(unused_imports, dead_code))]
 
 use serde::Serialize;
 use sqlx_paginated::{QueryBuilder, QueryParamsBuilder, QuerySortDirection};
@@ -66,6 +68,96 @@ mod postgres_tests {
         assert!(combined.contains("name") || combined.contains("email"));
     }
 
+    #[test]
+    fn test_search_any_token_any_column() {
+        use sqlx::Arguments;
+        use sqlx_paginated::SearchMode;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john smith", vec!["name", "email"])
+            .with_search_mode(SearchMode::AnyTokenAnyColumn)
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_search(&params)
+            .build();
+
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(
+            conditions[0],
+            "(LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1) \
+             OR LOWER(\"name\") LIKE LOWER($2) OR LOWER(\"email\") LIKE LOWER($2))"
+        );
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_search_min_should_match() {
+        let search = |min_should_match| {
+            let params = QueryParamsBuilder::<TestUser>::new()
+                .with_search("john smith doe", vec!["name", "email"])
+                .with_min_should_match(min_should_match)
+                .build();
+            QueryBuilder::<TestUser, Postgres>::new()
+                .with_search(&params)
+                .build()
+                .0
+        };
+
+        assert_eq!(
+            search(1),
+            vec![
+                "(LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1) \
+                 OR LOWER(\"name\") LIKE LOWER($2) OR LOWER(\"email\") LIKE LOWER($2) \
+                 OR LOWER(\"name\") LIKE LOWER($3) OR LOWER(\"email\") LIKE LOWER($3))"
+                    .to_string()
+            ]
+        );
+        assert_eq!(
+            search(3),
+            vec![
+                "((LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1)) \
+                 AND (LOWER(\"name\") LIKE LOWER($2) OR LOWER(\"email\") LIKE LOWER($2)) \
+                 AND (LOWER(\"name\") LIKE LOWER($3) OR LOWER(\"email\") LIKE LOWER($3)))"
+                    .to_string()
+            ]
+        );
+        assert_eq!(search(5), search(3));
+        assert_eq!(
+            search(2),
+            vec![
+                "((CASE WHEN (LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1)) THEN 1 ELSE 0 END \
+                 + CASE WHEN (LOWER(\"name\") LIKE LOWER($2) OR LOWER(\"email\") LIKE LOWER($2)) THEN 1 ELSE 0 END \
+                 + CASE WHEN (LOWER(\"name\") LIKE LOWER($3) OR LOWER(\"email\") LIKE LOWER($3)) THEN 1 ELSE 0 END) >= 2)"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_pattern_bound_once_for_relevance() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "email"])
+            .build();
+
+        let mut builder = QueryBuilder::<TestUser, Postgres>::new().with_search(&params);
+        let pattern = params.search.patterns().remove(0);
+        let relevance = format!(
+            "ORDER BY (LOWER(\"name\") LIKE LOWER({})) DESC",
+            builder.bind_text(pattern)
+        );
+        let (conditions, args) = builder.build();
+
+        assert_eq!(
+            conditions[0],
+            "(LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1))"
+        );
+        assert_eq!(relevance, "ORDER BY (LOWER(\"name\") LIKE LOWER($1)) DESC");
+        assert_eq!(args.len(), 1);
+    }
+
     #[test]
     fn test_date_range_filtering() {
         use chrono::DateTime;
@@ -146,6 +238,264 @@ mod postgres_tests {
 
         assert_eq!(conditions.len(), 2);
     }
+
+    #[test]
+    fn test_search_restricted_to_indexed_columns() {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "email", "status"])
+            .with_search_anchor(sqlx_paginated::SearchAnchor::Prefix)
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_indexed_search_columns(vec!["email", "name"])
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1))".to_string()]
+        );
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_indexed_search_columns(vec!["id"])
+            .with_search(&params)
+            .build();
+        assert!(conditions.is_empty());
+    }
+
+    #[test]
+    fn test_required_indexed_search_rejects_unindexed_columns() {
+        let indexed = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "email"])
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_require_indexed_search(vec!["email", "name"])
+            .with_search(&indexed)
+            .build();
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1))".to_string()]
+        );
+
+        let unindexed = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "status"])
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_require_indexed_search(vec!["email", "name"])
+            .with_search(&unindexed)
+            .build();
+        assert!(conditions.is_empty());
+    }
+
+    #[test]
+    fn test_search_options_control_case_sensitivity() {
+        use sqlx_paginated::{SearchAnchor, SearchOptions};
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("John", vec!["name", "email"])
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_search_options(SearchOptions {
+                case_sensitive: true,
+                anchor: SearchAnchor::Prefix,
+            })
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec!["(\"name\" LIKE $1 OR \"email\" LIKE $1)".to_string()]
+        );
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_search_options(SearchOptions {
+                case_sensitive: false,
+                anchor: SearchAnchor::Exact,
+            })
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(\"name\") = LOWER($1) OR LOWER(\"email\") = LOWER($1))".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_full_text_search_uses_tsvector() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_full_text_search("rust ownership", vec!["name", "email"], "english")
+            .build();
+        let (conditions, args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec![
+                "(to_tsvector('english', coalesce(\"name\", '') || ' ' || coalesce(\"email\", '')) \
+                 @@ plainto_tsquery('english', $1))"
+                    .to_string()
+            ]
+        );
+        assert_eq!(args.len(), 1);
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_full_text_search("rust", vec!["name", "unknown"], "pg_catalog.simple")
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec![
+                "(to_tsvector('pg_catalog.simple', \"name\") @@ plainto_tsquery('pg_catalog.simple', $1))"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_full_text_search_with_invalid_config_falls_back_to_like() {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_full_text_search("rust", vec!["name"], "english'); DROP TABLE users; --")
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(\"name\") LIKE LOWER($1))".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_typed_search_matches_numeric_columns_by_equality() {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("42", vec!["name", "id"])
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_typed_search()
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(\"name\") LIKE LOWER($1) OR \"id\" = $2::smallint)".to_string()]
+        );
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john 7", vec!["name", "id"])
+            .with_search_mode(sqlx_paginated::SearchMode::AnyTokenAnyColumn)
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_typed_search()
+            .with_search(&params)
+            .build();
+        assert_eq!(
+            conditions,
+            vec![
+                "(LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"name\") LIKE LOWER($2) OR \"id\" = $3::smallint)"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typed_search_skips_numeric_columns_for_text_terms() {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["id"])
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_typed_search()
+            .with_search(&params)
+            .build();
+        assert!(conditions.is_empty());
+    }
+
+    #[test]
+    fn test_where_tree_ors_search_with_id_filter() {
+        use sqlx::Arguments;
+        use sqlx_paginated::WhereTree;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "email"])
+            .with_filter("id", Some("7"))
+            .with_filter("status", Some("active"))
+            .build();
+        let tree = WhereTree::and(vec![
+            WhereTree::or(vec![WhereTree::Search, WhereTree::filter("id")]),
+            WhereTree::Filters,
+        ]);
+
+        let (conditions, args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_where(&params, tree)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "(((LOWER(\"name\") LIKE LOWER($1) OR LOWER(\"email\") LIKE LOWER($1)) \
+                 OR \"id\" = $2::smallint) AND \"status\" = $3)"
+                    .to_string()
+            ]
+        );
+        assert_eq!(args.len(), 3);
+    }
+
+    #[test]
+    fn test_where_tree_absent_leaves_leave_or_groups_unconstrained() {
+        use sqlx::Arguments;
+        use sqlx_paginated::WhereTree;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_filter("id", Some("7"))
+            .with_filter("status", Some("active"))
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_where(
+                &params,
+                WhereTree::and(vec![
+                    WhereTree::or(vec![WhereTree::Search, WhereTree::filter("id")]),
+                    WhereTree::filter("status"),
+                ]),
+            )
+            .build();
+        assert_eq!(conditions, vec!["\"status\" = $1".to_string()]);
+        assert_eq!(args.len(), 1);
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_where(
+                &params,
+                WhereTree::and(vec![WhereTree::Search, WhereTree::filter("id")]),
+            )
+            .build();
+        assert_eq!(conditions, vec!["\"id\" = $1::smallint".to_string()]);
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .with_where(
+                &params,
+                WhereTree::or(vec![WhereTree::Search, WhereTree::DateRange]),
+            )
+            .build();
+        assert!(conditions.is_empty());
+    }
+
+    #[test]
+    fn test_when_applies_closure_only_when_condition_holds() {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_filter("status", Some("active"))
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Postgres>::new()
+            .when(false, |builder| builder.with_filters(&params))
+            .when(true, |builder| builder.with_raw_condition("\"id\" > 0"))
+            .build();
+
+        assert_eq!(conditions, vec!["\"id\" > 0".to_string()]);
+    }
 }
 
 #[cfg(feature = "sqlite")]
@@ -189,6 +539,90 @@ mod sqlite_tests {
         }
     }
 
+    #[test]
+    fn test_full_text_search_falls_back_to_like_sqlite() {
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_full_text_search("john", vec!["name", "email"], "english")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestUser, Sqlite>::new()
+            .with_search(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec!["(LOWER(\"name\") LIKE LOWER(?) OR LOWER(\"email\") LIKE LOWER(?))".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_search_pattern_rebound_per_column_sqlite() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "email"])
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestUser, Sqlite>::new()
+            .with_search(&params)
+            .build();
+
+        assert_eq!(
+            conditions[0],
+            "(LOWER(\"name\") LIKE LOWER(?) OR LOWER(\"email\") LIKE LOWER(?))"
+        );
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_duplicate_search_columns_searched_once_sqlite() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "name", "email", "name"])
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestUser, Sqlite>::new()
+            .with_search(&params)
+            .build();
+
+        assert_eq!(
+            conditions[0],
+            "(LOWER(\"name\") LIKE LOWER(?) OR LOWER(\"email\") LIKE LOWER(?))"
+        );
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_where_tree_binds_in_tree_order_sqlite() {
+        use sqlx::Arguments;
+        use sqlx_paginated::WhereTree;
+
+        let params = QueryParamsBuilder::<TestUser>::new()
+            .with_search("john", vec!["name", "email"])
+            .with_filter("id", Some("7"))
+            .with_filter("status", Some("active"))
+            .build();
+        let tree = WhereTree::and(vec![
+            WhereTree::or(vec![WhereTree::Search, WhereTree::filter("id")]),
+            WhereTree::Filters,
+        ]);
+
+        let (conditions, args) = QueryBuilder::<TestUser, Sqlite>::new()
+            .with_where(&params, tree)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "(((LOWER(\"name\") LIKE LOWER(?) OR LOWER(\"email\") LIKE LOWER(?)) \
+                 OR \"id\" = ?) AND \"status\" = ?)"
+                    .to_string()
+            ]
+        );
+        assert_eq!(args.len(), 4);
+    }
+
     #[test]
     fn test_filters_sqlite() {
         let params = QueryParamsBuilder::<TestUser>::new()
@@ -1,7 +1,12 @@
 #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+// Most tests target PostgreSQL and share the models with the other databases
+#![cfg_attr(not(feature = "postgres"), allow(unused_imports, dead_code))]
 
 use serde::Serialize;
-use sqlx_paginated::{QueryBuilder, QueryFilterCondition, QueryFilterOperator, QueryParamsBuilder};
+use sqlx_paginated::{
+    FieldType, FilterLogic, QueryBuilder, QueryFilterCondition, QueryFilterOperator,
+    QueryParamsBuilder,
+};
 
 #[derive(Serialize, Default, Debug)]
 struct TestProduct {
@@ -14,6 +19,20 @@ struct TestProduct {
     deleted_at: Option<String>,
 }
 
+#[derive(Serialize, Default, Debug)]
+struct TestSignup {
+    id: i64,
+    email: String,
+    confirmed: bool,
+    newsletter: Option<bool>,
+}
+
+#[derive(Serialize, Default, Debug)]
+struct TestDevice {
+    id: i64,
+    last_ip: String,
+}
+
 #[cfg(feature = "postgres")]
 mod postgres_tests {
     use super::*;
@@ -136,6 +155,71 @@ mod postgres_tests {
         assert!(conditions[0].contains("$2"));
     }
 
+    #[test]
+    fn test_not_in_operator_binds_one_array_with_array_exclusions() {
+        use sqlx::Arguments;
+
+        let excluded: Vec<String> = (1..=5000).map(|id| id.to_string()).collect();
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_not_in("id", excluded.iter().map(String::as_str).collect())
+            .with_filter_not_in("status", vec!["deleted", "banned"])
+            .with_filter_not_in("category", vec!["10", "20"])
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_array_exclusions()
+            .with_filters(&params)
+            .try_build()
+            .unwrap();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"category\" NOT IN (SELECT x FROM unnest($1) x)".to_string(),
+                "\"id\" NOT IN (SELECT x FROM unnest($2) x)".to_string(),
+                "\"status\" NOT IN (SELECT x FROM unnest($3) x)".to_string(),
+            ]
+        );
+        assert_eq!(args.len(), 3);
+    }
+
+    #[test]
+    fn test_type_cast_fn_overrides_the_dialect_cast() {
+        let params = QueryParamsBuilder::<TestDevice>::new()
+            .with_filter("last_ip", Some("192.168.1.1"))
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestDevice, Postgres>::new()
+            .with_filters(&params)
+            .build();
+        assert_eq!(conditions, vec!["\"last_ip\" = $1::inet"]);
+
+        let (conditions, _args) = QueryBuilder::<TestDevice, Postgres>::new()
+            .with_type_cast_fn(|_value, field_type| match field_type {
+                FieldType::String => String::new(),
+                _ => "::inet".to_string(),
+            })
+            .with_filters(&params)
+            .build();
+        assert_eq!(conditions, vec!["\"last_ip\" = $1"]);
+    }
+
+    #[test]
+    fn test_type_cast_fn_receives_the_field_type() {
+        let params = QueryParamsBuilder::<TestDevice>::new()
+            .with_filter_in("id", vec!["1", "2"])
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestDevice, Postgres>::new()
+            .with_type_cast_fn(|value, field_type| {
+                format!("::{}_{:?}", value, field_type).to_lowercase()
+            })
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["\"id\" IN ($1::1_number, $2::2_number)"]);
+    }
+
     #[test]
     fn test_is_null_operator() {
         let params = QueryParamsBuilder::<TestProduct>::new()
@@ -205,9 +289,458 @@ mod postgres_tests {
             .with_filters(&params)
             .build();
 
-        // Note: The second price filter will overwrite the first
-        // This is expected HashMap behavior
-        assert!(conditions.len() >= 2);
+        // Both price filters apply, in the order they were added
+        assert_eq!(
+            conditions,
+            vec![
+                "\"price\" > $1::real".to_string(),
+                "\"price\" < $2::real".to_string(),
+                "\"status\" = $3".to_string(),
+                "\"stock\" >= $4::smallint".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_group_renders_parenthesized_or() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_group(
+                FilterLogic::Or,
+                vec![
+                    ("status", QueryFilterCondition::equal("active")),
+                    ("status", QueryFilterCondition::equal("pending")),
+                    ("unknown", QueryFilterCondition::equal("ignored")),
+                ],
+            )
+            .with_filter_group(
+                FilterLogic::Or,
+                vec![("category", QueryFilterCondition::equal("books"))],
+            )
+            .with_filter_null("deleted_at", true)
+            .with_filter_operator("stock", QueryFilterOperator::GreaterThan, "0")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"deleted_at\" IS NULL".to_string(),
+                "\"stock\" > $1::smallint".to_string(),
+                "(\"status\" = $2 OR \"status\" = $3)".to_string(),
+                "\"category\" = $4".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_between_operator() {
+        use sqlx_paginated::{FlatQueryParams, QueryParams};
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_between("price", "10.5", "99.5")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["\"price\" BETWEEN $1::real AND $2::real"]);
+
+        // Bounds other than exactly two skip the filter
+        for bounds in ["10", "10,20,30", ""] {
+            let params: QueryParams<TestProduct> = serde_json::from_value::<FlatQueryParams>(
+                serde_json::json!({ "stock[between]": bounds, "status": "active" }),
+            )
+            .unwrap()
+            .into();
+            let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+                .with_filters(&params)
+                .build();
+
+            assert_eq!(conditions, vec!["\"status\" = $1"], "{}", bounds);
+            assert_eq!(sqlx::Arguments::len(&args), 1);
+        }
+    }
+
+    #[test]
+    fn test_not_between_operator() {
+        use sqlx_paginated::{FlatQueryParams, QueryParams};
+
+        let params: QueryParams<TestProduct> = serde_json::from_value::<FlatQueryParams>(
+            serde_json::json!({ "price[not_between]": "10.5,99.5" }),
+        )
+        .unwrap()
+        .into();
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec!["\"price\" NOT BETWEEN $1::real AND $2::real"]
+        );
+        assert_eq!(sqlx::Arguments::len(&args), 2);
+    }
+
+    #[test]
+    fn test_boolean_test_operators() {
+        use sqlx_paginated::{FlatQueryParams, QueryParams};
+
+        let params = QueryParamsBuilder::<TestSignup>::new()
+            .with_filter_is("confirmed", true)
+            .with_filter_is_not("newsletter", false)
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestSignup, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec!["\"confirmed\" IS TRUE", "\"newsletter\" IS NOT FALSE"]
+        );
+        assert_eq!(sqlx::Arguments::len(&args), 0);
+
+        let params: QueryParams<TestSignup> = serde_json::from_value::<FlatQueryParams>(
+            serde_json::json!({ "confirmed[is_false]": "", "newsletter[not_true]": "" }),
+        )
+        .unwrap()
+        .into();
+        let (conditions, _args) = QueryBuilder::<TestSignup, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec!["\"confirmed\" IS FALSE", "\"newsletter\" IS NOT TRUE"]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_filter_binds_without_text_cast() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_operator(
+                "deleted_at",
+                QueryFilterOperator::GreaterOrEqual,
+                "2024-01-01T10:00:00.123456Z",
+            )
+            .with_filter_operator("price", QueryFilterOperator::LessThan, "2024-01-01")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions.len(), 2);
+        assert!(conditions
+            .iter()
+            .any(|condition| condition.ends_with("\"deleted_at\" >= $1")
+                || condition.ends_with("\"deleted_at\" >= $2")));
+        assert!(conditions
+            .iter()
+            .any(|condition| condition.contains("::date")));
+        assert!(!conditions
+            .iter()
+            .any(|condition| condition.contains("timestamp")));
+    }
+
+    #[test]
+    fn test_time_function_values_are_inlined() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_operator("deleted_at", QueryFilterOperator::LessThan, "NOW()")
+            .with_filter_operator("status", QueryFilterOperator::NotEqual, "NOW() - 1")
+            .with_filter_operator("price", QueryFilterOperator::LessThan, "NOW()")
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"deleted_at\" < NOW()".to_string(),
+                "\"price\" < $1".to_string(),
+                "\"status\" != $2".to_string()
+            ]
+        );
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_raw_value_filter_binds_without_type_cast() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_raw_value("category", QueryFilterOperator::Equal, "7")
+            .with_filter_operator("stock", QueryFilterOperator::Equal, "42")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"category\" = $1".to_string(),
+                "\"stock\" = $2::smallint".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_value_in_filter_binds_without_type_cast() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_raw_value("category", QueryFilterOperator::In, "1,2")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["\"category\" IN ($1, $2)".to_string()]);
+    }
+
+    #[test]
+    fn test_enum_column_accepts_allowed_values() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter("status", Some("active"))
+            .with_filter_in("category", vec!["books", "games"])
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_enum_column("status", vec!["active", "archived"])
+            .with_enum_column("category", vec!["books", "games", "music"])
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"category\" IN ($1, $2)".to_string(),
+                "\"status\" = $3".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_column_drops_unknown_values() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter("status", Some("bananas"))
+            .with_filter_not_in("category", vec!["books", "bananas"])
+            .with_filter_operator("name", QueryFilterOperator::Equal, "bananas")
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_enum_column("status", vec!["active", "archived"])
+            .with_enum_column("category", vec!["books", "games"])
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"category\" NOT IN ($1)".to_string(),
+                "\"name\" = $2".to_string()
+            ]
+        );
+        assert_eq!(args.len(), 2);
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_in("category", vec!["bananas", "apples"])
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_enum_column("category", vec!["books", "games"])
+            .with_filters(&params)
+            .build();
+        assert!(conditions.is_empty());
+    }
+
+    #[test]
+    fn test_strict_enum_column_matches_nothing_for_unknown_values() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter("status", Some("bananas"))
+            .with_filter_in("category", vec!["bananas", "books"])
+            .with_filter_not_in("name", vec!["bananas"])
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_enum_column("status", vec!["active", "archived"])
+            .with_enum_column("category", vec!["books", "games"])
+            .with_enum_column("name", vec!["widget"])
+            .with_strict_enum_columns()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions.len(), 2);
+        assert!(conditions.contains(&"1 = 0".to_string()));
+        assert!(conditions.contains(&"\"category\" IN ($1)".to_string()));
+        assert_eq!(args.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_order_is_deterministic() {
+        let build = || {
+            let params = QueryParamsBuilder::<TestProduct>::new()
+                .with_filter("status", Some("active"))
+                .with_filter_operator("price", QueryFilterOperator::GreaterThan, "10")
+                .with_filter_in("category", vec!["books", "games"])
+                .with_filter_operator("stock", QueryFilterOperator::LessOrEqual, "5")
+                .with_filter_null("deleted_at", true)
+                .build();
+            let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+                .with_filters(&params)
+                .build();
+            conditions.join(" AND ")
+        };
+
+        let first = build();
+        for _ in 0..10 {
+            assert_eq!(build(), first);
+        }
+        assert_eq!(
+            first,
+            "\"category\" IN ($1, $2) AND \"deleted_at\" IS NULL AND \"price\" > $3::smallint \
+             AND \"status\" = $4 AND \"stock\" <= $5::smallint"
+        );
+    }
+
+    #[test]
+    fn test_boolean_null_flag() {
+        use sqlx_paginated::{FlatQueryParams, QueryParams};
+
+        let parse = |value: &str| -> QueryParams<TestProduct> {
+            serde_json::from_value::<FlatQueryParams>(
+                serde_json::json!({ "deleted_at[is_null]": value }),
+            )
+            .unwrap()
+            .into()
+        };
+
+        for (value, expected) in [
+            ("true", "\"deleted_at\" IS NULL"),
+            ("false", "\"deleted_at\" IS NOT NULL"),
+            ("", "\"deleted_at\" IS NULL"),
+        ] {
+            let params = parse(value);
+            let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+                .with_boolean_null_flag()
+                .with_filters(&params)
+                .build();
+            assert_eq!(conditions, vec![expected], "is_null={}", value);
+        }
+
+        let params = parse("false");
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+        assert_eq!(conditions, vec!["\"deleted_at\" IS NULL"]);
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_condition(
+                "deleted_at",
+                QueryFilterCondition::new(QueryFilterOperator::IsNotNull, Some("false")),
+            )
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_boolean_null_flag()
+            .with_filters(&params)
+            .build();
+        assert_eq!(conditions, vec!["\"deleted_at\" IS NULL"]);
+    }
+
+    #[test]
+    fn test_empty_filter_values_kept_or_dropped() {
+        use sqlx_paginated::{FlatQueryParams, QueryParams};
+
+        let flat: FlatQueryParams = serde_json::from_value(serde_json::json!({
+            "status": "",
+            "name[like]": " ",
+            "stock[gt]": "5",
+            "deleted_at[is_null]": "",
+        }))
+        .unwrap();
+        let params: QueryParams<TestProduct> = flat.into();
+        let sorted = |mut conditions: Vec<String>| {
+            conditions.sort();
+            conditions
+        };
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .build();
+        assert_eq!(conditions.len(), 4);
+        assert!(conditions
+            .iter()
+            .any(|condition| condition.starts_with("\"status\" = $")));
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_drop_empty_filters()
+            .with_filters(&params)
+            .build();
+        assert_eq!(
+            sorted(conditions),
+            vec![
+                "\"deleted_at\" IS NULL".to_string(),
+                "\"stock\" > $1::smallint".to_string(),
+            ]
+        );
+        assert_eq!(sqlx::Arguments::len(&args), 1);
+    }
+
+    #[test]
+    fn test_checkbox_on_binds_boolean() {
+        use sqlx::Arguments;
+        use sqlx_paginated::{FlatQueryParams, QueryParams};
+
+        let flat: FlatQueryParams =
+            serde_json::from_value(serde_json::json!({ "confirmed": "on" })).unwrap();
+        let params: QueryParams<TestSignup> = flat.into();
+
+        let (conditions, args) = QueryBuilder::<TestSignup, Postgres>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["\"confirmed\" = $1::boolean"]);
+        assert_eq!(args.len(), 1);
+    }
+
+    #[test]
+    fn test_checkbox_filters_tri_state() {
+        let checked = QueryParamsBuilder::<TestSignup>::new()
+            .with_checkbox_filters(vec!["newsletter"])
+            .with_filter("newsletter", Some("ON"))
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestSignup, Postgres>::new()
+            .with_filters(&checked)
+            .build();
+        assert_eq!(conditions, vec!["\"newsletter\" = $1::boolean"]);
+
+        let omitted = QueryParamsBuilder::<TestSignup>::new()
+            .with_checkbox_filters(vec!["newsletter"])
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestSignup, Postgres>::new()
+            .with_filters(&omitted)
+            .build();
+        assert!(conditions.is_empty());
+
+        let empty = QueryParamsBuilder::<TestSignup>::new()
+            .with_checkbox_filters(vec!["newsletter"])
+            .with_filter("newsletter", Some(""))
+            .build();
+        let (conditions, _args) = QueryBuilder::<TestSignup, Postgres>::new()
+            .with_filters(&empty)
+            .build();
+        assert!(conditions.is_empty());
     }
 
     #[test]
@@ -252,6 +785,59 @@ mod postgres_tests {
         let not_like = QueryFilterCondition::not_like("%pattern%");
         assert_eq!(not_like.operator, QueryFilterOperator::NotLike);
     }
+
+    #[test]
+    fn test_exists_binds_after_previous_conditions() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter("status", Some("active"))
+            .build();
+
+        let (conditions, arguments) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_filters(&params)
+            .with_exists(
+                "reviews",
+                ("product_id", "id"),
+                vec![
+                    ("rating", QueryFilterCondition::greater_than("3")),
+                    ("hidden", QueryFilterCondition::is_null()),
+                ],
+            )
+            .with_exists_condition(
+                "SELECT 1 FROM tags t WHERE t.product_id = base_query.id AND t.name IN ($1, $2)",
+                vec!["sale".to_string(), "new".to_string()],
+            )
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"status\" = $1".to_string(),
+                "EXISTS (SELECT 1 FROM \"reviews\" WHERE \"reviews\".\"product_id\" = \"base_query\".\"id\" \
+                 AND \"reviews\".\"rating\" > $2::smallint AND \"reviews\".\"hidden\" IS NULL)"
+                    .to_string(),
+                "EXISTS (SELECT 1 FROM tags t WHERE t.product_id = base_query.id AND t.name IN ($3, $4))"
+                    .to_string(),
+            ]
+        );
+        assert_eq!(sqlx::Arguments::len(&arguments), 4);
+    }
+
+    #[test]
+    fn test_exists_skips_invalid_identifiers_and_sql() {
+        let (conditions, _) = QueryBuilder::<TestProduct, Postgres>::new()
+            .with_exists("reviews", ("product_id", "missing"), vec![])
+            .with_not_exists("reviews; DROP TABLE x", ("product_id", "id"), vec![])
+            .with_not_exists(
+                "reviews",
+                ("product_id", "id"),
+                vec![("rating) OR (1=1", QueryFilterCondition::equal("5"))],
+            )
+            .with_exists_condition("SELECT 1; DROP TABLE products", Vec::<String>::new())
+            .with_exists_condition("  ", Vec::<String>::new())
+            .build();
+
+        assert!(conditions.is_empty());
+    }
 }
 
 #[cfg(feature = "sqlite")]
@@ -259,6 +845,68 @@ mod sqlite_tests {
     use super::*;
     use sqlx::Sqlite;
 
+    #[test]
+    fn test_checkbox_on_binds_integer_boolean_sqlite() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestSignup>::new()
+            .with_checkbox_filters(vec!["newsletter"])
+            .with_filter("confirmed", Some("on"))
+            .with_filter("newsletter", Some("off"))
+            .build();
+
+        let (mut conditions, args) = QueryBuilder::<TestSignup, Sqlite>::new()
+            .with_filters(&params)
+            .build();
+        conditions.sort();
+
+        assert_eq!(conditions, vec!["\"confirmed\" = ?", "\"newsletter\" = ?"]);
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_not_between_operator_sqlite() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_not_between("stock", "1", "100")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Sqlite>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["\"stock\" NOT BETWEEN ? AND ?"]);
+    }
+
+    #[test]
+    fn test_between_operator_sqlite() {
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_between("stock", "1", "100")
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestProduct, Sqlite>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(conditions, vec!["\"stock\" BETWEEN ? AND ?"]);
+    }
+
+    #[test]
+    fn test_boolean_test_operators_sqlite() {
+        let params = QueryParamsBuilder::<TestSignup>::new()
+            .with_filter_is("confirmed", false)
+            .with_filter_is_not("newsletter", true)
+            .build();
+
+        let (conditions, _args) = QueryBuilder::<TestSignup, Sqlite>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec!["\"confirmed\" = 0", "\"newsletter\" IS NOT 1"]
+        );
+    }
+
     #[test]
     fn test_equality_operator_sqlite() {
         let params = QueryParamsBuilder::<TestProduct>::new()
@@ -289,6 +937,31 @@ mod sqlite_tests {
         assert!(conditions.iter().any(|c| c.contains("?")));
     }
 
+    #[test]
+    fn test_time_function_values_are_inlined_sqlite() {
+        use sqlx::Arguments;
+
+        let params = QueryParamsBuilder::<TestProduct>::new()
+            .with_filter_operator("deleted_at", QueryFilterOperator::GreaterOrEqual, "NOW()")
+            .with_filter_operator("status", QueryFilterOperator::Equal, "CURRENT_DATE")
+            .with_filter_operator("category", QueryFilterOperator::Equal, "current_date")
+            .build();
+
+        let (conditions, args) = QueryBuilder::<TestProduct, Sqlite>::new()
+            .with_filters(&params)
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "\"category\" = ?".to_string(),
+                "\"deleted_at\" >= CURRENT_TIMESTAMP".to_string(),
+                "\"status\" = ?".to_string()
+            ]
+        );
+        assert_eq!(args.len(), 2);
+    }
+
     #[test]
     fn test_in_operator_sqlite() {
         let params = QueryParamsBuilder::<TestProduct>::new()
@@ -303,6 +976,32 @@ mod sqlite_tests {
         assert!(conditions[0].contains("\"category\" IN"));
         assert!(conditions[0].contains("?"));
     }
+
+    #[test]
+    fn test_not_exists_uses_sqlite_placeholders() {
+        let (conditions, _) = QueryBuilder::<TestProduct, Sqlite>::new()
+            .with_not_exists(
+                "reviews",
+                ("product_id", "id"),
+                vec![("rating", QueryFilterCondition::less_than("2"))],
+            )
+            .with_exists_condition(
+                "SELECT 1 FROM tags t WHERE t.product_id = base_query.id AND t.name = ?",
+                vec!["sale".to_string()],
+            )
+            .build();
+
+        assert_eq!(
+            conditions,
+            vec![
+                "NOT EXISTS (SELECT 1 FROM \"reviews\" WHERE \"reviews\".\"product_id\" = \"base_query\".\"id\" \
+                 AND \"reviews\".\"rating\" < ?)"
+                    .to_string(),
+                "EXISTS (SELECT 1 FROM tags t WHERE t.product_id = base_query.id AND t.name = ?2)"
+                    .to_string(),
+            ]
+        );
+    }
 }
 
 #[test]